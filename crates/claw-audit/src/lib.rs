@@ -50,6 +50,7 @@ pub enum AuditAction {
     ProvisionStandby,
     TeardownInstance,
     ResizeInstance,
+    HibernateInstance,
     InitiateAutoHeal,
     DockerRestartOpenclaw,
     TriggerFailover,
@@ -164,6 +165,67 @@ impl AuditLogger {
         correlation_id: Option<Uuid>,
         operator_confirmation: Option<String>,
     ) -> String {
+        let (_, record_hash) = self.push(
+            agent,
+            action,
+            target_type,
+            target_id,
+            parameters,
+            result,
+            correlation_id,
+            operator_confirmation,
+        );
+        self.snapshot();
+        record_hash
+    }
+
+    /// Like [`append`](Self::append), but fails if the record cannot be
+    /// written to disk, in which case it is not kept. Use this for the entry
+    /// a provider delete depends on.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_append(
+        &mut self,
+        agent: AgentId,
+        action: AuditAction,
+        target_type: TargetType,
+        target_id: &str,
+        parameters: serde_json::Value,
+        result: AuditResult,
+        correlation_id: Option<Uuid>,
+        operator_confirmation: Option<String>,
+    ) -> std::io::Result<String> {
+        let previous_hash = self.last_hash.clone();
+        let (record_id, record_hash) = self.push(
+            agent,
+            action,
+            target_type,
+            target_id,
+            parameters,
+            result,
+            correlation_id,
+            operator_confirmation,
+        );
+        if let Err(e) = self.flush() {
+            self.records.remove(&record_id);
+            self.last_hash = previous_hash;
+            return Err(e);
+        }
+        Ok(record_hash)
+    }
+
+    /// Chain a record in memory. Returns its id and hash.
+    #[allow(clippy::too_many_arguments)]
+    fn push(
+        &mut self,
+        agent: AgentId,
+        action: AuditAction,
+        target_type: TargetType,
+        target_id: &str,
+        parameters: serde_json::Value,
+        result: AuditResult,
+        correlation_id: Option<Uuid>,
+        operator_confirmation: Option<String>,
+    ) -> (String, String) {
         let record_id = Uuid::new_v4();
         let timestamp = Utc::now();
 
@@ -207,9 +269,8 @@ impl AuditLogger {
 
         self.last_hash = record_hash.clone();
         self.records.insert(record_id.to_string(), record);
-        self.snapshot();
 
-        record_hash
+        (record_id.to_string(), record_hash)
     }
 
     /// Query audit records with filters.
@@ -272,13 +333,24 @@ impl AuditLogger {
         true
     }
 
+    /// Write the current state to disk. Also runs on drop.
+    pub fn flush(&self) -> std::io::Result<()> {
+        self.store.save(&self.records)
+    }
+
     fn snapshot(&self) {
-        if let Err(e) = self.store.save(&self.records) {
+        if let Err(e) = self.flush() {
             warn!(error = %e, "failed to snapshot audit chain");
         }
     }
 }
 
+impl Drop for AuditLogger {
+    fn drop(&mut self) {
+        self.snapshot();
+    }
+}
+
 fn sha256_hex(input: &str) -> String {
     let mut hasher = Sha256::new();
     hasher.update(input.as_bytes());
@@ -360,4 +432,26 @@ mod tests {
         // Last hash should match
         assert_eq!(logger2.last_hash, hash1);
     }
+
+    #[test]
+    fn test_try_append_fails_when_the_store_cannot_be_written() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        // A file where the state directory should be makes every save fail.
+        std::fs::write(dir.path().join("state"), b"").unwrap();
+        let mut logger = AuditLogger::new(dir.path());
+
+        let err = logger.try_append(
+            AgentId::Commander,
+            AuditAction::TeardownInstance,
+            TargetType::Instance,
+            "i-old",
+            serde_json::json!({}),
+            ok_result("about to tear down"),
+            None,
+            None,
+        );
+        assert!(err.is_err());
+        assert!(logger.records.is_empty());
+        assert!(logger.last_hash.is_empty());
+    }
 }
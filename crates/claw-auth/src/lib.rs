@@ -9,6 +9,7 @@
 #![forbid(unsafe_code)]
 
 use claw_persist::JsonStore;
+use claw_proto::ClawError;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
@@ -33,6 +34,20 @@ pub enum AuthError {
     InvalidInput(String),
 }
 
+impl From<AuthError> for ClawError {
+    fn from(err: AuthError) -> Self {
+        let message = err.to_string();
+        match err {
+            AuthError::KeyNotFound(_) => ClawError::NotFound(message),
+            AuthError::TokenExpired(_) => ClawError::Unauthorized(message),
+            AuthError::RateLimitExceeded(_) => ClawError::Transient(message),
+            AuthError::KeyAlreadyExists(_) | AuthError::InvalidInput(_) => {
+                ClawError::Permanent(message)
+            }
+        }
+    }
+}
+
 // ─────────────────────────────────────────────────────────────
 // API Key Store
 // ─────────────────────────────────────────────────────────────
@@ -177,13 +192,24 @@ impl ApiKeyStore {
         }
     }
 
+    /// Write the current state to disk. Also runs on drop.
+    pub fn flush(&self) -> std::io::Result<()> {
+        self.store.save(&self.keys)
+    }
+
     fn snapshot(&self) {
-        if let Err(e) = self.store.save(&self.keys) {
+        if let Err(e) = self.flush() {
             warn!(error = %e, "failed to snapshot API key store");
         }
     }
 }
 
+impl Drop for ApiKeyStore {
+    fn drop(&mut self) {
+        self.snapshot();
+    }
+}
+
 // ─────────────────────────────────────────────────────────────
 // Audit Log Store
 // ─────────────────────────────────────────────────────────────
@@ -236,18 +262,54 @@ impl AuditLogStore {
         actor: Option<&str>,
         action: Option<&str>,
         limit: usize,
+    ) -> Vec<&AuditLogEntry> {
+        self.query_resource(actor, action, None, limit)
+    }
+
+    /// Query entries filtered by actor, action and/or resource ID, newest-first.
+    pub fn query_resource(
+        &self,
+        actor: Option<&str>,
+        action: Option<&str>,
+        resource_id: Option<&str>,
+        limit: usize,
+    ) -> Vec<&AuditLogEntry> {
+        self.query_since(actor, action, resource_id, None, limit)
+    }
+
+    /// `query_resource`, keeping only entries at or after `since` if given.
+    pub fn query_since(
+        &self,
+        actor: Option<&str>,
+        action: Option<&str>,
+        resource_id: Option<&str>,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        limit: usize,
     ) -> Vec<&AuditLogEntry> {
         let mut results: Vec<_> = self
             .entries
             .values()
             .filter(|e| actor.is_none_or(|a| e.actor == a))
             .filter(|e| action.is_none_or(|a| e.action == a))
+            .filter(|e| resource_id.is_none_or(|r| e.resource_id.as_deref() == Some(r)))
+            .filter(|e| since.is_none_or(|s| e.timestamp >= s))
             .collect();
         results.sort_by_key(|e| std::cmp::Reverse(e.timestamp));
         results.truncate(limit);
         results
     }
 
+    /// Every entry recorded against `resource_id`, oldest first.
+    pub fn history_for_instance(&self, resource_id: &str) -> Vec<&AuditLogEntry> {
+        let mut history: Vec<_> = self
+            .entries
+            .values()
+            .filter(|e| e.resource_id.as_deref() == Some(resource_id))
+            .collect();
+        history.sort_by_key(|e| e.timestamp);
+        history
+    }
+
     /// Returns `true` if the log contains at least one entry for the given
     /// `action` on `resource_id`. Used by safety guards to require prior audit.
     pub fn has_entry_for(&self, action: &str, resource_id: &str) -> bool {
@@ -258,13 +320,24 @@ impl AuditLogStore {
         })
     }
 
+    /// Write the current state to disk. Also runs on drop.
+    pub fn flush(&self) -> std::io::Result<()> {
+        self.store.save(&self.entries)
+    }
+
     fn snapshot(&self) {
-        if let Err(e) = self.store.save(&self.entries) {
+        if let Err(e) = self.flush() {
             warn!(error = %e, "failed to snapshot audit log");
         }
     }
 }
 
+impl Drop for AuditLogStore {
+    fn drop(&mut self) {
+        self.snapshot();
+    }
+}
+
 // ─────────────────────────────────────────────────────────────
 // Input Sanitizer
 // ─────────────────────────────────────────────────────────────
@@ -393,6 +466,8 @@ pub struct RateLimiter {
     windows: HashMap<String, Vec<chrono::DateTime<chrono::Utc>>>,
     /// Maximum allowed calls per 60-second window.
     limit: u32,
+    /// Per-key limits that replace `limit` for that key.
+    overrides: HashMap<String, u32>,
 }
 
 impl RateLimiter {
@@ -401,25 +476,47 @@ impl RateLimiter {
         Self {
             windows: HashMap::new(),
             limit,
+            overrides: HashMap::new(),
         }
     }
 
+    /// Give `key` its own per-minute limit instead of the default.
+    pub fn with_limit_for(mut self, key: &str, limit: u32) -> Self {
+        self.overrides.insert(key.to_string(), limit);
+        self
+    }
+
+    /// The per-minute limit that applies to `key`.
+    pub fn limit_for(&self, key: &str) -> u32 {
+        self.overrides.get(key).copied().unwrap_or(self.limit)
+    }
+
     /// Record a call for `key`. Returns `Ok(remaining)` or `Err(RateLimitExceeded)`.
     pub fn record_call(&mut self, key: &str) -> Result<u32, AuthError> {
-        let now = chrono::Utc::now();
+        self.record_call_at(key, chrono::Utc::now())
+    }
+
+    /// Like [`record_call`](Self::record_call), at `now`. Rejected calls are
+    /// not recorded, so a throttled caller is admitted again as soon as its
+    /// earlier calls leave the window.
+    pub fn record_call_at(
+        &mut self,
+        key: &str,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<u32, AuthError> {
         let window_start = now - chrono::Duration::seconds(60);
+        let limit = self.limit_for(key);
 
         let calls = self.windows.entry(key.to_string()).or_default();
         // Evict calls outside the window
         calls.retain(|t| *t > window_start);
-        calls.push(now);
 
         let count = calls.len() as u32;
-        if count > self.limit {
-            Err(AuthError::RateLimitExceeded(self.limit))
-        } else {
-            Ok(self.limit - count)
+        if count >= limit {
+            return Err(AuthError::RateLimitExceeded(limit));
         }
+        calls.push(now);
+        Ok(limit - count - 1)
     }
 
     /// Check without recording — returns remaining capacity.
@@ -431,7 +528,7 @@ impl RateLimiter {
             .get(key)
             .map(|calls| calls.iter().filter(|t| **t > window_start).count() as u32)
             .unwrap_or(0);
-        self.limit.saturating_sub(count)
+        self.limit_for(key).saturating_sub(count)
     }
 }
 
@@ -469,6 +566,33 @@ mod tests {
         assert!(store.find_by_hash("abc123hash").is_none());
     }
 
+    #[test]
+    fn test_api_key_store_flushes_on_drop() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        {
+            let mut store = ApiKeyStore::new(dir.path());
+            store
+                .create(ApiKeyRecord {
+                    key_id: "k-1".to_string(),
+                    name: "test-key".to_string(),
+                    secret_hash: "abc123hash".to_string(),
+                    scopes: vec![],
+                    role: "operator".to_string(),
+                    active: true,
+                    created_at: chrono::Utc::now(),
+                    last_used: None,
+                    expires_at: None,
+                    rotates_key_id: None,
+                })
+                .expect("create");
+            // get_mut edits are not snapshotted on their own.
+            store.get_mut("k-1").expect("get_mut").name = "renamed".to_string();
+        }
+
+        let reopened = ApiKeyStore::new(dir.path());
+        assert_eq!(reopened.get("k-1").expect("persisted").name, "renamed");
+    }
+
     #[test]
     fn test_key_expiry() {
         let dir = tempfile::tempdir().expect("tempdir");
@@ -654,6 +778,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_rate_limiter_rejected_calls_do_not_extend_the_window() {
+        let mut rl = RateLimiter::new(2);
+        let t0 = chrono::Utc::now();
+        rl.record_call_at("forge", t0).unwrap();
+        assert_eq!(rl.record_call_at("forge", t0).unwrap(), 0);
+
+        // Keep hammering while throttled.
+        for secs in [10, 30, 59] {
+            assert!(
+                rl.record_call_at("forge", t0 + chrono::Duration::seconds(secs))
+                    .is_err()
+            );
+        }
+
+        // The two admitted calls have aged out; the rejected ones never counted.
+        assert_eq!(
+            rl.record_call_at("forge", t0 + chrono::Duration::seconds(61))
+                .unwrap(),
+            1
+        );
+    }
+
     #[test]
     fn test_rate_limiter_separate_keys() {
         let mut rl = RateLimiter::new(2);
@@ -663,4 +810,41 @@ mod tests {
         assert!(rl.record_call("vultr").is_ok());
         assert!(rl.record_call("hetzner").is_err());
     }
+
+    #[test]
+    fn test_rate_limiter_per_key_override() {
+        let mut rl = RateLimiter::new(1).with_limit_for("forge", 3);
+        assert_eq!(rl.limit_for("forge"), 3);
+        assert_eq!(rl.limit_for("system"), 1);
+        for _ in 0..3 {
+            rl.record_call("forge").unwrap();
+        }
+        assert!(matches!(
+            rl.record_call("forge"),
+            Err(AuthError::RateLimitExceeded(3))
+        ));
+        rl.record_call("system").unwrap();
+        assert!(matches!(
+            rl.record_call("system"),
+            Err(AuthError::RateLimitExceeded(1))
+        ));
+    }
+
+    #[test]
+    fn test_auth_error_maps_to_claw_error() {
+        let rate_limited = ClawError::from(AuthError::RateLimitExceeded(10));
+        assert!(matches!(rate_limited, ClawError::Transient(_)));
+        assert!(rate_limited.is_retryable());
+        assert!(matches!(
+            ClawError::from(AuthError::TokenExpired(chrono::Utc::now())),
+            ClawError::Unauthorized(_)
+        ));
+        assert!(matches!(
+            ClawError::from(AuthError::KeyNotFound("k-1".to_string())),
+            ClawError::NotFound(_)
+        ));
+        let invalid = ClawError::from(AuthError::InvalidInput("bad".to_string()));
+        assert!(matches!(invalid, ClawError::Permanent(_)));
+        assert!(!invalid.is_retryable());
+    }
 }
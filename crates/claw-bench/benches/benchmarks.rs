@@ -13,6 +13,7 @@ use criterion::{Criterion, black_box, criterion_group, criterion_main};
 
 fn make_report(id: &str, cpu: f32, mem: f32) -> HealthReport {
     HealthReport {
+        report_version: claw_proto::HEALTH_REPORT_VERSION,
         instance_id: id.to_string(),
         account_id: "bench-acct".to_string(),
         provider: VpsProvider::Hetzner,
@@ -36,6 +37,8 @@ fn make_report(id: &str, cpu: f32, mem: f32) -> HealthReport {
         uptime_secs: 86400,
         bytes_sent_per_sec: 1024.0,
         bytes_recv_per_sec: 2048.0,
+        heartbeat_interval_secs: Some(30),
+        critical_services: None,
         reported_at: Utc::now(),
     }
 }
@@ -43,13 +46,19 @@ fn make_report(id: &str, cpu: f32, mem: f32) -> HealthReport {
 fn make_snapshot(id: &str, cost: f64) -> InstanceSnapshot {
     InstanceSnapshot {
         instance_id: id.to_string(),
+        account_id: format!("acct-{id}"),
         provider: "hetzner".to_string(),
+        role: InstanceRole::Primary,
+        state: InstanceState::Active,
         cpu_pct: 25.0,
         mem_pct: 40.0,
         disk_pct: 30.0,
         health_score: 95.0,
         monthly_cost_usd: cost,
         recorded_at: Utc::now(),
+        tier: None,
+        provider_instance_id: None,
+        region: None,
     }
 }
 
@@ -49,6 +49,9 @@ pub struct IncidentSummary {
     pub title: String,
     pub resolved: bool,
     pub affected_instances: u32,
+    /// PRD severity level ("P1" most severe … "P4"), when triage assigned one.
+    #[serde(default)]
+    pub severity: Option<String>,
 }
 
 // ─── Daily Briefing ───────────────────────────────────────────────────────────
@@ -62,6 +65,9 @@ pub struct FleetBriefing {
     pub incidents: Vec<IncidentSummary>,
     pub actions_taken: Vec<String>,
     pub recommendations: Vec<String>,
+    /// Most severe health alerts from the latest sweep, already formatted.
+    #[serde(default)]
+    pub top_alerts: Vec<String>,
 }
 
 /// Generate a daily briefing from current fleet state.
@@ -73,6 +79,17 @@ pub fn generate_daily_briefing(
     fleet: &FleetStatus,
     costs: &CostSummary,
     incidents: &[IncidentSummary],
+) -> FleetBriefing {
+    generate_daily_briefing_with_alerts(fleet, costs, incidents, &[])
+}
+
+/// Like `generate_daily_briefing`, including the health sweep's top alerts
+/// (see `FleetHealthSweepResult::top_alerts` in claw-health).
+pub fn generate_daily_briefing_with_alerts(
+    fleet: &FleetStatus,
+    costs: &CostSummary,
+    incidents: &[IncidentSummary],
+    top_alerts: &[String],
 ) -> FleetBriefing {
     let active_pairs = fleet.active_pairs;
     let degraded = fleet.degraded_instances;
@@ -122,6 +139,7 @@ pub fn generate_daily_briefing(
         incidents: incidents.to_vec(),
         actions_taken,
         recommendations,
+        top_alerts: top_alerts.to_vec(),
     }
 }
 
@@ -176,6 +194,10 @@ pub fn format_voice_script(briefing: &FleetBriefing) -> String {
         ));
     }
 
+    if let Some(alert) = briefing.top_alerts.first() {
+        lines.push(format!("Top alert — {alert}."));
+    }
+
     for rec in &briefing.recommendations {
         lines.push(rec.clone());
     }
@@ -183,6 +205,139 @@ pub fn format_voice_script(briefing: &FleetBriefing) -> String {
     lines.join(" ")
 }
 
+// ─── Briefing narrative ───────────────────────────────────────────────────────
+
+/// Cost deviation above projection (percent) that the narrative calls out as an overrun.
+pub const COST_OVERRUN_PCT: f64 = 5.0;
+
+/// Everything the 07:00 voice note covers, gathered from the overnight window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyBriefingData {
+    pub fleet: FleetStatus,
+    pub costs: CostSummary,
+    /// Incidents opened overnight, resolved or not.
+    pub incidents: Vec<IncidentSummary>,
+    pub auto_heals: u32,
+    pub failovers: u32,
+    pub sla_breaches: u32,
+    /// Recommended actions, most important first.
+    pub recommendations: Vec<String>,
+    /// Most severe health alerts from the latest sweep, already formatted
+    /// (see `FleetHealthSweepResult::top_alerts` in claw-health).
+    #[serde(default)]
+    pub top_alerts: Vec<String>,
+}
+
+impl DailyBriefingData {
+    /// A single spoken paragraph summarising the night, numbers rounded for speech.
+    pub fn narrative(&self) -> String {
+        let fleet = &self.fleet;
+        let mut parts = vec![format!(
+            "Fleet of {} instances, {} active pairs.",
+            fleet.total_instances, fleet.active_pairs
+        )];
+
+        if self.incidents.is_empty() {
+            parts.push("No incidents overnight.".to_string());
+        } else {
+            let open = self.incidents.iter().filter(|i| !i.resolved).count();
+            let mut line = format!(
+                "{} {} overnight",
+                self.incidents.len(),
+                plural(self.incidents.len(), "incident")
+            );
+            // "P1" sorts before "P4", so the minimum is the most severe.
+            if let Some(worst) = self
+                .incidents
+                .iter()
+                .filter_map(|i| i.severity.as_deref())
+                .min()
+            {
+                line.push_str(&format!(", worst {worst}"));
+            }
+            if open > 0 {
+                line.push_str(&format!(", {open} still open"));
+            }
+            line.push('.');
+            parts.push(line);
+        }
+
+        if self.auto_heals > 0 || self.failovers > 0 {
+            parts.push(format!(
+                "{} {} and {} {} executed.",
+                self.auto_heals,
+                plural(self.auto_heals as usize, "heal"),
+                self.failovers,
+                plural(self.failovers as usize, "failover")
+            ));
+        }
+
+        let variance = self.costs.variance_pct().round();
+        let deviation = if variance.abs() < 1.0 {
+            "on projection".to_string()
+        } else if variance > 0.0 {
+            format!("{variance:.0}% over projection")
+        } else {
+            format!("{:.0}% under projection", -variance)
+        };
+        parts.push(format!(
+            "Spend this week about {} against {} projected, {deviation}.",
+            speak_usd(self.costs.actual_usd),
+            speak_usd(self.costs.projected_usd)
+        ));
+
+        if self.sla_breaches > 0 {
+            parts.push(format!(
+                "{} SLA {}.",
+                self.sla_breaches,
+                if self.sla_breaches == 1 {
+                    "breach"
+                } else {
+                    "breaches"
+                }
+            ));
+        }
+
+        if let Some(alert) = self.top_alerts.first() {
+            parts.push(format!("Top alert: {alert}."));
+        }
+
+        let nominal = self.incidents.is_empty()
+            && self.top_alerts.is_empty()
+            && self.failovers == 0
+            && self.sla_breaches == 0
+            && fleet.degraded_instances == 0
+            && fleet.failed_instances == 0
+            && self.costs.variance_pct() <= COST_OVERRUN_PCT;
+        if nominal {
+            parts.push("All systems nominal.".to_string());
+        }
+
+        if let Some(action) = self.recommendations.first() {
+            parts.push(format!("Top action: {}.", action.trim_end_matches('.')));
+        }
+
+        parts.join(" ")
+    }
+}
+
+fn plural(n: usize, word: &str) -> String {
+    if n == 1 {
+        word.to_string()
+    } else {
+        format!("{word}s")
+    }
+}
+
+/// Dollars rounded for speech: whole dollars under $100, nearest $10 above.
+fn speak_usd(usd: f64) -> String {
+    if usd.abs() < 100.0 {
+        format!("${:.0}", usd)
+    } else {
+        format!("${:.0}", (usd / 10.0).round() * 10.0)
+    }
+}
+
 // ─── Weekly Report ────────────────────────────────────────────────────────────
 
 /// A snapshot of one day's fleet and cost data for weekly rollup.
@@ -351,6 +506,7 @@ mod tests {
             title: "Nuremberg outage".to_string(),
             resolved: true,
             affected_instances: 94,
+            severity: None,
         }];
 
         let briefing = generate_daily_briefing(&fleet, &costs, &incidents);
@@ -363,6 +519,22 @@ mod tests {
         assert!(!briefing.actions_taken.is_empty());
     }
 
+    #[test]
+    fn test_daily_briefing_carries_top_alerts() {
+        let fleet = make_fleet(100, 3, 0);
+        let costs = make_costs(500.0, 500.0, 0);
+        let alerts = vec![
+            "Critical: OpenClaw down on 2 of 203 instances (e.g. i-4)".to_string(),
+            "Warning: disk usage high on 3 of 203 instances (e.g. i-1)".to_string(),
+        ];
+        let briefing = generate_daily_briefing_with_alerts(&fleet, &costs, &[], &alerts);
+        assert_eq!(briefing.top_alerts, alerts);
+
+        let script = format_voice_script(&briefing);
+        assert!(script.contains("Top alert — Critical: OpenClaw down"));
+        assert!(!script.contains("disk usage"));
+    }
+
     #[test]
     fn test_generate_daily_briefing_healthy_fleet() {
         let fleet = make_fleet(100, 0, 0);
@@ -398,6 +570,7 @@ mod tests {
             title: "Vultr degraded".to_string(),
             resolved: false,
             affected_instances: 5,
+            severity: None,
         }];
         let briefing = generate_daily_briefing(&fleet, &costs, &incidents);
         let script = format_voice_script(&briefing);
@@ -497,4 +670,77 @@ mod tests {
         let back: FleetBriefing = serde_json::from_str(&json).expect("deserialize");
         assert_eq!(back.overnight_summary, briefing.overnight_summary);
     }
+
+    fn make_briefing_data(
+        incidents: Vec<IncidentSummary>,
+        costs: CostSummary,
+    ) -> DailyBriefingData {
+        DailyBriefingData {
+            fleet: make_fleet(420, 0, 0),
+            costs,
+            incidents,
+            auto_heals: 0,
+            failovers: 0,
+            sla_breaches: 0,
+            recommendations: vec![],
+            top_alerts: vec![],
+        }
+    }
+
+    #[test]
+    fn test_narrative_mentions_incidents_and_cost_overrun() {
+        let incidents = vec![
+            IncidentSummary {
+                id: "inc-1".to_string(),
+                title: "Nuremberg outage".to_string(),
+                resolved: true,
+                affected_instances: 94,
+                severity: Some("P1".to_string()),
+            },
+            IncidentSummary {
+                id: "inc-2".to_string(),
+                title: "Disk pressure".to_string(),
+                resolved: false,
+                affected_instances: 3,
+                severity: Some("P3".to_string()),
+            },
+        ];
+        let mut data = make_briefing_data(incidents, make_costs(1247.0, 1090.0, 0));
+        data.auto_heals = 4;
+        data.failovers = 1;
+        data.sla_breaches = 1;
+        data.recommendations = vec!["Move 12 accounts off Vultr ams".to_string()];
+
+        let text = data.narrative();
+        assert!(text.contains("Fleet of 840 instances, 420 active pairs."));
+        assert!(text.contains("2 incidents overnight, worst P1, 1 still open."));
+        assert!(text.contains("4 heals and 1 failover executed."));
+        assert!(text.contains("about $1250 against $1090 projected, 14% over projection."));
+        assert!(text.contains("1 SLA breach."));
+        assert!(text.ends_with("Top action: Move 12 accounts off Vultr ams."));
+        assert!(!text.contains("nominal"));
+    }
+
+    #[test]
+    fn test_narrative_clean_day_is_nominal() {
+        let data = make_briefing_data(vec![], make_costs(500.0, 502.0, 0));
+        let text = data.narrative();
+        assert!(text.contains("No incidents overnight."));
+        assert!(text.contains("on projection"));
+        assert!(text.contains("All systems nominal."));
+        assert!(!text.contains("SLA"));
+    }
+
+    #[test]
+    fn test_narrative_speaks_the_top_alert() {
+        let mut data = make_briefing_data(vec![], make_costs(500.0, 502.0, 0));
+        data.top_alerts = vec![
+            "Critical: OpenClaw down on 2 of 420 instances (e.g. i-4)".to_string(),
+            "Warning: disk usage high on 3 of 420 instances (e.g. i-1)".to_string(),
+        ];
+        let text = data.narrative();
+        assert!(text.contains("Top alert: Critical: OpenClaw down on 2 of 420 instances"));
+        assert!(!text.contains("disk usage"));
+        assert!(!text.contains("nominal"));
+    }
 }
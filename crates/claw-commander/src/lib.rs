@@ -12,10 +12,36 @@
 
 #![forbid(unsafe_code)]
 
+use chrono::{DateTime, Utc};
+use claw_audit::{AgentId, AuditAction, AuditLogger, TargetType};
+use claw_auth::{AuditLogEntry, AuditLogStore, AuthError, RateLimiter};
 use claw_briefer::{FleetBriefing, WeeklyReport};
-use claw_ledger::{Optimization, ProviderComparison, WasteReport};
-use claw_triage::IncidentReport;
+use claw_health::{
+    FailoverTrigger, FleetHealthSweepResult, HealthCheckResult, verify_standby_precondition,
+};
+use claw_ledger::{
+    CostProjection, CostTrajectory, IdleAccount, Optimization, OptimizationType,
+    ProviderComparison, WasteReport,
+};
+use claw_metrics::{
+    FleetMetrics, HEALTHY_INSTANCE_SCORE, InstanceSnapshot, ProvisionLatencyTracker,
+};
+use claw_proto::{
+    ClawError, InstancePairStatus, InstanceRole, InstanceTier, OsImage, ProvisionRequest,
+    ProvisionResult, VpsProvider,
+};
+use claw_provision::{
+    FALLBACK_MIN_HEALTH, FleetProvisioner, InstancePair, PairProvisionRequest, ProviderRegistry,
+    ProvisionGate, ProvisionObserver, RetryPolicy, Sleeper, TierSpec, TokioSleeper,
+    provider_from_name,
+};
+use claw_triage::{IncidentReport, TimelineEntry, TimelineEvent};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::{Arc, Mutex, PoisonError};
+
+pub use claw_proto::SpofKind;
 
 // ─── Operator Intent ──────────────────────────────────────────────────────────
 
@@ -23,13 +49,21 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum OperatorIntent {
-    /// Provision new VPS pairs.
+    /// Provision new VPS pairs, or lone primaries when `ha` is false.
     ProvisionRequest {
         count: u32,
         tier_hint: Option<String>,
+        /// Whether each account gets a standby. Trial and nano accounts don't.
+        #[serde(default = "default_ha")]
+        ha: bool,
     },
     /// Teardown instances.
     TeardownRequest { scope: TeardownScope },
+    /// Move instances to another tier ("upgrade i-123 to enterprise").
+    ResizeRequest {
+        scope: ResizeScope,
+        target_tier: Option<String>,
+    },
     /// Cost query or analysis.
     CostQuery { detail: CostQueryDetail },
     /// Fleet or instance health query.
@@ -45,10 +79,28 @@ pub enum OperatorIntent {
         operation: String,
         instance_count: u32,
     },
+    /// Audit history query ("who deleted acc-123").
+    AuditQuery {
+        actor: Option<String>,
+        action: Option<String>,
+        resource_id: Option<String>,
+        /// Only entries from this many hours back ("last week" is 168).
+        #[serde(default)]
+        within_hours: Option<u32>,
+    },
     /// Intent could not be determined.
     Unknown { raw_message: String },
 }
 
+/// An intent together with how strongly the message supported it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassifiedIntent {
+    pub intent: OperatorIntent,
+    /// 0.0–1.0; sums the specificity of matched keywords, split across competing intents.
+    pub confidence: f32,
+    pub matched_terms: Vec<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum TeardownScope {
@@ -58,6 +110,39 @@ pub enum TeardownScope {
     IdleAccounts,
     /// Operator-specified list.
     Custom { count: u32 },
+    /// A bare number that could be a count or an instance id ("teardown 42").
+    Ambiguous { number: u32 },
+}
+
+impl TeardownScope {
+    /// Whether the operator must say what they meant before anything is torn down.
+    pub fn requires_confirmation(&self) -> bool {
+        matches!(self, TeardownScope::Ambiguous { .. })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResizeScope {
+    /// Single instance by ID.
+    Instance { instance_id: String },
+    /// Every instance of one account.
+    Account { account_id: String },
+    /// A named pool ("the beta pool").
+    Pool { name: String },
+    /// No target named; Forge must ask which instances.
+    Unspecified,
+}
+
+impl std::fmt::Display for ResizeScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Instance { instance_id } => write!(f, "instance {instance_id}"),
+            Self::Account { account_id } => write!(f, "account {account_id}"),
+            Self::Pool { name } => write!(f, "pool {name}"),
+            Self::Unspecified => write!(f, "unspecified instances"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -135,6 +220,10 @@ pub enum SpecialistResult {
         report: Option<WeeklyReport>,
         summary: String,
     },
+    AuditResult {
+        entries: Vec<AuditLogEntry>,
+        summary: String,
+    },
     GenericResult {
         summary: String,
     },
@@ -155,8 +244,30 @@ pub struct SafetyRules {
     pub max_instances_direct_config_push: u32,
     /// Must write audit log entry before any provider delete.
     pub require_audit_before_delete: bool,
+    /// Max monthly cost (USD) of a torn-down instance before confirmation is required.
+    pub max_teardown_cost_without_confirm: f64,
+    /// Classifications scoring below this become `Unknown` so the Commander asks.
+    #[serde(default = "default_min_intent_confidence")]
+    pub min_intent_confidence: f32,
+    /// Healthy instances a region must keep for latency coverage; 0 disables the rule.
+    #[serde(default)]
+    pub min_instances_per_region: u32,
+    /// Most instances the fleet may hold; provisions past it are refused.
+    #[serde(default = "default_max_fleet_size")]
+    pub max_fleet_size: u32,
+}
+
+fn default_min_intent_confidence() -> f32 {
+    0.5
+}
+
+fn default_max_fleet_size() -> u32 {
+    DEFAULT_MAX_FLEET_SIZE
 }
 
+/// Fleet ceiling that stops a runaway provisioning loop.
+pub const DEFAULT_MAX_FLEET_SIZE: u32 = 10_000;
+
 impl Default for SafetyRules {
     fn default() -> Self {
         Self {
@@ -165,6 +276,10 @@ impl Default for SafetyRules {
             require_standby_before_teardown: true,
             max_instances_direct_config_push: 100,
             require_audit_before_delete: true,
+            max_teardown_cost_without_confirm: 50.0,
+            min_intent_confidence: default_min_intent_confidence(),
+            min_instances_per_region: 0,
+            max_fleet_size: default_max_fleet_size(),
         }
     }
 }
@@ -191,6 +306,17 @@ pub struct Action {
     pub standby_confirmed_active: bool,
     pub estimated_cost_change_pct: f64,
     pub has_audit_log_entry: bool,
+    /// Monthly cost (USD) of the instance(s) being torn down; 0 for other actions.
+    pub teardown_monthly_cost_usd: f64,
+    /// Healthy instances left in the target region once the teardown runs,
+    /// if known. Checked against `SafetyRules::min_instances_per_region`;
+    /// unknown counts as too few when that minimum is set.
+    #[serde(default)]
+    pub region_healthy_after_teardown: Option<u32>,
+    /// Expected downtime (seconds) of a `TierResize`; `Some(0)` for live
+    /// resizes. An unknown estimate counts as offline. `None` for other actions.
+    #[serde(default)]
+    pub estimated_downtime_secs: Option<u32>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -205,22 +331,181 @@ pub enum ActionType {
     CostAction,
 }
 
+// ─── Errors ───────────────────────────────────────────────────────────────────
+
+/// Why the Commander could not act on an operator message.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum CommanderError {
+    /// The message matched several intents; ask the operator which one they meant.
+    #[error("ambiguous request: matches {} intents", candidates.len())]
+    AmbiguousIntent { candidates: Vec<OperatorIntent> },
+    /// No specialist handles this intent.
+    #[error("no specialist available for {intent:?}")]
+    NoSpecialistAvailable { intent: OperatorIntent },
+    /// The action failed the PRD safety invariants.
+    #[error("safety check failed: {0:?}")]
+    SafetyViolation(SafetyResult),
+    /// The teardown scope does not name instances that can be listed.
+    #[error("cannot enumerate instances for teardown scope {scope:?}")]
+    UnresolvedTeardownScope { scope: TeardownScope },
+    /// The confirmation was issued for a different teardown proposal.
+    #[error("confirmation is for proposal {confirmed}, not {proposal}")]
+    ConfirmationMismatch {
+        proposal: uuid::Uuid,
+        confirmed: uuid::Uuid,
+    },
+    /// The proposal's contents no longer match what was confirmed.
+    #[error("teardown proposal {proposal} was changed after it was confirmed")]
+    ProposalAltered { proposal: uuid::Uuid },
+    /// The fleet changed after the proposal was prepared; prepare it again.
+    #[error("teardown proposal {proposal} is stale: the fleet changed since it was prepared")]
+    StaleProposal { proposal: uuid::Uuid },
+    /// The provision would take the fleet past `SafetyRules::max_fleet_size`.
+    #[error(
+        "fleet size cap: {current} instances running + {requested} requested exceeds the maximum of {max}"
+    )]
+    FleetSizeExceeded {
+        current: u32,
+        requested: u32,
+        max: u32,
+    },
+    /// The actor has provisioned too often in the last minute.
+    #[error("rate limited: {actor} may provision at most {limit} times per minute")]
+    RateLimited { actor: String, limit: u32 },
+}
+
+impl From<CommanderError> for ClawError {
+    fn from(err: CommanderError) -> Self {
+        let message = err.to_string();
+        match err {
+            CommanderError::RateLimited { .. } => ClawError::Transient(message),
+            CommanderError::SafetyViolation(_)
+            | CommanderError::ConfirmationMismatch { .. }
+            | CommanderError::ProposalAltered { .. }
+            | CommanderError::StaleProposal { .. }
+            | CommanderError::FleetSizeExceeded { .. } => ClawError::Safety(message),
+            CommanderError::NoSpecialistAvailable { .. } => ClawError::NotFound(message),
+            CommanderError::AmbiguousIntent { .. }
+            | CommanderError::UnresolvedTeardownScope { .. } => ClawError::Permanent(message),
+        }
+    }
+}
+
+/// Classify a failed specialist call into the shared [`ClawError`] taxonomy.
+///
+/// A [`CommanderError`] anywhere in the chain (for example a gate refusal)
+/// decides the category; otherwise the provisioning classifier applies.
+pub fn classify_error(err: &anyhow::Error) -> ClawError {
+    match err.chain().find_map(|e| e.downcast_ref::<CommanderError>()) {
+        Some(commander) => commander.clone().into(),
+        None => claw_provision::classify_error(err),
+    }
+}
+
 // ─── Commander Engine ─────────────────────────────────────────────────────────
 
+/// Provisions per minute allowed for an actor without its own limit.
+pub const DEFAULT_PROVISIONS_PER_MINUTE: u32 = 10;
+
+/// Provisions per minute allowed for Forge, which provisions in bulk.
+pub const FORGE_PROVISIONS_PER_MINUTE: u32 = 30;
+
 /// The orchestration brain of the ClawOps operator team.
 pub struct CommanderEngine {
     pub safety_rules: SafetyRules,
+    /// Per-actor provision rate, keyed by `provision_actor`. Shared with
+    /// the gates handed out by `provision_gate`.
+    provision_limiter: Arc<Mutex<RateLimiter>>,
+    /// Provision-to-healthy clock, started by the provisioner and stopped by
+    /// health checks.
+    provision_latency: Arc<Mutex<ProvisionLatencyTracker>>,
+    /// Backoff for retrying routed specialist actions.
+    specialist_retry: RetryPolicy,
+    sleeper: Arc<dyn Sleeper>,
 }
 
 impl CommanderEngine {
     pub fn new() -> Self {
+        Self::with_safety_rules(SafetyRules::default())
+    }
+
+    pub fn with_safety_rules(safety_rules: SafetyRules) -> Self {
         Self {
-            safety_rules: SafetyRules::default(),
+            safety_rules,
+            provision_limiter: Arc::new(Mutex::new(
+                RateLimiter::new(DEFAULT_PROVISIONS_PER_MINUTE)
+                    .with_limit_for("forge", FORGE_PROVISIONS_PER_MINUTE),
+            )),
+            provision_latency: Arc::new(Mutex::new(ProvisionLatencyTracker::new())),
+            specialist_retry: RetryPolicy::default(),
+            sleeper: Arc::new(TokioSleeper),
         }
     }
 
-    pub fn with_safety_rules(safety_rules: SafetyRules) -> Self {
-        Self { safety_rules }
+    /// Replace the backoff used by [`run_specialist`](Self::run_specialist).
+    pub fn with_specialist_retry(mut self, policy: RetryPolicy) -> Self {
+        self.specialist_retry = policy;
+        self
+    }
+
+    /// Replace the sleeper used between specialist retries (tests record
+    /// the delays instead of waiting).
+    pub fn with_sleeper(mut self, sleeper: Arc<dyn Sleeper>) -> Self {
+        self.sleeper = sleeper;
+        self
+    }
+
+    /// Replace the per-actor provision limits, keyed by actor name ("forge").
+    pub fn with_provision_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.provision_limiter = Arc::new(Mutex::new(limiter));
+        self
+    }
+
+    /// Count one provision against the actor behind `requested_by`.
+    ///
+    /// Returns the actor's remaining provisions this minute.
+    pub fn check_provision_rate(&self, requested_by: &str) -> Result<u32, CommanderError> {
+        record_provision(&self.provision_limiter, requested_by)
+    }
+
+    /// A gate enforcing this engine's provision rate and fleet size cap,
+    /// starting from the instances in `fleet`. Attach it with
+    /// `FleetProvisioner::with_gate` so every provision path is checked.
+    pub fn provision_gate(&self, fleet: &[InstanceSnapshot]) -> Arc<dyn ProvisionGate> {
+        Arc::new(CommanderGate {
+            limiter: self.provision_limiter.clone(),
+            max_fleet_size: self.safety_rules.max_fleet_size,
+            instances: Mutex::new(fleet_instance_count(fleet)),
+        })
+    }
+
+    /// An observer that starts the provision-to-healthy clock for every
+    /// instance provisioned. Attach it with `FleetProvisioner::with_observer`.
+    pub fn provision_observer(&self) -> Arc<dyn ProvisionObserver> {
+        Arc::new(LatencyObserver {
+            tracker: self.provision_latency.clone(),
+        })
+    }
+
+    /// Stop the provision-to-healthy clock for instances reporting healthy.
+    pub fn record_health_checks(&self, results: &[HealthCheckResult]) {
+        let mut tracker = self
+            .provision_latency
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        for result in results {
+            tracker.record_health(&result.instance_id, result.health_score, result.checked_at);
+        }
+    }
+
+    /// Fleet metrics for `snapshots`, with each provider's provision-to-healthy
+    /// percentiles.
+    pub fn fleet_metrics(&self, snapshots: &[InstanceSnapshot]) -> FleetMetrics {
+        let tracker = self
+            .provision_latency
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        FleetMetrics::compute(snapshots).with_time_to_healthy(&tracker)
     }
 
     /// Parse operator free-text into a classified OperatorIntent.
@@ -228,32 +513,110 @@ impl CommanderEngine {
     /// Uses keyword matching — in production this is backed by the LLM
     /// reading the clawops.md skill.
     pub fn parse_intent(&self, message: &str) -> OperatorIntent {
+        self.intent_candidates(message)
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| OperatorIntent::Unknown {
+                raw_message: message.to_string(),
+            })
+    }
+
+    /// Like `parse_intent`, with a confidence score and the keywords behind it.
+    ///
+    /// Results below `SafetyRules::min_intent_confidence` are returned as
+    /// `Unknown` so the Commander asks rather than guesses.
+    pub fn classify_intent(&self, message: &str) -> ClassifiedIntent {
+        let lower = message.to_lowercase();
+        let candidates = self.intent_candidates(message);
+        let unknown = || OperatorIntent::Unknown {
+            raw_message: message.to_string(),
+        };
+        let Some(intent) = candidates.first().cloned() else {
+            return ClassifiedIntent {
+                intent: unknown(),
+                confidence: 0.0,
+                matched_terms: Vec::new(),
+            };
+        };
+
+        let (matched_terms, weight): (Vec<String>, f32) = IntentKind::of(&intent)
+            .into_iter()
+            .flat_map(|kind| intent_terms_in(&lower, kind, None))
+            .fold((Vec::new(), 0.0), |(mut terms, sum), (term, w)| {
+                terms.push(term.trim().to_string());
+                (terms, sum + w)
+            });
+        // Competing intents split the confidence between them; a lone one
+        // is floored so a terse but unambiguous ask still goes through.
+        let confidence = match candidates.len() {
+            1 => weight.clamp(UNAMBIGUOUS_INTENT_CONFIDENCE, 1.0),
+            n => weight.min(1.0) / n as f32,
+        };
+
+        ClassifiedIntent {
+            intent: if confidence < self.safety_rules.min_intent_confidence {
+                unknown()
+            } else {
+                intent
+            },
+            confidence,
+            matched_terms,
+        }
+    }
+
+    /// Every intent the message plausibly matches, in `parse_intent` precedence order.
+    ///
+    /// The generic `FleetStatus` match is only returned when nothing more
+    /// specific matched. An audit match is always returned alone.
+    pub fn intent_candidates(&self, message: &str) -> Vec<OperatorIntent> {
         let lower = message.to_lowercase();
+        let mut candidates = Vec::new();
+
+        // Audit — checked first so "who deleted acc-123" is not a teardown.
+        // A bare "who …" is only an audit query when it asks who did something.
+        if triggers(&lower, IntentKind::Audit)
+            || lower.starts_with("who ") && names_audit_verb(&lower)
+        {
+            return vec![parse_audit_query(&lower)];
+        }
 
         // Provision
-        if lower.contains("provision") || lower.contains("create") && lower.contains("account") {
+        if triggers(&lower, IntentKind::Provision)
+            || lower.contains("create") && lower.contains("account")
+        {
             let count = extract_number(&lower).unwrap_or(1);
             let tier_hint = extract_tier(&lower);
-            return OperatorIntent::ProvisionRequest { count, tier_hint };
+            let ha = !wants_single_instance(&lower, tier_hint.as_deref());
+            candidates.push(OperatorIntent::ProvisionRequest {
+                count,
+                tier_hint,
+                ha,
+            });
         }
 
         // Teardown
-        if lower.contains("teardown") || lower.contains("tear down") || lower.contains("delete") {
-            let scope = if lower.contains("idle") {
-                TeardownScope::IdleAccounts
-            } else {
-                let count = extract_number(&lower).unwrap_or(1);
-                TeardownScope::Custom { count }
-            };
-            return OperatorIntent::TeardownRequest { scope };
+        let teardown = triggers(&lower, IntentKind::Teardown);
+        if teardown {
+            let scope = parse_teardown_scope(&lower);
+            candidates.push(OperatorIntent::TeardownRequest { scope });
+        }
+
+        // Resize — "upgrade"/"downgrade" only count with an explicit
+        // "to <tier>", and "downsize idle accounts" is a cost-optimization
+        // ask for Ledger
+        let target_tier = extract_target_tier(&lower);
+        let tier_change = mentions(&lower, "upgrade") || mentions(&lower, "downgrade");
+        let resize = triggers(&lower, IntentKind::Resize) || tier_change && target_tier.is_some();
+        let resize_idle = resize && lower.contains("idle");
+        if resize && !resize_idle {
+            candidates.push(OperatorIntent::ResizeRequest {
+                scope: parse_resize_scope(&lower),
+                target_tier,
+            });
         }
 
         // Cost
-        if lower.contains("cost")
-            || lower.contains("wast")
-            || lower.contains("spend")
-            || lower.contains("billing")
-        {
+        if triggers(&lower, IntentKind::Cost) || resize_idle {
             let detail = if lower.contains("wast") || lower.contains("idle") {
                 CostQueryDetail::Waste
             } else if lower.contains("project") || lower.contains("forecast") {
@@ -263,56 +626,81 @@ impl CommanderEngine {
             } else {
                 CostQueryDetail::General
             };
-            return OperatorIntent::CostQuery { detail };
+            candidates.push(OperatorIntent::CostQuery { detail });
         }
 
         // Config push
-        if lower.contains("config") || lower.contains("push") && lower.contains("model") {
+        if triggers(&lower, IntentKind::ConfigPush)
+            || lower.contains("push") && lower.contains("model")
+        {
             let instance_count_hint = extract_number(&lower);
-            return OperatorIntent::ConfigPush {
+            candidates.push(OperatorIntent::ConfigPush {
                 instance_count_hint,
-            };
+            });
         }
 
-        // Incident
-        if lower.contains("incident") || lower.contains("down") || lower.contains("outage") {
-            return OperatorIntent::IncidentQuery;
+        // Incident ("tear down" and "scale down" are not outages)
+        if triggers(&lower, IntentKind::Incident) || lower.contains("down") && !teardown && !resize
+        {
+            candidates.push(OperatorIntent::IncidentQuery);
         }
 
         // Health
-        if lower.contains("health") || lower.contains("status") || lower.contains("degraded") {
-            return OperatorIntent::HealthQuery {
+        if triggers(&lower, IntentKind::Health) {
+            candidates.push(OperatorIntent::HealthQuery {
                 scope: HealthScope::Fleet,
-            };
+            });
         }
 
         // Bulk
-        if lower.contains("all instance") || lower.contains("bulk") || lower.contains("restart all")
-        {
+        if triggers(&lower, IntentKind::Bulk) {
             let count = extract_number(&lower).unwrap_or(0);
-            return OperatorIntent::BulkOperation {
+            candidates.push(OperatorIntent::BulkOperation {
                 operation: extract_bulk_op(&lower),
                 instance_count: count,
-            };
+            });
         }
 
         // Fleet status
-        if lower.contains("fleet") || lower.contains("overview") || lower.contains("summary") {
-            return OperatorIntent::FleetStatus;
+        if candidates.is_empty() && triggers(&lower, IntentKind::FleetStatus) {
+            candidates.push(OperatorIntent::FleetStatus);
         }
 
-        OperatorIntent::Unknown {
-            raw_message: message.to_string(),
+        candidates
+    }
+
+    /// Parse and route a message, refusing to guess between competing intents.
+    pub fn route_message(&self, message: &str) -> Result<SpecialistAction, CommanderError> {
+        let mut candidates = self.intent_candidates(message);
+        match candidates.len() {
+            0 => Err(CommanderError::NoSpecialistAvailable {
+                intent: OperatorIntent::Unknown {
+                    raw_message: message.to_string(),
+                },
+            }),
+            1 => self.route_to_specialist(&candidates.remove(0)),
+            _ => Err(CommanderError::AmbiguousIntent { candidates }),
         }
     }
 
     /// Determine which specialist should handle the intent.
-    pub fn route_to_specialist(&self, intent: &OperatorIntent) -> SpecialistAction {
-        match intent {
-            OperatorIntent::ProvisionRequest { count, tier_hint } => SpecialistAction::SpawnForge {
+    ///
+    /// `Unknown` intents have no specialist and are returned as an error so
+    /// the caller can ask the operator to rephrase.
+    pub fn route_to_specialist(
+        &self,
+        intent: &OperatorIntent,
+    ) -> Result<SpecialistAction, CommanderError> {
+        let action = match intent {
+            OperatorIntent::ProvisionRequest {
+                count,
+                tier_hint,
+                ha,
+            } => SpecialistAction::SpawnForge {
                 task: format!(
-                    "Provision {} pairs (tier: {})",
+                    "Provision {} {} (tier: {})",
                     count,
+                    if *ha { "pairs" } else { "single instances" },
                     tier_hint.as_deref().unwrap_or("standard")
                 ),
             },
@@ -327,6 +715,20 @@ impl CommanderEngine {
                 TeardownScope::Custom { count } => SpecialistAction::SpawnForge {
                     task: format!("Teardown {} instances", count),
                 },
+                TeardownScope::Ambiguous { number } => SpecialistAction::HandleDirectly {
+                    task: format!(
+                        "Confirm teardown scope: instance {number} or {number} instances?"
+                    ),
+                },
+            },
+
+            OperatorIntent::ResizeRequest { scope, target_tier } => match target_tier {
+                Some(tier) => SpecialistAction::SpawnForge {
+                    task: format!("Resize {scope} to {tier}"),
+                },
+                None => SpecialistAction::HandleDirectly {
+                    task: format!("Confirm target tier for resizing {scope}"),
+                },
             },
 
             OperatorIntent::CostQuery { detail } => match detail {
@@ -364,10 +766,14 @@ impl CommanderEngine {
                 task: "Investigate and report current incident".to_string(),
             },
 
-            OperatorIntent::FleetStatus | OperatorIntent::Unknown { .. } => {
-                SpecialistAction::HandleDirectly {
-                    task: "Return fleet status overview".to_string(),
-                }
+            OperatorIntent::FleetStatus => SpecialistAction::HandleDirectly {
+                task: "Return fleet status overview".to_string(),
+            },
+
+            OperatorIntent::Unknown { .. } => {
+                return Err(CommanderError::NoSpecialistAvailable {
+                    intent: intent.clone(),
+                });
             }
 
             OperatorIntent::BulkOperation {
@@ -376,6 +782,93 @@ impl CommanderEngine {
             } => SpecialistAction::SendToGuardian {
                 task: format!("Bulk {} across {} instances", operation, instance_count),
             },
+
+            OperatorIntent::AuditQuery {
+                actor,
+                action,
+                resource_id,
+                within_hours,
+            } => SpecialistAction::HandleDirectly {
+                task: format!(
+                    "Query audit log (actor: {}, action: {}, resource: {}, window: {})",
+                    actor.as_deref().unwrap_or("any"),
+                    action.as_deref().unwrap_or("any"),
+                    resource_id.as_deref().unwrap_or("any"),
+                    within_hours.map_or("all time".to_string(), |h| format!("last {h}h"))
+                ),
+            },
+        };
+        Ok(action)
+    }
+
+    /// Answer an `AuditQuery` intent directly from the audit log.
+    ///
+    /// Returns `None` for any other intent.
+    pub fn answer_audit_query(
+        &self,
+        intent: &OperatorIntent,
+        store: &AuditLogStore,
+    ) -> Option<SpecialistResult> {
+        self.answer_audit_query_at(intent, store, Utc::now())
+    }
+
+    /// `answer_audit_query`, measuring the query's time window back from `now`.
+    pub fn answer_audit_query_at(
+        &self,
+        intent: &OperatorIntent,
+        store: &AuditLogStore,
+        now: DateTime<Utc>,
+    ) -> Option<SpecialistResult> {
+        let OperatorIntent::AuditQuery {
+            actor,
+            action,
+            resource_id,
+            within_hours,
+        } = intent
+        else {
+            return None;
+        };
+
+        let since = within_hours.map(|h| now - chrono::Duration::hours(i64::from(h)));
+        let entries: Vec<AuditLogEntry> = store
+            .query_since(
+                actor.as_deref(),
+                action.as_deref(),
+                resource_id.as_deref(),
+                since,
+                AUDIT_QUERY_LIMIT,
+            )
+            .into_iter()
+            .cloned()
+            .collect();
+
+        let summary = if entries.is_empty() {
+            "No matching audit entries.".to_string()
+        } else {
+            let lines: Vec<String> = entries
+                .iter()
+                .map(|e| {
+                    format!(
+                        "{} {} {} {} ({})",
+                        e.timestamp.format("%Y-%m-%d %H:%M"),
+                        e.actor,
+                        e.action,
+                        e.resource_id.as_deref().unwrap_or(&e.resource),
+                        e.result
+                    )
+                })
+                .collect();
+            format!("{} audit entries:\n{}", entries.len(), lines.join("\n"))
+        };
+
+        Some(SpecialistResult::AuditResult { entries, summary })
+    }
+
+    /// `safety_check` as a `Result`: anything but `Approved` is a `SafetyViolation`.
+    pub fn ensure_safe(&self, action: &Action) -> Result<(), CommanderError> {
+        match self.safety_check(action) {
+            SafetyResult::Approved => Ok(()),
+            other => Err(CommanderError::SafetyViolation(other)),
         }
     }
 
@@ -404,6 +897,37 @@ impl CommanderEngine {
             };
         }
 
+        // Hard block: teardown would leave a region below its minimum, or
+        // cannot say how many healthy instances the region keeps
+        let min_per_region = self.safety_rules.min_instances_per_region;
+        if action.action_type == ActionType::Teardown && min_per_region > 0 {
+            match action.region_healthy_after_teardown {
+                Some(remaining) if remaining < min_per_region => {
+                    return SafetyResult::Blocked {
+                        reason: format!(
+                            "SAFETY: Teardown would leave {remaining} healthy instance(s) in the region (minimum: {min_per_region})"
+                        ),
+                    };
+                }
+                None => {
+                    return SafetyResult::Blocked {
+                        reason: format!(
+                            "SAFETY: Cannot confirm the region keeps {min_per_region} healthy instance(s) — region or fleet unknown"
+                        ),
+                    };
+                }
+                Some(_) => {}
+            }
+        }
+
+        // Hard block: promoting a standby that is not ACTIVE leaves the
+        // account with no working gateway at all
+        if action.action_type == ActionType::Failover && !action.standby_confirmed_active {
+            return SafetyResult::Blocked {
+                reason: "SAFETY: Cannot fail over — standby is not confirmed ACTIVE".to_string(),
+            };
+        }
+
         // Hard block: config push to > 100 without rolling validation
         if action.action_type == ActionType::ConfigPush
             && action.affected_instance_count > self.safety_rules.max_instances_direct_config_push
@@ -427,6 +951,39 @@ impl CommanderEngine {
             };
         }
 
+        // Require confirmation: resize that takes (or may take) the instance offline
+        if action.action_type == ActionType::TierResize {
+            match action.estimated_downtime_secs {
+                Some(0) => {}
+                Some(secs) => {
+                    return SafetyResult::RequiresConfirmation {
+                        reason: format!(
+                            "Tier resize takes ~{secs}s of downtime — explicit confirmation required"
+                        ),
+                    };
+                }
+                None => {
+                    return SafetyResult::RequiresConfirmation {
+                        reason: "Tier resize has no downtime estimate — assuming offline, explicit confirmation required".to_string(),
+                    };
+                }
+            }
+        }
+
+        // Require confirmation: expensive teardown
+        if action.action_type == ActionType::Teardown
+            && action.teardown_monthly_cost_usd
+                > self.safety_rules.max_teardown_cost_without_confirm
+        {
+            return SafetyResult::RequiresConfirmation {
+                reason: format!(
+                    "Teardown removes ${:.2}/month of capacity — explicit confirmation required (threshold: ${:.2})",
+                    action.teardown_monthly_cost_usd,
+                    self.safety_rules.max_teardown_cost_without_confirm
+                ),
+            };
+        }
+
         // Require confirmation: cost spike
         if action.estimated_cost_change_pct > self.safety_rules.max_cost_spike_percent {
             return SafetyResult::RequiresConfirmation {
@@ -500,6 +1057,9 @@ impl CommanderEngine {
                 SpecialistResult::BriefingResult { summary, .. } => {
                     format!("[Briefer] {}", summary)
                 }
+                SpecialistResult::AuditResult { summary, .. } => {
+                    format!("[CMD] Audit: {}", summary)
+                }
                 SpecialistResult::GenericResult { summary } => {
                     format!("[CMD] {}", summary)
                 }
@@ -517,358 +1077,4816 @@ impl Default for CommanderEngine {
     }
 }
 
-// ─── Helpers ──────────────────────────────────────────────────────────────────
+// ─── Specialist retry ─────────────────────────────────────────────────────────
 
-fn extract_number(text: &str) -> Option<u32> {
-    let words: Vec<&str> = text.split_whitespace().collect();
-    for w in words {
-        if let Ok(n) = w.parse::<u32>() {
-            return Some(n);
+impl CommanderEngine {
+    /// Run a routed specialist action, retrying while the failure is
+    /// retryable in the shared taxonomy.
+    ///
+    /// Safety, not-found, unauthorized and permanent failures are returned
+    /// after the first attempt; transient ones are retried under the
+    /// specialist retry policy, backing off through the engine's sleeper.
+    pub async fn run_specialist<T, E, F, Fut>(&self, mut run: F) -> Result<T, ClawError>
+    where
+        E: Into<ClawError>,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match run().await {
+                Ok(value) => return Ok(value),
+                Err(err) => {
+                    let err = err.into();
+                    if !err.is_retryable() || !self.specialist_retry.should_retry(attempt) {
+                        return Err(err);
+                    }
+                    self.sleeper
+                        .sleep(self.specialist_retry.delay_for_attempt(attempt))
+                        .await;
+                    attempt += 1;
+                }
+            }
         }
     }
-    None
 }
 
-fn extract_tier(text: &str) -> Option<String> {
-    if text.contains("enterprise") {
-        Some("enterprise".to_string())
-    } else if text.contains("pro") {
-        Some("pro".to_string())
-    } else if text.contains("nano") {
-        Some("nano".to_string())
-    } else if text.contains("standard") {
-        Some("standard".to_string())
-    } else {
-        None
+// ─── Capability manifest ──────────────────────────────────────────────────────
+
+/// Everything the Commander can be asked to do, for the web console and the
+/// LLM skill to discover rather than hard-code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapabilityManifest {
+    pub version: String,
+    pub intents: Vec<IntentCapability>,
+    /// Command namespaces a clawnode accepts (e.g. `vps.`, `config.`).
+    pub command_prefixes: Vec<String>,
+}
+
+/// One operator intent and what it takes to use it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntentCapability {
+    /// Serialized `OperatorIntent` variant name.
+    pub intent: String,
+    pub description: String,
+    pub parameters: Vec<IntentParameter>,
+    /// Agent the intent is routed to; `None` if it is not routable.
+    pub specialist: Option<AgentId>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntentParameter {
+    pub name: String,
+    /// JSON type of the parameter (`integer`, `string`, `boolean`, `object`).
+    pub kind: String,
+    pub required: bool,
+}
+
+impl IntentCapability {
+    fn new(
+        intent: &str,
+        description: &str,
+        parameters: &[(&str, &str, bool)],
+        specialist: Option<AgentId>,
+    ) -> Self {
+        Self {
+            intent: intent.to_string(),
+            description: description.to_string(),
+            parameters: parameters
+                .iter()
+                .map(|(name, kind, required)| IntentParameter {
+                    name: name.to_string(),
+                    kind: kind.to_string(),
+                    required: *required,
+                })
+                .collect(),
+            specialist,
+        }
     }
 }
 
-fn extract_bulk_op(text: &str) -> String {
-    if text.contains("restart") {
-        "restart".to_string()
-    } else if text.contains("config") {
-        "config push".to_string()
-    } else if text.contains("health") {
-        "health check".to_string()
-    } else {
-        "operation".to_string()
+impl CommanderEngine {
+    /// Machine-readable list of supported intents and node command prefixes.
+    pub fn capabilities_manifest(&self) -> CapabilityManifest {
+        use IntentCapability as C;
+        let intents = vec![
+            C::new(
+                "provision_request",
+                "Provision new accounts, as HA pairs or single instances",
+                &[
+                    ("count", "integer", true),
+                    ("tier_hint", "string", false),
+                    ("ha", "boolean", false),
+                ],
+                Some(AgentId::Forge),
+            ),
+            C::new(
+                "teardown_request",
+                "Tear down one instance, a batch, or all idle accounts",
+                &[("scope", "object", true)],
+                Some(AgentId::Forge),
+            ),
+            C::new(
+                "resize_request",
+                "Move an instance, account, or pool to another tier",
+                &[("scope", "object", true), ("target_tier", "string", false)],
+                Some(AgentId::Forge),
+            ),
+            C::new(
+                "cost_query",
+                "Waste, projection, or provider comparison reports",
+                &[("detail", "string", true)],
+                Some(AgentId::Ledger),
+            ),
+            C::new(
+                "health_query",
+                "Health sweep for the fleet, an instance, a provider, or a region",
+                &[("scope", "object", true)],
+                Some(AgentId::Guardian),
+            ),
+            C::new(
+                "config_push",
+                "Push config to instances; rolling above the direct-push limit",
+                &[("instance_count_hint", "integer", false)],
+                Some(AgentId::Guardian),
+            ),
+            C::new(
+                "incident_query",
+                "Investigate and report the current incident",
+                &[],
+                Some(AgentId::Triage),
+            ),
+            C::new(
+                "fleet_status",
+                "Fleet-wide status overview",
+                &[],
+                Some(AgentId::Commander),
+            ),
+            C::new(
+                "bulk_operation",
+                "Run one operation across many instances",
+                &[
+                    ("operation", "string", true),
+                    ("instance_count", "integer", true),
+                ],
+                Some(AgentId::Guardian),
+            ),
+            C::new(
+                "audit_query",
+                "Search the audit trail by actor, action, or resource",
+                &[
+                    ("actor", "string", false),
+                    ("action", "string", false),
+                    ("resource_id", "string", false),
+                ],
+                Some(AgentId::Commander),
+            ),
+            C::new(
+                "unknown",
+                "Unclassified message; the operator is asked to rephrase",
+                &[("raw_message", "string", true)],
+                None,
+            ),
+        ];
+
+        CapabilityManifest {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            intents,
+            command_prefixes: claw_proto::NODE_COMMAND_PREFIXES
+                .iter()
+                .map(|p| p.to_string())
+                .collect(),
+        }
     }
 }
 
-// ─── Tests ────────────────────────────────────────────────────────────────────
+// ─── Provider ranking ─────────────────────────────────────────────────────────
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Weight given to live fleet health (claw-metrics) in the unified score.
+/// The remainder goes to the Ledger's cost/performance `overall_score`.
+pub const METRICS_SCORE_WEIGHT: f64 = 0.5;
 
-    fn eng() -> CommanderEngine {
-        CommanderEngine::new()
-    }
+/// Default gap (in score points, 0–100) above which the sources are flagged as disagreeing.
+pub const DEFAULT_DIVERGENCE_THRESHOLD: f64 = 20.0;
 
-    // ─── Intent parsing ─────────────────────────────────────────────────────
+/// One provider's position in a `UnifiedProviderRanking`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnifiedProviderEntry {
+    pub provider: String,
+    /// Share of the provider's instances that are healthy (0–100), if it hosts any.
+    pub metrics_score: Option<f64>,
+    /// Ledger `overall_score`, if the provider appears in the comparison.
+    pub ledger_score: Option<f64>,
+    pub unified_score: f64,
+    pub divergent: bool,
+}
 
-    #[test]
-    fn test_parse_provision_intent() {
-        let intent = eng().parse_intent("Provision 20 new standard-tier accounts");
-        assert!(
-            matches!(intent, OperatorIntent::ProvisionRequest { count: 20, .. }),
-            "got {:?}",
-            intent
-        );
+/// Single provider ranking reconciling fleet metrics with the Ledger comparison.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnifiedProviderRanking {
+    /// Highest unified score first.
+    pub entries: Vec<UnifiedProviderEntry>,
+    /// Human-readable notes for each provider where the sources disagree.
+    pub divergence_notes: Vec<String>,
+    pub generated_at: DateTime<Utc>,
+}
+
+impl UnifiedProviderRanking {
+    /// The top-ranked provider, if any.
+    pub fn top(&self) -> Option<&str> {
+        self.entries.first().map(|e| e.provider.as_str())
     }
 
-    #[test]
-    fn test_parse_provision_with_tier() {
-        let intent = eng().parse_intent("Provision 5 enterprise accounts for beta");
-        if let OperatorIntent::ProvisionRequest { tier_hint, .. } = intent {
-            assert_eq!(tier_hint, Some("enterprise".to_string()));
-        } else {
-            panic!("Expected ProvisionRequest");
-        }
+    pub fn has_divergence(&self) -> bool {
+        !self.divergence_notes.is_empty()
     }
+}
 
-    #[test]
-    fn test_parse_cost_waste_query() {
-        let intent = eng().parse_intent("What are we wasting money on this month?");
-        assert_eq!(
-            intent,
-            OperatorIntent::CostQuery {
-                detail: CostQueryDetail::Waste
-            }
-        );
+/// Blend live fleet health with the Ledger provider comparison.
+///
+/// `unified = METRICS_SCORE_WEIGHT × healthy_pct + (1 − METRICS_SCORE_WEIGHT) × overall_score`.
+/// A provider seen by only one source takes that source's score. Providers whose
+/// two scores differ by more than `divergence_threshold` are flagged.
+pub fn reconcile_provider_rankings(
+    metrics: &FleetMetrics,
+    comparison: &ProviderComparison,
+    divergence_threshold: f64,
+) -> UnifiedProviderRanking {
+    let mut providers: Vec<String> = metrics.by_provider.keys().cloned().collect();
+    for entry in &comparison.entries {
+        let name = entry.provider.to_string();
+        if !providers.contains(&name) {
+            providers.push(name);
+        }
     }
 
-    #[test]
-    fn test_parse_cost_general_query() {
-        let intent = eng().parse_intent("How much are we spending?");
-        assert_eq!(
-            intent,
-            OperatorIntent::CostQuery {
-                detail: CostQueryDetail::General
+    let mut divergence_notes = Vec::new();
+    let mut entries: Vec<UnifiedProviderEntry> = providers
+        .into_iter()
+        .map(|provider| {
+            // Metrics saved before healthy_pct was tracked fall back to the mean.
+            let metrics_score = metrics
+                .by_provider
+                .get(&provider)
+                .map(|m| m.healthy_pct.unwrap_or(m.avg_health_score));
+            let ledger_score = comparison
+                .entries
+                .iter()
+                .find(|e| e.provider.to_string() == provider)
+                .map(|e| e.overall_score);
+
+            let (unified_score, divergent) = match (metrics_score, ledger_score) {
+                (Some(m), Some(l)) => {
+                    let gap = (m - l).abs();
+                    if gap > divergence_threshold {
+                        divergence_notes.push(format!(
+                            "{provider}: live health {m:.0} vs ledger score {l:.0} ({gap:.0} points apart)"
+                        ));
+                    }
+                    (
+                        m * METRICS_SCORE_WEIGHT + l * (1.0 - METRICS_SCORE_WEIGHT),
+                        gap > divergence_threshold,
+                    )
+                }
+                (Some(m), None) => (m, false),
+                (None, Some(l)) => (l, false),
+                (None, None) => (0.0, false),
+            };
+
+            UnifiedProviderEntry {
+                provider,
+                metrics_score,
+                ledger_score,
+                unified_score,
+                divergent,
             }
-        );
-    }
+        })
+        .collect();
+
+    entries.sort_by(|a, b| {
+        b.unified_score
+            .total_cmp(&a.unified_score)
+            .then_with(|| a.provider.cmp(&b.provider))
+    });
+    divergence_notes.sort();
+
+    UnifiedProviderRanking {
+        entries,
+        divergence_notes,
+        generated_at: Utc::now(),
+    }
+}
+
+// ─── Fleet risk ───────────────────────────────────────────────────────────────
+
+/// Maximum risk points from unhealthy instances.
+const RISK_HEALTH_POINTS: f64 = 35.0;
+/// Risk points for an `Elevated` / `Anomaly` cost trajectory.
+const RISK_COST_ELEVATED_POINTS: f64 = 10.0;
+const RISK_COST_ANOMALY_POINTS: f64 = 20.0;
+/// Risk points per single point of failure, capped at `RISK_SPOF_MAX_POINTS`.
+const RISK_SPOF_POINTS: f64 = 5.0;
+const RISK_SPOF_MAX_POINTS: f64 = 25.0;
+/// Risk points per active incident, capped at `RISK_INCIDENT_MAX_POINTS`.
+const RISK_INCIDENT_POINTS: f64 = 10.0;
+const RISK_INCIDENT_MAX_POINTS: f64 = 20.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpofFinding {
+    pub pair_id: String,
+    pub account_id: String,
+    pub kind: SpofKind,
+    pub detail: String,
+}
+
+impl SpofFinding {
+    /// Single points of failure among `pairs`, worst kind first per pair.
+    pub fn detect(pairs: &[InstancePairStatus]) -> Vec<SpofFinding> {
+        pairs
+            .iter()
+            .filter_map(|pair| {
+                let primary = &pair.primary;
+                let (kind, detail) = match &pair.standby {
+                    None => (SpofKind::NoStandby, "no standby".to_string()),
+                    Some(s) if s.provider == primary.provider && s.region == primary.region => (
+                        SpofKind::SameRegion,
+                        format!("primary and standby both in {} {}", s.provider, s.region),
+                    ),
+                    Some(s) if s.provider == primary.provider => (
+                        SpofKind::SameProvider,
+                        format!("primary and standby both on {}", s.provider),
+                    ),
+                    Some(_) => return None,
+                };
+                Some(SpofFinding {
+                    pair_id: pair.pair_id.clone(),
+                    account_id: pair.account_id.clone(),
+                    kind,
+                    detail,
+                })
+            })
+            .collect()
+    }
+
+    /// Incident timeline entry recording this finding, so a post-mortem
+    /// counts it as a contributing factor.
+    pub fn timeline_entry(&self, at: DateTime<Utc>) -> TimelineEntry {
+        TimelineEntry {
+            timestamp: at,
+            actor: "commander".to_string(),
+            action: "spof detected".to_string(),
+            outcome: format!("{}: {}", self.account_id, self.detail),
+            event: Some(TimelineEvent::SinglePointOfFailure {
+                account_id: self.account_id.clone(),
+                kind: self.kind,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskLevel {
+    Low,
+    Elevated,
+    High,
+    Critical,
+}
+
+/// One input's share of the overall risk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskFactor {
+    pub name: String,
+    pub points: f64,
+    pub detail: String,
+}
+
+/// "How worried should I be", 0 (calm) to 100.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskScore {
+    pub score: u8,
+    pub level: RiskLevel,
+    /// Non-zero contributors, largest first.
+    pub factors: Vec<RiskFactor>,
+}
+
+pub struct FleetRiskScore;
+
+impl FleetRiskScore {
+    /// Blend fleet health, cost trajectory, SPOFs and open incidents into one score.
+    ///
+    /// Health contributes up to 35 points (degraded instances count half), cost
+    /// up to 20, SPOFs 5 each up to 25, and incidents 10 each up to 20.
+    pub fn compute(
+        summary: &FleetMetrics,
+        sweep: &FleetHealthSweepResult,
+        spof: &[SpofFinding],
+        cost: &CostProjection,
+        active_incidents: u32,
+    ) -> RiskScore {
+        let mut factors = Vec::new();
+
+        let total = sweep.total_instances.max(summary.total_instances);
+        if total > 0 {
+            let critical = sweep.critical.max(summary.failed_instances);
+            let degraded = sweep.degraded.max(summary.degraded_instances);
+            let unhealthy = (f64::from(critical) + f64::from(degraded) * 0.5) / f64::from(total);
+            factors.push(RiskFactor {
+                name: "health".to_string(),
+                points: RISK_HEALTH_POINTS * unhealthy.min(1.0),
+                detail: format!("{critical} critical and {degraded} degraded of {total} instances"),
+            });
+        }
+
+        let cost_points = match cost.trajectory {
+            CostTrajectory::Anomaly => RISK_COST_ANOMALY_POINTS,
+            CostTrajectory::Elevated => RISK_COST_ELEVATED_POINTS,
+            CostTrajectory::OnTrack | CostTrajectory::BelowBudget => 0.0,
+        };
+        factors.push(RiskFactor {
+            name: "cost".to_string(),
+            points: cost_points,
+            detail: format!(
+                "spend {:+.1}% vs projection ({:?})",
+                cost.variance_pct, cost.trajectory
+            ),
+        });
+
+        factors.push(RiskFactor {
+            name: "spof".to_string(),
+            points: (spof.len() as f64 * RISK_SPOF_POINTS).min(RISK_SPOF_MAX_POINTS),
+            detail: format!("{} single point(s) of failure", spof.len()),
+        });
+
+        factors.push(RiskFactor {
+            name: "incidents".to_string(),
+            points: (f64::from(active_incidents) * RISK_INCIDENT_POINTS)
+                .min(RISK_INCIDENT_MAX_POINTS),
+            detail: format!("{active_incidents} active incident(s)"),
+        });
+
+        factors.retain(|f| f.points > 0.0);
+        factors.sort_by(|a, b| b.points.total_cmp(&a.points).then(a.name.cmp(&b.name)));
+
+        let score = factors
+            .iter()
+            .map(|f| f.points)
+            .sum::<f64>()
+            .round()
+            .min(100.0) as u8;
+        let level = match score {
+            0..25 => RiskLevel::Low,
+            25..50 => RiskLevel::Elevated,
+            50..75 => RiskLevel::High,
+            _ => RiskLevel::Critical,
+        };
+        RiskScore {
+            score,
+            level,
+            factors,
+        }
+    }
+}
+
+// ─── Provision pre-authorization ──────────────────────────────────────────────
+
+/// Current spend and the ceiling a new batch is checked against.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ProvisionBudget {
+    pub current_monthly_usd: f64,
+    pub monthly_budget_usd: f64,
+}
+
+/// Distinct instances in a fleet snapshot; repeated snapshots of one
+/// instance count once.
+pub fn fleet_instance_count(fleet: &[InstanceSnapshot]) -> u32 {
+    fleet
+        .iter()
+        .map(|s| s.instance_id.as_str())
+        .collect::<HashSet<_>>()
+        .len() as u32
+}
+
+/// Monthly cost a provision batch would add.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchCostEstimate {
+    /// Digest of the batch and budget; an over-budget batch only proceeds
+    /// when the operator confirms with this id.
+    pub estimate_id: String,
+    pub instance_count: u32,
+    pub added_monthly_usd: f64,
+    /// Spend after the batch lands.
+    pub projected_monthly_usd: f64,
+}
+
+/// Hex digits of the batch digest kept in `BatchCostEstimate::estimate_id`.
+const ESTIMATE_ID_LEN: usize = 12;
+
+impl BatchCostEstimate {
+    /// Cost of a batch of pairs, which may mix tiers.
+    pub fn for_pairs(requests: &[PairProvisionRequest], budget: &ProvisionBudget) -> Self {
+        let added_monthly_usd: f64 = requests
+            .iter()
+            .map(|r| 2.0 * f64::from(TierSpec::monthly_cost(&r.tier)))
+            .sum();
+        let batch: Vec<_> = requests
+            .iter()
+            .map(|r| {
+                serde_json::json!({
+                    "account_id": r.account_id,
+                    "tier": r.tier,
+                    "primary": [r.primary_provider.to_string(), r.primary_region.clone()],
+                    "standby": [r.standby_provider.to_string(), r.standby_region.clone()],
+                })
+            })
+            .collect();
+        let contents = serde_json::json!({
+            "batch": batch,
+            "current_monthly_usd": budget.current_monthly_usd,
+            "monthly_budget_usd": budget.monthly_budget_usd,
+        });
+        let mut estimate_id = sha256_hex(contents.to_string().as_bytes());
+        estimate_id.truncate(ESTIMATE_ID_LEN);
+        Self {
+            estimate_id,
+            instance_count: requests.len() as u32 * 2,
+            added_monthly_usd,
+            projected_monthly_usd: budget.current_monthly_usd + added_monthly_usd,
+        }
+    }
+}
+
+/// Result of a pre-authorized batch: nothing was provisioned unless `authorization`
+/// is `Approved` or was confirmed.
+#[derive(Debug)]
+pub struct BatchProvisionOutcome {
+    pub estimate: BatchCostEstimate,
+    pub authorization: SafetyResult,
+    pub pairs: Vec<InstancePair>,
+    /// One entry per pair that failed to provision.
+    pub failures: Vec<PairFailure>,
+}
+
+/// A pair in a pre-authorized batch that failed, classified so the caller can
+/// tell a retryable failure from a safety stop.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PairFailure {
+    pub account_id: String,
+    pub error: ClawError,
+}
+
+impl CommanderEngine {
+    /// Refuse `requested` new instances if they would take the fleet past
+    /// `SafetyRules::max_fleet_size`.
+    pub fn check_fleet_size(&self, current: u32, requested: u32) -> Result<(), CommanderError> {
+        check_fleet_size(self.safety_rules.max_fleet_size, current, requested)
+    }
+
+    /// Check a provision batch against the safety rules and the budget before
+    /// any provider is called.
+    ///
+    /// Hard safety blocks (including the fleet size cap, counted from
+    /// `fleet`) win; otherwise a batch that would take spend past the budget
+    /// requires confirmation.
+    pub fn preauthorize_provision(
+        &self,
+        estimate: &BatchCostEstimate,
+        budget: &ProvisionBudget,
+        fleet: &[InstanceSnapshot],
+    ) -> SafetyResult {
+        if let Err(e) = self.check_fleet_size(fleet_instance_count(fleet), estimate.instance_count)
+        {
+            return SafetyResult::Blocked {
+                reason: format!("SAFETY: {e}"),
+            };
+        }
+        let action = Action {
+            action_type: if estimate.instance_count > 1 {
+                ActionType::BulkOperation
+            } else {
+                ActionType::Provision
+            },
+            affected_users: 0,
+            affected_instance_count: estimate.instance_count,
+            is_primary_teardown: false,
+            standby_confirmed_active: false,
+            estimated_cost_change_pct: if budget.current_monthly_usd > 0.0 {
+                estimate.added_monthly_usd / budget.current_monthly_usd * 100.0
+            } else {
+                0.0
+            },
+            has_audit_log_entry: false,
+            teardown_monthly_cost_usd: 0.0,
+            region_healthy_after_teardown: None,
+            estimated_downtime_secs: None,
+        };
+        let safety = self.safety_check(&action);
+        if matches!(safety, SafetyResult::Blocked { .. }) {
+            return safety;
+        }
+        if estimate.projected_monthly_usd > budget.monthly_budget_usd {
+            return SafetyResult::RequiresConfirmation {
+                reason: format!(
+                    "Batch adds ${:.0}/month; projected ${:.0}/month exceeds budget ${:.0}/month — confirm estimate {}",
+                    estimate.added_monthly_usd,
+                    estimate.projected_monthly_usd,
+                    budget.monthly_budget_usd,
+                    estimate.estimate_id
+                ),
+            };
+        }
+        safety
+    }
+
+    /// Pre-authorize `requests` as one batch and, if approved (or confirmed by
+    /// the operator), provision each pair in turn.
+    ///
+    /// A batch that needs confirmation only proceeds when `confirmation` is
+    /// the `estimate_id` of this exact batch, as shown to the operator.
+    pub async fn provision_pairs_preauthorized(
+        &self,
+        provisioner: &FleetProvisioner<'_>,
+        requests: Vec<PairProvisionRequest>,
+        budget: &ProvisionBudget,
+        fleet: &[InstanceSnapshot],
+        confirmation: Option<&str>,
+    ) -> BatchProvisionOutcome {
+        let provisioner = provisioner
+            .clone()
+            .with_gate(self.provision_gate(fleet))
+            .with_observer(self.provision_observer());
+        let estimate = BatchCostEstimate::for_pairs(&requests, budget);
+        let authorization = self.preauthorize_provision(&estimate, budget, fleet);
+        let mut outcome = BatchProvisionOutcome {
+            estimate,
+            authorization,
+            pairs: Vec::new(),
+            failures: Vec::new(),
+        };
+        let proceed = match &outcome.authorization {
+            SafetyResult::Approved => true,
+            SafetyResult::RequiresConfirmation { .. } => {
+                confirmation == Some(outcome.estimate.estimate_id.as_str())
+            }
+            SafetyResult::Blocked { .. } => false,
+        };
+        if !proceed {
+            return outcome;
+        }
+
+        for req in requests {
+            let account_id = req.account_id.clone();
+            let provisioned = self
+                .run_specialist(|| async {
+                    provisioner
+                        .provision_pair(req.clone())
+                        .await
+                        .map_err(|e| classify_error(&e))
+                })
+                .await;
+            match provisioned {
+                Ok(pair) => outcome.pairs.push(pair),
+                Err(error) => outcome.failures.push(PairFailure { account_id, error }),
+            }
+        }
+        outcome
+    }
+}
+
+fn check_fleet_size(max: u32, current: u32, requested: u32) -> Result<(), CommanderError> {
+    if current.saturating_add(requested) > max {
+        return Err(CommanderError::FleetSizeExceeded {
+            current,
+            requested,
+            max,
+        });
+    }
+    Ok(())
+}
+
+fn record_provision(
+    limiter: &Mutex<RateLimiter>,
+    requested_by: &str,
+) -> Result<u32, CommanderError> {
+    let actor = provision_actor(requested_by);
+    let mut limiter = limiter.lock().unwrap_or_else(PoisonError::into_inner);
+    limiter.record_call(&actor).map_err(|e| match e {
+        AuthError::RateLimitExceeded(limit) => CommanderError::RateLimited { actor, limit },
+        other => CommanderError::SafetyViolation(SafetyResult::Blocked {
+            reason: other.to_string(),
+        }),
+    })
+}
+
+/// The Commander's provision rate limit and fleet size cap, enforced by
+/// `FleetProvisioner` before each provision.
+struct CommanderGate {
+    limiter: Arc<Mutex<RateLimiter>>,
+    max_fleet_size: u32,
+    /// The fleet at creation plus every instance admitted since.
+    instances: Mutex<u32>,
+}
+
+impl ProvisionGate for CommanderGate {
+    fn admit(&self, requested_by: &str, instances: u32) -> anyhow::Result<()> {
+        let mut current = self
+            .instances
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        check_fleet_size(self.max_fleet_size, *current, instances)?;
+        record_provision(&self.limiter, requested_by)?;
+        *current += instances;
+        Ok(())
+    }
+}
+
+/// Starts the Commander's provision-to-healthy clock for each new instance.
+struct LatencyObserver {
+    tracker: Arc<Mutex<ProvisionLatencyTracker>>,
+}
+
+impl ProvisionObserver for LatencyObserver {
+    fn provisioned(&self, provider: &str, result: &ProvisionResult) {
+        if let Some(instance_id) = &result.instance_id {
+            self.tracker
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .record_provision(instance_id, provider, Utc::now());
+        }
+    }
+}
+
+// ─── Mass failover ────────────────────────────────────────────────────────────
+
+/// Most standby reprovisions issued at once during a provider outage.
+pub const MASS_FAILOVER_BATCH_SIZE: usize = 10;
+
+/// An account whose existing out-of-provider standby takes over immediately.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StandbyPromotion {
+    pub account_id: String,
+    pub failed_primary: String,
+    pub standby_instance_id: String,
+    pub standby_provider: String,
+}
+
+/// An account that needs a fresh standby on a healthy provider.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledReprovision {
+    pub account_id: String,
+    pub failed_primary: String,
+    pub target_provider: VpsProvider,
+}
+
+/// Coordinated re-homing of every account hit by a whole-provider outage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MassFailoverPlan {
+    pub failed_provider: VpsProvider,
+    pub trigger: FailoverTrigger,
+    /// Sorted by account id.
+    pub promotions: Vec<StandbyPromotion>,
+    /// At most `MASS_FAILOVER_BATCH_SIZE` per batch; run batches in order.
+    pub reprovision_batches: Vec<Vec<ScheduledReprovision>>,
+    /// Accounts with no healthy standby and no healthy provider to rebuild on.
+    pub unplaceable: Vec<String>,
+    pub planned_at: DateTime<Utc>,
+}
+
+impl MassFailoverPlan {
+    pub fn reprovision_count(&self) -> usize {
+        self.reprovision_batches.iter().map(Vec::len).sum()
+    }
+}
+
+/// Plan failover for every primary on `failed_provider` among `affected`.
+///
+/// `affected` holds the snapshots of the hit accounts, standbys included. A
+/// primary whose standby is ACTIVE, healthy and on another provider is
+/// promoted right away. The rest get a new standby, spread round-robin over
+/// unpaused providers at or above `FALLBACK_MIN_HEALTH` (healthiest first),
+/// so no single provider absorbs the whole outage.
+pub async fn plan_mass_failover(
+    failed_provider: VpsProvider,
+    affected: &[InstanceSnapshot],
+    registry: &ProviderRegistry,
+) -> MassFailoverPlan {
+    let on_failed = |s: &InstanceSnapshot| provider_from_name(&s.provider) == Some(failed_provider);
+    let mut primaries: Vec<&InstanceSnapshot> = affected
+        .iter()
+        .filter(|s| s.role == InstanceRole::Primary && on_failed(s))
+        .collect();
+    primaries.sort_by(|a, b| a.account_id.cmp(&b.account_id));
+
+    // Polling health also refreshes the registry's quota and incident pauses.
+    let mut targets: Vec<(u8, VpsProvider)> = registry
+        .all_health()
+        .await
+        .iter()
+        .filter_map(|status| status.health())
+        .filter(|h| {
+            h.provider != failed_provider
+                && h.health_score >= FALLBACK_MIN_HEALTH
+                && !registry.is_paused(&h.provider)
+        })
+        .map(|h| (h.health_score, h.provider))
+        .collect();
+    targets.sort_by_key(|(score, provider)| (std::cmp::Reverse(*score), provider.to_string()));
+
+    let mut promotions = Vec::new();
+    let mut reprovisions = Vec::new();
+    let mut unplaceable = Vec::new();
+    for primary in primaries {
+        let standby = affected.iter().find(|s| {
+            s.account_id == primary.account_id
+                && s.role == InstanceRole::Standby
+                && !on_failed(s)
+                && verify_standby_precondition(s.state)
+                && s.health_score >= HEALTHY_INSTANCE_SCORE
+        });
+        if let Some(standby) = standby {
+            promotions.push(StandbyPromotion {
+                account_id: primary.account_id.clone(),
+                failed_primary: primary.instance_id.clone(),
+                standby_instance_id: standby.instance_id.clone(),
+                standby_provider: standby.provider.clone(),
+            });
+        } else if targets.is_empty() {
+            unplaceable.push(primary.account_id.clone());
+        } else {
+            let (_, target_provider) = targets[reprovisions.len() % targets.len()];
+            reprovisions.push(ScheduledReprovision {
+                account_id: primary.account_id.clone(),
+                failed_primary: primary.instance_id.clone(),
+                target_provider,
+            });
+        }
+    }
+
+    MassFailoverPlan {
+        failed_provider,
+        trigger: FailoverTrigger::ProviderOutage,
+        promotions,
+        reprovision_batches: reprovisions
+            .chunks(MASS_FAILOVER_BATCH_SIZE)
+            .map(<[ScheduledReprovision]>::to_vec)
+            .collect(),
+        unplaceable,
+        planned_at: Utc::now(),
+    }
+}
+
+// ─── Two-phase teardown ───────────────────────────────────────────────────────
+
+/// One instance a `TeardownProposal` will delete.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProposedTeardown {
+    pub instance_id: String,
+    pub account_id: String,
+    pub provider: String,
+    pub provider_instance_id: Option<String>,
+    pub role: InstanceRole,
+    pub monthly_cost_usd: f64,
+}
+
+/// Exactly what a teardown will delete, shown to the operator before it runs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TeardownProposal {
+    pub proposal_id: uuid::Uuid,
+    pub scope: TeardownScope,
+    pub instances: Vec<ProposedTeardown>,
+    /// Sorted, de-duplicated.
+    pub accounts: Vec<String>,
+    pub total_monthly_recovery_usd: f64,
+    /// SHA-256 of the fleet membership the proposal was prepared against.
+    pub fleet_fingerprint: String,
+    pub prepared_at: DateTime<Utc>,
+}
+
+impl TeardownProposal {
+    /// Operator approval of this specific plan.
+    pub fn confirm(&self, confirmed_by: &str) -> ConfirmationToken {
+        ConfirmationToken {
+            proposal_id: self.proposal_id,
+            proposal_digest: self.digest(),
+            confirmed_by: confirmed_by.to_string(),
+        }
+    }
+
+    /// SHA-256 of everything the operator approves: the instances, scope,
+    /// recovery and the fleet the plan was prepared against.
+    pub fn digest(&self) -> String {
+        let contents = serde_json::json!({
+            "proposal_id": self.proposal_id,
+            "scope": self.scope,
+            "instances": self.instances,
+            "accounts": self.accounts,
+            "total_monthly_recovery_usd": self.total_monthly_recovery_usd,
+            "fleet_fingerprint": self.fleet_fingerprint,
+        });
+        sha256_hex(contents.to_string().as_bytes())
+    }
+
+    /// "Tear down 4 instances across 2 accounts, recovering $48/mo".
+    pub fn summary(&self) -> String {
+        format!(
+            "Tear down {} instance{} across {} account{}, recovering ${:.0}/mo",
+            self.instances.len(),
+            if self.instances.len() == 1 { "" } else { "s" },
+            self.accounts.len(),
+            if self.accounts.len() == 1 { "" } else { "s" },
+            self.total_monthly_recovery_usd
+        )
+    }
+}
+
+/// Proof the operator approved one `TeardownProposal`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfirmationToken {
+    pub proposal_id: uuid::Uuid,
+    /// `TeardownProposal::digest` of the plan as it was confirmed.
+    pub proposal_digest: String,
+    pub confirmed_by: String,
+}
+
+/// What `execute_teardown` did.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TeardownExecution {
+    pub torn_down: Vec<String>,
+    pub failures: Vec<String>,
+    pub recovered_monthly_usd: f64,
+}
+
+/// SHA-256 of fleet membership: who exists, where, in which role and state.
+///
+/// Usage metrics are left out so a proposal is not invalidated by a
+/// routine metrics refresh.
+fn fleet_fingerprint(fleet: &[InstanceSnapshot]) -> String {
+    let mut members: Vec<_> = fleet
+        .iter()
+        .map(|s| {
+            (
+                &s.instance_id,
+                &s.account_id,
+                &s.provider,
+                &s.provider_instance_id,
+                &s.region,
+                s.role.to_string(),
+                format!("{:?}", s.state),
+            )
+        })
+        .collect();
+    members.sort();
+    members.dedup();
+    sha256_hex(&serde_json::to_vec(&members).unwrap_or_default())
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// Healthy instances left in each region that loses an instance in `proposed`.
+fn region_healthy_after(
+    fleet: &[InstanceSnapshot],
+    proposed: &BTreeSet<&str>,
+) -> BTreeMap<String, u32> {
+    let affected: BTreeSet<&str> = fleet
+        .iter()
+        .filter(|s| proposed.contains(s.instance_id.as_str()))
+        .filter_map(|s| s.region.as_deref())
+        .collect();
+    affected
+        .into_iter()
+        .map(|region| {
+            (
+                region.to_string(),
+                healthy_in_region(fleet, region, proposed),
+            )
+        })
+        .collect()
+}
+
+/// Healthy instances in `region`, not counting those in `excluding`.
+fn healthy_in_region(fleet: &[InstanceSnapshot], region: &str, excluding: &BTreeSet<&str>) -> u32 {
+    let healthy: BTreeSet<&str> = fleet
+        .iter()
+        .filter(|s| s.region.as_deref() == Some(region))
+        .filter(|s| !excluding.contains(s.instance_id.as_str()))
+        .filter(|s| s.state == claw_proto::InstanceState::Active)
+        .filter(|s| s.health_score >= HEALTHY_INSTANCE_SCORE)
+        .map(|s| s.instance_id.as_str())
+        .collect();
+    healthy.len() as u32
+}
+
+impl CommanderEngine {
+    /// Phase one: enumerate exactly what `scope` would tear down.
+    ///
+    /// `Custom` and `Ambiguous` scopes name no instances and are rejected so
+    /// the operator is asked which ones they mean.
+    pub fn prepare_teardown(
+        &self,
+        scope: &TeardownScope,
+        fleet: &[InstanceSnapshot],
+        idle_accounts: &[IdleAccount],
+    ) -> Result<TeardownProposal, CommanderError> {
+        let selected: Vec<&InstanceSnapshot> = match scope {
+            TeardownScope::Single { instance_id } => fleet
+                .iter()
+                .filter(|s| {
+                    s.instance_id == *instance_id
+                        || s.provider_instance_id.as_deref() == Some(instance_id.as_str())
+                })
+                .collect(),
+            TeardownScope::IdleAccounts => {
+                let idle: BTreeSet<&str> = idle_accounts
+                    .iter()
+                    .map(|a| a.account_id.as_str())
+                    .collect();
+                fleet
+                    .iter()
+                    .filter(|s| idle.contains(s.account_id.as_str()))
+                    .collect()
+            }
+            TeardownScope::Custom { .. } | TeardownScope::Ambiguous { .. } => {
+                return Err(CommanderError::UnresolvedTeardownScope {
+                    scope: scope.clone(),
+                });
+            }
+        };
+
+        let mut instances: Vec<ProposedTeardown> = selected
+            .into_iter()
+            .map(|s| ProposedTeardown {
+                instance_id: s.instance_id.clone(),
+                account_id: s.account_id.clone(),
+                provider: s.provider.clone(),
+                provider_instance_id: s.provider_instance_id.clone(),
+                role: s.role,
+                monthly_cost_usd: s.monthly_cost_usd,
+            })
+            .collect();
+        instances.sort_by(|a, b| a.instance_id.cmp(&b.instance_id));
+        instances.dedup_by(|a, b| a.instance_id == b.instance_id);
+        let accounts: BTreeSet<String> = instances.iter().map(|i| i.account_id.clone()).collect();
+
+        Ok(TeardownProposal {
+            proposal_id: uuid::Uuid::new_v4(),
+            scope: scope.clone(),
+            total_monthly_recovery_usd: instances.iter().map(|i| i.monthly_cost_usd).sum(),
+            instances,
+            accounts: accounts.into_iter().collect(),
+            fleet_fingerprint: fleet_fingerprint(fleet),
+            prepared_at: Utc::now(),
+        })
+    }
+
+    /// Phase two: tear down exactly the instances in `proposal`.
+    ///
+    /// Rejected if `confirmation` is for another proposal, if `fleet` no
+    /// longer matches what the proposal was prepared against, or if the plan
+    /// would remove a primary whose standby is not confirmed active or leave
+    /// any region below `SafetyRules::min_instances_per_region`. Each
+    /// deletion is audited before the provider call.
+    pub async fn execute_teardown(
+        &self,
+        provisioner: &FleetProvisioner<'_>,
+        proposal: &TeardownProposal,
+        confirmation: &ConfirmationToken,
+        fleet: &[InstanceSnapshot],
+        audit: &mut AuditLogger,
+    ) -> Result<TeardownExecution, CommanderError> {
+        if confirmation.proposal_id != proposal.proposal_id {
+            return Err(CommanderError::ConfirmationMismatch {
+                proposal: proposal.proposal_id,
+                confirmed: confirmation.proposal_id,
+            });
+        }
+        if confirmation.proposal_digest != proposal.digest() {
+            return Err(CommanderError::ProposalAltered {
+                proposal: proposal.proposal_id,
+            });
+        }
+        if fleet_fingerprint(fleet) != proposal.fleet_fingerprint {
+            return Err(CommanderError::StaleProposal {
+                proposal: proposal.proposal_id,
+            });
+        }
+
+        // A primary is only "torn down alone" when its standby is not also going.
+        let proposed: BTreeSet<&str> = proposal
+            .instances
+            .iter()
+            .map(|i| i.instance_id.as_str())
+            .collect();
+        let lone_primaries: Vec<&ProposedTeardown> = proposal
+            .instances
+            .iter()
+            .filter(|i| i.role == InstanceRole::Primary)
+            .filter(|i| {
+                !fleet.iter().any(|s| {
+                    s.account_id == i.account_id
+                        && s.role == InstanceRole::Standby
+                        && proposed.contains(s.instance_id.as_str())
+                })
+            })
+            .collect();
+        let standbys_active = lone_primaries.iter().all(|p| {
+            fleet.iter().any(|s| {
+                s.account_id == p.account_id
+                    && s.role == InstanceRole::Standby
+                    && s.state == claw_proto::InstanceState::Active
+            })
+        });
+        // Known regions are checked one by one below; an instance whose
+        // region is unknown leaves the count unknown, which the rule refuses.
+        let regions_known = proposal.instances.iter().all(|i| {
+            fleet
+                .iter()
+                .any(|s| s.instance_id == i.instance_id && s.region.is_some())
+        });
+        let action = Action {
+            action_type: ActionType::Teardown,
+            affected_users: proposal.accounts.len() as u32,
+            affected_instance_count: proposal.instances.len() as u32,
+            is_primary_teardown: !lone_primaries.is_empty(),
+            standby_confirmed_active: standbys_active,
+            estimated_cost_change_pct: 0.0,
+            has_audit_log_entry: true,
+            teardown_monthly_cost_usd: proposal.total_monthly_recovery_usd,
+            region_healthy_after_teardown: regions_known
+                .then_some(self.safety_rules.min_instances_per_region),
+            estimated_downtime_secs: None,
+        };
+        if let blocked @ SafetyResult::Blocked { .. } = self.safety_check(&action) {
+            return Err(CommanderError::SafetyViolation(blocked));
+        }
+        for (region, remaining) in region_healthy_after(fleet, &proposed) {
+            let action = Action {
+                region_healthy_after_teardown: Some(remaining),
+                ..action.clone()
+            };
+            if let SafetyResult::Blocked { reason } = self.safety_check(&action) {
+                return Err(CommanderError::SafetyViolation(SafetyResult::Blocked {
+                    reason: format!("{reason} [{region}]"),
+                }));
+            }
+        }
+
+        let mut execution = TeardownExecution::default();
+        for instance in &proposal.instances {
+            let target = provider_from_name(&instance.provider)
+                .zip(instance.provider_instance_id.as_deref());
+            let Some((provider, provider_instance_id)) = target else {
+                execution.failures.push(format!(
+                    "{}: no provider instance id on {}",
+                    instance.instance_id, instance.provider
+                ));
+                continue;
+            };
+            let parameters = serde_json::json!({
+                "account_id": instance.account_id,
+                "provider": provider,
+                "provider_instance_id": provider_instance_id,
+                "proposal_id": proposal.proposal_id,
+                "proposal_digest": confirmation.proposal_digest,
+            });
+            audit.append(
+                AgentId::Commander,
+                AuditAction::OperatorConfirmationReceived,
+                TargetType::Instance,
+                &instance.instance_id,
+                parameters.clone(),
+                claw_audit::AuditResult {
+                    success: true,
+                    message: "teardown proposal confirmed; executing".to_string(),
+                    details: None,
+                },
+                Some(proposal.proposal_id),
+                Some(confirmation.confirmed_by.clone()),
+            );
+            let outcome = provisioner
+                .teardown_instance(
+                    provider,
+                    provider_instance_id,
+                    &instance.account_id,
+                    &confirmation.confirmed_by,
+                )
+                .await;
+            audit.append(
+                AgentId::Commander,
+                AuditAction::TeardownInstance,
+                TargetType::Instance,
+                &instance.instance_id,
+                parameters,
+                claw_audit::AuditResult {
+                    success: outcome.is_ok(),
+                    message: match &outcome {
+                        Ok(()) => "instance torn down".to_string(),
+                        Err(e) => format!("teardown failed: {e:#}"),
+                    },
+                    details: None,
+                },
+                Some(proposal.proposal_id),
+                Some(confirmation.confirmed_by.clone()),
+            );
+            match outcome {
+                Ok(()) => {
+                    execution.torn_down.push(instance.instance_id.clone());
+                    execution.recovered_monthly_usd += instance.monthly_cost_usd;
+                }
+                Err(e) => execution
+                    .failures
+                    .push(format!("{}: {e:#}", instance.instance_id)),
+            }
+        }
+        Ok(execution)
+    }
+}
+
+// ─── Optimization applier ─────────────────────────────────────────────────────
+
+/// What the applier needs to know about the instance an `Optimization` targets.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplyTarget {
+    pub provider: VpsProvider,
+    pub provider_instance_id: String,
+    pub role: InstanceRole,
+    pub tier: InstanceTier,
+    pub standby_confirmed_active: bool,
+    pub affected_users: u32,
+    pub monthly_cost_usd: f64,
+    /// Region to place the replacement in for `Migrate`.
+    pub migration_region: Option<String>,
+    /// Image the instance runs; a `Migrate` replacement boots the same one.
+    #[serde(default)]
+    pub os_image: OsImage,
+    /// Region the instance runs in.
+    #[serde(default)]
+    pub region: Option<String>,
+    /// The fleet as last observed, for counting the healthy instances a
+    /// teardown or migration leaves in `region`.
+    #[serde(default)]
+    pub fleet: Vec<InstanceSnapshot>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "outcome")]
+pub enum ApplyOutcome {
+    /// The provider operation ran successfully.
+    Applied { detail: String },
+    /// Safety check wants operator confirmation; nothing was executed.
+    RequiresConfirmation { reason: String },
+    /// Safety check refused; nothing was executed.
+    Blocked { reason: String },
+    /// Approved, but the provider operation failed.
+    Failed { error: String },
+}
+
+/// Result of applying one `Optimization`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplyResult {
+    pub instance_id: String,
+    pub optimization_type: OptimizationType,
+    pub outcome: ApplyOutcome,
+    pub monthly_cost_before_usd: f64,
+    /// Equal to the before cost unless the operation was applied.
+    pub monthly_cost_after_usd: f64,
+    pub applied_at: DateTime<Utc>,
+}
+
+impl ApplyResult {
+    pub fn is_applied(&self) -> bool {
+        matches!(self.outcome, ApplyOutcome::Applied { .. })
+    }
+}
+
+/// Dollar and downtime impact of a batch of tier changes, before execution.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TierChangePreview {
+    pub instance_count: u32,
+    pub monthly_savings_usd: f64,
+    pub estimated_downtime_secs: u32,
+    /// How many of the resizes the provider can do without a reboot.
+    pub live_resizes: u32,
+    /// Sorted, de-duplicated.
+    pub affected_accounts: Vec<String>,
+}
+
+impl TierChangePreview {
+    /// One line for the synthesized response, e.g.
+    /// "downsizing 18 instances saves $216/mo with ~6 min total downtime".
+    pub fn summary(&self) -> String {
+        let downtime = match self.estimated_downtime_secs {
+            0 => "no downtime".to_string(),
+            s if s < 60 => format!("~{s} s total downtime"),
+            s => format!("~{:.0} min total downtime", f64::from(s) / 60.0),
+        };
+        format!(
+            "downsizing {} instance{} saves ${:.0}/mo with {downtime}",
+            self.instance_count,
+            if self.instance_count == 1 { "" } else { "s" },
+            self.monthly_savings_usd
+        )
+    }
+}
+
+/// Turns Ledger `Optimization`s into provider operations, gated by the
+/// Commander safety check.
+///
+/// Every approved operation is written to the audit chain before it runs and
+/// again with its outcome, success or failure. Teardowns and migrations are
+/// blocked by `require_audit_before_delete` unless that first entry was
+/// persisted. With a history log, the outcome of each
+/// resize and teardown is also recorded there per instance.
+pub struct OptimizationApplier<'a> {
+    provisioner: &'a FleetProvisioner<'a>,
+    engine: CommanderEngine,
+    audit: Option<&'a mut AuditLogger>,
+    history: Option<&'a mut AuditLogStore>,
+}
+
+/// What `execute` did, for the result detail and the history log.
+struct Executed {
+    detail: String,
+    resize: Option<claw_provision::ResizeResult>,
+    migration: Option<claw_provision::MigrationResult>,
+}
+
+impl<'a> OptimizationApplier<'a> {
+    pub fn new(provisioner: &'a FleetProvisioner<'a>, safety_rules: &SafetyRules) -> Self {
+        Self {
+            provisioner,
+            engine: CommanderEngine::with_safety_rules(safety_rules.clone()),
+            audit: None,
+            history: None,
+        }
+    }
+
+    pub fn with_audit_logger(mut self, audit: &'a mut AuditLogger) -> Self {
+        self.audit = Some(audit);
+        self
+    }
+
+    /// Record every resize and teardown outcome in `history`.
+    pub fn with_history_log(mut self, history: &'a mut AuditLogStore) -> Self {
+        self.history = Some(history);
+        self
+    }
+
+    /// Preview the `Downsize` recommendations in `recommendations` without
+    /// executing anything. Other optimization types are ignored.
+    ///
+    /// `targets` maps instance id to its target; instances without one are
+    /// assumed to need an offline resize.
+    pub fn preview_tier_changes(
+        &self,
+        recommendations: &[Optimization],
+        targets: &HashMap<String, ApplyTarget>,
+    ) -> TierChangePreview {
+        let mut preview = TierChangePreview::default();
+        for opt in recommendations {
+            if !matches!(opt.optimization_type, OptimizationType::Downsize { .. }) {
+                continue;
+            }
+            let downtime = match targets.get(&opt.instance_id) {
+                Some(target) => self
+                    .provisioner
+                    .estimated_resize_downtime_secs(target.provider),
+                None => claw_provision::OFFLINE_RESIZE_DOWNTIME_SECS,
+            };
+            if downtime == claw_provision::LIVE_RESIZE_DOWNTIME_SECS {
+                preview.live_resizes += 1;
+            }
+            preview.instance_count += 1;
+            preview.monthly_savings_usd += opt.estimated_savings_monthly_usd;
+            preview.estimated_downtime_secs += downtime;
+            preview.affected_accounts.push(opt.account_id.clone());
+        }
+        preview.affected_accounts.sort();
+        preview.affected_accounts.dedup();
+        preview
+    }
+
+    /// Safety-check and, if approved, execute `optimization`.
+    pub async fn apply(
+        &mut self,
+        optimization: &Optimization,
+        target: &ApplyTarget,
+    ) -> ApplyResult {
+        self.apply_inner(optimization, target, None).await
+    }
+
+    /// Like [`apply`](Self::apply), but for an optimization the operator has
+    /// confirmed. Hard safety blocks still apply.
+    pub async fn apply_confirmed(
+        &mut self,
+        optimization: &Optimization,
+        target: &ApplyTarget,
+        confirmation: &str,
+    ) -> ApplyResult {
+        self.apply_inner(optimization, target, Some(confirmation))
+            .await
+    }
+
+    async fn apply_inner(
+        &mut self,
+        optimization: &Optimization,
+        target: &ApplyTarget,
+        confirmation: Option<&str>,
+    ) -> ApplyResult {
+        let before = target.monthly_cost_usd;
+        let expected_after = match optimization.optimization_type {
+            OptimizationType::Teardown { .. } => 0.0,
+            _ => (before - optimization.estimated_savings_monthly_usd).max(0.0),
+        };
+        let result = |outcome: ApplyOutcome| {
+            let after = if matches!(outcome, ApplyOutcome::Applied { .. }) {
+                expected_after
+            } else {
+                before
+            };
+            ApplyResult {
+                instance_id: optimization.instance_id.clone(),
+                optimization_type: optimization.optimization_type.clone(),
+                outcome,
+                monthly_cost_before_usd: before,
+                monthly_cost_after_usd: after,
+                applied_at: Utc::now(),
+            }
+        };
+
+        let action = self.action_for(optimization, target, before, expected_after);
+        // Check everything but the audit requirement before writing the intent.
+        let precheck = Action {
+            has_audit_log_entry: true,
+            ..action.clone()
+        };
+        match self.engine.safety_check(&precheck) {
+            SafetyResult::Approved => {}
+            SafetyResult::Blocked { reason } => return result(ApplyOutcome::Blocked { reason }),
+            SafetyResult::RequiresConfirmation { reason } if confirmation.is_none() => {
+                return result(ApplyOutcome::RequiresConfirmation { reason });
+            }
+            SafetyResult::RequiresConfirmation { .. } => {}
+        }
+        if optimization.requires_confirmation && confirmation.is_none() {
+            return result(ApplyOutcome::RequiresConfirmation {
+                reason: "optimization is marked as requiring operator confirmation".to_string(),
+            });
+        }
+        let action = Action {
+            has_audit_log_entry: self.record_intent(optimization, target, confirmation),
+            ..action
+        };
+        if let SafetyResult::Blocked { reason } = self.engine.safety_check(&action) {
+            return result(ApplyOutcome::Blocked { reason });
+        }
+
+        let requested_by = confirmation.unwrap_or("commander");
+        let executed = self.execute(optimization, target, requested_by).await;
+        self.record_audit(optimization, target, confirmation, &executed);
+        self.record_history(optimization, target, confirmation, &executed);
+        let outcome = match executed {
+            Ok(done) => ApplyOutcome::Applied {
+                detail: done.detail,
+            },
+            Err(e) => ApplyOutcome::Failed {
+                error: format!("{e:#}"),
+            },
+        };
+        result(outcome)
+    }
+
+    fn action_for(
+        &self,
+        optimization: &Optimization,
+        target: &ApplyTarget,
+        before: f64,
+        after: f64,
+    ) -> Action {
+        // Migration deletes the original, so it gets teardown-level scrutiny.
+        let action_type = match optimization.optimization_type {
+            OptimizationType::Downsize { .. } | OptimizationType::Upsize { .. } => {
+                ActionType::TierResize
+            }
+            OptimizationType::Teardown { .. } | OptimizationType::Migrate { .. } => {
+                ActionType::Teardown
+            }
+            OptimizationType::Archive { .. } => ActionType::CostAction,
+        };
+        let estimated_cost_change_pct = if before > 0.0 {
+            (after - before) / before * 100.0
+        } else {
+            0.0
+        };
+        let is_teardown = action_type == ActionType::Teardown;
+        let region_healthy_after_teardown = target
+            .region
+            .as_deref()
+            .filter(|_| is_teardown && !target.fleet.is_empty())
+            .map(|region| {
+                let leaving = BTreeSet::from([optimization.instance_id.as_str()]);
+                healthy_in_region(&target.fleet, region, &leaving)
+            });
+        let estimated_downtime_secs = (action_type == ActionType::TierResize).then(|| {
+            self.provisioner
+                .estimated_resize_downtime_secs(target.provider)
+        });
+        Action {
+            is_primary_teardown: is_teardown && target.role == InstanceRole::Primary,
+            teardown_monthly_cost_usd: if is_teardown { before } else { 0.0 },
+            region_healthy_after_teardown,
+            estimated_downtime_secs,
+            action_type,
+            affected_users: target.affected_users,
+            affected_instance_count: 1,
+            standby_confirmed_active: target.standby_confirmed_active,
+            estimated_cost_change_pct,
+            // Set once the intent entry is persisted.
+            has_audit_log_entry: false,
+        }
+    }
+
+    /// Persist the approved operation to the audit chain before it runs.
+    /// Returns whether the entry was written.
+    fn record_intent(
+        &mut self,
+        optimization: &Optimization,
+        target: &ApplyTarget,
+        confirmation: Option<&str>,
+    ) -> bool {
+        let Some(audit) = self.audit.as_deref_mut() else {
+            return false;
+        };
+        audit
+            .try_append(
+                AgentId::Commander,
+                AuditAction::OperatorConfirmationReceived,
+                TargetType::Instance,
+                &optimization.instance_id,
+                serde_json::json!({
+                    "account_id": optimization.account_id,
+                    "provider": target.provider,
+                    "provider_instance_id": target.provider_instance_id,
+                    "optimization": optimization.optimization_type,
+                }),
+                claw_audit::AuditResult {
+                    success: true,
+                    message: "optimization approved; executing".to_string(),
+                    details: None,
+                },
+                None,
+                confirmation.map(str::to_string),
+            )
+            .is_ok()
+    }
+
+    /// Append the executed operation and its real outcome to the audit chain.
+    fn record_audit(
+        &mut self,
+        optimization: &Optimization,
+        target: &ApplyTarget,
+        confirmation: Option<&str>,
+        executed: &anyhow::Result<Executed>,
+    ) {
+        let Some(audit) = self.audit.as_deref_mut() else {
+            return;
+        };
+        let action = match optimization.optimization_type {
+            OptimizationType::Downsize { .. } | OptimizationType::Upsize { .. } => {
+                AuditAction::ResizeInstance
+            }
+            OptimizationType::Teardown { .. } | OptimizationType::Migrate { .. } => {
+                AuditAction::TeardownInstance
+            }
+            OptimizationType::Archive { .. } => AuditAction::HibernateInstance,
+        };
+        audit.append(
+            AgentId::Commander,
+            action,
+            TargetType::Instance,
+            &optimization.instance_id,
+            serde_json::json!({
+                "account_id": optimization.account_id,
+                "provider": target.provider,
+                "provider_instance_id": target.provider_instance_id,
+                "optimization": optimization.optimization_type,
+            }),
+            match executed {
+                Ok(done) => claw_audit::AuditResult {
+                    success: true,
+                    message: format!("optimization applied: {}", done.detail),
+                    details: None,
+                },
+                Err(e) => claw_audit::AuditResult {
+                    success: false,
+                    message: format!("optimization failed: {e:#}"),
+                    details: None,
+                },
+            },
+            None,
+            confirmation.map(str::to_string),
+        );
+    }
+
+    /// Append the resize or teardown outcome to the per-instance history log.
+    fn record_history(
+        &mut self,
+        optimization: &Optimization,
+        target: &ApplyTarget,
+        confirmation: Option<&str>,
+        executed: &anyhow::Result<Executed>,
+    ) {
+        let Some(history) = self.history.as_deref_mut() else {
+            return;
+        };
+        let requested_by = confirmation.unwrap_or("commander");
+        let (action, mut details) = match &optimization.optimization_type {
+            OptimizationType::Downsize { to_tier, .. }
+            | OptimizationType::Upsize { to_tier, .. } => {
+                let resize = executed.as_ref().ok().and_then(|e| e.resize.as_ref());
+                (
+                    "resize",
+                    serde_json::json!({
+                        "old_tier": resize.map_or(target.tier, |r| r.old_tier),
+                        "new_tier": resize.map_or(*to_tier, |r| r.new_tier),
+                        "downtime_seconds": resize.map(|r| r.downtime_seconds),
+                    }),
+                )
+            }
+            OptimizationType::Teardown { idle_days } => (
+                "teardown",
+                serde_json::json!({ "reason": format!("idle for {idle_days} days") }),
+            ),
+            OptimizationType::Migrate { to_provider, .. } => {
+                let migration = executed.as_ref().ok().and_then(|e| e.migration.as_ref());
+                (
+                    "migrate",
+                    serde_json::json!({
+                        "from_provider": target.provider,
+                        "to_provider": to_provider,
+                        "replacement_instance_id":
+                            migration.and_then(|m| m.replacement.instance_id.clone()),
+                        "snapshot_id": migration.and_then(|m| m.snapshot_id.clone()),
+                    }),
+                )
+            }
+            OptimizationType::Archive { .. } => return,
+        };
+        details["requested_by"] = requested_by.into();
+        details["provider_instance_id"] = target.provider_instance_id.clone().into();
+        if let Err(e) = executed {
+            details["error"] = format!("{e:#}").into();
+        }
+
+        history.append(AuditLogEntry {
+            id: uuid::Uuid::new_v4().to_string(),
+            timestamp: Utc::now(),
+            actor: requested_by.to_string(),
+            action: action.to_string(),
+            resource: "instance".to_string(),
+            resource_id: Some(optimization.instance_id.clone()),
+            result: if executed.is_ok() {
+                "success"
+            } else {
+                "failure"
+            }
+            .to_string(),
+            details: Some(details.to_string()),
+        });
+    }
+
+    async fn execute(
+        &self,
+        optimization: &Optimization,
+        target: &ApplyTarget,
+        requested_by: &str,
+    ) -> anyhow::Result<Executed> {
+        let id = target.provider_instance_id.as_str();
+        let done = |detail: String| Executed {
+            detail,
+            resize: None,
+            migration: None,
+        };
+        match &optimization.optimization_type {
+            OptimizationType::Downsize { to_tier, .. }
+            | OptimizationType::Upsize { to_tier, .. } => {
+                let resized = self
+                    .provisioner
+                    .resize_instance(target.provider, id, to_tier, requested_by)
+                    .await?;
+                Ok(Executed {
+                    detail: format!("resized {id} to {:?}", resized.new_tier),
+                    resize: Some(resized),
+                    migration: None,
+                })
+            }
+            OptimizationType::Teardown { .. } => {
+                self.provisioner
+                    .teardown_instance(target.provider, id, &optimization.account_id, requested_by)
+                    .await?;
+                Ok(done(format!("tore down {id}")))
+            }
+            OptimizationType::Migrate { to_provider, .. } => {
+                let region = target.migration_region.clone().ok_or_else(|| {
+                    anyhow::anyhow!("no migration region given for {}", optimization.instance_id)
+                })?;
+                let req = ProvisionRequest {
+                    request_id: uuid::Uuid::new_v4(),
+                    account_id: optimization.account_id.clone(),
+                    tier: target.tier,
+                    role: target.role,
+                    provider: *to_provider,
+                    region,
+                    os_image: target.os_image,
+                    pair_instance_id: None,
+                    openclaw_config: None,
+                    requested_by: "commander:apply_optimization".to_string(),
+                    requested_at: Utc::now(),
+                };
+                let migrated = self
+                    .provisioner
+                    .migrate_instance(&req, target.provider, id)
+                    .await?;
+                Ok(Executed {
+                    detail: format!(
+                        "migrated {id} to {to_provider} as {}",
+                        migrated
+                            .replacement
+                            .instance_id
+                            .as_deref()
+                            .unwrap_or_default()
+                    ),
+                    resize: None,
+                    migration: Some(migrated),
+                })
+            }
+            OptimizationType::Archive { .. } => {
+                self.provisioner
+                    .hibernate_instance(target.provider, id)
+                    .await?;
+                Ok(done(format!("hibernated {id}")))
+            }
+        }
+    }
+}
+
+// ─── Helpers ──────────────────────────────────────────────────────────────────
+
+/// Max audit entries returned for a conversational audit query.
+const AUDIT_QUERY_LIMIT: usize = 20;
+
+fn parse_audit_query(lower: &str) -> OperatorIntent {
+    let words: Vec<&str> = lower
+        .split_whitespace()
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric() && c != '-' && c != '_'))
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    let action = if lower.contains("delete")
+        || lower.contains("tore down")
+        || lower.contains("teardown")
+        || lower.contains("tear down")
+    {
+        Some("teardown".to_string())
+    } else if lower.contains("provision") {
+        Some("provision".to_string())
+    } else if lower.contains("resize") {
+        Some("resize".to_string())
+    } else if lower.contains("rotate") {
+        Some("rotate".to_string())
+    } else {
+        None
+    };
+
+    let resource_id = words
+        .iter()
+        .find(|w| looks_like_resource_id(w))
+        .map(|w| w.to_string());
+
+    // "audit log for forge" / "actions by forge" name an actor unless the
+    // word after the preposition is the resource itself.
+    let actor = words
+        .windows(2)
+        .find(|pair| matches!(pair[0], "for" | "by") && !looks_like_resource_id(pair[1]))
+        .map(|pair| pair[1].to_string());
+
+    OperatorIntent::AuditQuery {
+        actor,
+        action,
+        resource_id,
+        within_hours: parse_time_window_hours(&words),
+    }
+}
+
+/// Past-tense verbs that make "who …" a question about the audit trail.
+const AUDIT_VERBS: &[&str] = &[
+    "deleted",
+    "removed",
+    "tore",
+    "created",
+    "provisioned",
+    "resized",
+    "upgraded",
+    "downgraded",
+    "migrated",
+    "hibernated",
+    "archived",
+    "changed",
+    "modified",
+    "updated",
+    "rotated",
+    "restarted",
+    "pushed",
+    "approved",
+    "confirmed",
+    "ran",
+    "did",
+    "touched",
+];
+
+fn names_audit_verb(lower: &str) -> bool {
+    lower
+        .split(|c: char| !c.is_alphanumeric())
+        .any(|word| AUDIT_VERBS.contains(&word))
+}
+
+/// Hours covered by a phrase like "today", "last week" or "past 3 days".
+fn parse_time_window_hours(words: &[&str]) -> Option<u32> {
+    let unit_hours = |unit: &str| match unit.trim_end_matches('s') {
+        "hour" => Some(1),
+        "day" => Some(24),
+        "week" => Some(7 * 24),
+        "month" => Some(30 * 24),
+        _ => None,
+    };
+    if words.contains(&"today") {
+        return Some(24);
+    }
+    if words.contains(&"yesterday") {
+        return Some(48);
+    }
+    words
+        .windows(3)
+        .find_map(|w| {
+            if !matches!(w[0], "last" | "past" | "this") {
+                return None;
+            }
+            match w[1].parse::<u32>() {
+                Ok(n) => unit_hours(w[2]).map(|h| n.saturating_mul(h)),
+                Err(_) => unit_hours(w[1]),
+            }
+        })
+        .or_else(|| {
+            // "last week" at the very end of the message has no third word.
+            words
+                .windows(2)
+                .rev()
+                .find(|w| matches!(w[0], "last" | "past" | "this"))
+                .and_then(|w| unit_hours(w[1]))
+        })
+}
+
+/// The intent families `intent_candidates` can produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IntentKind {
+    Provision,
+    Teardown,
+    Resize,
+    Cost,
+    ConfigPush,
+    Incident,
+    Health,
+    Bulk,
+    FleetStatus,
+    Audit,
+}
+
+impl IntentKind {
+    fn of(intent: &OperatorIntent) -> Option<Self> {
+        Some(match intent {
+            OperatorIntent::ProvisionRequest { .. } => Self::Provision,
+            OperatorIntent::TeardownRequest { .. } => Self::Teardown,
+            OperatorIntent::ResizeRequest { .. } => Self::Resize,
+            OperatorIntent::CostQuery { .. } => Self::Cost,
+            OperatorIntent::ConfigPush { .. } => Self::ConfigPush,
+            OperatorIntent::IncidentQuery => Self::Incident,
+            OperatorIntent::HealthQuery { .. } => Self::Health,
+            OperatorIntent::BulkOperation { .. } => Self::Bulk,
+            OperatorIntent::FleetStatus => Self::FleetStatus,
+            OperatorIntent::AuditQuery { .. } => Self::Audit,
+            OperatorIntent::Unknown { .. } => return None,
+        })
+    }
+}
+
+/// Whether a keyword makes its intent a candidate on its own or only backs
+/// one up (alone or in combination, as `intent_candidates` decides).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TermRole {
+    Trigger,
+    Support,
+}
+
+/// Keywords behind each intent, weighted by how specific they are to it.
+/// `intent_candidates` matches on the triggers; `classify_intent` scores a
+/// candidate by every one of its terms present in the message.
+const INTENT_TERMS: &[(IntentKind, &str, f32, TermRole)] = &[
+    (IntentKind::Provision, "provision", 0.6, TermRole::Trigger),
+    (IntentKind::Provision, "create", 0.3, TermRole::Support),
+    (IntentKind::Provision, "account", 0.2, TermRole::Support),
+    (IntentKind::Provision, "pair", 0.2, TermRole::Support),
+    (IntentKind::Provision, "nano", 0.2, TermRole::Support),
+    (IntentKind::Provision, "standard", 0.2, TermRole::Support),
+    (IntentKind::Provision, "enterprise", 0.2, TermRole::Support),
+    (IntentKind::Teardown, "teardown", 0.6, TermRole::Trigger),
+    (IntentKind::Teardown, "tear down", 0.6, TermRole::Trigger),
+    (IntentKind::Teardown, "delete", 0.4, TermRole::Trigger),
+    (IntentKind::Teardown, "idle", 0.2, TermRole::Support),
+    (IntentKind::Teardown, "instance", 0.1, TermRole::Support),
+    (IntentKind::Resize, "resize", 0.6, TermRole::Trigger),
+    (IntentKind::Resize, "downsize", 0.6, TermRole::Trigger),
+    (IntentKind::Resize, "scale", 0.4, TermRole::Trigger),
+    (IntentKind::Resize, "upgrade", 0.4, TermRole::Support),
+    (IntentKind::Resize, "downgrade", 0.4, TermRole::Support),
+    (IntentKind::Resize, "nano", 0.2, TermRole::Support),
+    (IntentKind::Resize, "standard", 0.2, TermRole::Support),
+    (IntentKind::Resize, "enterprise", 0.2, TermRole::Support),
+    (IntentKind::Cost, "cost", 0.5, TermRole::Trigger),
+    (IntentKind::Cost, "billing", 0.5, TermRole::Trigger),
+    (IntentKind::Cost, "spend", 0.4, TermRole::Trigger),
+    (IntentKind::Cost, "wast", 0.4, TermRole::Trigger),
+    (IntentKind::Cost, "idle", 0.2, TermRole::Support),
+    (IntentKind::Cost, "forecast", 0.2, TermRole::Support),
+    (IntentKind::Cost, "compare", 0.2, TermRole::Support),
+    (IntentKind::ConfigPush, "config", 0.5, TermRole::Trigger),
+    (IntentKind::ConfigPush, "push", 0.3, TermRole::Support),
+    (IntentKind::ConfigPush, "model", 0.2, TermRole::Support),
+    (IntentKind::Incident, "incident", 0.6, TermRole::Trigger),
+    (IntentKind::Incident, "outage", 0.6, TermRole::Trigger),
+    (IntentKind::Incident, "down", 0.3, TermRole::Support),
+    (IntentKind::Health, "health", 0.5, TermRole::Trigger),
+    (IntentKind::Health, "degraded", 0.4, TermRole::Trigger),
+    (IntentKind::Health, "status", 0.3, TermRole::Trigger),
+    (IntentKind::Bulk, "bulk", 0.5, TermRole::Trigger),
+    (IntentKind::Bulk, "all instance", 0.5, TermRole::Trigger),
+    (IntentKind::Bulk, "restart all", 0.5, TermRole::Trigger),
+    (IntentKind::FleetStatus, "fleet", 0.3, TermRole::Trigger),
+    (IntentKind::FleetStatus, "overview", 0.3, TermRole::Trigger),
+    (IntentKind::FleetStatus, "summary", 0.3, TermRole::Trigger),
+    (IntentKind::Audit, "audit", 0.6, TermRole::Trigger),
+    (IntentKind::Audit, "who ", 0.4, TermRole::Support),
+    (IntentKind::Audit, "log", 0.1, TermRole::Support),
+];
+
+/// Confidence given to the only intent a message matches: a lone
+/// candidate is not a guess, however few of its keywords appear.
+const UNAMBIGUOUS_INTENT_CONFIDENCE: f32 = 0.6;
+
+/// Terms of `kind` present in `lower`, restricted to `role` if given.
+fn intent_terms_in<'a>(
+    lower: &'a str,
+    kind: IntentKind,
+    role: Option<TermRole>,
+) -> impl Iterator<Item = (&'static str, f32)> + 'a {
+    INTENT_TERMS
+        .iter()
+        .filter(move |(k, term, _, r)| {
+            *k == kind && role.is_none_or(|role| role == *r) && mentions(lower, term)
+        })
+        .map(|(_, term, weight, _)| (*term, *weight))
+}
+
+/// Terms that only count as whole words: "escalate" is not "scale" and
+/// "upgraded" reports a past change rather than asking for one.
+const WHOLE_WORD_TERMS: &[&str] = &["scale", "upgrade", "downgrade"];
+
+/// Whether `term` appears in `lower`, as a whole word if it must be one.
+fn mentions(lower: &str, term: &str) -> bool {
+    if WHOLE_WORD_TERMS.contains(&term) {
+        lower
+            .split(|c: char| !c.is_alphanumeric())
+            .any(|word| word == term)
+    } else {
+        lower.contains(term)
+    }
+}
+
+/// Whether one of the trigger terms for `kind` appears in `lower`.
+fn triggers(lower: &str, kind: IntentKind) -> bool {
+    intent_terms_in(lower, kind, Some(TermRole::Trigger))
+        .next()
+        .is_some()
+}
+
+/// The actor a `requested_by` tag belongs to: "forge:provision_single" → "forge".
+fn provision_actor(requested_by: &str) -> String {
+    requested_by
+        .split(':')
+        .next()
+        .unwrap_or(requested_by)
+        .trim()
+        .to_lowercase()
+}
+
+fn looks_like_resource_id(word: &str) -> bool {
+    word.contains('-') && word.chars().any(|c| c.is_ascii_digit())
+}
+
+/// Words that make a preceding number a count ("42 instances", "3 idle").
+const TEARDOWN_COUNT_NOUNS: &[&str] = &[
+    "instance", "server", "vps", "machine", "pair", "account", "idle", "box",
+];
+
+/// Tell an instance id ("instance 42", "i-abc", a UUID) from a bulk count.
+///
+/// A number with neither an id marker before it nor a count noun after it is
+/// `Ambiguous` rather than silently read as a count.
+fn parse_teardown_scope(lower: &str) -> TeardownScope {
+    let words: Vec<&str> = lower
+        .split_whitespace()
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric() && c != '-' && c != '_'))
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    let marked_id = words.windows(2).find_map(|pair| {
+        let is_marker = matches!(pair[0], "instance" | "server" | "vps" | "id");
+        (is_marker && looks_like_instance_id(pair[1])).then(|| pair[1])
+    });
+    let bare_id = words
+        .iter()
+        .find(|w| w.starts_with("i-") || is_uuid(w))
+        .copied();
+    if let Some(instance_id) = marked_id.or(bare_id) {
+        return TeardownScope::Single {
+            instance_id: instance_id.to_string(),
+        };
+    }
+
+    if lower.contains("idle") {
+        return TeardownScope::IdleAccounts;
+    }
+
+    let counted = words.iter().enumerate().find_map(|(i, w)| {
+        let n = w.parse::<u32>().ok()?;
+        let noun = words.get(i + 1)?;
+        TEARDOWN_COUNT_NOUNS
+            .iter()
+            .any(|c| noun.starts_with(c))
+            .then_some(n)
+    });
+    match (counted, extract_number(lower)) {
+        (Some(count), _) => TeardownScope::Custom { count },
+        (None, Some(number)) => TeardownScope::Ambiguous { number },
+        (None, None) => TeardownScope::Custom { count: 1 },
+    }
+}
+
+/// Which instances a resize names: an instance id, "account X", or "X pool".
+fn parse_resize_scope(lower: &str) -> ResizeScope {
+    let words: Vec<&str> = lower
+        .split_whitespace()
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric() && c != '-' && c != '_'))
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    if let Some(instance_id) = words.iter().find(|w| w.starts_with("i-") || is_uuid(w)) {
+        return ResizeScope::Instance {
+            instance_id: instance_id.to_string(),
+        };
+    }
+    let named = |pair: &[&str], marker: &str| {
+        (pair[0] == marker && pair[1] != "to").then(|| pair[1].to_string())
+    };
+    if let Some(account_id) = words.windows(2).find_map(|pair| named(pair, "account")) {
+        return ResizeScope::Account { account_id };
+    }
+    let pool = words.windows(2).find_map(|pair| {
+        (pair[1] == "pool" && pair[0] != "the")
+            .then(|| pair[0].to_string())
+            .or_else(|| named(pair, "pool"))
+    });
+    match pool {
+        Some(name) => ResizeScope::Pool { name },
+        None => ResizeScope::Unspecified,
+    }
+}
+
+/// The tier named right after a "to" ("resize the pro accounts to nano").
+fn extract_target_tier(lower: &str) -> Option<String> {
+    let words: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    words
+        .windows(2)
+        .rev()
+        .find(|pair| pair[0] == "to" && TIERS.contains(&pair[1]))
+        .map(|pair| pair[1].to_string())
+}
+
+fn looks_like_instance_id(word: &str) -> bool {
+    word.chars().any(|c| c.is_ascii_digit()) || word.starts_with("i-")
+}
+
+fn is_uuid(word: &str) -> bool {
+    let groups: Vec<&str> = word.split('-').collect();
+    groups.iter().map(|g| g.len()).eq([8, 4, 4, 4, 12])
+        && groups
+            .iter()
+            .all(|g| g.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+fn extract_number(text: &str) -> Option<u32> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    for w in words {
+        if let Ok(n) = w.parse::<u32>() {
+            return Some(n);
+        }
+    }
+    None
+}
+
+/// Tier names, most specific first.
+const TIERS: &[&str] = &["enterprise", "pro", "nano", "standard"];
+
+/// A tier named as a whole word ("standard-tier" counts, "production" does not).
+fn extract_tier(text: &str) -> Option<String> {
+    let words: Vec<&str> = text.split(|c: char| !c.is_alphanumeric()).collect();
+    TIERS
+        .iter()
+        .find(|tier| words.contains(tier))
+        .map(|tier| tier.to_string())
+}
+
+fn default_ha() -> bool {
+    true
+}
+
+/// Trial and nano accounts, or an explicit "no standby", skip the standby.
+fn wants_single_instance(text: &str, tier_hint: Option<&str>) -> bool {
+    // Whole words only, so "single-region pairs" or "industrial" stay HA.
+    let words: Vec<&str> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    let normalized = format!(" {} ", words.join(" "));
+    tier_hint == Some("nano")
+        || SINGLE_INSTANCE_PHRASES
+            .iter()
+            .any(|phrase| normalized.contains(&format!(" {phrase} ")))
+}
+
+/// Phrases that explicitly ask for accounts without a standby.
+const SINGLE_INSTANCE_PHRASES: &[&str] = &[
+    "trial",
+    "single instance",
+    "single instances",
+    "no standby",
+    "without standby",
+    "without a standby",
+];
+
+fn extract_bulk_op(text: &str) -> String {
+    if text.contains("restart") {
+        "restart".to_string()
+    } else if text.contains("config") {
+        "config push".to_string()
+    } else if text.contains("health") {
+        "health check".to_string()
+    } else {
+        "operation".to_string()
+    }
+}
+
+// ─── Tests ────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eng() -> CommanderEngine {
+        CommanderEngine::new().with_sleeper(Arc::new(RecordingSleeper::default()))
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingSleeper {
+        slept: Mutex<Vec<std::time::Duration>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Sleeper for RecordingSleeper {
+        async fn sleep(&self, duration: std::time::Duration) {
+            self.slept.lock().unwrap().push(duration);
+        }
+    }
+
+    // ─── Intent parsing ─────────────────────────────────────────────────────
+
+    #[test]
+    fn test_parse_provision_intent() {
+        let intent = eng().parse_intent("Provision 20 new standard-tier accounts");
+        assert!(
+            matches!(intent, OperatorIntent::ProvisionRequest { count: 20, .. }),
+            "got {:?}",
+            intent
+        );
+    }
+
+    #[test]
+    fn test_parse_provision_with_tier() {
+        let intent = eng().parse_intent("Provision 5 enterprise accounts for beta");
+        if let OperatorIntent::ProvisionRequest { tier_hint, .. } = intent {
+            assert_eq!(tier_hint, Some("enterprise".to_string()));
+        } else {
+            panic!("Expected ProvisionRequest");
+        }
+    }
+
+    #[test]
+    fn test_parse_trial_provision_routes_to_single_instances() {
+        let intent = eng().parse_intent("provision 20 trial accounts");
+        assert!(
+            matches!(
+                intent,
+                OperatorIntent::ProvisionRequest {
+                    count: 20,
+                    ha: false,
+                    ..
+                }
+            ),
+            "got {intent:?}"
+        );
+        match eng().route_to_specialist(&intent).unwrap() {
+            SpecialistAction::SpawnForge { task } => assert!(task.contains("single instances")),
+            other => panic!("expected SpawnForge, got {other:?}"),
+        }
+
+        for text in [
+            "provision 3 single-instance accounts",
+            "provision 3 accounts without a standby",
+        ] {
+            assert!(
+                matches!(
+                    eng().parse_intent(text),
+                    OperatorIntent::ProvisionRequest { ha: false, .. }
+                ),
+                "{text}"
+            );
+        }
+        // "single" alone does not drop the standby.
+        assert!(matches!(
+            eng().parse_intent("provision 3 accounts in a single region"),
+            OperatorIntent::ProvisionRequest { ha: true, .. }
+        ));
+
+        let legacy: OperatorIntent =
+            serde_json::from_str(r#"{"provision_request":{"count":2,"tier_hint":null}}"#).unwrap();
+        assert!(matches!(
+            legacy,
+            OperatorIntent::ProvisionRequest { ha: true, .. }
+        ));
+    }
+
+    #[test]
+    fn test_parse_cost_waste_query() {
+        let intent = eng().parse_intent("What are we wasting money on this month?");
+        assert_eq!(
+            intent,
+            OperatorIntent::CostQuery {
+                detail: CostQueryDetail::Waste
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_cost_general_query() {
+        let intent = eng().parse_intent("How much are we spending?");
+        assert_eq!(
+            intent,
+            OperatorIntent::CostQuery {
+                detail: CostQueryDetail::General
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_teardown_idle() {
+        let intent = eng().parse_intent("Teardown idle accounts");
+        assert_eq!(
+            intent,
+            OperatorIntent::TeardownRequest {
+                scope: TeardownScope::IdleAccounts
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_teardown_instance_id_is_single() {
+        for (message, id) in [
+            ("teardown instance 42", "42"),
+            ("Tear down instance i-abc", "i-abc"),
+            (
+                "delete 6f1c2a9e-3b4d-4e5f-8a7b-9c0d1e2f3a4b",
+                "6f1c2a9e-3b4d-4e5f-8a7b-9c0d1e2f3a4b",
+            ),
+        ] {
+            assert_eq!(
+                eng().parse_intent(message),
+                OperatorIntent::TeardownRequest {
+                    scope: TeardownScope::Single {
+                        instance_id: id.to_string()
+                    }
+                },
+                "{message}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_teardown_count_is_custom() {
+        let intent = eng().parse_intent("teardown 42 instances");
+        assert_eq!(
+            intent,
+            OperatorIntent::TeardownRequest {
+                scope: TeardownScope::Custom { count: 42 }
+            }
+        );
+        assert_eq!(
+            eng().parse_intent("teardown 42 idle"),
+            OperatorIntent::TeardownRequest {
+                scope: TeardownScope::IdleAccounts
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_teardown_bare_number_requires_confirmation() {
+        let OperatorIntent::TeardownRequest { scope } = eng().parse_intent("teardown 42 now")
+        else {
+            panic!("expected a teardown intent");
+        };
+        assert_eq!(scope, TeardownScope::Ambiguous { number: 42 });
+        assert!(scope.requires_confirmation());
+        let action = eng()
+            .route_to_specialist(&OperatorIntent::TeardownRequest { scope })
+            .unwrap();
+        assert!(matches!(action, SpecialistAction::HandleDirectly { .. }));
+    }
+
+    #[test]
+    fn test_parse_resize_upgrade_instance() {
+        let intent = eng().parse_intent("Upgrade i-123 to enterprise");
+        assert_eq!(
+            intent,
+            OperatorIntent::ResizeRequest {
+                scope: ResizeScope::Instance {
+                    instance_id: "i-123".to_string()
+                },
+                target_tier: Some("enterprise".to_string()),
+            }
+        );
+        let action = eng().route_message("Upgrade i-123 to enterprise").unwrap();
+        match action {
+            SpecialistAction::SpawnForge { task } => {
+                assert_eq!(task, "Resize instance i-123 to enterprise")
+            }
+            other => panic!("expected SpawnForge, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_resize_scale_down_pool() {
+        let candidates = eng().intent_candidates("scale down the beta pool to nano");
+        assert_eq!(
+            candidates,
+            vec![OperatorIntent::ResizeRequest {
+                scope: ResizeScope::Pool {
+                    name: "beta".to_string()
+                },
+                target_tier: Some("nano".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_resize_account() {
+        assert_eq!(
+            eng().parse_intent("Resize account acc-42 to pro"),
+            OperatorIntent::ResizeRequest {
+                scope: ResizeScope::Account {
+                    account_id: "acc-42".to_string()
+                },
+                target_tier: Some("pro".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_resize_without_tier_asks() {
+        let intent = eng().parse_intent("resize i-9");
+        assert!(matches!(
+            intent,
+            OperatorIntent::ResizeRequest {
+                target_tier: None,
+                ..
+            }
+        ));
+        let action = eng().route_to_specialist(&intent).unwrap();
+        assert!(matches!(action, SpecialistAction::HandleDirectly { .. }));
+    }
+
+    #[test]
+    fn test_downsize_idle_accounts_routes_to_cost() {
+        assert_eq!(
+            eng().intent_candidates("downsize idle accounts"),
+            vec![OperatorIntent::CostQuery {
+                detail: CostQueryDetail::Waste
+            }]
+        );
+        let action = eng().route_message("downsize idle accounts").unwrap();
+        assert!(matches!(action, SpecialistAction::SendToLedger { .. }));
+    }
+
+    #[test]
+    fn test_software_upgrade_is_not_resize() {
+        let intent = eng().parse_intent("upgrade openclaw config on 3 instances");
+        assert!(!matches!(intent, OperatorIntent::ResizeRequest { .. }));
+        let candidates = eng().intent_candidates("upgrade openclaw on production instances");
+        assert!(
+            !candidates
+                .iter()
+                .any(|c| matches!(c, OperatorIntent::ResizeRequest { .. })),
+            "{candidates:?}"
+        );
+    }
+
+    #[test]
+    fn test_resize_keywords_match_whole_words() {
+        let engine = eng();
+        for message in [
+            "escalate the billing ticket",
+            "acc-7 was upgraded to pro last week",
+            "list the downgrades to nano",
+        ] {
+            assert!(
+                !engine
+                    .intent_candidates(message)
+                    .iter()
+                    .any(|c| matches!(c, OperatorIntent::ResizeRequest { .. })),
+                "{message}"
+            );
+        }
+        assert!(matches!(
+            engine.parse_intent("Scale the beta pool to nano"),
+            OperatorIntent::ResizeRequest { .. }
+        ));
+        assert!(matches!(
+            engine.parse_intent("downgrade acc-7 to nano"),
+            OperatorIntent::ResizeRequest { .. }
+        ));
+    }
+
+    #[test]
+    fn test_scale_without_tier_is_resize_that_asks() {
+        let candidates = eng().intent_candidates("scale down the beta pool");
+        assert_eq!(
+            candidates,
+            vec![OperatorIntent::ResizeRequest {
+                scope: ResizeScope::Pool {
+                    name: "beta".to_string()
+                },
+                target_tier: None,
+            }]
+        );
+        let action = eng().route_message("scale down the beta pool").unwrap();
+        assert!(matches!(action, SpecialistAction::HandleDirectly { .. }));
+    }
+
+    #[test]
+    fn test_extract_tier_matches_whole_words() {
+        assert_eq!(extract_tier("production instances"), None);
+        assert_eq!(extract_tier("provision 3 pairs"), None);
+        assert_eq!(
+            extract_tier("20 standard-tier accounts"),
+            Some("standard".to_string())
+        );
+        assert_eq!(extract_tier("move to pro"), Some("pro".to_string()));
+    }
+
+    #[test]
+    fn test_classify_clear_request_scores_high() {
+        let classified = eng().classify_intent("provision 20 standard pairs");
+        assert!(matches!(
+            classified.intent,
+            OperatorIntent::ProvisionRequest { count: 20, .. }
+        ));
+        assert!(classified.confidence >= 0.9, "{}", classified.confidence);
+        assert_eq!(
+            classified.matched_terms,
+            vec!["provision", "pair", "standard"]
+        );
+    }
+
+    #[test]
+    fn test_classify_vague_request_is_unknown() {
+        let classified = eng().classify_intent("do the thing");
+        assert_eq!(classified.confidence, 0.0);
+        assert!(matches!(classified.intent, OperatorIntent::Unknown { .. }));
+
+        // Weak matches split between competing intents fall below the threshold.
+        let classified = eng().classify_intent("status of the config");
+        assert!(classified.confidence < 0.5, "{}", classified.confidence);
+        assert!(matches!(classified.intent, OperatorIntent::Unknown { .. }));
+
+        let lenient = CommanderEngine::with_safety_rules(SafetyRules {
+            min_intent_confidence: 0.2,
+            ..SafetyRules::default()
+        });
+        assert!(matches!(
+            lenient.classify_intent("status of the config").intent,
+            OperatorIntent::ConfigPush { .. }
+        ));
+    }
+
+    #[test]
+    fn test_classify_terse_unambiguous_requests_pass_threshold() {
+        let engine = eng();
+        for message in [
+            "who deleted acc-123",
+            "what's our spend",
+            "what's the fleet status",
+            "upgrade i-9 to pro",
+            "give me a summary",
+        ] {
+            assert_eq!(engine.intent_candidates(message).len(), 1, "{message}");
+            let classified = engine.classify_intent(message);
+            assert!(
+                classified.confidence >= 0.5,
+                "{message}: {}",
+                classified.confidence
+            );
+            assert!(
+                !matches!(classified.intent, OperatorIntent::Unknown { .. }),
+                "{message}"
+            );
+            assert!(!classified.matched_terms.is_empty(), "{message}");
+        }
+        assert!(matches!(
+            engine.classify_intent("upgrade i-9 to pro").intent,
+            OperatorIntent::ResizeRequest { .. }
+        ));
+    }
+
+    #[test]
+    fn test_parse_config_push() {
+        let intent = eng().parse_intent("Push the new config to 847 instances");
+        assert!(
+            matches!(
+                intent,
+                OperatorIntent::ConfigPush {
+                    instance_count_hint: Some(847)
+                }
+            ),
+            "got {:?}",
+            intent
+        );
+    }
+
+    #[test]
+    fn test_parse_incident_query() {
+        let intent = eng().parse_intent("Hetzner Nuremberg looks down. What's our exposure?");
+        assert_eq!(intent, OperatorIntent::IncidentQuery);
+    }
+
+    #[test]
+    fn test_parse_health_query() {
+        let intent = eng().parse_intent("What's the fleet health?");
+        assert!(
+            matches!(intent, OperatorIntent::HealthQuery { .. }),
+            "got {:?}",
+            intent
+        );
+    }
+
+    #[test]
+    fn test_parse_fleet_status() {
+        let intent = eng().parse_intent("Give me a fleet overview");
+        assert_eq!(intent, OperatorIntent::FleetStatus);
+    }
+
+    #[test]
+    fn test_parse_unknown() {
+        let intent = eng().parse_intent("Hello there");
+        assert!(matches!(intent, OperatorIntent::Unknown { .. }));
+    }
+
+    // ─── Routing ────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_route_provision_to_forge() {
+        let action = eng()
+            .route_to_specialist(&OperatorIntent::ProvisionRequest {
+                count: 20,
+                tier_hint: Some("standard".to_string()),
+                ha: true,
+            })
+            .unwrap();
+        assert!(matches!(action, SpecialistAction::SpawnForge { .. }));
+    }
+
+    #[test]
+    fn test_route_cost_to_ledger() {
+        let action = eng()
+            .route_to_specialist(&OperatorIntent::CostQuery {
+                detail: CostQueryDetail::Waste,
+            })
+            .unwrap();
+        assert!(matches!(action, SpecialistAction::SendToLedger { .. }));
+    }
+
+    #[test]
+    fn test_route_incident_to_triage() {
+        let action = eng()
+            .route_to_specialist(&OperatorIntent::IncidentQuery)
+            .unwrap();
+        assert!(matches!(action, SpecialistAction::SpawnTriage { .. }));
+    }
+
+    #[test]
+    fn test_route_health_to_guardian() {
+        let action = eng()
+            .route_to_specialist(&OperatorIntent::HealthQuery {
+                scope: HealthScope::Fleet,
+            })
+            .unwrap();
+        assert!(matches!(action, SpecialistAction::SendToGuardian { .. }));
+    }
+
+    #[test]
+    fn test_route_large_config_push_to_direct() {
+        let action = eng()
+            .route_to_specialist(&OperatorIntent::ConfigPush {
+                instance_count_hint: Some(847),
+            })
+            .unwrap();
+        // > 100 instances → must handle directly (rolling push required)
+        assert!(matches!(action, SpecialistAction::HandleDirectly { .. }));
+    }
+
+    #[test]
+    fn test_route_small_config_push_to_guardian() {
+        let action = eng()
+            .route_to_specialist(&OperatorIntent::ConfigPush {
+                instance_count_hint: Some(50),
+            })
+            .unwrap();
+        assert!(matches!(action, SpecialistAction::SendToGuardian { .. }));
+    }
+
+    // ─── Safety checks ──────────────────────────────────────────────────────
+
+    fn safe_action(action_type: ActionType) -> Action {
+        Action {
+            action_type,
+            affected_users: 5,
+            affected_instance_count: 10,
+            is_primary_teardown: false,
+            standby_confirmed_active: true,
+            estimated_cost_change_pct: 0.0,
+            has_audit_log_entry: true,
+            teardown_monthly_cost_usd: 0.0,
+            region_healthy_after_teardown: None,
+            estimated_downtime_secs: Some(0),
+        }
+    }
+
+    #[test]
+    fn test_safety_approved_simple() {
+        let result = eng().safety_check(&safe_action(ActionType::Provision));
+        assert_eq!(result, SafetyResult::Approved);
+    }
+
+    #[test]
+    fn test_safety_blocked_teardown_no_standby() {
+        let mut action = safe_action(ActionType::Teardown);
+        action.is_primary_teardown = true;
+        action.standby_confirmed_active = false;
+
+        let result = eng().safety_check(&action);
+        assert!(
+            matches!(result, SafetyResult::Blocked { .. }),
+            "expected Blocked, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_safety_blocked_teardown_no_audit() {
+        let mut action = safe_action(ActionType::Teardown);
+        action.has_audit_log_entry = false;
+
+        let result = eng().safety_check(&action);
+        assert!(matches!(result, SafetyResult::Blocked { .. }));
+    }
+
+    #[test]
+    fn test_safety_blocked_config_push_too_large() {
+        let mut action = safe_action(ActionType::ConfigPush);
+        action.affected_instance_count = 150;
+
+        let result = eng().safety_check(&action);
+        assert!(matches!(result, SafetyResult::Blocked { .. }));
+    }
+
+    #[test]
+    fn test_safety_requires_confirmation_many_users() {
+        let mut action = safe_action(ActionType::BulkOperation);
+        action.affected_users = 50;
+
+        let result = eng().safety_check(&action);
+        assert!(matches!(result, SafetyResult::RequiresConfirmation { .. }));
+    }
+
+    #[test]
+    fn test_safety_requires_confirmation_cost_spike() {
+        let mut action = safe_action(ActionType::Provision);
+        action.estimated_cost_change_pct = 25.0;
+
+        let result = eng().safety_check(&action);
+        assert!(matches!(result, SafetyResult::RequiresConfirmation { .. }));
+    }
+
+    #[test]
+    fn test_safety_blocks_emptying_region_when_minimum_set() {
+        let mut action = safe_action(ActionType::Teardown);
+        action.affected_instance_count = 1;
+        action.region_healthy_after_teardown = Some(0);
+
+        let engine = CommanderEngine::with_safety_rules(SafetyRules {
+            min_instances_per_region: 1,
+            ..SafetyRules::default()
+        });
+        let result = engine.safety_check(&action);
+        assert!(
+            matches!(&result, SafetyResult::Blocked { reason } if reason.contains("minimum: 1")),
+            "{result:?}"
+        );
+
+        action.region_healthy_after_teardown = Some(1);
+        assert_eq!(engine.safety_check(&action), SafetyResult::Approved);
+
+        // An unknown count is not assumed to be enough.
+        action.region_healthy_after_teardown = None;
+        assert!(
+            matches!(&engine.safety_check(&action), SafetyResult::Blocked { reason } if reason.contains("unknown"))
+        );
+    }
+
+    #[test]
+    fn test_safety_allows_emptying_region_when_rule_disabled() {
+        let mut action = safe_action(ActionType::Teardown);
+        action.affected_instance_count = 1;
+        action.region_healthy_after_teardown = Some(0);
+
+        assert_eq!(eng().safety_check(&action), SafetyResult::Approved);
+    }
+
+    #[test]
+    fn test_safety_config_push_exactly_100_approved() {
+        let mut action = safe_action(ActionType::ConfigPush);
+        action.affected_instance_count = 100;
+        // 100 is NOT > 100, so it should pass
+
+        let result = eng().safety_check(&action);
+        assert_eq!(result, SafetyResult::Approved);
+    }
+
+    #[test]
+    fn test_safety_teardown_standby_confirmed_approved() {
+        let mut action = safe_action(ActionType::Teardown);
+        action.is_primary_teardown = true;
+        action.standby_confirmed_active = true;
+        action.has_audit_log_entry = true;
+
+        let result = eng().safety_check(&action);
+        assert_eq!(result, SafetyResult::Approved);
+    }
+
+    #[test]
+    fn test_safety_teardown_cost_under_threshold_approved() {
+        let mut action = safe_action(ActionType::Teardown);
+        action.affected_users = 1;
+        action.teardown_monthly_cost_usd = 40.0;
+
+        assert_eq!(eng().safety_check(&action), SafetyResult::Approved);
+    }
+
+    #[test]
+    fn test_safety_teardown_cost_over_threshold_requires_confirmation() {
+        let mut action = safe_action(ActionType::Teardown);
+        action.affected_users = 1;
+        action.teardown_monthly_cost_usd = 60.0;
+
+        let result = eng().safety_check(&action);
+        assert!(
+            matches!(&result, SafetyResult::RequiresConfirmation { reason } if reason.contains("$60.00")),
+            "expected RequiresConfirmation, got {result:?}"
+        );
+
+        // Cost guard only applies to teardowns.
+        let mut resize = safe_action(ActionType::TierResize);
+        resize.teardown_monthly_cost_usd = 60.0;
+        assert_eq!(eng().safety_check(&resize), SafetyResult::Approved);
+    }
+
+    #[test]
+    fn test_safety_failover_inactive_standby_blocked() {
+        let mut failover = safe_action(ActionType::Failover);
+        failover.standby_confirmed_active = false;
+        let result = eng().safety_check(&failover);
+        assert!(
+            matches!(&result, SafetyResult::Blocked { reason } if reason.contains("standby")),
+            "expected Blocked, got {result:?}"
+        );
+
+        failover.standby_confirmed_active = true;
+        assert_eq!(eng().safety_check(&failover), SafetyResult::Approved);
+    }
+
+    #[test]
+    fn test_safety_resize_many_users_requires_confirmation() {
+        let mut resize = safe_action(ActionType::TierResize);
+        resize.affected_users = 30;
+        let result = eng().safety_check(&resize);
+        assert!(
+            matches!(&result, SafetyResult::RequiresConfirmation { reason } if reason.contains("30 users")),
+            "expected RequiresConfirmation, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn test_safety_offline_resize_requires_confirmation() {
+        let mut resize = safe_action(ActionType::TierResize);
+        resize.estimated_downtime_secs = Some(claw_provision::OFFLINE_RESIZE_DOWNTIME_SECS);
+        let result = eng().safety_check(&resize);
+        assert!(
+            matches!(&result, SafetyResult::RequiresConfirmation { reason } if reason.contains("downtime")),
+            "expected RequiresConfirmation, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn test_safety_resize_without_downtime_estimate_requires_confirmation() {
+        let mut resize = safe_action(ActionType::TierResize);
+        assert_eq!(eng().safety_check(&resize), SafetyResult::Approved);
+
+        resize.estimated_downtime_secs = None;
+        let result = eng().safety_check(&resize);
+        assert!(
+            matches!(&result, SafetyResult::RequiresConfirmation { reason } if reason.contains("no downtime estimate")),
+            "expected RequiresConfirmation, got {result:?}"
+        );
+    }
+
+    // ─── Response synthesis ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_synthesize_empty() {
+        let r = eng().synthesize_response(vec![]);
+        assert!(r.contains("No results"));
+    }
+
+    #[test]
+    fn test_synthesize_fleet_status() {
+        let results = vec![SpecialistResult::FleetStatusResult {
+            summary: "All pairs healthy.".to_string(),
+            active_pairs: 847,
+        }];
+        let r = eng().synthesize_response(results);
+        assert!(r.contains("[CMD]"));
+        assert!(r.contains("847"));
+    }
+
+    #[test]
+    fn test_synthesize_multiple_results() {
+        let results = vec![
+            SpecialistResult::FleetStatusResult {
+                summary: "Nominal.".to_string(),
+                active_pairs: 100,
+            },
+            SpecialistResult::CostResult {
+                waste_report: None,
+                summary: "$492/month recoverable.".to_string(),
+            },
+        ];
+        let r = eng().synthesize_response(results);
+        assert!(r.contains("[CMD]"));
+        assert!(r.contains("[Ledger]"));
+    }
+
+    #[test]
+    fn test_synthesize_provision_result() {
+        let results = vec![SpecialistResult::ProvisionResult {
+            success_count: 20,
+            failed_count: 0,
+            summary: "All pairs ACTIVE.".to_string(),
+        }];
+        let r = eng().synthesize_response(results);
+        assert!(r.contains("20/20"));
+    }
 
     #[test]
-    fn test_parse_teardown_idle() {
-        let intent = eng().parse_intent("Teardown idle accounts");
+    fn test_safety_rules_default_values() {
+        let rules = SafetyRules::default();
+        assert_eq!(rules.max_affected_users_without_confirm, 10);
+        assert_eq!(rules.max_cost_spike_percent, 20.0);
+        assert!(rules.require_standby_before_teardown);
+        assert_eq!(rules.max_instances_direct_config_push, 100);
+        assert!(rules.require_audit_before_delete);
+    }
+
+    #[test]
+    fn test_capabilities_manifest_covers_every_intent() {
+        // Each variant names the one after it. The match is exhaustive, so a
+        // new variant does not compile until it is chained in here.
+        fn next_intent(intent: &OperatorIntent) -> Option<OperatorIntent> {
+            Some(match intent {
+                OperatorIntent::ProvisionRequest { .. } => OperatorIntent::TeardownRequest {
+                    scope: TeardownScope::IdleAccounts,
+                },
+                OperatorIntent::TeardownRequest { .. } => OperatorIntent::ResizeRequest {
+                    scope: ResizeScope::Unspecified,
+                    target_tier: None,
+                },
+                OperatorIntent::ResizeRequest { .. } => OperatorIntent::CostQuery {
+                    detail: CostQueryDetail::General,
+                },
+                OperatorIntent::CostQuery { .. } => OperatorIntent::HealthQuery {
+                    scope: HealthScope::Fleet,
+                },
+                OperatorIntent::HealthQuery { .. } => OperatorIntent::ConfigPush {
+                    instance_count_hint: None,
+                },
+                OperatorIntent::ConfigPush { .. } => OperatorIntent::IncidentQuery,
+                OperatorIntent::IncidentQuery => OperatorIntent::FleetStatus,
+                OperatorIntent::FleetStatus => OperatorIntent::BulkOperation {
+                    operation: "restart".to_string(),
+                    instance_count: 2,
+                },
+                OperatorIntent::BulkOperation { .. } => OperatorIntent::AuditQuery {
+                    actor: None,
+                    action: None,
+                    resource_id: None,
+                    within_hours: None,
+                },
+                OperatorIntent::AuditQuery { .. } => OperatorIntent::Unknown {
+                    raw_message: String::new(),
+                },
+                OperatorIntent::Unknown { .. } => return None,
+            })
+        }
+
+        let manifest = eng().capabilities_manifest();
+        let every_intent: Vec<OperatorIntent> = std::iter::successors(
+            Some(OperatorIntent::ProvisionRequest {
+                count: 1,
+                tier_hint: None,
+                ha: true,
+            }),
+            next_intent,
+        )
+        .collect();
+        for intent in &every_intent {
+            let name = match serde_json::to_value(intent).unwrap() {
+                serde_json::Value::String(s) => s,
+                serde_json::Value::Object(m) => m.keys().next().unwrap().clone(),
+                other => panic!("unexpected intent encoding {other}"),
+            };
+            assert!(
+                manifest.intents.iter().any(|c| c.intent == name),
+                "manifest is missing intent {name}"
+            );
+        }
+        assert_eq!(manifest.intents.len(), every_intent.len());
+
+        for prefix in claw_proto::NODE_COMMAND_PREFIXES {
+            assert!(manifest.command_prefixes.iter().any(|p| p == prefix));
+        }
+        let json = serde_json::to_value(&manifest).unwrap();
+        assert_eq!(json["intents"][0]["specialist"], "forge");
+    }
+
+    #[test]
+    fn test_intent_serialization() {
+        let intent = OperatorIntent::ProvisionRequest {
+            count: 20,
+            tier_hint: Some("standard".to_string()),
+            ha: true,
+        };
+        let json = serde_json::to_string(&intent).expect("serialize");
+        let back: OperatorIntent = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(intent, back);
+    }
+
+    // ─── Provider ranking ───────────────────────────────────────────────────
+
+    fn make_metrics(health: &[(&str, f64)]) -> FleetMetrics {
+        let mut fm = FleetMetrics::compute(&[]);
+        for (provider, score) in health {
+            fm.by_provider.insert(
+                provider.to_string(),
+                claw_metrics::ProviderMetrics {
+                    provider: provider.to_string(),
+                    instance_count: 10,
+                    avg_health_score: *score,
+                    avg_cpu_pct: 30.0,
+                    avg_mem_pct: 40.0,
+                    monthly_cost_usd: 120.0,
+                    healthy_pct: Some(*score),
+                    time_to_healthy: None,
+                },
+            );
+        }
+        fm
+    }
+
+    fn make_comparison(scores: &[(claw_proto::VpsProvider, f64)]) -> ProviderComparison {
+        ProviderComparison {
+            generated_at: Utc::now(),
+            entries: scores
+                .iter()
+                .map(|(provider, score)| claw_ledger::ProviderComparisonEntry {
+                    provider: *provider,
+                    instance_count: 10,
+                    avg_health_score: 90.0,
+                    avg_provision_secs: 250.0,
+                    failure_rate_pct: 1.0,
+                    cost_per_instance_usd: 12.0,
+                    overall_score: *score,
+                    recommendation: claw_ledger::ProviderRecommendation::Acceptable,
+                })
+                .collect(),
+            recommended_primary: claw_proto::VpsProvider::Hetzner,
+            recommended_standby: claw_proto::VpsProvider::Vultr,
+            currency: claw_ledger::Currency::Usd,
+        }
+    }
+
+    #[test]
+    fn test_reconcile_rankings_agree() {
+        use claw_proto::VpsProvider;
+        let metrics = make_metrics(&[("hetzner", 92.0), ("vultr", 80.0)]);
+        let comparison =
+            make_comparison(&[(VpsProvider::Hetzner, 88.0), (VpsProvider::Vultr, 76.0)]);
+        let ranking =
+            reconcile_provider_rankings(&metrics, &comparison, DEFAULT_DIVERGENCE_THRESHOLD);
+
+        assert_eq!(ranking.top(), Some("hetzner"));
+        assert!(!ranking.has_divergence());
+        assert!((ranking.entries[0].unified_score - 90.0).abs() < 0.001);
+        assert!((ranking.entries[1].unified_score - 78.0).abs() < 0.001);
+        assert!(ranking.entries.iter().all(|e| !e.divergent));
+    }
+
+    #[test]
+    fn test_reconcile_rankings_flags_divergence() {
+        use claw_proto::VpsProvider;
+        let metrics = make_metrics(&[("hetzner", 95.0), ("vultr", 40.0)]);
+        let comparison =
+            make_comparison(&[(VpsProvider::Hetzner, 90.0), (VpsProvider::Vultr, 85.0)]);
+        let ranking =
+            reconcile_provider_rankings(&metrics, &comparison, DEFAULT_DIVERGENCE_THRESHOLD);
+
+        assert!(ranking.has_divergence());
+        assert_eq!(ranking.divergence_notes.len(), 1);
+        assert!(ranking.divergence_notes[0].starts_with("vultr"));
+        let vultr = ranking
+            .entries
+            .iter()
+            .find(|e| e.provider == "vultr")
+            .unwrap();
+        assert!(vultr.divergent);
+        let hetzner = ranking
+            .entries
+            .iter()
+            .find(|e| e.provider == "hetzner")
+            .unwrap();
+        assert!(!hetzner.divergent);
+    }
+
+    #[test]
+    fn test_reconcile_rankings_single_source_provider() {
+        use claw_proto::VpsProvider;
+        let metrics = make_metrics(&[("hetzner", 90.0)]);
+        let comparison = make_comparison(&[(VpsProvider::Contabo, 60.0)]);
+        let ranking =
+            reconcile_provider_rankings(&metrics, &comparison, DEFAULT_DIVERGENCE_THRESHOLD);
+
+        assert_eq!(ranking.entries.len(), 2);
+        let contabo = ranking
+            .entries
+            .iter()
+            .find(|e| e.provider == "contabo")
+            .unwrap();
+        assert!(contabo.metrics_score.is_none());
+        assert!((contabo.unified_score - 60.0).abs() < 0.001);
+        assert!(!ranking.has_divergence());
+    }
+
+    #[test]
+    fn test_reconcile_rankings_uses_healthy_share_not_mean() {
+        use claw_proto::VpsProvider;
+        let mut snapshots: Vec<claw_metrics::InstanceSnapshot> = Vec::new();
+        for i in 0..10 {
+            let score = if i == 0 { 0.0 } else { 95.0 };
+            snapshots.push(claw_metrics::InstanceSnapshot {
+                instance_id: format!("i-{i}"),
+                account_id: format!("acc-{i}"),
+                provider: "hetzner".to_string(),
+                role: InstanceRole::Primary,
+                state: claw_proto::InstanceState::Active,
+                cpu_pct: 10.0,
+                mem_pct: 10.0,
+                disk_pct: 10.0,
+                health_score: score,
+                monthly_cost_usd: 12.0,
+                recorded_at: Utc::now(),
+                tier: None,
+                provider_instance_id: None,
+                region: None,
+            });
+        }
+        let metrics = FleetMetrics::compute(&snapshots);
+        let comparison = make_comparison(&[(VpsProvider::Hetzner, 90.0)]);
+        let ranking =
+            reconcile_provider_rankings(&metrics, &comparison, DEFAULT_DIVERGENCE_THRESHOLD);
+
+        // Mean health is 85.5, but 90% of instances are healthy.
+        assert_eq!(ranking.entries[0].metrics_score, Some(90.0));
+        assert!(!ranking.has_divergence());
+    }
+
+    // ─── Audit queries ──────────────────────────────────────────────────────
+
+    #[test]
+    fn test_parse_audit_who_deleted() {
+        let intent = eng().parse_intent("Who deleted acc-123 last week?");
         assert_eq!(
             intent,
-            OperatorIntent::TeardownRequest {
-                scope: TeardownScope::IdleAccounts
+            OperatorIntent::AuditQuery {
+                actor: None,
+                action: Some("teardown".to_string()),
+                resource_id: Some("acc-123".to_string()),
+                within_hours: Some(168),
             }
         );
     }
 
     #[test]
-    fn test_parse_config_push() {
-        let intent = eng().parse_intent("Push the new config to 847 instances");
+    fn test_who_without_audit_verb_is_not_an_audit_query() {
+        let engine = eng();
+        for message in ["who is on call", "who owns the fleet overview"] {
+            assert!(
+                !matches!(
+                    engine.parse_intent(message),
+                    OperatorIntent::AuditQuery { .. }
+                ),
+                "{message}"
+            );
+        }
+        assert!(matches!(
+            engine.parse_intent("who changed acc-7 yesterday"),
+            OperatorIntent::AuditQuery {
+                within_hours: Some(48),
+                ..
+            }
+        ));
+        assert!(matches!(
+            engine.parse_intent("who resized acc-7 in the past 3 days?"),
+            OperatorIntent::AuditQuery {
+                within_hours: Some(72),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_audit_log_for_actor() {
+        let intent = eng().parse_intent("Show audit log for forge");
+        assert_eq!(
+            intent,
+            OperatorIntent::AuditQuery {
+                actor: Some("forge".to_string()),
+                action: None,
+                resource_id: None,
+                within_hours: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_route_audit_query_handled_directly() {
+        let intent = eng().parse_intent("Show audit log for forge");
+        let action = eng().route_to_specialist(&intent).unwrap();
         assert!(
-            matches!(
-                intent,
-                OperatorIntent::ConfigPush {
-                    instance_count_hint: Some(847)
-                }
-            ),
+            matches!(action, SpecialistAction::HandleDirectly { ref task } if task.contains("forge")),
             "got {:?}",
-            intent
+            action
+        );
+    }
+
+    #[test]
+    fn test_answer_audit_query_filters_resource() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut store = AuditLogStore::new(dir.path());
+        for (id, actor, resource) in [
+            ("e-1", "forge", "acc-123"),
+            ("e-2", "forge", "acc-456"),
+            ("e-3", "ledger", "acc-123"),
+        ] {
+            store.append(AuditLogEntry {
+                id: id.to_string(),
+                timestamp: Utc::now(),
+                actor: actor.to_string(),
+                action: "teardown".to_string(),
+                resource: "instance".to_string(),
+                resource_id: Some(resource.to_string()),
+                result: "success".to_string(),
+                details: None,
+            });
+        }
+
+        let engine = eng();
+        let intent = engine.parse_intent("who deleted acc-123");
+        let result = engine
+            .answer_audit_query(&intent, &store)
+            .expect("audit result");
+        let SpecialistResult::AuditResult { ref entries, .. } = result else {
+            panic!("expected AuditResult");
+        };
+        assert_eq!(entries.len(), 2);
+        assert!(
+            entries
+                .iter()
+                .all(|e| e.resource_id.as_deref() == Some("acc-123"))
+        );
+
+        let response = engine.synthesize_response(vec![result]);
+        assert!(response.starts_with("[CMD] Audit: 2 audit entries"));
+        assert!(response.contains("ledger teardown acc-123"));
+    }
+
+    #[test]
+    fn test_answer_audit_query_applies_time_window() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut store = AuditLogStore::new(dir.path());
+        let now = Utc::now();
+        for (id, days_ago) in [("recent", 2), ("old", 10)] {
+            store.append(AuditLogEntry {
+                id: id.to_string(),
+                timestamp: now - chrono::Duration::days(days_ago),
+                actor: "forge".to_string(),
+                action: "teardown".to_string(),
+                resource: "instance".to_string(),
+                resource_id: Some("acc-123".to_string()),
+                result: "success".to_string(),
+                details: None,
+            });
+        }
+
+        let engine = eng();
+        let ids = |message: &str| {
+            let intent = engine.parse_intent(message);
+            let Some(SpecialistResult::AuditResult { entries, .. }) =
+                engine.answer_audit_query_at(&intent, &store, now)
+            else {
+                panic!("expected AuditResult");
+            };
+            entries.into_iter().map(|e| e.id).collect::<Vec<_>>()
+        };
+        assert_eq!(ids("who deleted acc-123 last week"), ["recent"]);
+        assert_eq!(ids("who deleted acc-123"), ["recent", "old"]);
+    }
+
+    // ─── Optimization applier ───────────────────────────────────────────────
+
+    #[derive(Debug, Default)]
+    struct RecordingProvider {
+        calls: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+        /// Registered name; defaults to "hetzner".
+        name: Option<&'static str>,
+        live_resize: bool,
+        /// Reported health score; health checks fail when unset.
+        health_score: Option<u8>,
+        /// Advertise and take snapshots.
+        snapshots: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl claw_provision::Provider for RecordingProvider {
+        fn name(&self) -> &str {
+            self.name.unwrap_or("hetzner")
+        }
+
+        async fn provision(
+            &self,
+            req: &ProvisionRequest,
+        ) -> anyhow::Result<claw_proto::ProvisionResult> {
+            self.calls.lock().unwrap().push("provision".to_string());
+            Ok(claw_proto::ProvisionResult {
+                request_id: req.request_id,
+                instance_id: Some(format!("{}-{}", req.account_id, req.role)),
+                success: true,
+                error: None,
+                provision_duration_ms: 1,
+                instance_ip: None,
+                tailscale_ip: None,
+                provider_instance_id: Some(format!("srv-{}", req.role)),
+            })
+        }
+
+        async fn teardown(&self, id: &str, _account_id: &str) -> anyhow::Result<()> {
+            self.calls.lock().unwrap().push(format!("teardown {id}"));
+            Ok(())
+        }
+
+        async fn resize(
+            &self,
+            id: &str,
+            tier: &InstanceTier,
+        ) -> anyhow::Result<claw_provision::ResizeResult> {
+            self.calls.lock().unwrap().push(format!("resize {id}"));
+            Ok(claw_provision::ResizeResult {
+                instance_id: id.to_string(),
+                old_tier: InstanceTier::Standard,
+                new_tier: *tier,
+                downtime_seconds: 0,
+                completed_at: Utc::now(),
+            })
+        }
+
+        async fn provider_health(&self) -> anyhow::Result<claw_provision::ProviderHealth> {
+            let Some(health_score) = self.health_score else {
+                anyhow::bail!("not used")
+            };
+            Ok(claw_provision::ProviderHealth {
+                provider: provider_from_name(self.name()).unwrap(),
+                api_reachable: true,
+                health_score,
+                provision_avg_ms: 1,
+                provision_success_rate_7d: 1.0,
+                active_incident: false,
+                incident_description: None,
+                quota_used_pct: 10.0,
+                checked_at: Utc::now(),
+            })
+        }
+
+        fn supported_regions(&self) -> Vec<claw_provision::Region> {
+            vec![]
+        }
+
+        fn supports_live_resize(&self) -> bool {
+            self.live_resize
+        }
+
+        fn capabilities(&self) -> claw_provision::ProviderCapabilities {
+            claw_provision::ProviderCapabilities {
+                live_resize: self.live_resize,
+                snapshot: self.snapshots,
+                ..Default::default()
+            }
+        }
+
+        async fn snapshot(&self, id: &str) -> anyhow::Result<String> {
+            self.calls.lock().unwrap().push(format!("snapshot {id}"));
+            Ok(format!("snap-{id}"))
+        }
+    }
+
+    fn make_target(role: InstanceRole) -> ApplyTarget {
+        ApplyTarget {
+            provider: VpsProvider::Hetzner,
+            provider_instance_id: "srv-1".to_string(),
+            role,
+            tier: InstanceTier::Standard,
+            standby_confirmed_active: false,
+            affected_users: 1,
+            monthly_cost_usd: 10.0,
+            migration_region: None,
+            os_image: OsImage::default(),
+            region: None,
+            fleet: Vec::new(),
+        }
+    }
+
+    fn make_optimization(optimization_type: OptimizationType) -> Optimization {
+        Optimization {
+            instance_id: "i-1".to_string(),
+            account_id: "acc-1".to_string(),
+            optimization_type,
+            estimated_savings_monthly_usd: 4.0,
+            confidence: claw_ledger::OptimizationConfidence::High,
+            requires_confirmation: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_apply_downsize_resizes_instance() {
+        let provider = RecordingProvider::default();
+        let calls = provider.calls.clone();
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(provider));
+        let provisioner = FleetProvisioner::new(&registry);
+        let dir = tempfile::tempdir().unwrap();
+        let mut audit = AuditLogger::new(dir.path());
+        let mut applier = OptimizationApplier::new(&provisioner, &SafetyRules::default())
+            .with_audit_logger(&mut audit);
+
+        let opt = make_optimization(OptimizationType::Downsize {
+            from_tier: InstanceTier::Standard,
+            to_tier: InstanceTier::Nano,
+        });
+        let target = make_target(InstanceRole::Primary);
+        // Hetzner resizes offline, so the downtime needs a confirmation.
+        let result = applier.apply(&opt, &target).await;
+        assert!(
+            matches!(&result.outcome, ApplyOutcome::RequiresConfirmation { reason } if reason.contains("downtime")),
+            "{:?}",
+            result.outcome
+        );
+        assert!(calls.lock().unwrap().is_empty());
+
+        let result = applier.apply_confirmed(&opt, &target, "op-ok").await;
+        assert!(result.is_applied(), "{:?}", result.outcome);
+        assert_eq!(*calls.lock().unwrap(), vec!["resize srv-1".to_string()]);
+        assert!((result.monthly_cost_before_usd - 10.0).abs() < 0.001);
+        assert!((result.monthly_cost_after_usd - 6.0).abs() < 0.001);
+        assert_eq!(
+            audit
+                .query(
+                    None,
+                    Some("i-1"),
+                    None,
+                    Some(AuditAction::ResizeInstance),
+                    10
+                )
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_apply_audits_the_real_outcome() {
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(RecordingProvider::default()));
+        let provisioner = FleetProvisioner::new(&registry);
+        let dir = tempfile::tempdir().unwrap();
+        let mut audit = AuditLogger::new(dir.path());
+        let mut applier = OptimizationApplier::new(&provisioner, &SafetyRules::default())
+            .with_audit_logger(&mut audit);
+
+        let opt = make_optimization(OptimizationType::Downsize {
+            from_tier: InstanceTier::Standard,
+            to_tier: InstanceTier::Nano,
+        });
+        // No Vultr backend is registered, so the resize fails.
+        let target = ApplyTarget {
+            provider: VpsProvider::Vultr,
+            ..make_target(InstanceRole::Standby)
+        };
+        let result = applier.apply_confirmed(&opt, &target, "op-ok").await;
+        assert!(matches!(result.outcome, ApplyOutcome::Failed { .. }));
+
+        let records = audit.query(
+            None,
+            Some("i-1"),
+            None,
+            Some(AuditAction::ResizeInstance),
+            10,
+        );
+        assert_eq!(records.len(), 1);
+        assert!(!records[0].result.success);
+        assert!(records[0].result.message.contains("optimization failed"));
+    }
+
+    #[tokio::test]
+    async fn test_apply_records_resize_and_teardown_history() {
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(RecordingProvider {
+            live_resize: true,
+            ..Default::default()
+        }));
+        let provisioner = FleetProvisioner::new(&registry);
+        let dir = tempfile::tempdir().unwrap();
+        let mut audit = AuditLogger::new(dir.path());
+        let mut history = AuditLogStore::new(dir.path());
+        let mut applier = OptimizationApplier::new(&provisioner, &SafetyRules::default())
+            .with_audit_logger(&mut audit)
+            .with_history_log(&mut history);
+
+        let resize = make_optimization(OptimizationType::Downsize {
+            from_tier: InstanceTier::Standard,
+            to_tier: InstanceTier::Nano,
+        });
+        let standby = make_target(InstanceRole::Standby);
+        assert!(applier.apply(&resize, &standby).await.is_applied());
+        let teardown = make_optimization(OptimizationType::Teardown { idle_days: 30 });
+        assert!(applier.apply(&teardown, &standby).await.is_applied());
+
+        let entries = history.history_for_instance("i-1");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].action, "resize");
+        assert_eq!(entries[0].result, "success");
+        let details: serde_json::Value =
+            serde_json::from_str(entries[0].details.as_deref().unwrap()).unwrap();
+        assert_eq!(details["old_tier"], "standard");
+        assert_eq!(details["new_tier"], "nano");
+        assert_eq!(details["downtime_seconds"], 0);
+        assert_eq!(details["requested_by"], "commander");
+
+        assert_eq!(entries[1].action, "teardown");
+        assert!(
+            entries[1]
+                .details
+                .as_deref()
+                .unwrap()
+                .contains("idle for 30 days")
+        );
+        assert!(history.history_for_instance("i-2").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_apply_records_migration_with_snapshot() {
+        let source = RecordingProvider {
+            snapshots: true,
+            ..Default::default()
+        };
+        let calls = source.calls.clone();
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(source));
+        registry.register(Box::new(RecordingProvider {
+            name: Some("vultr"),
+            calls: calls.clone(),
+            ..Default::default()
+        }));
+        let provisioner = FleetProvisioner::new(&registry);
+        let dir = tempfile::tempdir().unwrap();
+        let mut audit = AuditLogger::new(dir.path());
+        let mut history = AuditLogStore::new(dir.path());
+        let mut applier = OptimizationApplier::new(&provisioner, &SafetyRules::default())
+            .with_audit_logger(&mut audit)
+            .with_history_log(&mut history);
+
+        let migrate = make_optimization(OptimizationType::Migrate {
+            from_provider: VpsProvider::Hetzner,
+            to_provider: VpsProvider::Vultr,
+        });
+        let target = ApplyTarget {
+            migration_region: Some("eu-vultr-ams".to_string()),
+            ..make_target(InstanceRole::Standby)
+        };
+        let result = applier.apply(&migrate, &target).await;
+        assert!(result.is_applied(), "{:?}", result.outcome);
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec!["provision", "snapshot srv-1", "teardown srv-1"]
+        );
+
+        let entries = history.history_for_instance("i-1");
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].action, "migrate");
+        let details: serde_json::Value =
+            serde_json::from_str(entries[0].details.as_deref().unwrap()).unwrap();
+        assert_eq!(details["from_provider"], "hetzner");
+        assert_eq!(details["to_provider"], "vultr");
+        assert_eq!(details["snapshot_id"], "snap-srv-1");
+        assert_eq!(details["replacement_instance_id"], "acc-1-standby");
+    }
+
+    #[tokio::test]
+    async fn test_apply_migration_boots_the_instances_os_image() {
+        let source = RecordingProvider::default();
+        let calls = source.calls.clone();
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(source));
+        registry.register(Box::new(RecordingProvider {
+            name: Some("vultr"),
+            calls: calls.clone(),
+            ..Default::default()
+        }));
+        let provisioner = FleetProvisioner::new(&registry);
+        let dir = tempfile::tempdir().unwrap();
+        let mut audit = AuditLogger::new(dir.path());
+        let mut applier = OptimizationApplier::new(&provisioner, &SafetyRules::default())
+            .with_audit_logger(&mut audit);
+
+        // The target provider does not offer the instance's image, so the
+        // replacement is refused rather than booted on the default.
+        let migrate = make_optimization(OptimizationType::Migrate {
+            from_provider: VpsProvider::Hetzner,
+            to_provider: VpsProvider::Vultr,
+        });
+        let target = ApplyTarget {
+            migration_region: Some("eu-vultr-ams".to_string()),
+            os_image: OsImage::Debian12,
+            ..make_target(InstanceRole::Standby)
+        };
+        let result = applier.apply(&migrate, &target).await;
+        assert!(
+            matches!(&result.outcome, ApplyOutcome::Failed { error } if error.contains("debian-12")),
+            "{:?}",
+            result.outcome
+        );
+        assert!(calls.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_apply_blocked_primary_teardown_does_not_execute() {
+        let provider = RecordingProvider::default();
+        let calls = provider.calls.clone();
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(provider));
+        let provisioner = FleetProvisioner::new(&registry);
+        let dir = tempfile::tempdir().unwrap();
+        let mut audit = AuditLogger::new(dir.path());
+        let mut applier = OptimizationApplier::new(&provisioner, &SafetyRules::default())
+            .with_audit_logger(&mut audit);
+
+        let opt = make_optimization(OptimizationType::Teardown { idle_days: 30 });
+        let result = applier
+            .apply(&opt, &make_target(InstanceRole::Primary))
+            .await;
+
+        assert!(matches!(result.outcome, ApplyOutcome::Blocked { .. }));
+        assert!(calls.lock().unwrap().is_empty());
+        assert!((result.monthly_cost_after_usd - 10.0).abs() < 0.001);
+    }
+
+    #[tokio::test]
+    async fn test_apply_teardown_is_blocked_when_the_audit_intent_cannot_be_written() {
+        let provider = RecordingProvider::default();
+        let calls = provider.calls.clone();
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(provider));
+        let provisioner = FleetProvisioner::new(&registry);
+        let dir = tempfile::tempdir().unwrap();
+        // A file where the state directory should be makes every save fail.
+        std::fs::write(dir.path().join("state"), b"").unwrap();
+        let mut audit = AuditLogger::new(dir.path());
+        let mut applier = OptimizationApplier::new(&provisioner, &SafetyRules::default())
+            .with_audit_logger(&mut audit);
+
+        let opt = make_optimization(OptimizationType::Teardown { idle_days: 30 });
+        let result = applier
+            .apply(&opt, &make_target(InstanceRole::Standby))
+            .await;
+
+        assert!(
+            matches!(&result.outcome, ApplyOutcome::Blocked { reason } if reason.contains("audit")),
+            "{:?}",
+            result.outcome
+        );
+        assert!(calls.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_apply_teardown_respects_region_minimum() {
+        let provider = RecordingProvider::default();
+        let calls = provider.calls.clone();
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(provider));
+        let provisioner = FleetProvisioner::new(&registry);
+        let dir = tempfile::tempdir().unwrap();
+        let mut audit = AuditLogger::new(dir.path());
+        let rules = SafetyRules {
+            min_instances_per_region: 1,
+            ..SafetyRules::default()
+        };
+        let mut applier =
+            OptimizationApplier::new(&provisioner, &rules).with_audit_logger(&mut audit);
+        let opt = make_optimization(OptimizationType::Teardown { idle_days: 30 });
+
+        // Without a region and fleet the remaining count is unknown.
+        let unknown = make_target(InstanceRole::Standby);
+        let result = applier.apply(&opt, &unknown).await;
+        assert!(
+            matches!(&result.outcome, ApplyOutcome::Blocked { reason } if reason.contains("unknown")),
+            "{:?}",
+            result.outcome
+        );
+
+        // i-1 is the only healthy instance in nbg1.
+        let mut fleet = vec![
+            make_fleet_member("i-1", "acc-1", InstanceRole::Standby),
+            make_fleet_member("i-2", "acc-2", InstanceRole::Primary),
+        ];
+        fleet[0].region = Some("nbg1".to_string());
+        fleet[1].region = Some("ams".to_string());
+        let target = ApplyTarget {
+            region: Some("nbg1".to_string()),
+            fleet: fleet.clone(),
+            ..make_target(InstanceRole::Standby)
+        };
+        let result = applier.apply(&opt, &target).await;
+        assert!(
+            matches!(&result.outcome, ApplyOutcome::Blocked { reason } if reason.contains("0 healthy instance(s)")),
+            "{:?}",
+            result.outcome
+        );
+        assert!(calls.lock().unwrap().is_empty());
+
+        fleet[1].region = Some("nbg1".to_string());
+        let target = ApplyTarget { fleet, ..target };
+        assert!(applier.apply(&opt, &target).await.is_applied());
+    }
+
+    #[tokio::test]
+    async fn test_apply_teardown_without_audit_logger_is_blocked() {
+        let provider = RecordingProvider::default();
+        let calls = provider.calls.clone();
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(provider));
+        let provisioner = FleetProvisioner::new(&registry);
+        let mut applier = OptimizationApplier::new(&provisioner, &SafetyRules::default());
+
+        let opt = make_optimization(OptimizationType::Teardown { idle_days: 30 });
+        let result = applier
+            .apply(&opt, &make_target(InstanceRole::Standby))
+            .await;
+
+        assert!(matches!(result.outcome, ApplyOutcome::Blocked { .. }));
+        assert!(calls.lock().unwrap().is_empty());
+    }
+
+    // ─── Errors ─────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_route_message_ambiguous_returns_candidates() {
+        let err = eng()
+            .route_message("what's the cost and health status of the fleet")
+            .unwrap_err();
+        let CommanderError::AmbiguousIntent { candidates } = err else {
+            panic!("expected AmbiguousIntent, got {err:?}");
+        };
+        assert_eq!(
+            candidates,
+            vec![
+                OperatorIntent::CostQuery {
+                    detail: CostQueryDetail::General
+                },
+                OperatorIntent::HealthQuery {
+                    scope: HealthScope::Fleet
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_route_message_single_intent_routes() {
+        let action = eng().route_message("Provision 20 standard pairs").unwrap();
+        assert!(matches!(action, SpecialistAction::SpawnForge { .. }));
+        // "tear down" alone is not read as an outage.
+        let action = eng().route_message("Tear down 5 instances").unwrap();
+        assert!(matches!(action, SpecialistAction::SpawnForge { .. }));
+        // The generic fleet match does not compete with a specific one.
+        let action = eng().route_message("fleet health").unwrap();
+        assert!(matches!(action, SpecialistAction::SendToGuardian { .. }));
+    }
+
+    #[test]
+    fn test_route_unknown_is_error() {
+        let err = eng().route_message("Hello there").unwrap_err();
+        assert!(matches!(err, CommanderError::NoSpecialistAvailable { .. }));
+    }
+
+    #[test]
+    fn test_ensure_safe_surfaces_violation() {
+        let mut action = safe_action(ActionType::Teardown);
+        action.has_audit_log_entry = false;
+        assert!(matches!(
+            eng().ensure_safe(&action),
+            Err(CommanderError::SafetyViolation(
+                SafetyResult::Blocked { .. }
+            ))
+        ));
+        assert!(
+            eng()
+                .ensure_safe(&safe_action(ActionType::Provision))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_preview_tier_changes_mixes_live_and_offline_resizes() {
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(RecordingProvider::default()));
+        registry.register(Box::new(RecordingProvider {
+            name: Some("vultr"),
+            live_resize: true,
+            ..Default::default()
+        }));
+        let provisioner = FleetProvisioner::new(&registry);
+        let applier = OptimizationApplier::new(&provisioner, &SafetyRules::default());
+
+        let downsize = OptimizationType::Downsize {
+            from_tier: InstanceTier::Standard,
+            to_tier: InstanceTier::Nano,
+        };
+        let mut recommendations = Vec::new();
+        let mut targets = HashMap::new();
+        for (i, provider) in [
+            VpsProvider::Hetzner,
+            VpsProvider::Hetzner,
+            VpsProvider::Vultr,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let mut opt = make_optimization(downsize.clone());
+            opt.instance_id = format!("i-{i}");
+            opt.account_id = format!("acc-{}", i % 2);
+            recommendations.push(opt);
+            targets.insert(
+                format!("i-{i}"),
+                ApplyTarget {
+                    provider,
+                    ..make_target(InstanceRole::Primary)
+                },
+            );
+        }
+        // Not a tier change; excluded from the preview.
+        recommendations.push(make_optimization(OptimizationType::Teardown {
+            idle_days: 30,
+        }));
+
+        let preview = applier.preview_tier_changes(&recommendations, &targets);
+        assert_eq!(preview.instance_count, 3);
+        assert!((preview.monthly_savings_usd - 12.0).abs() < 0.001);
+        assert_eq!(
+            preview.estimated_downtime_secs,
+            2 * claw_provision::OFFLINE_RESIZE_DOWNTIME_SECS
+        );
+        assert_eq!(preview.live_resizes, 1);
+        assert_eq!(preview.affected_accounts, vec!["acc-0", "acc-1"]);
+        assert_eq!(
+            preview.summary(),
+            "downsizing 3 instances saves $12/mo with ~40 s total downtime"
+        );
+    }
+
+    #[test]
+    fn test_tier_change_preview_summary_in_minutes() {
+        let preview = TierChangePreview {
+            instance_count: 18,
+            monthly_savings_usd: 216.0,
+            estimated_downtime_secs: 18 * claw_provision::OFFLINE_RESIZE_DOWNTIME_SECS,
+            ..Default::default()
+        };
+        assert_eq!(
+            preview.summary(),
+            "downsizing 18 instances saves $216/mo with ~6 min total downtime"
         );
     }
 
+    // ─── Fleet risk ─────────────────────────────────────────────────────────
+
+    fn make_sweep(
+        total: u32,
+        healthy: u32,
+        degraded: u32,
+        critical: u32,
+    ) -> FleetHealthSweepResult {
+        FleetHealthSweepResult {
+            total_instances: total,
+            healthy,
+            degraded,
+            critical,
+            ..FleetHealthSweepResult::new()
+        }
+    }
+
+    fn make_projection(trajectory: CostTrajectory, variance_pct: f64) -> CostProjection {
+        CostProjection {
+            generated_at: Utc::now(),
+            period_days: 30,
+            current_daily_usd: 40.0,
+            projected_total_usd: 1200.0,
+            actual_to_date_usd: 600.0,
+            variance_pct,
+            trajectory,
+            currency: Default::default(),
+        }
+    }
+
+    fn make_pair(pair_id: &str, standby_region: &str) -> InstancePairStatus {
+        let instance = |id: &str, region: &str| claw_proto::InstanceStatus {
+            instance_id: id.to_string(),
+            state: claw_proto::InstanceState::Active,
+            health_score: 95,
+            provider: VpsProvider::Hetzner,
+            region: region.to_string(),
+            ip_public: None,
+        };
+        InstancePairStatus {
+            pair_id: pair_id.to_string(),
+            account_id: format!("acc-{pair_id}"),
+            primary: instance("p", "nbg1"),
+            standby: Some(instance("s", standby_region)),
+            pair_health: 95,
+            checked_at: Utc::now(),
+        }
+    }
+
     #[test]
-    fn test_parse_incident_query() {
-        let intent = eng().parse_intent("Hetzner Nuremberg looks down. What's our exposure?");
-        assert_eq!(intent, OperatorIntent::IncidentQuery);
+    fn test_fleet_risk_low_for_healthy_fleet() {
+        let risk = FleetRiskScore::compute(
+            &FleetMetrics::compute(&[]),
+            &make_sweep(100, 100, 0, 0),
+            &[],
+            &make_projection(CostTrajectory::OnTrack, 1.0),
+            0,
+        );
+        assert_eq!(risk.score, 0);
+        assert_eq!(risk.level, RiskLevel::Low);
+        assert!(risk.factors.is_empty());
     }
 
     #[test]
-    fn test_parse_health_query() {
-        let intent = eng().parse_intent("What's the fleet health?");
-        assert!(
-            matches!(intent, OperatorIntent::HealthQuery { .. }),
-            "got {:?}",
-            intent
+    fn test_fleet_risk_rises_with_spofs_and_cost_anomaly() {
+        let pairs: Vec<_> = (0..4)
+            .map(|i| make_pair(&format!("pair-{i}"), "nbg1"))
+            .chain([make_pair("pair-ok", "fsn1")])
+            .collect();
+        let spof = SpofFinding::detect(&pairs);
+        assert_eq!(spof.len(), 5);
+        assert_eq!(spof[0].kind, SpofKind::SameRegion);
+        assert_eq!(spof[4].kind, SpofKind::SameProvider);
+        assert_eq!(
+            spof[0].timeline_entry(Utc::now()).event,
+            Some(TimelineEvent::SinglePointOfFailure {
+                account_id: spof[0].account_id.clone(),
+                kind: SpofKind::SameRegion,
+            })
+        );
+
+        let risk = FleetRiskScore::compute(
+            &FleetMetrics::compute(&[]),
+            &make_sweep(100, 90, 10, 0),
+            &spof,
+            &make_projection(CostTrajectory::Anomaly, 22.0),
+            1,
         );
+        // 25 (SPOF cap) + 20 (anomaly) + 10 (incident) + 1.75 (health)
+        assert_eq!(risk.score, 57);
+        assert_eq!(risk.level, RiskLevel::High);
+        let names: Vec<&str> = risk.factors.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["spof", "cost", "incidents", "health"]);
+        assert!(risk.factors[1].detail.contains("+22.0%"));
     }
 
-    #[test]
-    fn test_parse_fleet_status() {
-        let intent = eng().parse_intent("Give me a fleet overview");
-        assert_eq!(intent, OperatorIntent::FleetStatus);
+    // ─── Provision pre-authorization ────────────────────────────────────────
+
+    fn make_pair_requests(count: usize, tier: InstanceTier) -> Vec<PairProvisionRequest> {
+        (0..count)
+            .map(|i| PairProvisionRequest {
+                account_id: format!("acc-{i}"),
+                tier,
+                primary_provider: VpsProvider::Hetzner,
+                primary_region: "nbg1".to_string(),
+                standby_provider: VpsProvider::Hetzner,
+                standby_region: "fsn1".to_string(),
+                os_image: OsImage::default(),
+                node: claw_provision::AccountNodeConfig {
+                    gateway_url: "https://gw.example".to_string(),
+                    auth_token: "token".to_string(),
+                    ssh_authorized_keys: Vec::new(),
+                },
+                requested_by: "test".to_string(),
+            })
+            .collect()
     }
 
-    #[test]
-    fn test_parse_unknown() {
-        let intent = eng().parse_intent("Hello there");
-        assert!(matches!(intent, OperatorIntent::Unknown { .. }));
+    #[tokio::test]
+    async fn test_provision_batch_within_budget_proceeds() {
+        let provider = RecordingProvider::default();
+        let calls = provider.calls.clone();
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(provider));
+        let provisioner = FleetProvisioner::new(&registry);
+        let budget = ProvisionBudget {
+            current_monthly_usd: 1000.0,
+            monthly_budget_usd: 2000.0,
+        };
+        let fleet = make_fleet(100);
+
+        let outcome = eng()
+            .provision_pairs_preauthorized(
+                &provisioner,
+                make_pair_requests(5, InstanceTier::Standard),
+                &budget,
+                &fleet,
+                None,
+            )
+            .await;
+        assert_eq!(outcome.authorization, SafetyResult::Approved);
+        assert!((outcome.estimate.added_monthly_usd - 120.0).abs() < 0.001);
+        assert_eq!(outcome.pairs.len(), 5);
+        assert!(outcome.failures.is_empty());
+        assert_eq!(calls.lock().unwrap().len(), 10);
     }
 
-    // ─── Routing ────────────────────────────────────────────────────────────
+    #[tokio::test]
+    async fn test_provisioned_pairs_feed_time_to_healthy_metrics() {
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(RecordingProvider::default()));
+        let provisioner = FleetProvisioner::new(&registry);
+        let budget = ProvisionBudget {
+            current_monthly_usd: 1000.0,
+            monthly_budget_usd: 2000.0,
+        };
+        let engine = eng();
+        let outcome = engine
+            .provision_pairs_preauthorized(
+                &provisioner,
+                make_pair_requests(1, InstanceTier::Standard),
+                &budget,
+                &make_fleet(10),
+                None,
+            )
+            .await;
+        let primary = outcome.pairs[0].primary.instance_id.clone().unwrap();
 
-    #[test]
-    fn test_route_provision_to_forge() {
-        let action = eng().route_to_specialist(&OperatorIntent::ProvisionRequest {
-            count: 20,
-            tier_hint: Some("standard".to_string()),
-        });
-        assert!(matches!(action, SpecialistAction::SpawnForge { .. }));
+        let check = |score: u8, after_secs: i64| HealthCheckResult {
+            instance_id: primary.clone(),
+            provider: VpsProvider::Hetzner,
+            region: "eu-hetzner-nbg1".to_string(),
+            health_score: score,
+            status: claw_proto::InstanceState::Active,
+            alerts: vec![],
+            recommended_action: claw_health::RecommendedAction::None,
+            checked_at: Utc::now() + chrono::Duration::seconds(after_secs),
+        };
+        engine.record_health_checks(&[check(5, 60)]);
+        engine.record_health_checks(&[check(90, 300)]);
+
+        let metrics = engine.fleet_metrics(&make_fleet(1));
+        let latency = metrics.by_provider["hetzner"].time_to_healthy.unwrap();
+        assert_eq!(latency.samples, 1);
+        assert!((299_000..=301_000).contains(&latency.p50_ms), "{latency:?}");
     }
 
-    #[test]
-    fn test_route_cost_to_ledger() {
-        let action = eng().route_to_specialist(&OperatorIntent::CostQuery {
-            detail: CostQueryDetail::Waste,
-        });
-        assert!(matches!(action, SpecialistAction::SendToLedger { .. }));
+    #[tokio::test]
+    async fn test_provision_batch_over_budget_requires_confirmation() {
+        let provider = RecordingProvider::default();
+        let calls = provider.calls.clone();
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(provider));
+        let provisioner = FleetProvisioner::new(&registry);
+        let budget = ProvisionBudget {
+            current_monthly_usd: 20_000.0,
+            monthly_budget_usd: 22_000.0,
+        };
+        let fleet = make_fleet(1_600);
+
+        let outcome = eng()
+            .provision_pairs_preauthorized(
+                &provisioner,
+                make_pair_requests(50, InstanceTier::Enterprise),
+                &budget,
+                &fleet,
+                None,
+            )
+            .await;
+        assert!((outcome.estimate.added_monthly_usd - 4800.0).abs() < 0.001);
+        assert_eq!(outcome.estimate.instance_count, 100);
+        match &outcome.authorization {
+            SafetyResult::RequiresConfirmation { reason } => {
+                assert!(reason.contains("exceeds budget"), "{reason}");
+            }
+            other => panic!("expected confirmation, got {other:?}"),
+        }
+        assert!(outcome.pairs.is_empty());
+        assert!(calls.lock().unwrap().is_empty());
     }
 
-    #[test]
-    fn test_route_incident_to_triage() {
-        let action = eng().route_to_specialist(&OperatorIntent::IncidentQuery);
-        assert!(matches!(action, SpecialistAction::SpawnTriage { .. }));
+    #[tokio::test]
+    async fn test_provision_batch_confirmation_must_match_estimate_id() {
+        let provider = RecordingProvider::default();
+        let calls = provider.calls.clone();
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(provider));
+        let provisioner = FleetProvisioner::new(&registry);
+        let budget = ProvisionBudget {
+            current_monthly_usd: 1000.0,
+            monthly_budget_usd: 1010.0,
+        };
+        let fleet = make_fleet(10);
+        let engine = eng();
+        let run = |confirmation: Option<String>| {
+            let engine = &engine;
+            let provisioner = &provisioner;
+            let budget = &budget;
+            let fleet = &fleet;
+            async move {
+                engine
+                    .provision_pairs_preauthorized(
+                        provisioner,
+                        make_pair_requests(1, InstanceTier::Standard),
+                        budget,
+                        fleet,
+                        confirmation.as_deref(),
+                    )
+                    .await
+            }
+        };
+
+        let shown = run(None).await;
+        let estimate_id = shown.estimate.estimate_id.clone();
+        assert!(
+            matches!(&shown.authorization, SafetyResult::RequiresConfirmation { reason } if reason.contains(&estimate_id))
+        );
+
+        // Any other string, including another batch's id, is not a confirmation.
+        let other =
+            BatchCostEstimate::for_pairs(&make_pair_requests(2, InstanceTier::Standard), &budget);
+        for wrong in ["yes", other.estimate_id.as_str()] {
+            let outcome = run(Some(wrong.to_string())).await;
+            assert!(outcome.pairs.is_empty(), "{wrong}");
+        }
+        assert!(calls.lock().unwrap().is_empty());
+
+        let outcome = run(Some(estimate_id)).await;
+        assert_eq!(outcome.pairs.len(), 1);
+        assert_eq!(calls.lock().unwrap().len(), 2);
     }
 
-    #[test]
-    fn test_route_health_to_guardian() {
-        let action = eng().route_to_specialist(&OperatorIntent::HealthQuery {
-            scope: HealthScope::Fleet,
+    #[tokio::test]
+    async fn test_provision_past_fleet_cap_is_rejected_before_any_call() {
+        let provider = RecordingProvider::default();
+        let calls = provider.calls.clone();
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(provider));
+        let provisioner = FleetProvisioner::new(&registry);
+        let engine = CommanderEngine::with_safety_rules(SafetyRules {
+            max_fleet_size: 100,
+            ..SafetyRules::default()
         });
-        assert!(matches!(action, SpecialistAction::SendToGuardian { .. }));
+        let budget = ProvisionBudget {
+            current_monthly_usd: 1000.0,
+            monthly_budget_usd: 100_000.0,
+        };
+        let mut fleet = make_fleet(96);
+        // Repeated snapshots of one instance count once.
+        fleet.push(fleet[0].clone());
+
+        // 2 pairs = 4 instances lands exactly on the cap.
+        let outcome = engine
+            .provision_pairs_preauthorized(
+                &provisioner,
+                make_pair_requests(2, InstanceTier::Standard),
+                &budget,
+                &fleet,
+                None,
+            )
+            .await;
+        assert_eq!(outcome.authorization, SafetyResult::Approved);
+        assert_eq!(outcome.pairs.len(), 2);
+        calls.lock().unwrap().clear();
+
+        // A third pair would overshoot, even with operator confirmation.
+        let outcome = engine
+            .provision_pairs_preauthorized(
+                &provisioner,
+                make_pair_requests(3, InstanceTier::Standard),
+                &budget,
+                &fleet,
+                Some("operator"),
+            )
+            .await;
+        match &outcome.authorization {
+            SafetyResult::Blocked { reason } => assert!(
+                reason.contains("96 instances running + 6 requested exceeds the maximum of 100"),
+                "{reason}"
+            ),
+            other => panic!("expected block, got {other:?}"),
+        }
+        assert!(outcome.pairs.is_empty());
+        assert!(calls.lock().unwrap().is_empty());
+        assert_eq!(
+            engine.check_fleet_size(96, 6),
+            Err(CommanderError::FleetSizeExceeded {
+                current: 96,
+                requested: 6,
+                max: 100
+            })
+        );
     }
 
-    #[test]
-    fn test_route_large_config_push_to_direct() {
-        let action = eng().route_to_specialist(&OperatorIntent::ConfigPush {
-            instance_count_hint: Some(847),
-        });
-        // > 100 instances → must handle directly (rolling push required)
-        assert!(matches!(action, SpecialistAction::HandleDirectly { .. }));
+    #[tokio::test]
+    async fn test_provision_rate_limited_per_actor() {
+        let provider = RecordingProvider::default();
+        let calls = provider.calls.clone();
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(provider));
+        let provisioner = FleetProvisioner::new(&registry);
+        let budget = ProvisionBudget {
+            current_monthly_usd: 1000.0,
+            monthly_budget_usd: 100_000.0,
+        };
+        let fleet = make_fleet(100);
+        let engine = eng().with_provision_limiter(RateLimiter::new(2).with_limit_for("forge", 4));
+
+        let outcome = engine
+            .provision_pairs_preauthorized(
+                &provisioner,
+                make_pair_requests(3, InstanceTier::Standard),
+                &budget,
+                &fleet,
+                None,
+            )
+            .await;
+        assert_eq!(outcome.pairs.len(), 2);
+        assert_eq!(outcome.failures.len(), 1);
+        let failure = &outcome.failures[0];
+        assert!(
+            failure
+                .error
+                .message()
+                .contains("rate limited: test may provision at most 2"),
+            "{failure:?}"
+        );
+        assert!(matches!(failure.error, ClawError::Transient(_)));
+        assert_eq!(calls.lock().unwrap().len(), 4);
+
+        // Forge has its own, higher budget, untouched by the runaway actor.
+        for _ in 0..4 {
+            engine
+                .check_provision_rate("forge:provision_single")
+                .unwrap();
+        }
+        assert_eq!(
+            engine.check_provision_rate("Forge:complete_pair"),
+            Err(CommanderError::RateLimited {
+                actor: "forge".to_string(),
+                limit: 4
+            })
+        );
     }
 
-    #[test]
-    fn test_route_small_config_push_to_guardian() {
-        let action = eng().route_to_specialist(&OperatorIntent::ConfigPush {
-            instance_count_hint: Some(50),
-        });
-        assert!(matches!(action, SpecialistAction::SendToGuardian { .. }));
+    #[tokio::test]
+    async fn test_gated_provisioner_enforces_cap_and_rate_on_every_path() {
+        let provider = RecordingProvider::default();
+        let calls = provider.calls.clone();
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(provider));
+        let engine = CommanderEngine::with_safety_rules(SafetyRules {
+            max_fleet_size: 100,
+            ..SafetyRules::default()
+        })
+        .with_provision_limiter(RateLimiter::new(10).with_limit_for("forge", 1));
+        let provisioner =
+            FleetProvisioner::new(&registry).with_gate(engine.provision_gate(&make_fleet(98)));
+
+        let pair = provisioner
+            .provision_pair(make_pair_requests(1, InstanceTier::Standard).remove(0))
+            .await
+            .unwrap();
+        assert!(pair.is_complete());
+
+        // The pair filled the fleet; a single instance is refused before any call.
+        calls.lock().unwrap().clear();
+        let err = provisioner
+            .provision_single(
+                "acc-x",
+                InstanceTier::Nano,
+                VpsProvider::Hetzner,
+                "fsn1",
+                OsImage::default(),
+                &claw_provision::AccountNodeConfig::default(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<CommanderError>(),
+            Some(CommanderError::FleetSizeExceeded { current: 100, .. })
+        ));
+        assert!(calls.lock().unwrap().is_empty());
+
+        // Below the cap, Forge's standby completions hit its rate limit.
+        let provisioner =
+            FleetProvisioner::new(&registry).with_gate(engine.provision_gate(&make_fleet(10)));
+        let partial = InstancePair {
+            standby: None,
+            ..pair
+        };
+        provisioner
+            .complete_pair(
+                &partial,
+                VpsProvider::Hetzner,
+                "fsn1",
+                &claw_provision::AccountNodeConfig::default(),
+            )
+            .await
+            .unwrap();
+        let err = provisioner
+            .complete_pair(
+                &partial,
+                VpsProvider::Hetzner,
+                "fsn1",
+                &claw_provision::AccountNodeConfig::default(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<CommanderError>(),
+            Some(CommanderError::RateLimited { limit: 1, .. })
+        ));
     }
 
-    // ─── Safety checks ──────────────────────────────────────────────────────
+    // ─── Two-phase teardown ─────────────────────────────────────────────────
 
-    fn safe_action(action_type: ActionType) -> Action {
-        Action {
-            action_type,
-            affected_users: 5,
-            affected_instance_count: 10,
-            is_primary_teardown: false,
-            standby_confirmed_active: true,
-            estimated_cost_change_pct: 0.0,
-            has_audit_log_entry: true,
+    fn make_fleet_member(
+        instance_id: &str,
+        account_id: &str,
+        role: InstanceRole,
+    ) -> InstanceSnapshot {
+        InstanceSnapshot {
+            instance_id: instance_id.to_string(),
+            account_id: account_id.to_string(),
+            provider: "hetzner".to_string(),
+            role,
+            state: claw_proto::InstanceState::Active,
+            cpu_pct: 1.0,
+            mem_pct: 1.0,
+            disk_pct: 10.0,
+            health_score: 95.0,
+            monthly_cost_usd: 12.0,
+            recorded_at: Utc::now(),
+            tier: Some(InstanceTier::Standard),
+            provider_instance_id: Some(format!("srv-{instance_id}")),
+            region: None,
         }
     }
 
-    #[test]
-    fn test_safety_approved_simple() {
-        let result = eng().safety_check(&safe_action(ActionType::Provision));
-        assert_eq!(result, SafetyResult::Approved);
+    fn make_idle(account_id: &str) -> IdleAccount {
+        IdleAccount {
+            account_id: account_id.to_string(),
+            last_activity: Utc::now() - chrono::Duration::days(30),
+            idle_days: 30,
+            monthly_cost_usd: 24.0,
+        }
     }
 
-    #[test]
-    fn test_safety_blocked_teardown_no_standby() {
-        let mut action = safe_action(ActionType::Teardown);
-        action.is_primary_teardown = true;
-        action.standby_confirmed_active = false;
+    /// `n` instances, paired two to an account.
+    fn make_fleet(n: usize) -> Vec<InstanceSnapshot> {
+        (0..n)
+            .map(|i| {
+                let role = if i % 2 == 0 {
+                    InstanceRole::Primary
+                } else {
+                    InstanceRole::Standby
+                };
+                make_fleet_member(&format!("i-{i}"), &format!("acc-{}", i / 2), role)
+            })
+            .collect()
+    }
 
-        let result = eng().safety_check(&action);
+    fn make_teardown_fleet() -> Vec<InstanceSnapshot> {
+        vec![
+            make_fleet_member("i-1", "acc-idle", InstanceRole::Primary),
+            make_fleet_member("i-2", "acc-idle", InstanceRole::Standby),
+            make_fleet_member("i-3", "acc-busy", InstanceRole::Primary),
+            make_fleet_member("i-4", "acc-busy", InstanceRole::Standby),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_teardown_fresh_proposal_executes() {
+        let provider = RecordingProvider::default();
+        let calls = provider.calls.clone();
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(provider));
+        let provisioner = FleetProvisioner::new(&registry);
+        let dir = tempfile::tempdir().unwrap();
+        let mut audit = AuditLogger::new(dir.path());
+
+        let mut fleet = make_teardown_fleet();
+        let proposal = eng()
+            .prepare_teardown(
+                &TeardownScope::IdleAccounts,
+                &fleet,
+                &[make_idle("acc-idle")],
+            )
+            .unwrap();
+        assert_eq!(proposal.accounts, vec!["acc-idle"]);
+        let ids: Vec<&str> = proposal
+            .instances
+            .iter()
+            .map(|i| i.instance_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["i-1", "i-2"]);
+        assert_eq!(proposal.total_monthly_recovery_usd, 24.0);
+        assert_eq!(
+            proposal.summary(),
+            "Tear down 2 instances across 1 account, recovering $24/mo"
+        );
+
+        // A metrics refresh between the phases does not make the plan stale.
+        fleet[0].cpu_pct = 3.0;
+        let token = proposal.confirm("operator");
+        let execution = eng()
+            .execute_teardown(&provisioner, &proposal, &token, &fleet, &mut audit)
+            .await
+            .unwrap();
+        assert_eq!(execution.torn_down, vec!["i-1", "i-2"]);
+        assert!(execution.failures.is_empty());
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![
+                "teardown srv-i-1".to_string(),
+                "teardown srv-i-2".to_string()
+            ]
+        );
+        assert_eq!(
+            audit
+                .query(
+                    None,
+                    Some("i-1"),
+                    None,
+                    Some(AuditAction::TeardownInstance),
+                    10
+                )
+                .len(),
+            1
+        );
         assert!(
-            matches!(result, SafetyResult::Blocked { .. }),
-            "expected Blocked, got {:?}",
-            result
+            audit
+                .query(
+                    None,
+                    Some("i-1"),
+                    None,
+                    Some(AuditAction::TeardownInstance),
+                    10
+                )
+                .iter()
+                .all(|r| r.result.success && r.result.message == "instance torn down")
+        );
+        assert_eq!(
+            audit
+                .query(
+                    None,
+                    Some("i-1"),
+                    None,
+                    Some(AuditAction::OperatorConfirmationReceived),
+                    10
+                )
+                .len(),
+            1
         );
     }
 
-    #[test]
-    fn test_safety_blocked_teardown_no_audit() {
-        let mut action = safe_action(ActionType::Teardown);
-        action.has_audit_log_entry = false;
+    #[tokio::test]
+    async fn test_teardown_rejects_proposal_altered_after_confirmation() {
+        let provider = RecordingProvider::default();
+        let calls = provider.calls.clone();
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(provider));
+        let provisioner = FleetProvisioner::new(&registry);
+        let dir = tempfile::tempdir().unwrap();
+        let mut audit = AuditLogger::new(dir.path());
 
-        let result = eng().safety_check(&action);
-        assert!(matches!(result, SafetyResult::Blocked { .. }));
+        let fleet = make_teardown_fleet();
+        let mut proposal = eng()
+            .prepare_teardown(
+                &TeardownScope::IdleAccounts,
+                &fleet,
+                &[make_idle("acc-idle")],
+            )
+            .unwrap();
+        let token = proposal.confirm("operator");
+
+        // Same proposal id, but a busy instance slipped into the plan.
+        let busy = ProposedTeardown {
+            instance_id: "i-3".to_string(),
+            account_id: "acc-busy".to_string(),
+            ..proposal.instances[0].clone()
+        };
+        proposal.instances.push(busy);
+        let err = eng()
+            .execute_teardown(&provisioner, &proposal, &token, &fleet, &mut audit)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            CommanderError::ProposalAltered {
+                proposal: proposal.proposal_id
+            }
+        );
+        assert!(calls.lock().unwrap().is_empty());
     }
 
-    #[test]
-    fn test_safety_blocked_config_push_too_large() {
-        let mut action = safe_action(ActionType::ConfigPush);
-        action.affected_instance_count = 150;
+    #[tokio::test]
+    async fn test_teardown_blocked_when_it_empties_a_region() {
+        let provider = RecordingProvider::default();
+        let calls = provider.calls.clone();
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(provider));
+        let provisioner = FleetProvisioner::new(&registry);
+        let dir = tempfile::tempdir().unwrap();
+        let mut audit = AuditLogger::new(dir.path());
+        let engine = CommanderEngine::with_safety_rules(SafetyRules {
+            min_instances_per_region: 1,
+            ..SafetyRules::default()
+        });
 
-        let result = eng().safety_check(&action);
-        assert!(matches!(result, SafetyResult::Blocked { .. }));
-    }
+        let mut fleet = make_teardown_fleet();
+        for (member, region) in fleet.iter_mut().zip(["nbg1", "ams", "nbg1", "fsn1"]) {
+            member.region = Some(region.to_string());
+        }
+        let proposal = engine
+            .prepare_teardown(
+                &TeardownScope::IdleAccounts,
+                &fleet,
+                &[make_idle("acc-idle")],
+            )
+            .unwrap();
+        let token = proposal.confirm("operator");
 
-    #[test]
-    fn test_safety_requires_confirmation_many_users() {
-        let mut action = safe_action(ActionType::BulkOperation);
-        action.affected_users = 50;
+        // nbg1 keeps i-3, but i-2 is the last instance in ams.
+        let err = engine
+            .execute_teardown(&provisioner, &proposal, &token, &fleet, &mut audit)
+            .await
+            .unwrap_err();
+        match err {
+            CommanderError::SafetyViolation(SafetyResult::Blocked { reason }) => {
+                assert!(reason.contains("0 healthy instance(s)"), "{reason}");
+                assert!(reason.contains("[ams]"), "{reason}");
+            }
+            other => panic!("expected block, got {other:?}"),
+        }
+        assert!(calls.lock().unwrap().is_empty());
 
-        let result = eng().safety_check(&action);
-        assert!(matches!(result, SafetyResult::RequiresConfirmation { .. }));
+        // Once ams has another healthy instance the plan goes ahead.
+        fleet[3].region = Some("ams".to_string());
+        let proposal = engine
+            .prepare_teardown(
+                &TeardownScope::IdleAccounts,
+                &fleet,
+                &[make_idle("acc-idle")],
+            )
+            .unwrap();
+        let token = proposal.confirm("operator");
+        let execution = engine
+            .execute_teardown(&provisioner, &proposal, &token, &fleet, &mut audit)
+            .await
+            .unwrap();
+        assert_eq!(execution.torn_down, vec!["i-1", "i-2"]);
     }
 
-    #[test]
-    fn test_safety_requires_confirmation_cost_spike() {
-        let mut action = safe_action(ActionType::Provision);
-        action.estimated_cost_change_pct = 25.0;
+    #[tokio::test]
+    async fn test_teardown_stale_proposal_is_rejected() {
+        let provider = RecordingProvider::default();
+        let calls = provider.calls.clone();
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(Box::new(provider));
+        let provisioner = FleetProvisioner::new(&registry);
+        let dir = tempfile::tempdir().unwrap();
+        let mut audit = AuditLogger::new(dir.path());
 
-        let result = eng().safety_check(&action);
-        assert!(matches!(result, SafetyResult::RequiresConfirmation { .. }));
-    }
+        let mut fleet = make_teardown_fleet();
+        let proposal = eng()
+            .prepare_teardown(
+                &TeardownScope::IdleAccounts,
+                &fleet,
+                &[make_idle("acc-idle")],
+            )
+            .unwrap();
+        let token = proposal.confirm("operator");
 
-    #[test]
-    fn test_safety_config_push_exactly_100_approved() {
-        let mut action = safe_action(ActionType::ConfigPush);
-        action.affected_instance_count = 100;
-        // 100 is NOT > 100, so it should pass
+        // Another proposal's confirmation does not authorize this one.
+        let other = eng()
+            .prepare_teardown(
+                &TeardownScope::IdleAccounts,
+                &fleet,
+                &[make_idle("acc-idle")],
+            )
+            .unwrap();
+        let err = eng()
+            .execute_teardown(&provisioner, &other, &token, &fleet, &mut audit)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CommanderError::ConfirmationMismatch { .. }));
 
-        let result = eng().safety_check(&action);
-        assert_eq!(result, SafetyResult::Approved);
+        // The account was re-provisioned onto a new server since.
+        fleet[0].provider_instance_id = Some("srv-new".to_string());
+        let err = eng()
+            .execute_teardown(&provisioner, &proposal, &token, &fleet, &mut audit)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            CommanderError::StaleProposal {
+                proposal: proposal.proposal_id
+            }
+        );
+        assert!(calls.lock().unwrap().is_empty());
+
+        let err = eng()
+            .prepare_teardown(&TeardownScope::Custom { count: 3 }, &fleet, &[])
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            CommanderError::UnresolvedTeardownScope { .. }
+        ));
     }
 
-    #[test]
-    fn test_safety_teardown_standby_confirmed_approved() {
-        let mut action = safe_action(ActionType::Teardown);
-        action.is_primary_teardown = true;
-        action.standby_confirmed_active = true;
-        action.has_audit_log_entry = true;
+    // ─── Mass failover ──────────────────────────────────────────────────────
 
-        let result = eng().safety_check(&action);
-        assert_eq!(result, SafetyResult::Approved);
+    fn healthy_provider(name: &'static str, health_score: u8) -> Box<RecordingProvider> {
+        Box::new(RecordingProvider {
+            name: Some(name),
+            health_score: Some(health_score),
+            ..RecordingProvider::default()
+        })
     }
 
-    // ─── Response synthesis ─────────────────────────────────────────────────
+    #[tokio::test]
+    async fn test_mass_failover_promotes_cross_provider_standby() {
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(healthy_provider("vultr", 90));
+        registry.register(healthy_provider("contabo", 80));
 
-    #[test]
-    fn test_synthesize_empty() {
-        let r = eng().synthesize_response(vec![]);
-        assert!(r.contains("No results"));
-    }
+        let mut standby = make_fleet_member("i-2", "acc-1", InstanceRole::Standby);
+        standby.provider = "vultr".to_string();
+        let affected = vec![
+            make_fleet_member("i-1", "acc-1", InstanceRole::Primary),
+            standby,
+            make_fleet_member("i-3", "acc-2", InstanceRole::Primary),
+            // Same-provider standby is down with the primary.
+            make_fleet_member("i-4", "acc-2", InstanceRole::Standby),
+            make_fleet_member("i-5", "acc-3", InstanceRole::Primary),
+        ];
 
-    #[test]
-    fn test_synthesize_fleet_status() {
-        let results = vec![SpecialistResult::FleetStatusResult {
-            summary: "All pairs healthy.".to_string(),
-            active_pairs: 847,
-        }];
-        let r = eng().synthesize_response(results);
-        assert!(r.contains("[CMD]"));
-        assert!(r.contains("847"));
+        let plan = plan_mass_failover(VpsProvider::Hetzner, &affected, &registry).await;
+        assert_eq!(plan.trigger, FailoverTrigger::ProviderOutage);
+        assert_eq!(
+            plan.promotions,
+            vec![StandbyPromotion {
+                account_id: "acc-1".to_string(),
+                failed_primary: "i-1".to_string(),
+                standby_instance_id: "i-2".to_string(),
+                standby_provider: "vultr".to_string(),
+            }]
+        );
+        assert_eq!(plan.reprovision_count(), 2);
+        let scheduled: Vec<(&str, VpsProvider)> = plan.reprovision_batches[0]
+            .iter()
+            .map(|r| (r.account_id.as_str(), r.target_provider))
+            .collect();
+        assert_eq!(
+            scheduled,
+            vec![
+                ("acc-2", VpsProvider::Vultr),
+                ("acc-3", VpsProvider::Contabo)
+            ]
+        );
+        assert!(plan.unplaceable.is_empty());
     }
 
-    #[test]
-    fn test_synthesize_multiple_results() {
-        let results = vec![
-            SpecialistResult::FleetStatusResult {
-                summary: "Nominal.".to_string(),
-                active_pairs: 100,
-            },
-            SpecialistResult::CostResult {
-                waste_report: None,
-                summary: "$492/month recoverable.".to_string(),
-            },
-        ];
-        let r = eng().synthesize_response(results);
-        assert!(r.contains("[CMD]"));
-        assert!(r.contains("[Ledger]"));
+    #[tokio::test]
+    async fn test_mass_failover_batches_reprovisions_and_skips_failed_provider() {
+        let mut registry = claw_provision::ProviderRegistry::new();
+        registry.register(healthy_provider("hetzner", 99));
+        registry.register(healthy_provider("vultr", 90));
+
+        let mut affected: Vec<InstanceSnapshot> = (0..12)
+            .map(|n| {
+                make_fleet_member(
+                    &format!("i-{n}"),
+                    &format!("acc-{n:02}"),
+                    InstanceRole::Primary,
+                )
+            })
+            .collect();
+        let mut degraded = make_fleet_member("i-s", "acc-00", InstanceRole::Standby);
+        degraded.provider = "vultr".to_string();
+        degraded.state = claw_proto::InstanceState::Degraded;
+        affected.push(degraded);
+
+        let plan = plan_mass_failover(VpsProvider::Hetzner, &affected, &registry).await;
+        assert!(plan.promotions.is_empty());
+        let sizes: Vec<usize> = plan.reprovision_batches.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![MASS_FAILOVER_BATCH_SIZE, 2]);
+        assert!(
+            plan.reprovision_batches
+                .iter()
+                .flatten()
+                .all(|r| r.target_provider == VpsProvider::Vultr)
+        );
+
+        let empty = claw_provision::ProviderRegistry::new();
+        let plan = plan_mass_failover(VpsProvider::Hetzner, &affected, &empty).await;
+        assert_eq!(plan.reprovision_count(), 0);
+        assert_eq!(plan.unplaceable.len(), 12);
     }
 
+    // ─── Error taxonomy ─────────────────────────────────────────────────────
+
     #[test]
-    fn test_synthesize_provision_result() {
-        let results = vec![SpecialistResult::ProvisionResult {
-            success_count: 20,
-            failed_count: 0,
-            summary: "All pairs ACTIVE.".to_string(),
-        }];
-        let r = eng().synthesize_response(results);
-        assert!(r.contains("20/20"));
+    fn test_commander_error_maps_to_claw_error() {
+        let limited = ClawError::from(CommanderError::RateLimited {
+            actor: "ops".to_string(),
+            limit: 10,
+        });
+        assert!(matches!(limited, ClawError::Transient(_)));
+        assert!(limited.is_retryable());
+        let capped = ClawError::from(CommanderError::FleetSizeExceeded {
+            current: 10,
+            requested: 1,
+            max: 10,
+        });
+        assert!(matches!(capped, ClawError::Safety(_)));
+        assert!(!capped.is_retryable());
+        assert!(matches!(
+            ClawError::from(CommanderError::StaleProposal {
+                proposal: uuid::Uuid::new_v4()
+            }),
+            ClawError::Safety(_)
+        ));
+        assert!(matches!(
+            ClawError::from(CommanderError::NoSpecialistAvailable {
+                intent: OperatorIntent::IncidentQuery
+            }),
+            ClawError::NotFound(_)
+        ));
+        assert!(matches!(
+            ClawError::from(CommanderError::AmbiguousIntent { candidates: vec![] }),
+            ClawError::Permanent(_)
+        ));
     }
 
-    #[test]
-    fn test_safety_rules_default_values() {
-        let rules = SafetyRules::default();
-        assert_eq!(rules.max_affected_users_without_confirm, 10);
-        assert_eq!(rules.max_cost_spike_percent, 20.0);
-        assert!(rules.require_standby_before_teardown);
-        assert_eq!(rules.max_instances_direct_config_push, 100);
-        assert!(rules.require_audit_before_delete);
+    #[tokio::test]
+    async fn test_run_specialist_retries_transient_failures_with_backoff() {
+        let sleeper = Arc::new(RecordingSleeper::default());
+        let engine = CommanderEngine::new().with_sleeper(sleeper.clone());
+        let calls = std::cell::Cell::new(0);
+        let result = engine
+            .run_specialist(|| {
+                calls.set(calls.get() + 1);
+                let n = calls.get();
+                async move {
+                    if n < 3 {
+                        Err(AuthError::RateLimitExceeded(10))
+                    } else {
+                        Ok("provisioned")
+                    }
+                }
+            })
+            .await;
+        assert_eq!(result, Ok("provisioned"));
+        assert_eq!(calls.get(), 3);
+        let slept = sleeper.slept.lock().unwrap().clone();
+        assert_eq!(
+            slept,
+            vec![
+                RetryPolicy::default().delay_for_attempt(0),
+                RetryPolicy::default().delay_for_attempt(1)
+            ]
+        );
     }
 
-    #[test]
-    fn test_intent_serialization() {
-        let intent = OperatorIntent::ProvisionRequest {
-            count: 20,
-            tier_hint: Some("standard".to_string()),
-        };
-        let json = serde_json::to_string(&intent).expect("serialize");
-        let back: OperatorIntent = serde_json::from_str(&json).expect("deserialize");
-        assert_eq!(intent, back);
+    #[tokio::test]
+    async fn test_run_specialist_stops_on_safety_and_permanent_failures() {
+        let sleeper = Arc::new(RecordingSleeper::default());
+        let engine = CommanderEngine::new().with_sleeper(sleeper.clone());
+        for error in [
+            ClawError::Safety("blocked".to_string()),
+            ClawError::Permanent("bad request".to_string()),
+        ] {
+            let calls = std::cell::Cell::new(0);
+            let result: Result<(), ClawError> = engine
+                .run_specialist(|| {
+                    calls.set(calls.get() + 1);
+                    let error = error.clone();
+                    async move { Err(error) }
+                })
+                .await;
+            assert_eq!(result, Err(error));
+            assert_eq!(calls.get(), 1);
+        }
+        assert!(sleeper.slept.lock().unwrap().is_empty());
+
+        // Transient failures give up once the retry budget is spent.
+        let calls = std::cell::Cell::new(0);
+        let result: Result<(), ClawError> = engine
+            .run_specialist(|| {
+                calls.set(calls.get() + 1);
+                async { Err(ClawError::Transient("timeout".to_string())) }
+            })
+            .await;
+        assert!(matches!(result, Err(ClawError::Transient(_))));
+        assert_eq!(calls.get(), 1 + RetryPolicy::default().max_retries);
     }
 }
@@ -90,13 +90,24 @@ impl ConfigStore {
             .collect()
     }
 
+    /// Write the current state to disk. Also runs on drop.
+    pub fn flush(&self) -> std::io::Result<()> {
+        self.store.save(&self.configs)
+    }
+
     fn snapshot(&self) {
-        if let Err(e) = self.store.save(&self.configs) {
+        if let Err(e) = self.flush() {
             warn!(error = %e, "failed to snapshot config store");
         }
     }
 }
 
+impl Drop for ConfigStore {
+    fn drop(&mut self) {
+        self.snapshot();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
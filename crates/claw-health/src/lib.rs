@@ -7,14 +7,21 @@
 #![forbid(unsafe_code)]
 
 use chrono::{DateTime, Utc};
-use claw_proto::{HealthReport, InstanceRole, InstanceState, ServiceStatus};
+use claw_metrics::{HEALTHY_INSTANCE_SCORE, InstanceSnapshot};
+use claw_persist::JsonStore;
+use claw_proto::{
+    HealthReport, InstanceRole, InstanceState, InstanceTier, ServiceStatus, VpsProvider,
+};
+use claw_triage::HealthEvent;
 use serde::{Deserialize, Serialize};
-use tracing::{info, warn};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use tracing::{debug, info, warn};
 
 // ─── Health Thresholds ────────────────────────────────────────────────────────
 
 /// Configurable thresholds for health decisions.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HealthThresholds {
     /// Score below this → DEGRADED
     pub degraded_score: u8,
@@ -26,26 +33,171 @@ pub struct HealthThresholds {
     pub mem_alert_pct: f32,
     /// Disk usage % above this → alert
     pub disk_alert_pct: f32,
-    /// Minutes without heartbeat → alert
+    /// Minutes without heartbeat → alert, for nodes that don't report their interval
     pub heartbeat_timeout_mins: u64,
+    /// Missed heartbeat intervals → alert, for nodes that report their interval
+    #[serde(default = "default_heartbeat_miss_multiplier")]
+    pub heartbeat_miss_multiplier: u32,
+    /// Seconds after provisioning during which an instance is at most Monitored
+    #[serde(default = "default_provision_grace_secs")]
+    pub provision_grace_secs: u64,
+    /// Services whose outage costs health points and raises an alert, for
+    /// nodes whose report does not list their own
+    #[serde(default)]
+    pub critical_services: CriticalServices,
+}
+
+fn default_heartbeat_miss_multiplier() -> u32 {
+    4
+}
+
+fn default_provision_grace_secs() -> u64 {
+    600
 }
 
 impl Default for HealthThresholds {
     fn default() -> Self {
         Self {
-            degraded_score: 70,
+            degraded_score: claw_proto::DEGRADED_HEALTH_SCORE,
             critical_score: 40,
             cpu_alert_pct: 90.0,
             mem_alert_pct: 85.0,
             disk_alert_pct: 85.0,
             heartbeat_timeout_mins: 5,
+            heartbeat_miss_multiplier: default_heartbeat_miss_multiplier(),
+            provision_grace_secs: default_provision_grace_secs(),
+            critical_services: CriticalServices::default(),
+        }
+    }
+}
+
+impl HealthThresholds {
+    /// Health score of `report`, counting only outages of its node's
+    /// critical services.
+    pub fn score(&self, report: &HealthReport) -> u8 {
+        compute_health_score_with(report, &self.critical_services_for(report))
+    }
+
+    /// Services `report`'s node must run: the ones it lists, or else
+    /// `critical_services`.
+    pub fn critical_services_for(&self, report: &HealthReport) -> CriticalServices {
+        report
+            .critical_services
+            .as_deref()
+            .map_or(self.critical_services, CriticalServices::parse)
+    }
+
+    /// How long a node may go without a heartbeat before it counts as missing.
+    ///
+    /// Scales with the node's own interval when known, so a 10s node is
+    /// flagged after 40s and a 120s node after 8 min; otherwise falls back to
+    /// the fleet-wide `heartbeat_timeout_mins`.
+    pub fn heartbeat_timeout(&self, interval_secs: Option<u64>) -> chrono::Duration {
+        match interval_secs {
+            Some(secs) if secs > 0 => {
+                chrono::Duration::seconds((secs * u64::from(self.heartbeat_miss_multiplier)) as i64)
+            }
+            _ => chrono::Duration::minutes(self.heartbeat_timeout_mins as i64),
+        }
+    }
+
+    /// Score bands for `classify_instance_status`, matching `recommend_action`:
+    /// `None` is Active, `Monitor`/`AutoHeal` Degraded, escalation Failed.
+    pub fn status_thresholds(&self) -> claw_proto::StatusThresholds {
+        claw_proto::StatusThresholds {
+            degraded_score: self.degraded_score,
+            failed_score: claw_proto::FAILED_HEALTH_SCORE,
+        }
+    }
+
+    /// Window after provisioning in which cloud-init may still be running.
+    pub fn provision_grace(&self) -> chrono::Duration {
+        chrono::Duration::seconds(self.provision_grace_secs as i64)
+    }
+}
+
+/// Per-tier threshold overrides; tiers without one use `default`.
+///
+/// Small tiers run hotter under the same load, so operators can raise their
+/// resource alert thresholds without loosening the whole fleet.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HealthThresholdsByTier {
+    #[serde(default)]
+    pub default: HealthThresholds,
+    #[serde(default)]
+    pub tiers: HashMap<InstanceTier, HealthThresholds>,
+}
+
+impl HealthThresholdsByTier {
+    pub fn new(default: HealthThresholds) -> Self {
+        Self {
+            default,
+            tiers: HashMap::new(),
         }
     }
+
+    /// Override the thresholds for one tier.
+    pub fn with_tier(mut self, tier: InstanceTier, thresholds: HealthThresholds) -> Self {
+        self.tiers.insert(tier, thresholds);
+        self
+    }
+
+    pub fn thresholds_for(&self, tier: InstanceTier) -> &HealthThresholds {
+        self.tiers.get(&tier).unwrap_or(&self.default)
+    }
+
+    /// `evaluate_alerts` with the thresholds for the report's tier.
+    pub fn evaluate_alerts(&self, report: &HealthReport) -> Vec<HealthAlert> {
+        evaluate_alerts(report, self.thresholds_for(report.tier))
+    }
+
+    /// `recommend_action_with_grace` with the thresholds for `tier`.
+    pub fn recommend_action(
+        &self,
+        tier: InstanceTier,
+        score: u8,
+        instance_age: Option<chrono::Duration>,
+    ) -> RecommendedAction {
+        recommend_action_with_grace(score, self.thresholds_for(tier), instance_age)
+    }
+}
+
+impl From<HealthThresholds> for HealthThresholdsByTier {
+    fn from(default: HealthThresholds) -> Self {
+        Self::new(default)
+    }
+}
+
+/// Thresholds to judge a report by: one `HealthThresholds` for the whole
+/// fleet, or a `HealthThresholdsByTier` resolved by the report's tier.
+pub trait ResolveThresholds {
+    fn for_tier(&self, tier: InstanceTier) -> &HealthThresholds;
+}
+
+impl ResolveThresholds for HealthThresholds {
+    fn for_tier(&self, _tier: InstanceTier) -> &HealthThresholds {
+        self
+    }
+}
+
+impl ResolveThresholds for HealthThresholdsByTier {
+    fn for_tier(&self, tier: InstanceTier) -> &HealthThresholds {
+        self.thresholds_for(tier)
+    }
+}
+
+/// True if `report` is older than its node's heartbeat timeout at `now`.
+pub fn is_heartbeat_stale(
+    report: &HealthReport,
+    now: DateTime<Utc>,
+    thresholds: &HealthThresholds,
+) -> bool {
+    now - report.reported_at > thresholds.heartbeat_timeout(report.heartbeat_interval_secs)
 }
 
 // ─── Alert types ─────────────────────────────────────────────────────────────
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AlertType {
     OpenClawDown,
@@ -58,6 +210,22 @@ pub enum AlertType {
     HealthScoreLow,
 }
 
+impl AlertType {
+    /// Short human-readable description, for briefings.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::OpenClawDown => "OpenClaw down",
+            Self::DockerDown => "Docker down",
+            Self::HeartbeatMissing => "heartbeat missing",
+            Self::DiskUsageHigh => "disk usage high",
+            Self::CpuUsageHigh => "CPU usage high",
+            Self::MemUsageHigh => "memory usage high",
+            Self::TailscaleDisconnected => "Tailscale disconnected",
+            Self::HealthScoreLow => "health score low",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthAlert {
     pub alert_type: AlertType,
@@ -67,7 +235,7 @@ pub struct HealthAlert {
     pub actual: Option<f32>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum AlertSeverity {
     Info,
@@ -92,6 +260,8 @@ pub enum RecommendedAction {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthCheckResult {
     pub instance_id: String,
+    pub provider: VpsProvider,
+    pub region: String,
     pub health_score: u8,
     pub status: InstanceState,
     pub alerts: Vec<HealthAlert>,
@@ -99,46 +269,141 @@ pub struct HealthCheckResult {
     pub checked_at: DateTime<Utc>,
 }
 
+impl HealthCheckResult {
+    /// The Triage trigger for a result that needs an incident.
+    ///
+    /// `None` unless the recommended action is a failover or an escalation.
+    pub fn to_health_event(
+        &self,
+        account_id: &str,
+        affected_users: u32,
+        data_loss_risk: bool,
+    ) -> Option<HealthEvent> {
+        if !matches!(
+            self.recommended_action,
+            RecommendedAction::Failover | RecommendedAction::EscalateToCommander
+        ) {
+            return None;
+        }
+        let critical: Vec<&str> = self
+            .alerts
+            .iter()
+            .filter(|a| a.severity == AlertSeverity::Critical)
+            .map(|a| a.message.as_str())
+            .collect();
+        let description = if critical.is_empty() {
+            format!("{} health check {:?}", self.instance_id, self.status)
+        } else {
+            critical.join("; ")
+        };
+        Some(HealthEvent {
+            instance_id: self.instance_id.clone(),
+            account_id: account_id.to_string(),
+            health_score: self.health_score,
+            provider: self.provider,
+            region: self.region.clone(),
+            description,
+            affected_users,
+            data_loss_risk,
+            detected_at: self.checked_at,
+        })
+    }
+}
+
 // ─── Health Score Engine ──────────────────────────────────────────────────────
 
+/// Services a node is required to run. A service outside the set costs no
+/// points when it is down (e.g. Tailscale on a directly networked node).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CriticalServices {
+    pub openclaw: bool,
+    pub docker: bool,
+    pub tailscale: bool,
+}
+
+impl Default for CriticalServices {
+    fn default() -> Self {
+        Self {
+            openclaw: true,
+            docker: true,
+            tailscale: true,
+        }
+    }
+}
+
+impl CriticalServices {
+    /// Parse a comma-separated list such as `"openclaw,docker"`, as set in a
+    /// node's `critical_services` label. Unknown names are ignored; a list
+    /// naming no known service falls back to the default (everything).
+    pub fn parse(spec: &str) -> Self {
+        let listed: Vec<String> = spec
+            .split(',')
+            .map(|s| s.trim().to_ascii_lowercase())
+            .collect();
+        let has = |name: &str| listed.iter().any(|s| s == name);
+        let parsed = Self {
+            openclaw: has("openclaw"),
+            docker: has("docker"),
+            tailscale: has("tailscale"),
+        };
+        if parsed.openclaw || parsed.docker || parsed.tailscale {
+            parsed
+        } else {
+            Self::default()
+        }
+    }
+}
+
 /// Compute a 0-100 health score from a health report.
 /// Deductions:
 /// - OpenClaw down: -40
 /// - Docker down: -20
 /// - Tailscale disconnected: -15
-/// - CPU > 90%: -10
-/// - Memory > 85%: -10
-/// - Disk > 85%: -10
+/// - CPU over 90%: up to -10, scaling linearly to 100%
+/// - Memory over 85%: up to -10, scaling linearly to 100%
+/// - Disk over 85%: up to -10, scaling linearly to 100%
 pub fn compute_health_score(report: &HealthReport) -> u8 {
+    compute_health_score_with(report, &CriticalServices::default())
+}
+
+/// Same as [`compute_health_score`], but service deductions only apply to
+/// services in `critical`.
+pub fn compute_health_score_with(report: &HealthReport, critical: &CriticalServices) -> u8 {
     let mut score: i32 = 100;
 
-    if report.openclaw_status != ServiceStatus::Healthy {
+    if critical.openclaw && report.openclaw_status != ServiceStatus::Healthy {
         score -= 40;
     }
-    if !report.docker_running {
+    if critical.docker && !report.docker_running {
         score -= 20;
     }
-    if !report.tailscale_connected {
+    if critical.tailscale && !report.tailscale_connected {
         score -= 15;
     }
-    if report.cpu_usage_1m > 90.0 {
-        score -= 10;
-    }
-    if report.mem_usage_pct > 85.0 {
-        score -= 10;
-    }
-    if report.disk_usage_pct > 85.0 {
-        score -= 10;
-    }
+    score -= graduated_penalty(report.cpu_usage_1m, 90.0, 10);
+    score -= graduated_penalty(report.mem_usage_pct, 85.0, 10);
+    score -= graduated_penalty(report.disk_usage_pct, 85.0, 10);
 
     score.clamp(0, 100) as u8
 }
 
+/// Deduction that grows linearly from 0 at `threshold` to `max` at 100%.
+fn graduated_penalty(usage_pct: f32, threshold: f32, max: i32) -> i32 {
+    if usage_pct.is_nan() || usage_pct <= threshold {
+        return 0;
+    }
+    let over = ((usage_pct - threshold) / (100.0 - threshold)).min(1.0);
+    (over * max as f32).round() as i32
+}
+
 /// Evaluate health alerts from a report.
+///
+/// Service alerts are raised only for the node's critical services.
 pub fn evaluate_alerts(report: &HealthReport, thresholds: &HealthThresholds) -> Vec<HealthAlert> {
     let mut alerts = Vec::new();
+    let critical = thresholds.critical_services_for(report);
 
-    if report.openclaw_status != ServiceStatus::Healthy {
+    if critical.openclaw && report.openclaw_status != ServiceStatus::Healthy {
         alerts.push(HealthAlert {
             alert_type: AlertType::OpenClawDown,
             severity: AlertSeverity::Critical,
@@ -148,7 +413,7 @@ pub fn evaluate_alerts(report: &HealthReport, thresholds: &HealthThresholds) ->
         });
     }
 
-    if !report.docker_running {
+    if critical.docker && !report.docker_running {
         alerts.push(HealthAlert {
             alert_type: AlertType::DockerDown,
             severity: AlertSeverity::Critical,
@@ -158,7 +423,7 @@ pub fn evaluate_alerts(report: &HealthReport, thresholds: &HealthThresholds) ->
         });
     }
 
-    if !report.tailscale_connected {
+    if critical.tailscale && !report.tailscale_connected {
         alerts.push(HealthAlert {
             alert_type: AlertType::TailscaleDisconnected,
             severity: AlertSeverity::Warning,
@@ -204,19 +469,95 @@ pub fn evaluate_alerts(report: &HealthReport, thresholds: &HealthThresholds) ->
     alerts
 }
 
+/// `evaluate_alerts`, plus a Critical `HeartbeatMissing` alert when more than
+/// the node's heartbeat timeout has passed since `last_heartbeat`.
+///
+/// The report's own metrics may look healthy: they are simply out of date.
+pub fn evaluate_alerts_at(
+    report: &HealthReport,
+    thresholds: &impl ResolveThresholds,
+    last_heartbeat: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Vec<HealthAlert> {
+    let thresholds = thresholds.for_tier(report.tier);
+    let mut alerts = evaluate_alerts(report, thresholds);
+    let timeout = thresholds.heartbeat_timeout(report.heartbeat_interval_secs);
+    let silent = now - last_heartbeat;
+    if silent > timeout {
+        alerts.push(HealthAlert {
+            alert_type: AlertType::HeartbeatMissing,
+            severity: AlertSeverity::Critical,
+            message: format!(
+                "No heartbeat for {}s (timeout {}s)",
+                silent.num_seconds(),
+                timeout.num_seconds()
+            ),
+            threshold: Some(timeout.num_seconds() as f32),
+            actual: Some(silent.num_seconds() as f32),
+        });
+    }
+    alerts
+}
+
 /// Determine the recommended action based on health score.
 pub fn recommend_action(score: u8, thresholds: &HealthThresholds) -> RecommendedAction {
     if score >= thresholds.degraded_score {
         RecommendedAction::None
     } else if score >= thresholds.critical_score {
         RecommendedAction::Monitor
-    } else if score >= 20 {
+    } else if score >= claw_proto::FAILED_HEALTH_SCORE {
         RecommendedAction::AutoHeal
     } else {
         RecommendedAction::EscalateToCommander
     }
 }
 
+/// `recommend_action`, capped at `Monitor` while the instance is younger than
+/// the provision grace window. `instance_age` is `None` when unknown.
+pub fn recommend_action_with_grace(
+    score: u8,
+    thresholds: &HealthThresholds,
+    instance_age: Option<chrono::Duration>,
+) -> RecommendedAction {
+    let action = recommend_action(score, thresholds);
+    match instance_age {
+        Some(age) if age < thresholds.provision_grace() && action != RecommendedAction::None => {
+            RecommendedAction::Monitor
+        }
+        _ => action,
+    }
+}
+
+/// `recommend_action_with_grace` for one reporting instance.
+///
+/// Below `FAILED_HEALTH_SCORE` a PRIMARY whose standby is ACTIVE fails over; without
+/// an active standby (or for a standby itself) it escalates as before.
+pub fn recommend_action_for_instance(
+    report: &HealthReport,
+    thresholds: &impl ResolveThresholds,
+    standby_active: bool,
+) -> RecommendedAction {
+    let thresholds = thresholds.for_tier(report.tier);
+    let score = thresholds.score(report);
+    match recommend_action_with_grace(score, thresholds, bootstrap_age(report)) {
+        RecommendedAction::EscalateToCommander
+            if report.role == InstanceRole::Primary && standby_active =>
+        {
+            RecommendedAction::Failover
+        }
+        action => action,
+    }
+}
+
+/// Age of a still-bootstrapping instance, taken from its uptime.
+///
+/// `None` once the instance has left `Bootstrapping`; callers that know
+/// `provisioned_at` can pass `now - provisioned_at` instead.
+pub fn bootstrap_age(report: &HealthReport) -> Option<chrono::Duration> {
+    (report.state == InstanceState::Bootstrapping)
+        .then(|| chrono::Duration::seconds(report.uptime_secs.min(i64::MAX as u64) as i64))
+}
+
 // ─── Auto-Heal Engine ─────────────────────────────────────────────────────────
 
 /// Result of an auto-heal attempt.
@@ -357,6 +698,80 @@ pub fn verify_standby_precondition(standby_state: InstanceState) -> bool {
     standby_state == InstanceState::Active
 }
 
+/// Swap roles so `standby` becomes the primary.
+///
+/// Refuses unless the two instances are a primary and standby of the same
+/// account and the standby is ACTIVE. The old primary is put in
+/// `Maintenance` while it recovers; both snapshots are stamped with the
+/// promotion time.
+pub fn promote_standby(
+    primary: &mut InstanceSnapshot,
+    standby: &mut InstanceSnapshot,
+) -> anyhow::Result<FailoverResult> {
+    let started = std::time::Instant::now();
+    if primary.account_id != standby.account_id {
+        anyhow::bail!(
+            "{} and {} belong to different accounts",
+            primary.instance_id,
+            standby.instance_id
+        );
+    }
+    if primary.role != InstanceRole::Primary || standby.role != InstanceRole::Standby {
+        anyhow::bail!(
+            "expected primary/standby, got {:?}/{:?}",
+            primary.role,
+            standby.role
+        );
+    }
+    if !verify_standby_precondition(standby.state) {
+        anyhow::bail!(
+            "SAFETY: standby {} is {:?}, not ACTIVE; refusing to promote",
+            standby.instance_id,
+            standby.state
+        );
+    }
+    let verified_ms = started.elapsed().as_millis() as u64;
+
+    let now = Utc::now();
+    primary.role = InstanceRole::Standby;
+    primary.state = InstanceState::Maintenance;
+    primary.recorded_at = now;
+    standby.role = InstanceRole::Primary;
+    standby.recorded_at = now;
+    let total_ms = started.elapsed().as_millis() as u64;
+
+    info!(
+        account_id = %primary.account_id,
+        old_primary = %primary.instance_id,
+        new_primary = %standby.instance_id,
+        "standby promoted"
+    );
+
+    Ok(FailoverResult {
+        account_id: primary.account_id.clone(),
+        success: true,
+        old_primary: primary.instance_id.clone(),
+        new_primary: standby.instance_id.clone(),
+        promotion_duration_ms: total_ms,
+        steps: vec![
+            FailoverStepRecord {
+                step: FailoverStepType::VerifyStandby,
+                success: true,
+                duration_ms: verified_ms,
+                error: None,
+            },
+            FailoverStepRecord {
+                step: FailoverStepType::UpdatePairStatus,
+                success: true,
+                duration_ms: total_ms - verified_ms,
+                error: None,
+            },
+        ],
+        reprovisioning_scheduled: false,
+        completed_at: now,
+    })
+}
+
 // ─── Fleet Health Sweep ───────────────────────────────────────────────────────
 
 /// Summary of a fleet-wide health sweep.
@@ -366,6 +781,10 @@ pub struct FleetHealthSweepResult {
     pub healthy: u32,
     pub degraded: u32,
     pub critical: u32,
+    /// Unhealthy instances still inside the provision grace window; not
+    /// counted as degraded or critical and never healed or failed over.
+    #[serde(default)]
+    pub bootstrapping: u32,
     pub auto_heal_triggered: u32,
     pub failovers_triggered: u32,
     pub escalated_to_commander: u32,
@@ -379,6 +798,7 @@ impl FleetHealthSweepResult {
             healthy: 0,
             degraded: 0,
             critical: 0,
+            bootstrapping: 0,
             auto_heal_triggered: 0,
             failovers_triggered: 0,
             escalated_to_commander: 0,
@@ -393,6 +813,46 @@ impl FleetHealthSweepResult {
         let healthy_pct = self.healthy as f32 / self.total_instances as f32;
         (healthy_pct * 100.0) as u8
     }
+
+    /// The `n` most severe alerts across the sweep, most widespread first
+    /// within a severity, formatted for the daily briefing.
+    ///
+    /// Each alert type counts once per instance.
+    pub fn top_alerts(&self, detailed: &[HealthCheckResult], n: usize) -> Vec<String> {
+        let mut groups: HashMap<(AlertSeverity, &AlertType), Vec<&str>> = HashMap::new();
+        for result in detailed {
+            for alert in &result.alerts {
+                let instances = groups
+                    .entry((alert.severity, &alert.alert_type))
+                    .or_default();
+                if !instances.contains(&result.instance_id.as_str()) {
+                    instances.push(&result.instance_id);
+                }
+            }
+        }
+
+        let mut ranked: Vec<_> = groups.into_iter().collect();
+        ranked.sort_by(|((sev_a, type_a), ids_a), ((sev_b, type_b), ids_b)| {
+            sev_b
+                .cmp(sev_a)
+                .then(ids_b.len().cmp(&ids_a.len()))
+                .then(type_a.label().cmp(type_b.label()))
+        });
+
+        let total = self.total_instances.max(detailed.len() as u32);
+        ranked
+            .into_iter()
+            .take(n)
+            .map(|((severity, alert_type), ids)| {
+                format!(
+                    "{severity:?}: {} on {} of {total} instances (e.g. {})",
+                    alert_type.label(),
+                    ids.len(),
+                    ids[0]
+                )
+            })
+            .collect()
+    }
 }
 
 impl Default for FleetHealthSweepResult {
@@ -401,17 +861,148 @@ impl Default for FleetHealthSweepResult {
     }
 }
 
+/// How far ahead of the ingester's clock a report's `reported_at` may be
+/// before it is treated as node clock skew.
+pub const DEFAULT_REPORT_SKEW_TOLERANCE_SECS: i64 = 300;
+
+/// How long an instance's `last_seen` is kept after its last report, so
+/// decommissioned instances do not accumulate.
+pub const LAST_SEEN_RETENTION_HOURS: i64 = 24;
+
+/// Cleans batches of incoming health reports before they reach `sweep_fleet`.
+///
+/// Nodes retry sends and their clocks drift, so a batch can hold duplicates
+/// or reports older than one already processed. The ingester remembers the
+/// newest `reported_at` it has passed through for each instance.
+#[derive(Debug, Clone)]
+pub struct HealthReportIngester {
+    last_seen: HashMap<String, DateTime<Utc>>,
+    skew_tolerance: chrono::Duration,
+}
+
+impl Default for HealthReportIngester {
+    fn default() -> Self {
+        Self {
+            last_seen: HashMap::new(),
+            skew_tolerance: chrono::Duration::seconds(DEFAULT_REPORT_SKEW_TOLERANCE_SECS),
+        }
+    }
+}
+
+impl HealthReportIngester {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override how far in the future a `reported_at` may be before it is
+    /// clamped.
+    pub fn with_skew_tolerance(mut self, tolerance: chrono::Duration) -> Self {
+        self.skew_tolerance = tolerance;
+        self
+    }
+
+    /// Keep the newest report per instance, dropping any no newer than the
+    /// last one ingested for it. Returned sorted by instance id.
+    pub fn ingest_health_reports(
+        &mut self,
+        reports: impl IntoIterator<Item = HealthReport>,
+    ) -> Vec<HealthReport> {
+        self.ingest_health_reports_at(reports, Utc::now())
+    }
+
+    /// [`ingest_health_reports`](Self::ingest_health_reports) as of `now`.
+    ///
+    /// A `reported_at` more than the skew tolerance past `now` is clamped to
+    /// `now`, so a node with a fast clock cannot push `last_seen` ahead and
+    /// get every later report dropped as stale. Instances silent for
+    /// [`LAST_SEEN_RETENTION_HOURS`] are forgotten.
+    pub fn ingest_health_reports_at(
+        &mut self,
+        reports: impl IntoIterator<Item = HealthReport>,
+        now: DateTime<Utc>,
+    ) -> Vec<HealthReport> {
+        let mut latest: HashMap<String, HealthReport> = HashMap::new();
+        for mut report in reports {
+            if report.reported_at > now + self.skew_tolerance {
+                warn!(
+                    instance_id = %report.instance_id,
+                    skew_secs = (report.reported_at - now).num_seconds(),
+                    "health report is from the future; clamping reported_at to now"
+                );
+                report.reported_at = now;
+            }
+            if self
+                .last_seen
+                .get(&report.instance_id)
+                .is_some_and(|seen| report.reported_at <= *seen)
+            {
+                continue;
+            }
+            match latest.get(&report.instance_id) {
+                Some(kept) if kept.reported_at >= report.reported_at => {}
+                _ => {
+                    latest.insert(report.instance_id.clone(), report);
+                }
+            }
+        }
+
+        let mut cleaned: Vec<HealthReport> = latest.into_values().collect();
+        cleaned.sort_by(|a, b| a.instance_id.cmp(&b.instance_id));
+        for report in &cleaned {
+            self.last_seen
+                .insert(report.instance_id.clone(), report.reported_at);
+        }
+        let cutoff = now - chrono::Duration::hours(LAST_SEEN_RETENTION_HOURS);
+        self.last_seen.retain(|_, seen| *seen >= cutoff);
+        cleaned
+    }
+
+    /// Newest `reported_at` ingested for `instance_id`.
+    pub fn last_seen(&self, instance_id: &str) -> Option<DateTime<Utc>> {
+        self.last_seen.get(instance_id).copied()
+    }
+}
+
 /// Process a batch of health reports and produce sweep results.
+///
+/// Reports are taken as given; run them through a `HealthReportIngester`
+/// first so duplicates are not double-counted. A failing primary counts as a
+/// failover when its account's standby reports ACTIVE in the same batch.
 pub fn sweep_fleet(
     reports: &[HealthReport],
-    thresholds: &HealthThresholds,
+    thresholds: &impl ResolveThresholds,
+) -> FleetHealthSweepResult {
+    sweep_fleet_at(reports, thresholds, &HashMap::new(), Utc::now())
+}
+
+/// [`sweep_fleet`] with known provision times, keyed by instance id.
+///
+/// Each report is judged by the thresholds for its tier. Unhealthy instances
+/// younger than `provision_grace()` count as bootstrapping. Instances missing
+/// from `provisioned_at` fall back to [`bootstrap_age`].
+pub fn sweep_fleet_at(
+    reports: &[HealthReport],
+    tiers: &impl ResolveThresholds,
+    provisioned_at: &HashMap<String, DateTime<Utc>>,
+    now: DateTime<Utc>,
 ) -> FleetHealthSweepResult {
     let mut result = FleetHealthSweepResult::new();
+    result.swept_at = now;
     result.total_instances = reports.len() as u32;
 
+    let active_standbys = active_standby_accounts(reports, tiers);
+
     for report in reports {
-        let score = compute_health_score(report);
-        let action = recommend_action(score, thresholds);
+        let thresholds = tiers.for_tier(report.tier);
+        let score = thresholds.score(report);
+        if within_provision_grace(report, score, thresholds, provisioned_at, now) {
+            result.bootstrapping += 1;
+            info!(instance = %report.instance_id, score, "instance still bootstrapping - within provision grace");
+            continue;
+        }
+
+        let standby_active = active_standbys.contains(report.account_id.as_str());
+        let action = recommend_action_for_instance(report, thresholds, standby_active);
 
         match action {
             RecommendedAction::None => result.healthy += 1,
@@ -440,11 +1031,157 @@ pub fn sweep_fleet(
     result
 }
 
+/// True if `report` is unhealthy but younger than the provision grace window,
+/// aged from `provisioned_at` when known, else by [`bootstrap_age`].
+fn within_provision_grace(
+    report: &HealthReport,
+    score: u8,
+    thresholds: &HealthThresholds,
+    provisioned_at: &HashMap<String, DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> bool {
+    let age = provisioned_at
+        .get(&report.instance_id)
+        .map(|at| now - *at)
+        .or_else(|| bootstrap_age(report));
+    score < thresholds.degraded_score && age.is_some_and(|age| age < thresholds.provision_grace())
+}
+
+/// Accounts whose standby reported in this batch as ACTIVE and scoring at
+/// least `HEALTHY_INSTANCE_SCORE`, i.e. fit to take over.
+fn active_standby_accounts<'a>(
+    reports: &'a [HealthReport],
+    tiers: &impl ResolveThresholds,
+) -> HashSet<&'a str> {
+    reports
+        .iter()
+        .filter(|r| r.role == InstanceRole::Standby && verify_standby_precondition(r.state))
+        .filter(|r| f64::from(tiers.for_tier(r.tier).score(r)) >= HEALTHY_INSTANCE_SCORE)
+        .map(|r| r.account_id.as_str())
+        .collect()
+}
+
+// ─── Fleet Health History ─────────────────────────────────────────────────────
+
+/// Default retention for recorded sweeps.
+pub const DEFAULT_HISTORY_RETENTION_DAYS: i64 = 90;
+
+/// Recorded fleet health scores over time, for SLA reporting.
+///
+/// Each sample's score holds until the next sample. Time before the first
+/// sample is unknown and excluded from every calculation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FleetHealthHistory {
+    /// `(swept_at, fleet_health_score)`, ordered by time.
+    samples: Vec<(DateTime<Utc>, u8)>,
+    retention: chrono::Duration,
+}
+
+impl FleetHealthHistory {
+    pub fn new() -> Self {
+        Self::with_retention(chrono::Duration::days(DEFAULT_HISTORY_RETENTION_DAYS))
+    }
+
+    pub fn with_retention(retention: chrono::Duration) -> Self {
+        Self {
+            samples: Vec::new(),
+            retention,
+        }
+    }
+
+    /// Record a sweep, dropping samples older than the retention window.
+    pub fn record(&mut self, sweep: &FleetHealthSweepResult) {
+        self.record_score(sweep.swept_at, sweep.fleet_health_score());
+    }
+
+    pub fn record_score(&mut self, at: DateTime<Utc>, score: u8) {
+        let idx = self.samples.partition_point(|(t, _)| *t <= at);
+        self.samples.insert(idx, (at, score));
+
+        let newest = self.samples.last().map(|(t, _)| *t).unwrap_or(at);
+        let cutoff = newest - self.retention;
+        // Keep the last sample before the cutoff: it defines the score at the cutoff.
+        let expired = self.samples.partition_point(|(t, _)| *t < cutoff);
+        if expired > 1 {
+            self.samples.drain(..expired - 1);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Percentage of known time in `[from, to)` the score was at least `min_score`.
+    ///
+    /// Returns 0.0 when there is no data for the range.
+    pub fn uptime_pct(&self, from: DateTime<Utc>, to: DateTime<Utc>, min_score: u8) -> f64 {
+        let mut known = chrono::Duration::zero();
+        let mut above = chrono::Duration::zero();
+        for (score, span) in self.segments(from, to) {
+            known += span;
+            if score >= min_score {
+                above += span;
+            }
+        }
+        if known <= chrono::Duration::zero() {
+            return 0.0;
+        }
+        above.num_milliseconds() as f64 / known.num_milliseconds() as f64 * 100.0
+    }
+
+    /// Total known time in `[from, to)` the score was below `score`.
+    pub fn time_below(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        score: u8,
+    ) -> chrono::Duration {
+        self.segments(from, to)
+            .filter(|(s, _)| *s < score)
+            .map(|(_, span)| span)
+            .fold(chrono::Duration::zero(), |acc, span| acc + span)
+    }
+
+    /// `(score, duration)` pieces of the step function clipped to `[from, to)`.
+    fn segments(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> impl Iterator<Item = (u8, chrono::Duration)> + '_ {
+        self.samples
+            .iter()
+            .enumerate()
+            .filter_map(move |(i, (start, score))| {
+                let end = self.samples.get(i + 1).map_or(to, |(next, _)| *next);
+                let start = (*start).max(from);
+                let end = end.min(to);
+                (end > start).then(|| (*score, end - start))
+            })
+    }
+}
+
+impl Default for FleetHealthHistory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // ─── Failover State Machine ────────────────────────────────────────────────────
 
 /// Maximum heal attempts before escalating to Commander.
 pub const MAX_HEAL_ATTEMPTS: u32 = 3;
 
+/// Consecutive healthy readings required before declaring an instance recovered.
+pub const DEFAULT_RECOVERY_CONFIRMATIONS: u32 = 2;
+
+/// Consecutive critical readings required before healing starts from Normal.
+/// One keeps the first restart immediate; raise it to ride out single blips.
+pub const DEFAULT_DECLINE_CONFIRMATIONS: u32 = 1;
+
 /// States of the failover state machine for a single instance.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -455,14 +1192,28 @@ pub enum FailoverState {
     Monitoring {
         since: DateTime<Utc>,
         low_score_count: u32,
+        /// Consecutive critical readings, counted towards `decline_confirmations`.
+        #[serde(default)]
+        critical_readings: u32,
+        /// Consecutive healthy readings, counted towards `recovery_confirmations`.
+        #[serde(default)]
+        good_readings: u32,
     },
     /// Attempting to self-heal (docker restart, process restart, etc.).
     Healing {
         attempt: u32,
         started_at: DateTime<Utc>,
+        /// Consecutive healthy readings, counted towards `recovery_confirmations`.
+        #[serde(default)]
+        good_readings: u32,
     },
     /// Actively failing over to standby.
-    FailingOver { started_at: DateTime<Utc> },
+    FailingOver {
+        started_at: DateTime<Utc>,
+        /// Consecutive healthy readings, counted towards `recovery_confirmations`.
+        #[serde(default)]
+        good_readings: u32,
+    },
     /// Failover/heal exhausted — human intervention required.
     Failed {
         since: DateTime<Utc>,
@@ -504,6 +1255,12 @@ pub struct FailoverStateMachine {
     pub role: InstanceRole,
     pub state: FailoverState,
     thresholds: HealthThresholds,
+    /// False for single-instance accounts: there is no standby to fail over to.
+    has_standby: bool,
+    /// Healthy readings in a row needed to leave Monitoring, Healing or FailingOver.
+    recovery_confirmations: u32,
+    /// Critical readings in a row needed to start healing from Normal.
+    decline_confirmations: u32,
 }
 
 /// What the state machine asks the caller to do after a transition.
@@ -531,9 +1288,46 @@ impl FailoverStateMachine {
             role,
             state: FailoverState::Normal,
             thresholds,
+            has_standby: true,
+            recovery_confirmations: DEFAULT_RECOVERY_CONFIRMATIONS,
+            decline_confirmations: DEFAULT_DECLINE_CONFIRMATIONS,
         }
     }
 
+    /// Machine for an account provisioned without a standby; exhausted heals
+    /// always escalate instead of failing over.
+    pub fn single_instance(
+        instance_id: String,
+        role: InstanceRole,
+        thresholds: HealthThresholds,
+    ) -> Self {
+        Self {
+            has_standby: false,
+            ..Self::new(instance_id, role, thresholds)
+        }
+    }
+
+    /// Require `n` consecutive healthy readings before transitioning to Recovered.
+    pub fn with_recovery_confirmations(mut self, n: u32) -> Self {
+        self.recovery_confirmations = n.max(1);
+        self
+    }
+
+    /// Require `n` consecutive critical readings before healing starts from Normal.
+    pub fn with_decline_confirmations(mut self, n: u32) -> Self {
+        self.decline_confirmations = n.max(1);
+        self
+    }
+
+    fn start_healing(&mut self, now: DateTime<Utc>) -> FailoverTransition {
+        self.state = FailoverState::Healing {
+            attempt: 1,
+            started_at: now,
+            good_readings: 0,
+        };
+        FailoverTransition::AttemptDockerRestart { attempt: 1 }
+    }
+
     /// Process a new health score and determine the next transition.
     ///
     /// PRD §5.3 six-step sequence:
@@ -549,18 +1343,17 @@ impl FailoverStateMachine {
         match &self.state.clone() {
             // ── Normal ──────────────────────────────────────────────────────
             FailoverState::Normal => {
-                if health_score < self.thresholds.critical_score {
-                    self.state = FailoverState::Healing {
-                        attempt: 1,
-                        started_at: now,
-                    };
+                let critical = health_score < self.thresholds.critical_score;
+                if critical && self.decline_confirmations <= 1 {
                     info!(instance = %self.instance_id, score = health_score, "entering healing state (attempt 1)");
-                    return FailoverTransition::AttemptDockerRestart { attempt: 1 };
+                    return self.start_healing(now);
                 }
                 if health_score < self.thresholds.degraded_score {
                     self.state = FailoverState::Monitoring {
                         since: now,
                         low_score_count: 1,
+                        critical_readings: u32::from(critical),
+                        good_readings: 0,
                     };
                     return FailoverTransition::Monitor;
                 }
@@ -571,43 +1364,79 @@ impl FailoverStateMachine {
             FailoverState::Monitoring {
                 since,
                 low_score_count,
+                critical_readings,
+                good_readings,
             } => {
                 if health_score >= self.thresholds.degraded_score {
-                    info!(instance = %self.instance_id, "health recovered from monitoring");
-                    self.state = FailoverState::Recovered { at: now };
-                    return FailoverTransition::LogRecovered;
-                }
-                if health_score < self.thresholds.critical_score {
-                    self.state = FailoverState::Healing {
-                        attempt: 1,
-                        started_at: now,
+                    if good_readings + 1 >= self.recovery_confirmations {
+                        info!(instance = %self.instance_id, "health recovered from monitoring");
+                        self.state = FailoverState::Recovered { at: now };
+                        return FailoverTransition::LogRecovered;
+                    }
+                    self.state = FailoverState::Monitoring {
+                        since: *since,
+                        low_score_count: *low_score_count,
+                        critical_readings: 0,
+                        good_readings: good_readings + 1,
                     };
+                    return FailoverTransition::Monitor;
+                }
+                let critical = health_score < self.thresholds.critical_score;
+                let critical_readings = if critical { critical_readings + 1 } else { 0 };
+                if critical && critical_readings >= self.decline_confirmations {
                     warn!(instance = %self.instance_id, score = health_score, since = %since, "health dropped to critical from monitoring");
-                    return FailoverTransition::AttemptDockerRestart { attempt: 1 };
+                    return self.start_healing(now);
                 }
                 self.state = FailoverState::Monitoring {
                     since: *since,
                     low_score_count: low_score_count + 1,
+                    critical_readings,
+                    good_readings: 0,
                 };
                 FailoverTransition::Monitor
             }
 
             // ── Healing ─────────────────────────────────────────────────────
-            FailoverState::Healing { attempt, .. } => {
+            FailoverState::Healing {
+                attempt,
+                started_at,
+                good_readings,
+            } => {
                 if health_score >= self.thresholds.degraded_score {
-                    info!(instance = %self.instance_id, attempt, "healed successfully");
-                    self.state = FailoverState::Recovered { at: now };
-                    return FailoverTransition::LogRecovered;
+                    if good_readings + 1 >= self.recovery_confirmations {
+                        info!(instance = %self.instance_id, attempt, "healed successfully");
+                        self.state = FailoverState::Recovered { at: now };
+                        return FailoverTransition::LogRecovered;
+                    }
+                    // Looks healed; hold off on another restart until confirmed.
+                    self.state = FailoverState::Healing {
+                        attempt: *attempt,
+                        started_at: *started_at,
+                        good_readings: good_readings + 1,
+                    };
+                    return FailoverTransition::NoAction;
                 }
 
                 let next_attempt = attempt + 1;
 
                 if next_attempt > MAX_HEAL_ATTEMPTS {
                     // Exhausted heal attempts — decide failover or escalate
+                    if self.role == InstanceRole::Primary && !self.has_standby {
+                        warn!(instance = %self.instance_id, "heal exhausted on single-instance account — escalating");
+                        let reason = "heal exhausted and account has no standby".to_string();
+                        self.state = FailoverState::Failed {
+                            since: now,
+                            reason: reason.clone(),
+                        };
+                        return FailoverTransition::EscalateToCommander { reason };
+                    }
                     if self.role == InstanceRole::Primary {
                         if standby_active {
                             warn!(instance = %self.instance_id, "heal exhausted, primary — initiating failover");
-                            self.state = FailoverState::FailingOver { started_at: now };
+                            self.state = FailoverState::FailingOver {
+                                started_at: now,
+                                good_readings: 0,
+                            };
                             return FailoverTransition::InitiateFailover;
                         } else {
                             warn!(instance = %self.instance_id, "heal exhausted, standby NOT active — escalating");
@@ -636,6 +1465,7 @@ impl FailoverStateMachine {
                 self.state = FailoverState::Healing {
                     attempt: next_attempt,
                     started_at: now,
+                    good_readings: 0,
                 };
                 FailoverTransition::AttemptDockerRestart {
                     attempt: next_attempt,
@@ -643,12 +1473,24 @@ impl FailoverStateMachine {
             }
 
             // ── FailingOver ─────────────────────────────────────────────────
-            FailoverState::FailingOver { .. } => {
-                if health_score >= self.thresholds.degraded_score {
+            FailoverState::FailingOver {
+                started_at,
+                good_readings,
+            } => {
+                let good_readings = if health_score >= self.thresholds.degraded_score {
+                    good_readings + 1
+                } else {
+                    0
+                };
+                if good_readings >= self.recovery_confirmations {
                     self.state = FailoverState::Recovered { at: now };
                     return FailoverTransition::LogRecovered;
                 }
                 // Still in failover — caller is handling it
+                self.state = FailoverState::FailingOver {
+                    started_at: *started_at,
+                    good_readings,
+                };
                 FailoverTransition::NoAction
             }
 
@@ -670,6 +1512,8 @@ impl FailoverStateMachine {
                 self.state = FailoverState::Monitoring {
                     since: now,
                     low_score_count: 1,
+                    critical_readings: u32::from(health_score < self.thresholds.critical_score),
+                    good_readings: 0,
                 };
                 FailoverTransition::Monitor
             }
@@ -681,15 +1525,450 @@ impl FailoverStateMachine {
         self.state = FailoverState::Normal;
         info!(instance = %self.instance_id, "failover state machine reset by operator");
     }
-}
 
-#[cfg(test)]
-mod tests {
+    /// Dry-run a sequence of scores from the current state without mutating it.
+    ///
+    /// `standby_active_seq` pairs with `scores` by index; if it is shorter, its
+    /// last value repeats (an empty slice means no active standby).
+    pub fn simulate(&self, scores: &[u8], standby_active_seq: &[bool]) -> Vec<FailoverTransition> {
+        let mut rehearsal = FailoverStateMachine {
+            instance_id: format!("{} (simulated)", self.instance_id),
+            role: self.role,
+            state: self.state.clone(),
+            thresholds: self.thresholds.clone(),
+            has_standby: self.has_standby,
+            recovery_confirmations: self.recovery_confirmations,
+            decline_confirmations: self.decline_confirmations,
+        };
+        scores
+            .iter()
+            .enumerate()
+            .map(|(i, &score)| {
+                let standby_active = standby_active_seq
+                    .get(i)
+                    .or(standby_active_seq.last())
+                    .copied()
+                    .unwrap_or(false);
+                rehearsal.transition(score, standby_active)
+            })
+            .collect()
+    }
+
+    /// Snapshot of the machine for `FailoverStateStore`.
+    pub fn to_persisted(&self) -> PersistedFailoverMachine {
+        PersistedFailoverMachine {
+            instance_id: self.instance_id.clone(),
+            role: self.role,
+            state: self.state.clone(),
+            thresholds: self.thresholds.clone(),
+            has_standby: self.has_standby,
+            recovery_confirmations: self.recovery_confirmations,
+            decline_confirmations: self.decline_confirmations,
+        }
+    }
+
+    /// Rebuild a machine exactly as it was persisted.
+    pub fn from_persisted(persisted: PersistedFailoverMachine) -> Self {
+        Self {
+            instance_id: persisted.instance_id,
+            role: persisted.role,
+            state: persisted.state,
+            thresholds: persisted.thresholds,
+            has_standby: persisted.has_standby,
+            recovery_confirmations: persisted.recovery_confirmations.max(1),
+            decline_confirmations: persisted.decline_confirmations.max(1),
+        }
+    }
+}
+
+/// Serialized form of a `FailoverStateMachine`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PersistedFailoverMachine {
+    pub instance_id: String,
+    pub role: InstanceRole,
+    pub state: FailoverState,
+    pub thresholds: HealthThresholds,
+    pub has_standby: bool,
+    #[serde(default = "default_recovery_confirmations")]
+    pub recovery_confirmations: u32,
+    #[serde(default = "default_decline_confirmations")]
+    pub decline_confirmations: u32,
+}
+
+fn default_recovery_confirmations() -> u32 {
+    DEFAULT_RECOVERY_CONFIRMATIONS
+}
+
+fn default_decline_confirmations() -> u32 {
+    DEFAULT_DECLINE_CONFIRMATIONS
+}
+
+// ─── Failover Registry ────────────────────────────────────────────────────────
+
+/// Failover state machines for the fleet, keyed by instance id.
+#[derive(Default)]
+pub struct FailoverRegistry {
+    machines: HashMap<String, FailoverStateMachine>,
+    /// Thresholds for machines created by `observe`, by instance tier.
+    thresholds: HealthThresholdsByTier,
+}
+
+impl FailoverRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry whose lazily created machines use `thresholds`; pass a
+    /// `HealthThresholdsByTier` to give each machine its report's tier.
+    pub fn with_thresholds(thresholds: impl Into<HealthThresholdsByTier>) -> Self {
+        Self {
+            thresholds: thresholds.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Feed a health score to an instance's machine, creating it on first sight.
+    ///
+    /// The machine's role follows `role`, so a promoted standby is driven as
+    /// a primary from its next report on. A new machine uses the default
+    /// thresholds; `observe_fleet` picks them by the report's tier.
+    pub fn observe(
+        &mut self,
+        instance_id: &str,
+        role: InstanceRole,
+        health_score: u8,
+        standby_active: bool,
+    ) -> FailoverTransition {
+        let thresholds = self.thresholds.default.clone();
+        self.observe_with(instance_id, role, thresholds, health_score, standby_active)
+    }
+
+    fn observe_with(
+        &mut self,
+        instance_id: &str,
+        role: InstanceRole,
+        thresholds: HealthThresholds,
+        health_score: u8,
+        standby_active: bool,
+    ) -> FailoverTransition {
+        let machine = self
+            .machines
+            .entry(instance_id.to_string())
+            .or_insert_with(|| {
+                debug!(
+                    instance = instance_id,
+                    ?role,
+                    "tracking new instance for failover"
+                );
+                FailoverStateMachine::new(instance_id.to_string(), role, thresholds)
+            });
+        if machine.role != role {
+            info!(instance = instance_id, from = ?machine.role, to = ?role, "instance role changed");
+            machine.role = role;
+        }
+        machine.transition(health_score, standby_active)
+    }
+
+    /// `observe` every report in a batch, pairing standbys as `sweep_fleet` does.
+    ///
+    /// Returns `(instance_id, transition)` in report order.
+    pub fn observe_fleet(&mut self, reports: &[HealthReport]) -> Vec<(String, FailoverTransition)> {
+        self.observe_fleet_at(reports, &HashMap::new(), Utc::now())
+    }
+
+    /// [`observe_fleet`](Self::observe_fleet) with known provision times,
+    /// applying the same provision grace as [`sweep_fleet_at`]: instances the
+    /// sweep counts as bootstrapping are not fed to their machines and are
+    /// left out of the result.
+    pub fn observe_fleet_at(
+        &mut self,
+        reports: &[HealthReport],
+        provisioned_at: &HashMap<String, DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Vec<(String, FailoverTransition)> {
+        let tiers = self.thresholds.clone();
+        let active_standbys = active_standby_accounts(reports, &tiers);
+        reports
+            .iter()
+            .filter_map(|report| {
+                let thresholds = tiers.thresholds_for(report.tier);
+                let score = thresholds.score(report);
+                if within_provision_grace(report, score, thresholds, provisioned_at, now) {
+                    return None;
+                }
+                let transition = self.observe_with(
+                    &report.instance_id,
+                    report.role,
+                    thresholds.clone(),
+                    score,
+                    active_standbys.contains(report.account_id.as_str()),
+                );
+                Some((report.instance_id.clone(), transition))
+            })
+            .collect()
+    }
+
+    /// Operator reset of one instance's machine; false if it is not tracked.
+    pub fn reset(&mut self, instance_id: &str) -> bool {
+        self.machines
+            .get_mut(instance_id)
+            .map(FailoverStateMachine::reset)
+            .is_some()
+    }
+
+    /// Machines in `Failed`, waiting on a human.
+    pub fn iter_needing_escalation(&self) -> impl Iterator<Item = &FailoverStateMachine> {
+        self.machines
+            .values()
+            .filter(|m| m.state.needs_escalation())
+    }
+
+    /// Track `machine`, replacing any machine already tracked for its instance.
+    pub fn insert(&mut self, machine: FailoverStateMachine) -> Option<FailoverStateMachine> {
+        self.machines.insert(machine.instance_id.clone(), machine)
+    }
+
+    pub fn remove(&mut self, instance_id: &str) -> Option<FailoverStateMachine> {
+        self.machines.remove(instance_id)
+    }
+
+    pub fn get(&self, instance_id: &str) -> Option<&FailoverStateMachine> {
+        self.machines.get(instance_id)
+    }
+
+    pub fn get_mut(&mut self, instance_id: &str) -> Option<&mut FailoverStateMachine> {
+        self.machines.get_mut(instance_id)
+    }
+
+    /// Feed a health score to one instance's machine; `None` if it is not tracked.
+    pub fn transition(
+        &mut self,
+        instance_id: &str,
+        health_score: u8,
+        standby_active: bool,
+    ) -> Option<FailoverTransition> {
+        self.machines
+            .get_mut(instance_id)
+            .map(|m| m.transition(health_score, standby_active))
+    }
+
+    /// Instance ids whose state matches `predicate`, sorted.
+    pub fn instances_in_state(&self, predicate: impl Fn(&FailoverState) -> bool) -> Vec<&str> {
+        let mut ids: Vec<&str> = self
+            .machines
+            .values()
+            .filter(|m| predicate(&m.state))
+            .map(|m| m.instance_id.as_str())
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    pub fn healing(&self) -> Vec<&str> {
+        self.instances_in_state(|s| matches!(s, FailoverState::Healing { .. }))
+    }
+
+    pub fn failing_over(&self) -> Vec<&str> {
+        self.instances_in_state(|s| matches!(s, FailoverState::FailingOver { .. }))
+    }
+
+    /// Instances needing a human; the Guardian escalates these.
+    pub fn failed(&self) -> Vec<&str> {
+        self.instances_in_state(|s| matches!(s, FailoverState::Failed { .. }))
+    }
+
+    pub fn len(&self) -> usize {
+        self.machines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.machines.is_empty()
+    }
+}
+
+// ─── Failover State Persistence ───────────────────────────────────────────────
+
+/// Age past which a persisted `Healing`/`FailingOver` state is not resumed.
+pub const DEFAULT_FAILOVER_STATE_TTL_SECS: i64 = 600;
+
+/// Failover state machines saved across Guardian restarts, keyed by instance id.
+pub struct FailoverStateStore {
+    store: JsonStore,
+    ttl: chrono::Duration,
+}
+
+impl FailoverStateStore {
+    pub fn new(state_path: &Path) -> Self {
+        Self {
+            store: JsonStore::new(state_path, "failover_state"),
+            ttl: chrono::Duration::seconds(DEFAULT_FAILOVER_STATE_TTL_SECS),
+        }
+    }
+
+    /// Override how long an in-flight heal or failover may be resumed.
+    pub fn with_ttl(mut self, ttl: chrono::Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Snapshot every machine in `registry`.
+    pub fn save(&self, registry: &FailoverRegistry) -> std::io::Result<()> {
+        let data: HashMap<String, PersistedFailoverMachine> = registry
+            .machines
+            .iter()
+            .map(|(id, m)| (id.clone(), m.to_persisted()))
+            .collect();
+        self.store.save(&data)
+    }
+
+    /// Restore the registry; see [`FailoverStateStore::load_at`].
+    pub fn load(&self, thresholds: impl Into<HealthThresholdsByTier>) -> FailoverRegistry {
+        self.load_at(thresholds, Utc::now())
+    }
+
+    /// Restore the registry as of `now`. Restored machines keep their saved
+    /// thresholds; `thresholds` apply to machines the registry creates later.
+    ///
+    /// A `Healing` or `FailingOver` state that started more than the TTL ago
+    /// is downgraded to `Monitoring`: the heal or failover it describes has
+    /// long since finished or died with the old process.
+    pub fn load_at(
+        &self,
+        thresholds: impl Into<HealthThresholdsByTier>,
+        now: DateTime<Utc>,
+    ) -> FailoverRegistry {
+        let data: HashMap<String, PersistedFailoverMachine> = self.store.load();
+        let mut registry = FailoverRegistry::with_thresholds(thresholds);
+        for (instance_id, persisted) in data {
+            let mut machine = FailoverStateMachine::from_persisted(persisted);
+            machine.instance_id = instance_id;
+            let started_at = match &machine.state {
+                FailoverState::Healing { started_at, .. }
+                | FailoverState::FailingOver { started_at, .. } => Some(*started_at),
+                _ => None,
+            };
+            if let Some(started_at) = started_at
+                && now - started_at > self.ttl
+            {
+                warn!(instance = %machine.instance_id, started_at = %started_at, "stale failover state on load — downgrading to monitoring");
+                machine.state = FailoverState::Monitoring {
+                    since: now,
+                    low_score_count: 1,
+                    critical_readings: 0,
+                    good_readings: 0,
+                };
+            }
+            registry.insert(machine);
+        }
+        debug!(count = registry.len(), "loaded failover state from disk");
+        registry
+    }
+}
+
+// ─── Health Check Scheduler ───────────────────────────────────────────────────
+
+/// Poll intervals per failover state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckCadence {
+    /// Interval for stable instances (Normal, Recovered) and Failed ones awaiting an operator.
+    pub stable_secs: u64,
+    /// Interval for instances being watched or repaired (Monitoring, Healing, FailingOver).
+    pub active_secs: u64,
+}
+
+impl Default for CheckCadence {
+    fn default() -> Self {
+        Self {
+            stable_secs: 300,
+            active_secs: 30,
+        }
+    }
+}
+
+impl CheckCadence {
+    /// How long to wait before checking an instance in `state` again.
+    pub fn interval_for(&self, state: &FailoverState) -> chrono::Duration {
+        let secs = match state {
+            FailoverState::Normal
+            | FailoverState::Recovered { .. }
+            | FailoverState::Failed { .. } => self.stable_secs,
+            FailoverState::Monitoring { .. }
+            | FailoverState::Healing { .. }
+            | FailoverState::FailingOver { .. } => self.active_secs,
+        };
+        chrono::Duration::seconds(secs as i64)
+    }
+}
+
+/// Tracks when each instance is next due for a health check.
+///
+/// Healthy instances are polled on the slow cadence; instances the failover
+/// state machine is watching or healing are polled on the fast one.
+#[derive(Debug, Clone, Default)]
+pub struct HealthScheduler {
+    cadence: CheckCadence,
+    next_due: HashMap<String, DateTime<Utc>>,
+}
+
+impl HealthScheduler {
+    pub fn new(cadence: CheckCadence) -> Self {
+        Self {
+            cadence,
+            next_due: HashMap::new(),
+        }
+    }
+
+    /// Start tracking an instance; it is due immediately. No-op if already tracked.
+    pub fn track(&mut self, instance_id: &str, now: DateTime<Utc>) {
+        self.next_due.entry(instance_id.to_string()).or_insert(now);
+    }
+
+    /// Record a completed check and return when the instance is next due.
+    pub fn record_check(
+        &mut self,
+        instance_id: &str,
+        state: &FailoverState,
+        checked_at: DateTime<Utc>,
+    ) -> DateTime<Utc> {
+        let due = checked_at + self.cadence.interval_for(state);
+        self.next_due.insert(instance_id.to_string(), due);
+        due
+    }
+
+    /// Stop tracking an instance (e.g. after teardown).
+    pub fn remove(&mut self, instance_id: &str) {
+        self.next_due.remove(instance_id);
+    }
+
+    pub fn next_due(&self, instance_id: &str) -> Option<DateTime<Utc>> {
+        self.next_due.get(instance_id).copied()
+    }
+
+    /// Instances due for a check at `now`, most overdue first.
+    pub fn due(&self, now: DateTime<Utc>) -> Vec<String> {
+        let mut due: Vec<(&String, &DateTime<Utc>)> =
+            self.next_due.iter().filter(|(_, at)| **at <= now).collect();
+        due.sort_by(|a, b| a.1.cmp(b.1).then_with(|| a.0.cmp(b.0)));
+        due.into_iter().map(|(id, _)| id.clone()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.next_due.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.next_due.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
     use super::*;
     use claw_proto::{InstanceTier, ServiceStatus, VpsProvider};
 
     fn make_healthy_report(instance_id: &str) -> HealthReport {
         HealthReport {
+            report_version: claw_proto::HEALTH_REPORT_VERSION,
             instance_id: instance_id.to_string(),
             account_id: "acc-1".to_string(),
             provider: VpsProvider::Hetzner,
@@ -713,6 +1992,8 @@ mod tests {
             uptime_secs: 86400,
             bytes_sent_per_sec: 1024.0,
             bytes_recv_per_sec: 2048.0,
+            heartbeat_interval_secs: Some(30),
+            critical_services: None,
             reported_at: Utc::now(),
         }
     }
@@ -742,19 +2023,120 @@ mod tests {
         assert_eq!(score, 25); // 100 - 40 - 20 - 15
     }
 
+    #[test]
+    fn test_non_critical_tailscale_not_deducted() {
+        let mut report = make_healthy_report("i-direct");
+        report.tailscale_connected = false;
+
+        let direct = CriticalServices::parse("openclaw, docker");
+        assert!(!direct.tailscale);
+        assert_eq!(compute_health_score_with(&report, &direct), 100);
+        assert_eq!(
+            compute_health_score_with(&report, &CriticalServices::default()),
+            85
+        );
+
+        report.docker_running = false;
+        assert_eq!(compute_health_score_with(&report, &direct), 80);
+    }
+
+    #[test]
+    fn test_critical_services_parse_empty_is_default() {
+        assert_eq!(CriticalServices::parse(""), CriticalServices::default());
+        assert_eq!(
+            CriticalServices::parse(" , bogus"),
+            CriticalServices::default()
+        );
+    }
+
+    #[test]
+    fn test_alerts_and_score_follow_the_nodes_critical_services() {
+        let mut report = make_healthy_report("i-direct");
+        report.tailscale_connected = false;
+        let thresholds = HealthThresholds::default();
+        let types = |report: &HealthReport, thresholds: &HealthThresholds| {
+            evaluate_alerts(report, thresholds)
+                .into_iter()
+                .map(|a| a.alert_type)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            types(&report, &thresholds),
+            vec![AlertType::TailscaleDisconnected]
+        );
+
+        // The node says it runs without Tailscale.
+        report.critical_services = Some("openclaw,docker".to_string());
+        assert!(types(&report, &thresholds).is_empty());
+        assert_eq!(thresholds.score(&report), 100);
+
+        // Fleet-wide thresholds apply to nodes that do not say.
+        report.critical_services = None;
+        let direct = HealthThresholds {
+            critical_services: CriticalServices::parse("openclaw,docker"),
+            ..HealthThresholds::default()
+        };
+        assert!(types(&report, &direct).is_empty());
+    }
+
+    #[test]
+    fn test_sweep_respects_critical_services() {
+        let mut report = make_healthy_report("i-direct");
+        report.openclaw_status = ServiceStatus::Down;
+        report.docker_running = false;
+        report.tailscale_connected = false;
+        let reports = [report];
+
+        let fleet_default = sweep_fleet(&reports, &HealthThresholds::default());
+        assert_eq!(fleet_default.critical, 1);
+        assert_eq!(fleet_default.auto_heal_triggered, 1);
+
+        let direct = HealthThresholds {
+            critical_services: CriticalServices::parse("openclaw,docker"),
+            ..HealthThresholds::default()
+        };
+        let sweep = sweep_fleet(&reports, &direct);
+        assert_eq!(sweep.critical, 0);
+        assert_eq!(sweep.degraded, 1);
+        assert_eq!(sweep.auto_heal_triggered, 0);
+    }
+
     #[test]
     fn test_health_score_minimum_zero() {
         let mut report = make_healthy_report("i-4");
         report.openclaw_status = ServiceStatus::Down;
         report.docker_running = false;
         report.tailscale_connected = false;
-        report.cpu_usage_1m = 95.0;
-        report.mem_usage_pct = 90.0;
-        report.disk_usage_pct = 90.0;
+        report.cpu_usage_1m = 100.0;
+        report.mem_usage_pct = 100.0;
+        report.disk_usage_pct = 100.0;
         let score = compute_health_score(&report);
         assert_eq!(score, 0); // would be -5, clamped to 0
     }
 
+    #[test]
+    fn test_health_score_resource_penalties_are_graduated() {
+        let mut report = make_healthy_report("i-cpu");
+        report.cpu_usage_1m = 90.0;
+        assert_eq!(compute_health_score(&report), 100);
+        report.cpu_usage_1m = 91.0;
+        let slightly_hot = compute_health_score(&report);
+        report.cpu_usage_1m = 95.0;
+        let hot = compute_health_score(&report);
+        assert!(
+            hot < slightly_hot,
+            "95% CPU ({hot}) should score below 91% ({slightly_hot})"
+        );
+        assert_eq!(slightly_hot, 99);
+        assert_eq!(hot, 95);
+
+        let mut report = make_healthy_report("i-disk");
+        report.disk_usage_pct = 92.5;
+        assert_eq!(compute_health_score(&report), 95);
+        report.disk_usage_pct = 100.0;
+        assert_eq!(compute_health_score(&report), 90);
+    }
+
     #[test]
     fn test_recommend_action_healthy() {
         let thresholds = HealthThresholds::default();
@@ -789,6 +2171,155 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_instance_status_agrees_with_recommend_action() {
+        let thresholds = HealthThresholds::default();
+        let bands = thresholds.status_thresholds();
+        let classify =
+            |score| claw_proto::classify_instance_status(score, InstanceState::Active, &bands);
+
+        assert_eq!(
+            recommend_action(50, &thresholds),
+            RecommendedAction::Monitor
+        );
+        assert_eq!(classify(50), InstanceState::Degraded);
+        assert_eq!(
+            recommend_action(10, &thresholds),
+            RecommendedAction::EscalateToCommander
+        );
+        assert_eq!(classify(10), InstanceState::Failed);
+
+        for score in 0..=100u8 {
+            let expected = match recommend_action(score, &thresholds) {
+                RecommendedAction::None => InstanceState::Active,
+                RecommendedAction::Monitor | RecommendedAction::AutoHeal => InstanceState::Degraded,
+                _ => InstanceState::Failed,
+            };
+            assert_eq!(classify(score), expected, "score {score}");
+        }
+    }
+
+    #[test]
+    fn test_provision_grace_caps_action_at_monitor() {
+        let thresholds = HealthThresholds::default();
+        let minutes = chrono::Duration::minutes;
+        assert_eq!(
+            recommend_action_with_grace(20, &thresholds, Some(minutes(2))),
+            RecommendedAction::Monitor
+        );
+        assert_eq!(
+            recommend_action_with_grace(20, &thresholds, Some(minutes(20))),
+            RecommendedAction::AutoHeal
+        );
+        assert_eq!(
+            recommend_action_with_grace(95, &thresholds, Some(minutes(2))),
+            RecommendedAction::None
+        );
+
+        // The sweep takes a bootstrapping box's age from its uptime.
+        let mut young = make_healthy_report("i-new");
+        young.state = InstanceState::Bootstrapping;
+        young.uptime_secs = 120;
+        young.openclaw_status = ServiceStatus::Down;
+        young.docker_running = false;
+        young.tailscale_connected = false;
+        let mut old = young.clone();
+        old.uptime_secs = 20 * 60;
+        assert!(compute_health_score(&young) < thresholds.critical_score);
+
+        let sweep = sweep_fleet(&[young, old], &thresholds);
+        assert_eq!(sweep.bootstrapping, 1);
+        assert_eq!(sweep.degraded, 0);
+        assert_eq!(sweep.critical, 1);
+    }
+
+    #[test]
+    fn test_sweep_skips_auto_heal_for_freshly_provisioned_instance() {
+        let thresholds = HealthThresholds::default();
+        let now = Utc::now();
+        let mut fresh = make_healthy_report("i-fresh");
+        fresh.docker_running = false;
+        fresh.openclaw_status = ServiceStatus::Down;
+        fresh.tailscale_connected = false;
+        let mut settled = fresh.clone();
+        settled.instance_id = "i-settled".to_string();
+        let score = compute_health_score(&fresh);
+        assert!(score < thresholds.critical_score && score >= claw_proto::FAILED_HEALTH_SCORE);
+
+        let provisioned_at = HashMap::from([
+            ("i-fresh".to_string(), now - chrono::Duration::minutes(1)),
+            ("i-settled".to_string(), now - chrono::Duration::hours(1)),
+        ]);
+        let sweep = sweep_fleet_at(&[fresh, settled], &thresholds, &provisioned_at, now);
+        assert_eq!(sweep.bootstrapping, 1);
+        assert_eq!(sweep.auto_heal_triggered, 1);
+        assert_eq!(sweep.critical, 1);
+        assert_eq!(sweep.swept_at, now);
+    }
+
+    fn make_failing_report(instance_id: &str) -> HealthReport {
+        let mut r = make_healthy_report(instance_id);
+        r.openclaw_status = ServiceStatus::Down;
+        r.docker_running = false;
+        r.tailscale_connected = false;
+        r.disk_usage_pct = 99.0;
+        r.mem_usage_pct = 99.0;
+        r
+    }
+
+    fn make_standby_report(instance_id: &str, state: InstanceState) -> HealthReport {
+        let mut r = make_healthy_report(instance_id);
+        r.role = InstanceRole::Standby;
+        r.state = state;
+        r
+    }
+
+    #[test]
+    fn test_recommend_action_for_primary_with_active_standby_fails_over() {
+        let thresholds = HealthThresholds::default();
+        let primary = make_failing_report("i-primary");
+        assert!(compute_health_score(&primary) < claw_proto::FAILED_HEALTH_SCORE);
+        assert_eq!(
+            recommend_action_for_instance(&primary, &thresholds, true),
+            RecommendedAction::Failover
+        );
+        assert_eq!(
+            recommend_action_for_instance(&primary, &thresholds, false),
+            RecommendedAction::EscalateToCommander
+        );
+    }
+
+    #[test]
+    fn test_recommend_action_for_standby_never_fails_over() {
+        let thresholds = HealthThresholds::default();
+        let mut standby = make_failing_report("i-standby");
+        standby.role = InstanceRole::Standby;
+        assert_eq!(
+            recommend_action_for_instance(&standby, &thresholds, true),
+            RecommendedAction::EscalateToCommander
+        );
+    }
+
+    #[test]
+    fn test_sweep_fleet_counts_failover_for_primary_with_active_standby() {
+        let thresholds = HealthThresholds::default();
+        let mut orphan = make_failing_report("i-orphan");
+        orphan.account_id = "acc-2".to_string();
+        let mut parked = make_standby_report("i-parked", InstanceState::Maintenance);
+        parked.account_id = "acc-2".to_string();
+        let reports = vec![
+            make_failing_report("i-primary"),
+            make_standby_report("i-standby", InstanceState::Active),
+            orphan,
+            parked,
+        ];
+
+        let result = sweep_fleet(&reports, &thresholds);
+        assert_eq!(result.failovers_triggered, 1);
+        assert_eq!(result.escalated_to_commander, 1);
+        assert_eq!(result.critical, 2);
+    }
+
     #[test]
     fn test_recommend_action_escalate() {
         let thresholds = HealthThresholds::default();
@@ -902,18 +2433,172 @@ mod tests {
         );
     }
 
-    // ─── FailoverStateMachine tests ───────────────────────────────────────────
+    #[test]
+    fn test_heartbeat_alert_at_threshold_is_not_raised() {
+        let thresholds = HealthThresholds::default();
+        let mut report = make_healthy_report("i-edge");
+        report.heartbeat_interval_secs = None;
+        let now = Utc::now();
 
-    fn make_fsm(role: InstanceRole) -> FailoverStateMachine {
-        FailoverStateMachine::new("i-test".to_string(), role, HealthThresholds::default())
+        // Exactly the flat 5-minute timeout: not yet missing.
+        let alerts = evaluate_alerts_at(
+            &report,
+            &thresholds,
+            now - chrono::Duration::minutes(5),
+            now,
+        );
+        assert!(
+            !alerts
+                .iter()
+                .any(|a| a.alert_type == AlertType::HeartbeatMissing)
+        );
+
+        let alerts = evaluate_alerts_at(
+            &report,
+            &thresholds,
+            now - chrono::Duration::minutes(5) - chrono::Duration::seconds(1),
+            now,
+        );
+        assert!(
+            alerts
+                .iter()
+                .any(|a| a.alert_type == AlertType::HeartbeatMissing)
+        );
     }
 
     #[test]
-    fn test_fsm_healthy_stays_normal() {
-        let mut fsm = make_fsm(InstanceRole::Primary);
-        let t = fsm.transition(95, true);
-        assert_eq!(t, FailoverTransition::NoAction);
-        assert_eq!(fsm.state, FailoverState::Normal);
+    fn test_stale_heartbeat_alerts_even_with_healthy_metrics() {
+        let thresholds = HealthThresholds::default();
+        let report = make_healthy_report("i-silent");
+        assert!(evaluate_alerts(&report, &thresholds).is_empty());
+        let now = Utc::now();
+        let timeout = thresholds.heartbeat_timeout(report.heartbeat_interval_secs);
+
+        let alerts = evaluate_alerts_at(
+            &report,
+            &thresholds,
+            now - chrono::Duration::minutes(30),
+            now,
+        );
+        assert_eq!(alerts.len(), 1);
+        let alert = &alerts[0];
+        assert_eq!(alert.alert_type, AlertType::HeartbeatMissing);
+        assert_eq!(alert.severity, AlertSeverity::Critical);
+        assert_eq!(alert.threshold, Some(timeout.num_seconds() as f32));
+        assert_eq!(alert.actual, Some(1800.0));
+
+        // At the node's own timeout it is still considered alive.
+        assert!(evaluate_alerts_at(&report, &thresholds, now - timeout, now).is_empty());
+    }
+
+    // ─── Report ingestion ───────────────────────────────────────────────────
+
+    #[test]
+    fn test_ingest_drops_duplicates_and_keeps_newest_per_instance() {
+        let t0 = Utc::now();
+        let at = |id: &str, secs: i64, score: u8| {
+            let mut report = make_healthy_report(id);
+            report.reported_at = t0 + chrono::Duration::seconds(secs);
+            report.health_score = score;
+            report
+        };
+
+        let mut ingester = HealthReportIngester::new();
+        let cleaned = ingester.ingest_health_reports(vec![
+            at("i-1", 0, 90),
+            at("i-1", 0, 90), // retried send
+            at("i-1", 30, 40),
+            at("i-1", 10, 80), // arrived out of order
+            at("i-2", 5, 95),
+        ]);
+
+        assert_eq!(cleaned.len(), 2);
+        assert_eq!(cleaned[0].instance_id, "i-1");
+        assert_eq!(cleaned[0].health_score, 40);
+        assert_eq!(cleaned[1].instance_id, "i-2");
+        assert_eq!(
+            ingester.last_seen("i-1"),
+            Some(t0 + chrono::Duration::seconds(30))
+        );
+        assert_eq!(
+            sweep_fleet(&cleaned, &HealthThresholds::default()).total_instances,
+            2
+        );
+    }
+
+    #[test]
+    fn test_ingest_drops_reports_older_than_last_seen() {
+        let t0 = Utc::now();
+        let at = |id: &str, secs: i64| {
+            let mut report = make_healthy_report(id);
+            report.reported_at = t0 + chrono::Duration::seconds(secs);
+            report
+        };
+
+        let mut ingester = HealthReportIngester::new();
+        ingester.ingest_health_reports(vec![at("i-1", 60)]);
+
+        // A stale retry and a replay of the same report are both dropped.
+        let cleaned = ingester.ingest_health_reports(vec![at("i-1", 30), at("i-1", 60)]);
+        assert!(cleaned.is_empty());
+
+        let cleaned = ingester.ingest_health_reports(vec![at("i-1", 90)]);
+        assert_eq!(cleaned.len(), 1);
+        assert_eq!(cleaned[0].reported_at, t0 + chrono::Duration::seconds(90));
+    }
+
+    #[test]
+    fn test_ingest_clamps_future_reports_and_forgets_silent_instances() {
+        let now = Utc::now();
+        let at = |id: &str, offset: chrono::Duration| {
+            let mut report = make_healthy_report(id);
+            report.reported_at = now + offset;
+            report
+        };
+
+        let mut ingester = HealthReportIngester::new();
+        // A node clock two hours fast is clamped rather than trusted.
+        let cleaned =
+            ingester.ingest_health_reports_at(vec![at("i-1", chrono::Duration::hours(2))], now);
+        assert_eq!(cleaned[0].reported_at, now);
+        assert_eq!(ingester.last_seen("i-1"), Some(now));
+
+        // The next correctly-timed report is not dropped as stale.
+        let later = now + chrono::Duration::minutes(1);
+        let cleaned =
+            ingester.ingest_health_reports_at(vec![at("i-1", chrono::Duration::minutes(1))], later);
+        assert_eq!(cleaned.len(), 1);
+
+        // Within the tolerance the timestamp is kept as reported.
+        let cleaned =
+            ingester.ingest_health_reports_at(vec![at("i-2", chrono::Duration::seconds(30))], now);
+        assert_eq!(cleaned[0].reported_at, now + chrono::Duration::seconds(30));
+
+        // A decommissioned instance's entry ages out.
+        let much_later = now + chrono::Duration::hours(LAST_SEEN_RETENTION_HOURS + 1);
+        ingester.ingest_health_reports_at(
+            vec![at(
+                "i-2",
+                chrono::Duration::hours(LAST_SEEN_RETENTION_HOURS + 1),
+            )],
+            much_later,
+        );
+        assert_eq!(ingester.last_seen("i-1"), None);
+        assert!(ingester.last_seen("i-2").is_some());
+    }
+
+    // ─── FailoverStateMachine tests ───────────────────────────────────────────
+
+    fn make_fsm(role: InstanceRole) -> FailoverStateMachine {
+        FailoverStateMachine::new("i-test".to_string(), role, HealthThresholds::default())
+    }
+
+    #[test]
+    fn test_fsm_healthy_stays_normal() {
+        let mut fsm = make_fsm(InstanceRole::Primary);
+        let t = fsm.transition(95, true);
+        assert_eq!(t, FailoverTransition::NoAction);
+        assert_eq!(fsm.state, FailoverState::Normal);
     }
 
     #[test]
@@ -941,7 +2626,8 @@ mod tests {
     fn test_fsm_heals_on_recovery() {
         let mut fsm = make_fsm(InstanceRole::Primary);
         fsm.transition(30, true); // → Healing attempt 1
-        let t = fsm.transition(85, true); // recovered
+        fsm.transition(85, true); // first good reading
+        let t = fsm.transition(85, true); // confirmed
         assert_eq!(t, FailoverTransition::LogRecovered);
         assert!(matches!(fsm.state, FailoverState::Recovered { .. }));
     }
@@ -997,7 +2683,8 @@ mod tests {
     fn test_fsm_monitoring_recovers() {
         let mut fsm = make_fsm(InstanceRole::Primary);
         fsm.transition(60, true); // → Monitoring
-        let t = fsm.transition(90, true); // recovered
+        assert_eq!(fsm.transition(90, true), FailoverTransition::Monitor);
+        let t = fsm.transition(90, true); // confirmed
         assert_eq!(t, FailoverTransition::LogRecovered);
     }
 
@@ -1005,4 +2692,830 @@ mod tests {
     fn test_fsm_max_heal_attempts_constant() {
         assert_eq!(MAX_HEAL_ATTEMPTS, 3);
     }
+
+    #[test]
+    fn test_scheduler_healthy_due_later_than_healing() {
+        let now = Utc::now();
+        let mut scheduler = HealthScheduler::new(CheckCadence::default());
+        let healthy_due = scheduler.record_check("i-ok", &FailoverState::Normal, now);
+        let healing_due = scheduler.record_check(
+            "i-sick",
+            &FailoverState::Healing {
+                attempt: 1,
+                started_at: now,
+                good_readings: 0,
+            },
+            now,
+        );
+        assert!(healthy_due > healing_due);
+        assert_eq!(healthy_due - now, chrono::Duration::seconds(300));
+        assert_eq!(healing_due - now, chrono::Duration::seconds(30));
+
+        // Only the healing instance is due after 30s.
+        let later = now + chrono::Duration::seconds(31);
+        assert_eq!(scheduler.due(later), vec!["i-sick".to_string()]);
+        assert_eq!(scheduler.due(now + chrono::Duration::seconds(301)).len(), 2);
+    }
+
+    #[test]
+    fn test_scheduler_track_and_remove() {
+        let now = Utc::now();
+        let mut scheduler = HealthScheduler::default();
+        scheduler.track("i-new", now);
+        assert_eq!(scheduler.due(now), vec!["i-new".to_string()]);
+
+        scheduler.record_check("i-new", &FailoverState::Normal, now);
+        scheduler.track("i-new", now);
+        assert!(scheduler.due(now).is_empty());
+
+        scheduler.remove("i-new");
+        assert!(scheduler.is_empty());
+    }
+
+    // ─── Heartbeat staleness ────────────────────────────────────────────────
+
+    #[test]
+    fn test_heartbeat_timeout_scales_with_node_interval() {
+        let t = HealthThresholds::default();
+        assert_eq!(t.heartbeat_timeout(Some(10)), chrono::Duration::seconds(40));
+        assert_eq!(
+            t.heartbeat_timeout(Some(120)),
+            chrono::Duration::seconds(480)
+        );
+        assert_eq!(t.heartbeat_timeout(None), chrono::Duration::minutes(5));
+        assert_eq!(t.heartbeat_timeout(Some(0)), chrono::Duration::minutes(5));
+    }
+
+    #[test]
+    fn test_heartbeat_staleness_proportional_to_interval() {
+        let t = HealthThresholds::default();
+        let now = Utc::now();
+        let mut fast = make_healthy_report("i-fast");
+        fast.heartbeat_interval_secs = Some(10);
+        let mut slow = make_healthy_report("i-slow");
+        slow.heartbeat_interval_secs = Some(120);
+
+        // 60s of silence: 6 missed beats for the fast node, half of one for the slow node.
+        fast.reported_at = now - chrono::Duration::seconds(60);
+        slow.reported_at = now - chrono::Duration::seconds(60);
+        assert!(is_heartbeat_stale(&fast, now, &t));
+        assert!(!is_heartbeat_stale(&slow, now, &t));
+
+        // 6 min: past the flat 5-min default, but still within 4×120s.
+        slow.reported_at = now - chrono::Duration::minutes(6);
+        assert!(!is_heartbeat_stale(&slow, now, &t));
+        slow.reported_at = now - chrono::Duration::minutes(9);
+        assert!(is_heartbeat_stale(&slow, now, &t));
+    }
+
+    #[test]
+    fn test_fleet_health_history_uptime() {
+        let t0 = Utc::now() - chrono::Duration::days(30);
+        let day = chrono::Duration::days(1);
+        let mut history = FleetHealthHistory::new();
+        // 20 days at 95, 6 days at 80, 4 days at 92.
+        history.record_score(t0, 95);
+        history.record_score(t0 + day * 26, 92);
+        history.record_score(t0 + day * 20, 80);
+
+        let to = t0 + day * 30;
+        let pct = history.uptime_pct(t0, to, 90);
+        assert!((pct - 80.0).abs() < 1e-9, "got {pct}");
+        assert_eq!(history.time_below(t0, to, 90), day * 6);
+
+        // Sub-range entirely inside the degraded period.
+        assert_eq!(history.uptime_pct(t0 + day * 21, t0 + day * 25, 90), 0.0);
+        // Time before the first sample is not counted.
+        let earlier = t0 - day * 10;
+        assert!((history.uptime_pct(earlier, to, 90) - 80.0).abs() < 1e-9);
+        assert_eq!(FleetHealthHistory::new().uptime_pct(t0, to, 90), 0.0);
+    }
+
+    #[test]
+    fn test_fleet_health_history_retention() {
+        let t0 = Utc::now();
+        let hour = chrono::Duration::hours(1);
+        let mut history = FleetHealthHistory::with_retention(hour * 2);
+        for i in 0..6 {
+            let mut sweep = FleetHealthSweepResult::new();
+            sweep.total_instances = 10;
+            sweep.healthy = 10;
+            sweep.swept_at = t0 + hour * i;
+            history.record(&sweep);
+        }
+        // Samples at 3h, 4h, 5h are within the window; the 2h sample still
+        // bounds the window start.
+        assert_eq!(history.len(), 4);
+        assert_eq!(history.uptime_pct(t0 + hour * 3, t0 + hour * 5, 100), 100.0);
+    }
+
+    fn check_with_alerts(
+        instance_id: &str,
+        alerts: &[(AlertType, AlertSeverity)],
+    ) -> HealthCheckResult {
+        HealthCheckResult {
+            instance_id: instance_id.to_string(),
+            provider: VpsProvider::Hetzner,
+            region: "nbg1".to_string(),
+            health_score: 50,
+            status: InstanceState::Degraded,
+            alerts: alerts
+                .iter()
+                .map(|(alert_type, severity)| HealthAlert {
+                    alert_type: alert_type.clone(),
+                    severity: *severity,
+                    message: String::new(),
+                    threshold: None,
+                    actual: None,
+                })
+                .collect(),
+            recommended_action: RecommendedAction::Monitor,
+            checked_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_top_alerts_ordered_by_severity_then_frequency() {
+        use AlertSeverity::{Critical, Warning};
+        let detailed = vec![
+            check_with_alerts("i-1", &[(AlertType::DiskUsageHigh, Warning)]),
+            check_with_alerts("i-2", &[(AlertType::DiskUsageHigh, Warning)]),
+            check_with_alerts(
+                "i-3",
+                &[
+                    (AlertType::DiskUsageHigh, Warning),
+                    (AlertType::DockerDown, Critical),
+                ],
+            ),
+            check_with_alerts("i-4", &[(AlertType::OpenClawDown, Critical)]),
+            check_with_alerts(
+                "i-5",
+                &[
+                    (AlertType::OpenClawDown, Critical),
+                    (AlertType::CpuUsageHigh, Warning),
+                ],
+            ),
+        ];
+        let mut sweep = FleetHealthSweepResult::new();
+        sweep.total_instances = 10;
+
+        let top = sweep.top_alerts(&detailed, 3);
+        assert_eq!(
+            top,
+            vec![
+                "Critical: OpenClaw down on 2 of 10 instances (e.g. i-4)".to_string(),
+                "Critical: Docker down on 1 of 10 instances (e.g. i-3)".to_string(),
+                "Warning: disk usage high on 3 of 10 instances (e.g. i-1)".to_string(),
+            ]
+        );
+        assert_eq!(sweep.top_alerts(&detailed, 10).len(), 4);
+        assert!(sweep.top_alerts(&detailed, 0).is_empty());
+    }
+
+    // ─── Failover simulation tests ────────────────────────────────────────────
+
+    #[test]
+    fn test_simulate_failover_on_fourth_critical_cycle() {
+        let fsm = make_fsm(InstanceRole::Primary);
+        let transitions = fsm.simulate(&[20, 20, 20, 20], &[true]);
+        assert_eq!(
+            transitions,
+            vec![
+                FailoverTransition::AttemptDockerRestart { attempt: 1 },
+                FailoverTransition::AttemptDockerRestart { attempt: 2 },
+                FailoverTransition::AttemptDockerRestart { attempt: 3 },
+                FailoverTransition::InitiateFailover,
+            ]
+        );
+        // The real machine is untouched.
+        assert_eq!(fsm.state, FailoverState::Normal);
+    }
+
+    #[test]
+    fn test_simulate_follows_standby_sequence_and_recovery() {
+        let fsm = make_fsm(InstanceRole::Primary);
+        let transitions = fsm.simulate(
+            &[60, 60, 90, 90, 95, 20, 20, 20, 20],
+            &[true, true, true, true, true, false],
+        );
+        assert_eq!(
+            transitions,
+            vec![
+                FailoverTransition::Monitor,
+                FailoverTransition::Monitor,
+                FailoverTransition::Monitor,
+                FailoverTransition::LogRecovered,
+                FailoverTransition::NoAction,
+                FailoverTransition::AttemptDockerRestart { attempt: 1 },
+                FailoverTransition::AttemptDockerRestart { attempt: 2 },
+                FailoverTransition::AttemptDockerRestart { attempt: 3 },
+                FailoverTransition::EscalateToCommander {
+                    reason: "heal exhausted and standby not active".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_simulate_starts_from_current_state() {
+        let mut fsm = make_fsm(InstanceRole::Primary);
+        fsm.transition(30, true); // Healing attempt 1
+        let transitions = fsm.simulate(&[30, 30, 30], &[true]);
+        assert_eq!(
+            transitions.last(),
+            Some(&FailoverTransition::InitiateFailover)
+        );
+        assert!(matches!(
+            fsm.state,
+            FailoverState::Healing { attempt: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn test_single_instance_never_fails_over() {
+        let fsm = FailoverStateMachine::single_instance(
+            "i-trial".to_string(),
+            InstanceRole::Primary,
+            HealthThresholds::default(),
+        );
+        let transitions = fsm.simulate(&[20, 20, 20, 20, 20], &[true]);
+        assert!(!transitions.contains(&FailoverTransition::InitiateFailover));
+        assert_eq!(
+            transitions[3],
+            FailoverTransition::EscalateToCommander {
+                reason: "heal exhausted and account has no standby".to_string(),
+            }
+        );
+    }
+
+    // ─── Standby promotion ──────────────────────────────────────────────────
+
+    fn make_member(id: &str, role: InstanceRole, state: InstanceState) -> InstanceSnapshot {
+        InstanceSnapshot {
+            instance_id: id.to_string(),
+            account_id: "acc-1".to_string(),
+            provider: "hetzner".to_string(),
+            role,
+            state,
+            cpu_pct: 10.0,
+            mem_pct: 10.0,
+            disk_pct: 10.0,
+            health_score: 90.0,
+            monthly_cost_usd: 12.0,
+            recorded_at: Utc::now() - chrono::Duration::minutes(5),
+            tier: None,
+            provider_instance_id: None,
+            region: None,
+        }
+    }
+
+    #[test]
+    fn test_promote_standby_swaps_roles() {
+        let mut primary = make_member("i-p", InstanceRole::Primary, InstanceState::Failed);
+        let mut standby = make_member("i-s", InstanceRole::Standby, InstanceState::Active);
+        let before = primary.recorded_at;
+
+        let result = promote_standby(&mut primary, &mut standby).expect("promotion");
+
+        assert_eq!(standby.role, InstanceRole::Primary);
+        assert_eq!(standby.state, InstanceState::Active);
+        assert_eq!(primary.role, InstanceRole::Standby);
+        assert_eq!(primary.state, InstanceState::Maintenance);
+        assert!(primary.recorded_at > before);
+        assert_eq!(standby.recorded_at, result.completed_at);
+
+        assert!(result.success);
+        assert_eq!(result.account_id, "acc-1");
+        assert_eq!(result.old_primary, "i-p");
+        assert_eq!(result.new_primary, "i-s");
+        let steps: Vec<_> = result.steps.iter().map(|s| s.step).collect();
+        assert_eq!(
+            steps,
+            vec![
+                FailoverStepType::VerifyStandby,
+                FailoverStepType::UpdatePairStatus
+            ]
+        );
+    }
+
+    #[test]
+    fn test_promote_standby_refuses_inactive_standby() {
+        let mut primary = make_member("i-p", InstanceRole::Primary, InstanceState::Failed);
+        let mut standby = make_member("i-s", InstanceRole::Standby, InstanceState::Degraded);
+
+        let err = promote_standby(&mut primary, &mut standby).unwrap_err();
+        assert!(err.to_string().contains("not ACTIVE"), "{err}");
+        // Nothing changed.
+        assert_eq!(primary.role, InstanceRole::Primary);
+        assert_eq!(primary.state, InstanceState::Failed);
+        assert_eq!(standby.role, InstanceRole::Standby);
+    }
+
+    // ─── Failover registry ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_failover_registry_partitions_by_state() {
+        let mut registry = FailoverRegistry::new();
+        for (id, role) in [
+            ("i-ok", InstanceRole::Primary),
+            ("i-heal", InstanceRole::Primary),
+            ("i-over", InstanceRole::Primary),
+            ("i-dead", InstanceRole::Standby),
+        ] {
+            registry.insert(FailoverStateMachine::new(
+                id.to_string(),
+                role,
+                HealthThresholds::default(),
+            ));
+        }
+
+        registry.transition("i-ok", 95, true);
+        registry.transition("i-heal", 10, true);
+        for _ in 0..=MAX_HEAL_ATTEMPTS {
+            registry.transition("i-over", 10, true);
+            registry.transition("i-dead", 10, true);
+        }
+        assert!(registry.transition("i-missing", 10, true).is_none());
+
+        assert_eq!(registry.healing(), vec!["i-heal"]);
+        assert_eq!(registry.failing_over(), vec!["i-over"]);
+        assert_eq!(registry.failed(), vec!["i-dead"]);
+        assert_eq!(
+            registry.instances_in_state(|s| *s == FailoverState::Normal),
+            vec!["i-ok"]
+        );
+        assert_eq!(registry.len(), 4);
+    }
+
+    // ─── Incident bridge ────────────────────────────────────────────────────
+
+    #[test]
+    fn test_critical_result_becomes_health_event() {
+        let mut result = check_with_alerts(
+            "i-down",
+            &[
+                (AlertType::OpenClawDown, AlertSeverity::Critical),
+                (AlertType::CpuUsageHigh, AlertSeverity::Warning),
+            ],
+        );
+        result.alerts[0].message = "OpenClaw is down".to_string();
+        result.health_score = 12;
+        result.status = InstanceState::Failed;
+        result.recommended_action = recommend_action(12, &HealthThresholds::default());
+        assert_eq!(
+            result.recommended_action,
+            RecommendedAction::EscalateToCommander
+        );
+
+        let event = result.to_health_event("acc-1", 3, true).unwrap();
+        assert_eq!(event.instance_id, "i-down");
+        assert_eq!(event.account_id, "acc-1");
+        assert_eq!(event.health_score, 12);
+        assert_eq!(event.provider, VpsProvider::Hetzner);
+        assert_eq!(event.region, "nbg1");
+        assert_eq!(event.description, "OpenClaw is down");
+        assert_eq!(event.affected_users, 3);
+        assert!(event.data_loss_risk);
+
+        let mut manager = claw_triage::IncidentManager::new();
+        let incident = manager.create_incident(event);
+        assert!(incident.title.contains("score: 12"), "{}", incident.title);
+    }
+
+    #[test]
+    fn test_healthy_result_has_no_health_event() {
+        let mut result = check_with_alerts("i-ok", &[]);
+        result.health_score = 95;
+        result.recommended_action = recommend_action(95, &HealthThresholds::default());
+        assert!(result.to_health_event("acc-1", 1, false).is_none());
+    }
+
+    // ─── Failover hysteresis tests ────────────────────────────────────────────
+
+    #[test]
+    fn test_fsm_single_good_reading_does_not_recover() {
+        let mut fsm = make_fsm(InstanceRole::Primary);
+        fsm.transition(30, true); // → Healing attempt 1
+        let t = fsm.transition(75, true);
+        assert_eq!(t, FailoverTransition::NoAction);
+        assert!(matches!(
+            fsm.state,
+            FailoverState::Healing {
+                attempt: 1,
+                good_readings: 1,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_fsm_recovers_after_n_consecutive_good_readings() {
+        let mut fsm = make_fsm(InstanceRole::Primary).with_recovery_confirmations(3);
+        fsm.transition(30, true); // → Healing attempt 1
+        assert_eq!(fsm.transition(80, true), FailoverTransition::NoAction);
+        assert_eq!(fsm.transition(80, true), FailoverTransition::NoAction);
+        assert_eq!(fsm.transition(80, true), FailoverTransition::LogRecovered);
+        assert!(matches!(fsm.state, FailoverState::Recovered { .. }));
+    }
+
+    #[test]
+    fn test_fsm_bad_reading_resets_recovery_count() {
+        let mut fsm = make_fsm(InstanceRole::Primary);
+        fsm.transition(30, true); // → Healing attempt 1
+        fsm.transition(80, true);
+        let t = fsm.transition(35, true); // relapse before confirmation
+        assert_eq!(t, FailoverTransition::AttemptDockerRestart { attempt: 2 });
+        assert_eq!(fsm.transition(80, true), FailoverTransition::NoAction);
+        assert_eq!(fsm.transition(80, true), FailoverTransition::LogRecovered);
+    }
+
+    #[test]
+    fn test_fsm_hovering_at_critical_does_not_flap() {
+        let fsm = make_fsm(InstanceRole::Primary);
+        // Alternating just above and just below degraded_score (70).
+        let transitions = fsm.simulate(&[30, 70, 69, 70, 69], &[true]);
+        assert!(!transitions.contains(&FailoverTransition::LogRecovered));
+    }
+
+    #[test]
+    fn test_fsm_decline_confirmations_delay_healing() {
+        let mut fsm = make_fsm(InstanceRole::Primary).with_decline_confirmations(2);
+        assert_eq!(fsm.transition(30, true), FailoverTransition::Monitor);
+        assert!(matches!(
+            fsm.state,
+            FailoverState::Monitoring {
+                critical_readings: 1,
+                ..
+            }
+        ));
+        // A non-critical reading in between breaks the streak.
+        assert_eq!(fsm.transition(60, true), FailoverTransition::Monitor);
+        assert_eq!(fsm.transition(30, true), FailoverTransition::Monitor);
+        assert_eq!(
+            fsm.transition(30, true),
+            FailoverTransition::AttemptDockerRestart { attempt: 1 }
+        );
+    }
+
+    #[test]
+    fn test_fsm_state_without_counters_deserializes() {
+        let state: FailoverState = serde_json::from_str(
+            r#"{"healing":{"attempt":2,"started_at":"2026-01-01T00:00:00Z"}}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            state,
+            FailoverState::Healing {
+                attempt: 2,
+                good_readings: 0,
+                ..
+            }
+        ));
+    }
+
+    // ─── Failover state persistence tests ─────────────────────────────────────
+
+    #[test]
+    fn test_failover_state_store_round_trip() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let store = FailoverStateStore::new(dir.path());
+
+        let mut registry = FailoverRegistry::new();
+        let mut healing = make_fsm(InstanceRole::Primary).with_recovery_confirmations(3);
+        healing.transition(30, true); // Healing attempt 1
+        healing.transition(25, true); // Healing attempt 2
+        registry.insert(healing);
+        registry.insert(FailoverStateMachine::single_instance(
+            "i-trial".to_string(),
+            InstanceRole::Primary,
+            HealthThresholds::default(),
+        ));
+        store.save(&registry).expect("save");
+
+        let loaded = store.load(HealthThresholds::default());
+        assert_eq!(loaded.len(), 2);
+        let machine = loaded.get("i-test").unwrap();
+        assert!(matches!(
+            machine.state,
+            FailoverState::Healing { attempt: 2, .. }
+        ));
+        assert_eq!(
+            machine.to_persisted(),
+            registry.get("i-test").unwrap().to_persisted()
+        );
+        assert!(!loaded.get("i-trial").unwrap().to_persisted().has_standby);
+    }
+
+    #[test]
+    fn test_failover_state_store_load_uses_the_given_thresholds() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let store = FailoverStateStore::new(dir.path());
+        store.save(&FailoverRegistry::new()).expect("save");
+
+        let strict = HealthThresholds {
+            degraded_score: 90,
+            ..HealthThresholds::default()
+        };
+        let mut loaded = store.load(strict);
+        loaded.observe("i-new", InstanceRole::Primary, 80, true);
+        assert!(matches!(
+            loaded.get("i-new").unwrap().state,
+            FailoverState::Monitoring { .. }
+        ));
+
+        let mut loaded = store.load(HealthThresholds::default());
+        loaded.observe("i-new", InstanceRole::Primary, 80, true);
+        assert_eq!(loaded.get("i-new").unwrap().state, FailoverState::Normal);
+    }
+
+    #[test]
+    fn test_failover_state_store_downgrades_stale_in_flight_states() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let store = FailoverStateStore::new(dir.path()).with_ttl(chrono::Duration::minutes(10));
+        let now = Utc::now();
+
+        let mut registry = FailoverRegistry::new();
+        for (id, state) in [
+            (
+                "i-stale-heal",
+                FailoverState::Healing {
+                    attempt: 2,
+                    started_at: now - chrono::Duration::minutes(30),
+                    good_readings: 0,
+                },
+            ),
+            (
+                "i-stale-failover",
+                FailoverState::FailingOver {
+                    started_at: now - chrono::Duration::minutes(11),
+                    good_readings: 0,
+                },
+            ),
+            (
+                "i-fresh-heal",
+                FailoverState::Healing {
+                    attempt: 3,
+                    started_at: now - chrono::Duration::minutes(2),
+                    good_readings: 0,
+                },
+            ),
+        ] {
+            let mut machine = FailoverStateMachine::new(
+                id.to_string(),
+                InstanceRole::Primary,
+                HealthThresholds::default(),
+            );
+            machine.state = state;
+            registry.insert(machine);
+        }
+        store.save(&registry).expect("save");
+
+        let loaded = store.load_at(HealthThresholds::default(), now);
+        assert!(matches!(
+            loaded.get("i-stale-heal").unwrap().state,
+            FailoverState::Monitoring { .. }
+        ));
+        assert!(matches!(
+            loaded.get("i-stale-failover").unwrap().state,
+            FailoverState::Monitoring { .. }
+        ));
+        assert!(matches!(
+            loaded.get("i-fresh-heal").unwrap().state,
+            FailoverState::Healing { attempt: 3, .. }
+        ));
+    }
+
+    // ─── Failover registry observe tests ──────────────────────────────────────
+
+    #[test]
+    fn test_registry_observe_creates_machine_on_first_report() {
+        let mut registry = FailoverRegistry::new();
+        assert!(registry.is_empty());
+
+        let t = registry.observe("i-new", InstanceRole::Primary, 30, true);
+        assert_eq!(t, FailoverTransition::AttemptDockerRestart { attempt: 1 });
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.healing(), vec!["i-new"]);
+
+        // The second report drives the same machine.
+        let t = registry.observe("i-new", InstanceRole::Primary, 30, true);
+        assert_eq!(t, FailoverTransition::AttemptDockerRestart { attempt: 2 });
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn test_registry_reset_leaves_other_instances_alone() {
+        let mut registry = FailoverRegistry::new();
+        for _ in 0..=MAX_HEAL_ATTEMPTS {
+            registry.observe("i-a", InstanceRole::Standby, 10, false);
+            registry.observe("i-b", InstanceRole::Standby, 10, false);
+        }
+        let mut escalating: Vec<&str> = registry
+            .iter_needing_escalation()
+            .map(|m| m.instance_id.as_str())
+            .collect();
+        escalating.sort_unstable();
+        assert_eq!(escalating, vec!["i-a", "i-b"]);
+
+        assert!(registry.reset("i-a"));
+        assert!(!registry.reset("i-missing"));
+        assert_eq!(registry.get("i-a").unwrap().state, FailoverState::Normal);
+        assert!(registry.get("i-b").unwrap().state.needs_escalation());
+        assert_eq!(registry.failed(), vec!["i-b"]);
+    }
+
+    #[test]
+    fn test_registry_observe_fleet_pairs_standbys() {
+        let mut registry = FailoverRegistry::new();
+        let mut primary = make_healthy_report("i-primary");
+        primary.openclaw_status = ServiceStatus::Down;
+        primary.docker_running = false;
+        primary.tailscale_connected = false;
+        let mut standby = make_healthy_report("i-standby");
+        standby.role = InstanceRole::Standby;
+
+        for _ in 0..MAX_HEAL_ATTEMPTS {
+            registry.observe_fleet(&[primary.clone(), standby.clone()]);
+        }
+        let transitions = registry.observe_fleet(&[primary, standby]);
+        assert_eq!(
+            transitions,
+            vec![
+                (
+                    "i-primary".to_string(),
+                    FailoverTransition::InitiateFailover
+                ),
+                ("i-standby".to_string(), FailoverTransition::NoAction),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_registry_observe_fleet_matches_sweep_grace_and_standby_fitness() {
+        let thresholds = HealthThresholds::default();
+        let mut registry = FailoverRegistry::with_thresholds(thresholds.clone());
+        let now = Utc::now();
+
+        // Freshly provisioned and still in cloud-init: the sweep calls it
+        // bootstrapping, so its machine must not start healing it.
+        let mut booting = make_healthy_report("i-booting");
+        booting.openclaw_status = ServiceStatus::Down;
+        booting.docker_running = false;
+        let provisioned_at = HashMap::from([("i-booting".to_string(), now)]);
+        assert!(
+            registry
+                .observe_fleet_at(&[booting.clone()], &provisioned_at, now)
+                .is_empty()
+        );
+        assert!(registry.get("i-booting").is_none());
+        let sweep = sweep_fleet_at(&[booting], &thresholds, &provisioned_at, now);
+        assert_eq!(sweep.bootstrapping, 1);
+
+        // An ACTIVE standby that is itself unhealthy cannot take over.
+        let mut primary = make_healthy_report("i-primary");
+        primary.openclaw_status = ServiceStatus::Down;
+        primary.docker_running = false;
+        primary.tailscale_connected = false;
+        let mut standby = make_healthy_report("i-standby");
+        standby.role = InstanceRole::Standby;
+        standby.openclaw_status = ServiceStatus::Down;
+        for _ in 0..MAX_HEAL_ATTEMPTS {
+            registry.observe_fleet(&[primary.clone(), standby.clone()]);
+        }
+        let transitions = registry.observe_fleet(&[primary.clone(), standby.clone()]);
+        assert_ne!(transitions[0].1, FailoverTransition::InitiateFailover);
+        assert_eq!(
+            sweep_fleet(&[primary, standby], &thresholds).failovers_triggered,
+            0
+        );
+    }
+
+    // ─── Per-tier threshold tests ─────────────────────────────────────────────
+
+    fn make_tiered_thresholds() -> HealthThresholdsByTier {
+        HealthThresholdsByTier::new(HealthThresholds {
+            cpu_alert_pct: 85.0,
+            ..HealthThresholds::default()
+        })
+        .with_tier(
+            InstanceTier::Nano,
+            HealthThresholds {
+                cpu_alert_pct: 95.0,
+                ..HealthThresholds::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_nano_cpu_override_suppresses_alert_enterprise_still_alerts() {
+        let by_tier = make_tiered_thresholds();
+        let mut nano = make_healthy_report("i-nano");
+        nano.tier = InstanceTier::Nano;
+        nano.cpu_usage_1m = 88.0;
+        let mut enterprise = nano.clone();
+        enterprise.instance_id = "i-enterprise".to_string();
+        enterprise.tier = InstanceTier::Enterprise;
+
+        assert!(
+            !by_tier
+                .evaluate_alerts(&nano)
+                .iter()
+                .any(|a| a.alert_type == AlertType::CpuUsageHigh)
+        );
+        let alerts = by_tier.evaluate_alerts(&enterprise);
+        let cpu = alerts
+            .iter()
+            .find(|a| a.alert_type == AlertType::CpuUsageHigh)
+            .expect("enterprise cpu alert");
+        assert_eq!(cpu.threshold, Some(85.0));
+    }
+
+    #[test]
+    fn test_thresholds_by_tier_falls_back_to_default() {
+        let by_tier = make_tiered_thresholds();
+        assert_eq!(by_tier.thresholds_for(InstanceTier::Pro), &by_tier.default);
+        assert_eq!(
+            by_tier.thresholds_for(InstanceTier::Nano).cpu_alert_pct,
+            95.0
+        );
+        assert_eq!(
+            by_tier.recommend_action(InstanceTier::Nano, 50, None),
+            RecommendedAction::Monitor
+        );
+        // Inside the provision grace window a critical score is only monitored.
+        assert_eq!(
+            by_tier.recommend_action(InstanceTier::Nano, 10, None),
+            RecommendedAction::EscalateToCommander
+        );
+        assert_eq!(
+            by_tier.recommend_action(InstanceTier::Nano, 10, Some(chrono::Duration::seconds(30))),
+            RecommendedAction::Monitor
+        );
+    }
+
+    #[test]
+    fn test_tiered_thresholds_drive_sweep_alerts_and_failover_registry() {
+        // Nano tolerates a lower score before it counts as degraded.
+        let tiers = HealthThresholdsByTier::default().with_tier(
+            InstanceTier::Nano,
+            HealthThresholds {
+                degraded_score: 50,
+                cpu_alert_pct: 95.0,
+                ..HealthThresholds::default()
+            },
+        );
+        let mut hot = make_healthy_report("i-hot");
+        hot.tailscale_connected = false;
+        hot.docker_running = false;
+        hot.cpu_usage_1m = 92.0;
+        let mut nano = hot.clone();
+        nano.instance_id = "i-nano".to_string();
+        nano.tier = InstanceTier::Nano;
+        let mut enterprise = hot;
+        enterprise.instance_id = "i-enterprise".to_string();
+        enterprise.tier = InstanceTier::Enterprise;
+
+        let sweep = sweep_fleet(&[nano.clone(), enterprise.clone()], &tiers);
+        assert_eq!((sweep.healthy, sweep.degraded), (1, 1));
+        assert_eq!(
+            recommend_action_for_instance(&nano, &tiers, false),
+            RecommendedAction::None
+        );
+
+        let now = Utc::now();
+        let cpu_alert = |report: &HealthReport| {
+            evaluate_alerts_at(report, &tiers, now, now)
+                .iter()
+                .any(|a| a.alert_type == AlertType::CpuUsageHigh)
+        };
+        assert!(!cpu_alert(&nano));
+        assert!(cpu_alert(&enterprise));
+
+        let mut registry = FailoverRegistry::with_thresholds(tiers);
+        registry.observe_fleet(&[nano, enterprise]);
+        assert_eq!(
+            registry.get("i-nano").unwrap().thresholds.degraded_score,
+            50
+        );
+        assert_eq!(
+            registry
+                .get("i-enterprise")
+                .unwrap()
+                .thresholds
+                .degraded_score,
+            claw_proto::DEGRADED_HEALTH_SCORE
+        );
+    }
+
+    #[test]
+    fn test_thresholds_by_tier_serde_round_trip() {
+        let by_tier = make_tiered_thresholds();
+        let json = serde_json::to_value(&by_tier).unwrap();
+        assert_eq!(json["tiers"]["nano"]["cpu_alert_pct"], 95.0);
+        let back: HealthThresholdsByTier = serde_json::from_value(json).unwrap();
+        assert_eq!(back, by_tier);
+
+        let empty: HealthThresholdsByTier = serde_json::from_str("{}").unwrap();
+        assert_eq!(empty, HealthThresholdsByTier::default());
+    }
 }
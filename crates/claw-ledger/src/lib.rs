@@ -6,9 +6,13 @@
 
 #![forbid(unsafe_code)]
 
-use chrono::{DateTime, Utc};
-use claw_proto::{FleetStatus, InstanceTier, VpsProvider};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use claw_proto::{FleetStatus, InstanceTier, NetworkMetrics, VpsProvider, percentile};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{PoisonError, RwLock};
+use tracing::warn;
 
 // ─── Provider stats ───────────────────────────────────────────────────────────
 
@@ -24,6 +28,159 @@ pub struct ProviderStats {
     pub period_days: u32,
 }
 
+// ─── Currency ─────────────────────────────────────────────────────────────────
+
+/// Currency all engine math is done in. Conversion only happens at render time.
+pub const BASE_CURRENCY: Currency = Currency::Usd;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Currency {
+    #[default]
+    Usd,
+    Eur,
+    Gbp,
+}
+
+impl Currency {
+    /// ISO 4217 code.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Usd => "USD",
+            Self::Eur => "EUR",
+            Self::Gbp => "GBP",
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Usd => "$",
+            Self::Eur => "€",
+            Self::Gbp => "£",
+        }
+    }
+}
+
+/// An amount tagged with its currency.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Money {
+    pub amount: f64,
+    pub currency: Currency,
+}
+
+impl Money {
+    pub fn new(amount: f64, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    /// Convert into `to` using `rates`. `None` if either rate is unknown.
+    pub fn convert(&self, to: Currency, rates: &ExchangeRates) -> Option<Money> {
+        let rate = rates.rate(self.currency, to)?;
+        Some(Money::new(self.amount * rate, to))
+    }
+}
+
+/// Renders as `<symbol><amount>`; honours `{:.N}` precision (default 2).
+impl std::fmt::Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        write!(f, "{}{:.*}", self.currency.symbol(), precision, self.amount)
+    }
+}
+
+/// How money amounts are rendered in summaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CostFormat {
+    /// Digits after the decimal point.
+    pub decimals: usize,
+    /// Group whole units in thousands: "$1,341" rather than "$1341".
+    pub thousands_separator: bool,
+}
+
+/// Whole units, no grouping — the `{:.0}` summaries have always used.
+impl Default for CostFormat {
+    fn default() -> Self {
+        Self {
+            decimals: 0,
+            thousands_separator: false,
+        }
+    }
+}
+
+impl CostFormat {
+    pub fn render(&self, money: Money) -> String {
+        let digits = format!("{:.*}", self.decimals, money.amount);
+        if !self.thousands_separator {
+            return format!("{}{digits}", money.currency.symbol());
+        }
+        let (sign, unsigned) = match digits.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", digits.as_str()),
+        };
+        let (whole, fraction) = match unsigned.split_once('.') {
+            Some((w, f)) => (w, Some(f)),
+            None => (unsigned, None),
+        };
+        let mut grouped = String::with_capacity(whole.len() + whole.len() / 3);
+        for (i, c) in whole.chars().enumerate() {
+            if i > 0 && (whole.len() - i) % 3 == 0 {
+                grouped.push(',');
+            }
+            grouped.push(c);
+        }
+        if let Some(fraction) = fraction {
+            grouped.push('.');
+            grouped.push_str(fraction);
+        }
+        format!("{}{sign}{grouped}", money.currency.symbol())
+    }
+}
+
+/// Fixed exchange-rate table, expressed as units of each currency per one unit of `base`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExchangeRates {
+    pub base: Currency,
+    rates: HashMap<Currency, f64>,
+    pub as_of: DateTime<Utc>,
+}
+
+impl ExchangeRates {
+    pub fn new(base: Currency) -> Self {
+        let mut rates = HashMap::new();
+        rates.insert(base, 1.0);
+        Self {
+            base,
+            rates,
+            as_of: Utc::now(),
+        }
+    }
+
+    /// Set how many units of `currency` one unit of the base currency buys.
+    pub fn with_rate(mut self, currency: Currency, per_base: f64) -> Self {
+        if currency != self.base && per_base > 0.0 {
+            self.rates.insert(currency, per_base);
+        }
+        self
+    }
+
+    /// Multiplier to turn an amount in `from` into `to`.
+    pub fn rate(&self, from: Currency, to: Currency) -> Option<f64> {
+        if from == to {
+            return Some(1.0);
+        }
+        let from_per_base = self.rates.get(&from)?;
+        let to_per_base = self.rates.get(&to)?;
+        Some(to_per_base / from_per_base)
+    }
+}
+
+/// Render a base-currency amount in `display`, falling back to the original
+/// currency when no rate is known.
+fn display_money(amount: f64, from: Currency, display: Currency, rates: &ExchangeRates) -> Money {
+    let money = Money::new(amount, from);
+    money.convert(display, rates).unwrap_or(money)
+}
+
 // ─── Waste report ─────────────────────────────────────────────────────────────
 
 /// An idle account: no activity for >= 14 days.
@@ -45,6 +202,7 @@ pub struct OversizedInstance {
     pub avg_cpu_pct: f64,
     pub avg_mem_pct: f64,
     pub monthly_savings_usd: f64,
+    pub confidence: OptimizationConfidence,
 }
 
 /// A provider arbitrage opportunity: same workload is cheaper elsewhere.
@@ -58,6 +216,18 @@ pub struct ProviderArbitrage {
     pub monthly_savings_usd: f64,
 }
 
+/// Monthly savings of `arb` after spreading the one-time migration cost
+/// (re-provisioning, egress, snapshot transfer) over `amortize_months`.
+///
+/// A zero-month window is treated as one month.
+pub fn net_arbitrage(
+    arb: &ProviderArbitrage,
+    migration_cost_usd: f64,
+    amortize_months: u32,
+) -> f64 {
+    arb.monthly_savings_usd - migration_cost_usd / f64::from(amortize_months.max(1))
+}
+
 /// Complete waste detection report.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WasteReport {
@@ -66,11 +236,36 @@ pub struct WasteReport {
     pub oversized_instances: Vec<OversizedInstance>,
     pub provider_arbitrage: Vec<ProviderArbitrage>,
     pub total_recoverable_monthly_usd: f64,
+    /// Currency the amounts above are held in.
+    #[serde(default)]
+    pub currency: Currency,
 }
 
 impl WasteReport {
     /// Human-readable three-category summary (matches PRD §4.1).
     pub fn summary(&self) -> String {
+        self.summary_in(self.currency, &ExchangeRates::new(self.currency))
+    }
+
+    /// Same as [`summary`](Self::summary), with amounts rendered in `display`.
+    pub fn summary_in(&self, display: Currency, rates: &ExchangeRates) -> String {
+        self.summary_in_fmt(display, rates, &CostFormat::default())
+    }
+
+    /// Same as [`summary`](Self::summary), with amounts rendered per `format`.
+    pub fn summary_fmt(&self, format: &CostFormat) -> String {
+        self.summary_in_fmt(self.currency, &ExchangeRates::new(self.currency), format)
+    }
+
+    /// Amounts converted to `display` and rendered per `format`.
+    pub fn summary_in_fmt(
+        &self,
+        display: Currency,
+        rates: &ExchangeRates,
+        format: &CostFormat,
+    ) -> String {
+        let money =
+            |amount: f64| format.render(display_money(amount, self.currency, display, rates));
         let idle_cost: f64 = self.idle_accounts.iter().map(|a| a.monthly_cost_usd).sum();
         let oversize_savings: f64 = self
             .oversized_instances
@@ -84,20 +279,161 @@ impl WasteReport {
             .sum();
 
         format!(
-            "Three categories: (1) {} idle accounts (14+ days no activity) — ${:.0}/month. \
+            "Three categories: (1) {} idle accounts (14+ days no activity) — {}/month. \
              Recommend teardown with 30-day archive. \
-             (2) {} accounts on oversized tier with low usage (< 20% CPU/RAM) — ${:.0}/month savings if downsized. \
-             (3) {} provider arbitrage opportunities — ${:.0}/month. \
-             Total recoverable: ~${:.0}/month.",
+             (2) {} accounts on oversized tier with low usage (< 20% CPU/RAM) — {}/month savings if downsized. \
+             (3) {} provider arbitrage opportunities — {}/month. \
+             Total recoverable: ~{}/month.",
             self.idle_accounts.len(),
-            idle_cost,
+            money(idle_cost),
             self.oversized_instances.len(),
-            oversize_savings,
+            money(oversize_savings),
             self.provider_arbitrage.len(),
-            arb_savings,
-            self.total_recoverable_monthly_usd,
+            money(arb_savings),
+            money(self.total_recoverable_monthly_usd),
+        )
+    }
+}
+
+// ─── Waste policy ─────────────────────────────────────────────────────────────
+
+/// Health reports arrive once a minute from each clawnode.
+pub const DEFAULT_REPORT_INTERVAL_SECS: u64 = 60;
+
+/// Usage below which an instance counts as oversized. Both must hold.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OversizeThresholds {
+    pub cpu_pct: f64,
+    pub mem_pct: f64,
+}
+
+impl Default for OversizeThresholds {
+    fn default() -> Self {
+        Self {
+            cpu_pct: 20.0,
+            mem_pct: 20.0,
+        }
+    }
+}
+
+/// Typical usage at or above which an instance counts as saturated. Either
+/// resource is enough.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SaturationThresholds {
+    pub cpu_pct: f64,
+    pub mem_pct: f64,
+}
+
+impl Default for SaturationThresholds {
+    fn default() -> Self {
+        Self {
+            cpu_pct: 80.0,
+            mem_pct: 80.0,
+        }
+    }
+}
+
+/// When an instance can be called oversized, and how much history that takes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WastePolicy {
+    /// Below this many days of data, oversized detection does not fire.
+    pub min_observation_days: u32,
+    /// At or above this many days, downsize recommendations are High confidence.
+    pub high_confidence_observation_days: u32,
+    #[serde(default)]
+    pub oversize: OversizeThresholds,
+    #[serde(default)]
+    pub saturation: SaturationThresholds,
+    /// One-time cost of moving an instance to another provider.
+    #[serde(default = "default_migration_cost_usd")]
+    pub migration_cost_usd: f64,
+    /// Months over which the migration cost must pay for itself.
+    #[serde(default = "default_migration_amortize_months")]
+    pub migration_amortize_months: u32,
+    /// Seconds between usage reports, for accounts that carry a report count.
+    #[serde(default = "default_report_interval_secs")]
+    pub report_interval_secs: u64,
+}
+
+fn default_migration_cost_usd() -> f64 {
+    20.0
+}
+
+fn default_migration_amortize_months() -> u32 {
+    6
+}
+
+fn default_report_interval_secs() -> u64 {
+    DEFAULT_REPORT_INTERVAL_SECS
+}
+
+impl Default for WastePolicy {
+    fn default() -> Self {
+        Self {
+            min_observation_days: 3,
+            high_confidence_observation_days: 7,
+            oversize: OversizeThresholds::default(),
+            saturation: SaturationThresholds::default(),
+            migration_cost_usd: default_migration_cost_usd(),
+            migration_amortize_months: default_migration_amortize_months(),
+            report_interval_secs: default_report_interval_secs(),
+        }
+    }
+}
+
+impl WastePolicy {
+    /// Confidence for a usage average backed by `observation_days` of data,
+    /// or `None` if there is not enough data to act on.
+    pub fn confidence_for(&self, observation_days: u32) -> Option<OptimizationConfidence> {
+        if observation_days >= self.high_confidence_observation_days {
+            Some(OptimizationConfidence::High)
+        } else if observation_days >= self.min_observation_days {
+            Some(OptimizationConfidence::Medium)
+        } else {
+            None
+        }
+    }
+
+    /// Report counts needed for (Medium, High) confidence at the given cadence.
+    /// Rounds up and never drops below one report, so cadences slower than a
+    /// day still need data.
+    pub fn required_reports(&self, report_interval_secs: u64) -> (u64, u64) {
+        let interval = report_interval_secs.max(1);
+        let reports_in = |days: u32| (u64::from(days) * 86_400).div_ceil(interval).max(1);
+        (
+            reports_in(self.min_observation_days),
+            reports_in(self.high_confidence_observation_days),
         )
     }
+
+    /// Confidence for a usage average built from `report_count` samples taken
+    /// every `report_interval_secs`, so the bands track real data density.
+    pub fn confidence_for_reports(
+        &self,
+        report_count: u64,
+        report_interval_secs: u64,
+    ) -> Option<OptimizationConfidence> {
+        let (medium, high) = self.required_reports(report_interval_secs);
+        if report_count >= high {
+            Some(OptimizationConfidence::High)
+        } else if report_count >= medium {
+            Some(OptimizationConfidence::Medium)
+        } else {
+            None
+        }
+    }
+
+    /// Confidence for an account's usage averages: from its report count at
+    /// `report_interval_secs` when known, otherwise from `observation_days`.
+    pub fn confidence_for_account(
+        &self,
+        account: &AccountActivity,
+    ) -> Option<OptimizationConfidence> {
+        match account.report_count {
+            Some(count) => self.confidence_for_reports(count, self.report_interval_secs),
+            None => self.confidence_for(account.observation_days),
+        }
+    }
 }
 
 // ─── Cost projection ──────────────────────────────────────────────────────────
@@ -111,6 +447,29 @@ pub struct CostProjection {
     pub actual_to_date_usd: f64,
     pub variance_pct: f64,
     pub trajectory: CostTrajectory,
+    #[serde(default)]
+    pub currency: Currency,
+}
+
+impl CostProjection {
+    /// One-line spend summary in the projection's own currency.
+    pub fn summary(&self) -> String {
+        self.summary_in(self.currency, &ExchangeRates::new(self.currency))
+    }
+
+    /// One-line spend summary rendered in `display`.
+    pub fn summary_in(&self, display: Currency, rates: &ExchangeRates) -> String {
+        let money = |amount: f64| display_money(amount, self.currency, display, rates);
+        format!(
+            "{}-day projection: {:.0} (actual to date {:.0}, {:.0}/day) — {:+.1}% variance, {:?}.",
+            self.period_days,
+            money(self.projected_total_usd),
+            money(self.actual_to_date_usd),
+            money(self.current_daily_usd),
+            self.variance_pct,
+            self.trajectory,
+        )
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -126,6 +485,239 @@ pub enum CostTrajectory {
     Anomaly,
 }
 
+// ─── Cost efficiency ──────────────────────────────────────────────────────────
+
+/// Per-account cost moves within ±this % count as `Stable`.
+pub const EFFICIENCY_STABLE_BAND_PCT: f64 = 5.0;
+
+/// One periodic reading of cost per active account.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EfficiencySample {
+    pub date: NaiveDate,
+    pub cost_per_account_usd: f64,
+    pub active_accounts: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EfficiencyDirection {
+    /// Per-account cost falling.
+    Improving,
+    Stable,
+    /// Per-account cost rising.
+    Worsening,
+}
+
+/// How cost per active account moved over a `CostEfficiencyHistory`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EfficiencyTrend {
+    pub direction: EfficiencyDirection,
+    /// Change from the first to the latest sample.
+    pub per_account_change_pct: f64,
+    /// Pearson correlation of per-account cost with fleet size; negative
+    /// means cost per account falls as the fleet grows. `None` without
+    /// enough variation to tell.
+    pub fleet_size_correlation: Option<f64>,
+    pub samples: usize,
+}
+
+impl EfficiencyTrend {
+    /// Per-account cost is falling and clearly tracks fleet growth.
+    pub fn shows_economies_of_scale(&self) -> bool {
+        self.direction == EfficiencyDirection::Improving
+            && self.fleet_size_correlation.is_some_and(|r| r <= -0.5)
+    }
+}
+
+/// Cost per active account over time, one sample per date.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CostEfficiencyHistory {
+    samples: Vec<EfficiencySample>,
+}
+
+impl CostEfficiencyHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a reading; a second reading for the same date replaces the first.
+    pub fn record(&mut self, date: NaiveDate, cost_per_account_usd: f64, active_accounts: u32) {
+        let sample = EfficiencySample {
+            date,
+            cost_per_account_usd,
+            active_accounts,
+        };
+        match self.samples.binary_search_by_key(&date, |s| s.date) {
+            Ok(i) => self.samples[i] = sample,
+            Err(i) => self.samples.insert(i, sample),
+        }
+    }
+
+    /// Record from fleet totals. Ignored when there are no active accounts.
+    pub fn record_totals(&mut self, date: NaiveDate, monthly_cost_usd: f64, active_accounts: u32) {
+        if active_accounts > 0 {
+            self.record(
+                date,
+                monthly_cost_usd / f64::from(active_accounts),
+                active_accounts,
+            );
+        }
+    }
+
+    /// Samples, oldest first.
+    pub fn samples(&self) -> &[EfficiencySample] {
+        &self.samples
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Compare the latest per-account cost with the first. Fewer than two
+    /// samples reads as `Stable`.
+    pub fn trend(&self) -> EfficiencyTrend {
+        let per_account_change_pct = match (self.samples.first(), self.samples.last()) {
+            (Some(first), Some(last)) if first.cost_per_account_usd > 0.0 => {
+                (last.cost_per_account_usd - first.cost_per_account_usd)
+                    / first.cost_per_account_usd
+                    * 100.0
+            }
+            _ => 0.0,
+        };
+        let direction = if per_account_change_pct < -EFFICIENCY_STABLE_BAND_PCT {
+            EfficiencyDirection::Improving
+        } else if per_account_change_pct > EFFICIENCY_STABLE_BAND_PCT {
+            EfficiencyDirection::Worsening
+        } else {
+            EfficiencyDirection::Stable
+        };
+        let sizes: Vec<f64> = self
+            .samples
+            .iter()
+            .map(|s| f64::from(s.active_accounts))
+            .collect();
+        let costs: Vec<f64> = self
+            .samples
+            .iter()
+            .map(|s| s.cost_per_account_usd)
+            .collect();
+
+        EfficiencyTrend {
+            direction,
+            per_account_change_pct,
+            fleet_size_correlation: pearson(&sizes, &costs),
+            samples: self.samples.len(),
+        }
+    }
+}
+
+// ─── Cost alerts ──────────────────────────────────────────────────────────────
+
+/// Inputs to `CostEngine::project_and_alert`.
+#[derive(Debug, Clone)]
+pub struct CostProjectionInputs<'a> {
+    pub fleet: &'a FleetStatus,
+    pub days: u32,
+    pub current_daily_usd: f64,
+    pub actual_to_date_usd: f64,
+    /// Accounts considered when listing the top cost contributors.
+    pub accounts: &'a [AccountActivity],
+    /// Also alert on `Elevated` trajectories, not just `Anomaly`.
+    pub alert_on_elevated: bool,
+}
+
+/// A single account's contribution to fleet spend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostContributor {
+    pub account_id: String,
+    pub instance_id: String,
+    pub provider: VpsProvider,
+    pub monthly_cost_usd: f64,
+}
+
+/// Structured alert raised when spend runs above projection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostAnomalyAlert {
+    pub trajectory: CostTrajectory,
+    /// Spend the fleet is actually on course for.
+    pub projected_usd: f64,
+    /// Spend expected at the baseline daily burn rate.
+    pub budget_usd: f64,
+    pub variance_pct: f64,
+    /// Most expensive accounts, highest cost first.
+    pub top_contributors: Vec<CostContributor>,
+    pub raised_at: DateTime<Utc>,
+}
+
+/// Number of contributors attached to a `CostAnomalyAlert`.
+pub const TOP_CONTRIBUTOR_COUNT: usize = 5;
+
+/// Destination for Ledger cost alerts.
+#[async_trait]
+pub trait CostAlertSink: Send + Sync {
+    async fn emit(&self, alert: &CostAnomalyAlert) -> Result<(), String>;
+}
+
+/// Sink that writes alerts to the tracing log.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LogCostAlertSink;
+
+#[async_trait]
+impl CostAlertSink for LogCostAlertSink {
+    async fn emit(&self, alert: &CostAnomalyAlert) -> Result<(), String> {
+        warn!(
+            trajectory = ?alert.trajectory,
+            projected_usd = alert.projected_usd,
+            budget_usd = alert.budget_usd,
+            variance_pct = alert.variance_pct,
+            top_contributors = alert.top_contributors.len(),
+            "cost anomaly detected"
+        );
+        Ok(())
+    }
+}
+
+/// Sink that POSTs alerts as JSON to a webhook URL.
+#[derive(Debug, Clone)]
+pub struct WebhookCostAlertSink {
+    url: String,
+    client: reqwest::Client,
+}
+
+impl WebhookCostAlertSink {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+#[async_trait]
+impl CostAlertSink for WebhookCostAlertSink {
+    async fn emit(&self, alert: &CostAnomalyAlert) -> Result<(), String> {
+        let resp = self
+            .client
+            .post(&self.url)
+            .json(alert)
+            .send()
+            .await
+            .map_err(|e| format!("cost alert webhook request failed: {e}"))?;
+        if !resp.status().is_success() {
+            return Err(format!("cost alert webhook returned {}", resp.status()));
+        }
+        Ok(())
+    }
+}
+
 // ─── Optimisation recommendations ────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -135,6 +727,12 @@ pub enum OptimizationType {
         from_tier: InstanceTier,
         to_tier: InstanceTier,
     },
+    /// Upsize a saturated instance to a larger tier. Costs money, so its
+    /// `estimated_savings_monthly_usd` is negative.
+    Upsize {
+        from_tier: InstanceTier,
+        to_tier: InstanceTier,
+    },
     /// Teardown idle instance (archive first).
     Teardown { idle_days: u32 },
     /// Migrate to a cheaper provider.
@@ -199,6 +797,337 @@ pub struct ProviderComparison {
     pub entries: Vec<ProviderComparisonEntry>,
     pub recommended_primary: VpsProvider,
     pub recommended_standby: VpsProvider,
+    /// Currency of `cost_per_instance_usd` in each entry.
+    #[serde(default)]
+    pub currency: Currency,
+}
+
+impl ProviderComparison {
+    /// Recommended providers with their per-instance cost.
+    pub fn summary(&self) -> String {
+        self.summary_in(self.currency, &ExchangeRates::new(self.currency))
+    }
+
+    /// Recommended providers with per-instance cost rendered in `display`.
+    pub fn summary_in(&self, display: Currency, rates: &ExchangeRates) -> String {
+        let cost_of = |provider: VpsProvider| {
+            self.entries
+                .iter()
+                .find(|e| e.provider == provider)
+                .map(|e| {
+                    format!(
+                        "{:.2}/instance",
+                        display_money(e.cost_per_instance_usd, self.currency, display, rates)
+                    )
+                })
+                .unwrap_or_else(|| "no data".to_string())
+        };
+        format!(
+            "Primary: {} ({}). Standby: {} ({}).",
+            self.recommended_primary,
+            cost_of(self.recommended_primary),
+            self.recommended_standby,
+            cost_of(self.recommended_standby),
+        )
+    }
+}
+
+/// Monthly list price per provider and tier, in `BASE_CURRENCY`.
+///
+/// A missing entry means the provider does not offer that tier.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PriceBook {
+    prices: HashMap<VpsProvider, HashMap<InstanceTier, f64>>,
+}
+
+impl PriceBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_price(
+        mut self,
+        provider: VpsProvider,
+        tier: InstanceTier,
+        monthly_usd: f64,
+    ) -> Self {
+        self.prices
+            .entry(provider)
+            .or_default()
+            .insert(tier, monthly_usd);
+        self
+    }
+
+    pub fn price(&self, provider: VpsProvider, tier: InstanceTier) -> Option<f64> {
+        self.prices.get(&provider)?.get(&tier).copied()
+    }
+}
+
+/// Where tier prices come from: a static `PriceBook` or a `LivePriceBook`.
+pub trait PriceLookup: Send + Sync {
+    /// Monthly price in `BASE_CURRENCY` for `tier` in `region`, or the
+    /// provider's list price when no region is given. `None` if the tier is
+    /// not offered.
+    fn price(&self, provider: VpsProvider, tier: InstanceTier, region: Option<&str>)
+    -> Option<f64>;
+}
+
+impl PriceLookup for PriceBook {
+    /// Static prices are the same in every region.
+    fn price(
+        &self,
+        provider: VpsProvider,
+        tier: InstanceTier,
+        _region: Option<&str>,
+    ) -> Option<f64> {
+        PriceBook::price(self, provider, tier)
+    }
+}
+
+// ─── Live pricing ─────────────────────────────────────────────────────────────
+
+/// How long fetched provider prices are trusted before refetching.
+pub const DEFAULT_PRICE_CACHE_TTL_HOURS: i64 = 24;
+
+/// One provider price for a tier in a region, in `BASE_CURRENCY`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegionalPrice {
+    pub tier: InstanceTier,
+    pub region: String,
+    pub monthly_usd: f64,
+}
+
+/// Fetches a provider's current price list from its pricing API.
+#[async_trait]
+pub trait PriceFetcher: Send + Sync {
+    fn provider(&self) -> VpsProvider;
+    async fn fetch(&self) -> Result<Vec<RegionalPrice>, String>;
+}
+
+struct CachedPrices {
+    fetched_at: DateTime<Utc>,
+    prices: HashMap<(InstanceTier, String), f64>,
+}
+
+/// Provider prices fetched live and cached for a TTL, over a static fallback.
+///
+/// Lookups never block on the network: call [`refresh`](Self::refresh)
+/// periodically. A provider whose fetch fails is priced from the fallback
+/// until a later fetch succeeds.
+pub struct LivePriceBook {
+    fallback: PriceBook,
+    fetchers: Vec<Box<dyn PriceFetcher>>,
+    ttl: chrono::Duration,
+    cache: RwLock<HashMap<VpsProvider, CachedPrices>>,
+}
+
+impl LivePriceBook {
+    pub fn new(fallback: PriceBook) -> Self {
+        Self {
+            fallback,
+            fetchers: Vec::new(),
+            ttl: chrono::Duration::hours(DEFAULT_PRICE_CACHE_TTL_HOURS),
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn with_fetcher(mut self, fetcher: Box<dyn PriceFetcher>) -> Self {
+        self.fetchers.push(fetcher);
+        self
+    }
+
+    pub fn with_ttl(mut self, ttl: chrono::Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Refetch every provider whose cached prices are older than the TTL.
+    pub async fn refresh(&self) {
+        for fetcher in &self.fetchers {
+            let provider = fetcher.provider();
+            let fresh = self
+                .cache
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get(&provider)
+                .is_some_and(|c| Utc::now() - c.fetched_at < self.ttl);
+            if fresh {
+                continue;
+            }
+            let fetched = fetcher.fetch().await;
+            let mut cache = self.cache.write().unwrap_or_else(PoisonError::into_inner);
+            match fetched {
+                Ok(list) => {
+                    let prices = list
+                        .into_iter()
+                        .map(|p| ((p.tier, p.region), p.monthly_usd))
+                        .collect();
+                    cache.insert(
+                        provider,
+                        CachedPrices {
+                            fetched_at: Utc::now(),
+                            prices,
+                        },
+                    );
+                }
+                Err(e) => {
+                    warn!(%provider, error = %e, "price fetch failed; using static prices");
+                    cache.remove(&provider);
+                }
+            }
+        }
+    }
+}
+
+impl PriceLookup for LivePriceBook {
+    /// The fetched price for exactly `region`; the static price when there is
+    /// no region, no fresh fetch, or no fetched price for that region.
+    fn price(
+        &self,
+        provider: VpsProvider,
+        tier: InstanceTier,
+        region: Option<&str>,
+    ) -> Option<f64> {
+        let cached = region.and_then(|region| {
+            self.cache
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get(&provider)?
+                .prices
+                .get(&(tier, region.to_string()))
+                .copied()
+        });
+        cached.or_else(|| self.fallback.price(provider, tier))
+    }
+}
+
+/// Hetzner Cloud API root the price fetcher calls by default.
+pub const HETZNER_API_URL: &str = "https://api.hetzner.cloud/v1";
+
+/// Fetches Hetzner Cloud prices per location from `GET /v1/pricing`.
+#[derive(Debug, Clone)]
+pub struct HetznerPriceFetcher {
+    token: String,
+    rates: ExchangeRates,
+    base_url: String,
+    client: reqwest::Client,
+}
+
+impl HetznerPriceFetcher {
+    /// `rates` converts Hetzner's EUR prices into `BASE_CURRENCY`.
+    pub fn new(token: impl Into<String>, rates: ExchangeRates) -> Self {
+        Self {
+            token: token.into(),
+            rates,
+            base_url: HETZNER_API_URL.to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Call a different API root, e.g. a mock server in tests.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+}
+
+#[async_trait]
+impl PriceFetcher for HetznerPriceFetcher {
+    fn provider(&self) -> VpsProvider {
+        VpsProvider::Hetzner
+    }
+
+    async fn fetch(&self) -> Result<Vec<RegionalPrice>, String> {
+        let resp = self
+            .client
+            .get(format!("{}/pricing", self.base_url))
+            .bearer_auth(&self.token)
+            .send()
+            .await
+            .map_err(|e| format!("hetzner pricing request failed: {e}"))?;
+        if !resp.status().is_success() {
+            return Err(format!("hetzner pricing returned {}", resp.status()));
+        }
+        let body: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| format!("hetzner pricing body invalid: {e}"))?;
+
+        let mut prices = Vec::new();
+        let server_types = body["pricing"]["server_types"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default();
+        for server_type in server_types {
+            let Some(tier) = server_type["name"]
+                .as_str()
+                .and_then(InstanceTier::from_hetzner_server_type)
+            else {
+                continue;
+            };
+            for entry in server_type["prices"].as_array().into_iter().flatten() {
+                let (Some(region), Some(eur)) = (
+                    entry["location"].as_str(),
+                    entry["price_monthly"]["gross"]
+                        .as_str()
+                        .and_then(|p| p.parse::<f64>().ok()),
+                ) else {
+                    continue;
+                };
+                let usd = Money::new(eur, Currency::Eur)
+                    .convert(BASE_CURRENCY, &self.rates)
+                    .ok_or("no EUR exchange rate for hetzner pricing")?;
+                prices.push(RegionalPrice {
+                    tier,
+                    region: region.to_string(),
+                    monthly_usd: usd.amount,
+                });
+            }
+        }
+        Ok(prices)
+    }
+}
+
+/// Relative weight of each factor in a tier-specific provider comparison.
+/// Weights are normalized, so only their ratios matter.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ComparisonWeights {
+    pub health: f64,
+    pub speed: f64,
+    pub reliability: f64,
+    pub cost: f64,
+}
+
+impl ComparisonWeights {
+    /// Defaults by tier: small tiers are cost-driven, large tiers reliability-driven.
+    pub fn for_tier(tier: InstanceTier) -> Self {
+        match tier {
+            InstanceTier::Nano => Self {
+                health: 0.20,
+                speed: 0.10,
+                reliability: 0.20,
+                cost: 0.50,
+            },
+            InstanceTier::Standard => Self {
+                health: 0.30,
+                speed: 0.15,
+                reliability: 0.30,
+                cost: 0.25,
+            },
+            InstanceTier::Pro => Self {
+                health: 0.35,
+                speed: 0.10,
+                reliability: 0.40,
+                cost: 0.15,
+            },
+            InstanceTier::Enterprise => Self {
+                health: 0.35,
+                speed: 0.10,
+                reliability: 0.50,
+                cost: 0.05,
+            },
+        }
+    }
 }
 
 // ─── Cost Engine ──────────────────────────────────────────────────────────────
@@ -207,26 +1136,51 @@ pub struct ProviderComparison {
 pub struct CostEngine;
 
 impl CostEngine {
-    /// Analyse waste in the fleet (idle, oversized, arbitrage).
+    /// Analyse waste in the fleet (idle, oversized, arbitrage) using the default policy.
     pub fn analyze_waste(fleet: &FleetStatus, accounts: &[AccountActivity]) -> WasteReport {
+        Self::analyze_waste_with_policy(fleet, accounts, &WastePolicy::default())
+    }
+
+    /// Analyse waste in the fleet, applying `policy` to oversized detection.
+    pub fn analyze_waste_with_policy(
+        fleet: &FleetStatus,
+        accounts: &[AccountActivity],
+        policy: &WastePolicy,
+    ) -> WasteReport {
+        Self::analyze_waste_with_arbitrage(fleet, accounts, policy, &PriceBook::new(), &[])
+    }
+
+    /// Like `analyze_waste_with_policy`, also pricing each account on
+    /// `alternatives` in its own region. Only moves that still save money
+    /// after the policy's amortized migration cost are reported.
+    pub fn analyze_waste_with_arbitrage(
+        fleet: &FleetStatus,
+        accounts: &[AccountActivity],
+        policy: &WastePolicy,
+        prices: &dyn PriceLookup,
+        alternatives: &[VpsProvider],
+    ) -> WasteReport {
+        let now = Utc::now();
         let idle_accounts: Vec<IdleAccount> = accounts
             .iter()
-            .filter(|a| a.idle_days >= 14)
-            .map(|a| IdleAccount {
+            .map(|a| (a, a.effective_idle_days(now)))
+            .filter(|(_, idle_days)| *idle_days >= 14)
+            .map(|(a, idle_days)| IdleAccount {
                 account_id: a.account_id.clone(),
                 last_activity: a.last_activity,
-                idle_days: a.idle_days,
-                monthly_cost_usd: a.monthly_cost_usd,
+                idle_days,
+                monthly_cost_usd: a.total_monthly_cost_usd(),
             })
             .collect();
 
         let oversized_instances: Vec<OversizedInstance> = accounts
             .iter()
-            .filter(|a| a.avg_cpu_pct < 20.0 && a.avg_mem_pct < 20.0)
+            .filter(|a| a.is_underutilized_by(&policy.oversize))
             .filter(|a| a.current_tier != InstanceTier::Nano)
-            .map(|a| {
+            .filter_map(|a| {
+                let confidence = policy.confidence_for_account(a)?;
                 let recommended_tier = downsize_tier(&a.current_tier);
-                OversizedInstance {
+                Some(OversizedInstance {
                     instance_id: a.instance_id.clone(),
                     account_id: a.account_id.clone(),
                     current_tier: a.current_tier,
@@ -234,12 +1188,32 @@ impl CostEngine {
                     avg_cpu_pct: a.avg_cpu_pct,
                     avg_mem_pct: a.avg_mem_pct,
                     monthly_savings_usd: a.monthly_cost_usd * 0.40,
-                }
+                    confidence,
+                })
             })
             .collect();
 
-        // Provider arbitrage: stub — in production this queries live pricing APIs
-        let provider_arbitrage = vec![];
+        let net = |arb: &ProviderArbitrage| {
+            net_arbitrage(
+                arb,
+                policy.migration_cost_usd,
+                policy.migration_amortize_months,
+            )
+        };
+        let provider_arbitrage: Vec<ProviderArbitrage> = accounts
+            .iter()
+            .filter_map(|a| {
+                Self::arbitrage_for(
+                    &a.instance_id,
+                    a.provider,
+                    a.current_tier,
+                    a.region.as_deref(),
+                    alternatives,
+                    prices,
+                )
+            })
+            .filter(|arb| net(arb) > 0.0)
+            .collect();
 
         let idle_cost: f64 = idle_accounts.iter().map(|a| a.monthly_cost_usd).sum();
         let oversize_savings: f64 = oversized_instances
@@ -247,7 +1221,9 @@ impl CostEngine {
             .map(|o| o.monthly_savings_usd)
             .sum();
 
-        let total_recoverable = idle_cost + oversize_savings;
+        let arbitrage_savings: f64 = provider_arbitrage.iter().map(net).sum();
+
+        let total_recoverable = idle_cost + oversize_savings + arbitrage_savings;
 
         let _ = fleet;
         WasteReport {
@@ -256,6 +1232,7 @@ impl CostEngine {
             oversized_instances,
             provider_arbitrage,
             total_recoverable_monthly_usd: total_recoverable,
+            currency: BASE_CURRENCY,
         }
     }
 
@@ -284,35 +1261,111 @@ impl CostEngine {
             actual_to_date_usd,
             variance_pct,
             trajectory,
+            currency: BASE_CURRENCY,
         }
     }
 
-    /// Generate actionable optimisation recommendations.
+    /// Like `project_costs`, with the daily burn rate priced from `prices` for
+    /// each account's provider, tier and region, so live regional prices
+    /// flow into the projection.
+    pub fn project_costs_priced(
+        fleet: &FleetStatus,
+        accounts: &[AccountActivity],
+        prices: &dyn PriceLookup,
+        days: u32,
+        actual_to_date_usd: f64,
+    ) -> CostProjection {
+        Self::project_costs(
+            fleet,
+            days,
+            daily_run_rate_usd(accounts, prices),
+            actual_to_date_usd,
+        )
+    }
+
+    /// Project costs and emit a `CostAnomalyAlert` to `sink` when spend is anomalous.
+    ///
+    /// Returns the projection along with the alert, if one was raised.
+    pub async fn project_and_alert(
+        inputs: CostProjectionInputs<'_>,
+        sink: &dyn CostAlertSink,
+    ) -> Result<(CostProjection, Option<CostAnomalyAlert>), String> {
+        let projection = Self::project_costs(
+            inputs.fleet,
+            inputs.days,
+            inputs.current_daily_usd,
+            inputs.actual_to_date_usd,
+        );
+
+        let should_alert = match projection.trajectory {
+            CostTrajectory::Anomaly => true,
+            CostTrajectory::Elevated => inputs.alert_on_elevated,
+            CostTrajectory::BelowBudget | CostTrajectory::OnTrack => false,
+        };
+        if !should_alert {
+            return Ok((projection, None));
+        }
+
+        let mut contributors: Vec<CostContributor> = inputs
+            .accounts
+            .iter()
+            .map(|a| CostContributor {
+                account_id: a.account_id.clone(),
+                instance_id: a.instance_id.clone(),
+                provider: a.provider,
+                monthly_cost_usd: a.total_monthly_cost_usd(),
+            })
+            .collect();
+        contributors.sort_by(|a, b| b.monthly_cost_usd.total_cmp(&a.monthly_cost_usd));
+        contributors.truncate(TOP_CONTRIBUTOR_COUNT);
+
+        let alert = CostAnomalyAlert {
+            trajectory: projection.trajectory,
+            projected_usd: projection.actual_to_date_usd,
+            budget_usd: projection.projected_total_usd,
+            variance_pct: projection.variance_pct,
+            top_contributors: contributors,
+            raised_at: Utc::now(),
+        };
+        sink.emit(&alert).await?;
+        Ok((projection, Some(alert)))
+    }
+
+    /// Generate actionable optimisation recommendations using the default policy.
     pub fn recommend_optimizations(
         fleet: &FleetStatus,
         accounts: &[AccountActivity],
+    ) -> Vec<Optimization> {
+        Self::recommend_optimizations_with_policy(fleet, accounts, &WastePolicy::default())
+    }
+
+    /// Generate optimisation recommendations, applying `policy` to resizing.
+    pub fn recommend_optimizations_with_policy(
+        fleet: &FleetStatus,
+        accounts: &[AccountActivity],
+        policy: &WastePolicy,
     ) -> Vec<Optimization> {
         let mut opts = Vec::new();
+        let now = Utc::now();
 
         for account in accounts {
             // Teardown idle (>= 14 days)
-            if account.idle_days >= 14 {
+            let idle_days = account.effective_idle_days(now);
+            if idle_days >= 14 {
                 opts.push(Optimization {
                     instance_id: account.instance_id.clone(),
                     account_id: account.account_id.clone(),
-                    optimization_type: OptimizationType::Teardown {
-                        idle_days: account.idle_days,
-                    },
-                    estimated_savings_monthly_usd: account.monthly_cost_usd,
+                    optimization_type: OptimizationType::Teardown { idle_days },
+                    estimated_savings_monthly_usd: account.total_monthly_cost_usd(),
                     confidence: OptimizationConfidence::High,
-                    requires_confirmation: account.monthly_cost_usd > 50.0,
+                    requires_confirmation: account.total_monthly_cost_usd() > 50.0,
                 });
             }
 
-            // Downsize oversized (< 20% usage, not nano)
-            if account.avg_cpu_pct < 20.0
-                && account.avg_mem_pct < 20.0
+            // Downsize oversized (under policy thresholds, not nano, enough history)
+            if account.is_underutilized_by(&policy.oversize)
                 && account.current_tier != InstanceTier::Nano
+                && let Some(confidence) = policy.confidence_for_account(account)
             {
                 let savings = account.monthly_cost_usd * 0.40;
                 opts.push(Optimization {
@@ -323,10 +1376,30 @@ impl CostEngine {
                         to_tier: downsize_tier(&account.current_tier),
                     },
                     estimated_savings_monthly_usd: savings,
-                    confidence: OptimizationConfidence::High,
+                    confidence,
                     requires_confirmation: false,
                 });
             }
+
+            // Upsize saturated (typical usage over policy thresholds, not enterprise)
+            if account.is_saturated_by(&policy.saturation)
+                && account.current_tier != InstanceTier::Enterprise
+                && let Some(confidence) = policy.confidence_for_account(account)
+            {
+                // Mirrors the downsize estimate: this tier is ~60% of the next.
+                let extra_cost = account.monthly_cost_usd / 0.60 - account.monthly_cost_usd;
+                opts.push(Optimization {
+                    instance_id: account.instance_id.clone(),
+                    account_id: account.account_id.clone(),
+                    optimization_type: OptimizationType::Upsize {
+                        from_tier: account.current_tier,
+                        to_tier: upsize_tier(&account.current_tier),
+                    },
+                    estimated_savings_monthly_usd: -extra_cost,
+                    confidence,
+                    requires_confirmation: true,
+                });
+            }
         }
 
         let _ = fleet;
@@ -335,7 +1408,7 @@ impl CostEngine {
 
     /// Compare providers side-by-side on cost and performance.
     pub fn compare_providers(providers: &[ProviderStats]) -> ProviderComparison {
-        let mut entries: Vec<ProviderComparisonEntry> = providers
+        let entries: Vec<ProviderComparisonEntry> = providers
             .iter()
             .map(|p| {
                 let overall_score = compute_provider_score(p);
@@ -353,34 +1426,117 @@ impl CostEngine {
             })
             .collect();
 
-        // Sort: highest score first
-        entries.sort_by(|a, b| b.overall_score.partial_cmp(&a.overall_score).unwrap());
+        rank_providers(entries)
+    }
 
-        let recommended_primary = entries
+    /// Compare providers for one tier: cost comes from the tier's price in
+    /// `prices`, and providers without a price for the tier are left out.
+    pub fn compare_providers_for_tier(
+        providers: &[ProviderStats],
+        prices: &dyn PriceLookup,
+        tier: InstanceTier,
+        weights: &ComparisonWeights,
+    ) -> ProviderComparison {
+        let offered: Vec<(&ProviderStats, f64)> = providers
             .iter()
-            .find(|e| matches!(e.recommendation, ProviderRecommendation::PreferForPrimary))
-            .map(|e| e.provider)
-            .unwrap_or(VpsProvider::Hetzner);
-
-        let recommended_standby = entries
+            .filter_map(|p| prices.price(p.provider, tier, None).map(|price| (p, price)))
+            .collect();
+        let cheapest = offered
             .iter()
-            .find(|e| {
-                e.provider != recommended_primary
-                    && matches!(
-                        e.recommendation,
-                        ProviderRecommendation::PreferForPrimary
-                            | ProviderRecommendation::GoodForStandby
-                    )
+            .map(|(_, price)| *price)
+            .fold(f64::INFINITY, f64::min);
+        let total_weight = weights.health + weights.speed + weights.reliability + weights.cost;
+
+        let entries = offered
+            .into_iter()
+            .map(|(p, price)| {
+                let cost_score = if price > 0.0 {
+                    cheapest / price * 100.0
+                } else {
+                    100.0
+                };
+                let weighted = p.avg_health_score * weights.health
+                    + provision_speed_score(p.avg_provision_time_secs) * weights.speed
+                    + (100.0 - p.provision_failure_rate_pct.min(100.0)) * weights.reliability
+                    + cost_score * weights.cost;
+                let overall_score = if total_weight > 0.0 {
+                    (weighted / total_weight).clamp(0.0, 100.0)
+                } else {
+                    0.0
+                };
+                ProviderComparisonEntry {
+                    provider: p.provider,
+                    instance_count: p.instance_count,
+                    avg_health_score: p.avg_health_score,
+                    avg_provision_secs: p.avg_provision_time_secs,
+                    failure_rate_pct: p.provision_failure_rate_pct,
+                    cost_per_instance_usd: price,
+                    overall_score,
+                    recommendation: classify_provider(overall_score, p.provision_failure_rate_pct),
+                }
             })
-            .map(|e| e.provider)
-            .unwrap_or(VpsProvider::Vultr);
+            .collect();
 
-        ProviderComparison {
-            generated_at: Utc::now(),
-            entries,
-            recommended_primary,
-            recommended_standby,
-        }
+        rank_providers(entries)
+    }
+
+    /// The cheapest of `alternatives` for `tier` in `region`, if it undercuts
+    /// `current` there. Both sides are priced for the same region.
+    pub fn arbitrage_for(
+        instance_id: &str,
+        current: VpsProvider,
+        tier: InstanceTier,
+        region: Option<&str>,
+        alternatives: &[VpsProvider],
+        prices: &dyn PriceLookup,
+    ) -> Option<ProviderArbitrage> {
+        let current_monthly_usd = prices.price(current, tier, region)?;
+        let (cheaper_provider, alternative_monthly_usd) = alternatives
+            .iter()
+            .filter(|p| **p != current)
+            .filter_map(|p| prices.price(*p, tier, region).map(|price| (*p, price)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))?;
+        (alternative_monthly_usd < current_monthly_usd).then(|| ProviderArbitrage {
+            instance_id: instance_id.to_string(),
+            current_provider: current,
+            cheaper_provider,
+            current_monthly_usd,
+            alternative_monthly_usd,
+            monthly_savings_usd: current_monthly_usd - alternative_monthly_usd,
+        })
+    }
+}
+
+/// Sort entries best-first and pick the primary and standby recommendations.
+fn rank_providers(mut entries: Vec<ProviderComparisonEntry>) -> ProviderComparison {
+    // Sort: highest score first
+    entries.sort_by(|a, b| b.overall_score.partial_cmp(&a.overall_score).unwrap());
+
+    let recommended_primary = entries
+        .iter()
+        .find(|e| matches!(e.recommendation, ProviderRecommendation::PreferForPrimary))
+        .map(|e| e.provider)
+        .unwrap_or(VpsProvider::Hetzner);
+
+    let recommended_standby = entries
+        .iter()
+        .find(|e| {
+            e.provider != recommended_primary
+                && matches!(
+                    e.recommendation,
+                    ProviderRecommendation::PreferForPrimary
+                        | ProviderRecommendation::GoodForStandby
+                )
+        })
+        .map(|e| e.provider)
+        .unwrap_or(VpsProvider::Vultr);
+
+    ProviderComparison {
+        generated_at: Utc::now(),
+        entries,
+        recommended_primary,
+        recommended_standby,
+        currency: BASE_CURRENCY,
     }
 }
 
@@ -398,10 +1554,151 @@ pub struct AccountActivity {
     pub avg_cpu_pct: f64,
     pub avg_mem_pct: f64,
     pub monthly_cost_usd: f64,
+    /// Days of usage data backing `avg_cpu_pct` / `avg_mem_pct`. Records
+    /// saved before this was tracked load as 0, so they are not called
+    /// oversized until fresh data arrives.
+    #[serde(default)]
+    pub observation_days: u32,
+    /// Usage reports behind the averages, when the ingester counts them.
+    /// Takes precedence over `observation_days` for confidence.
+    #[serde(default)]
+    pub report_count: Option<u64>,
+    /// Distribution of the same samples, when available.
+    #[serde(default)]
+    pub utilization: Option<UtilizationProfile>,
+    /// Projected charge for egress beyond the tier's included bandwidth.
+    #[serde(default)]
+    pub bandwidth_overage_usd: f64,
+    /// Region as the provider's pricing API names it (e.g. `nbg1`), for
+    /// regional prices. `None` prices the account at list price.
+    #[serde(default)]
+    pub region: Option<String>,
+}
+
+/// p50/p95 CPU and memory usage for one instance over the observation window.
+///
+/// The mean hides bimodal workloads; the p95 shows whether an instance ever
+/// needs its capacity.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct UtilizationProfile {
+    pub cpu_p50: f64,
+    pub cpu_p95: f64,
+    pub mem_p50: f64,
+    pub mem_p95: f64,
+}
+
+impl UtilizationProfile {
+    /// Build from raw CPU and memory samples. `None` if either series is empty.
+    pub fn from_samples(cpu_pct: &[f64], mem_pct: &[f64]) -> Option<Self> {
+        Some(Self {
+            cpu_p50: percentile(cpu_pct, 50.0)?,
+            cpu_p95: percentile(cpu_pct, 95.0)?,
+            mem_p50: percentile(mem_pct, 50.0)?,
+            mem_p95: percentile(mem_pct, 95.0)?,
+        })
+    }
+}
+
+impl AccountActivity {
+    /// What the instance really costs per month: tier price plus bandwidth overage.
+    pub fn total_monthly_cost_usd(&self) -> f64 {
+        self.monthly_cost_usd + self.bandwidth_overage_usd
+    }
+
+    /// Set `bandwidth_overage_usd` from the node's reported egress rate.
+    pub fn apply_bandwidth(
+        &mut self,
+        network: &NetworkMetrics,
+        included_tb: f64,
+        overage_per_tb_usd: f64,
+    ) {
+        let over_tb = (network.projected_monthly_egress_tb() - included_tb).max(0.0);
+        self.bandwidth_overage_usd = over_tb * overage_per_tb_usd;
+    }
+
+    /// Usage stays under the default 20% CPU and memory.
+    pub fn is_underutilized(&self) -> bool {
+        self.is_underutilized_by(&OversizeThresholds::default())
+    }
+
+    /// Usage stays under both `thresholds`. Judged on p95 when a
+    /// distribution is available, so periodic peaks block a downsize;
+    /// otherwise on the mean.
+    pub fn is_underutilized_by(&self, thresholds: &OversizeThresholds) -> bool {
+        let (cpu, mem) = match &self.utilization {
+            Some(profile) => (profile.cpu_p95, profile.mem_p95),
+            None => (self.avg_cpu_pct, self.avg_mem_pct),
+        };
+        cpu < thresholds.cpu_pct && mem < thresholds.mem_pct
+    }
+
+    /// Typical usage reaches either of `thresholds`. Judged on p50 when a
+    /// distribution is available, so a few busy hours do not trigger an
+    /// upsize; otherwise on the mean.
+    pub fn is_saturated_by(&self, thresholds: &SaturationThresholds) -> bool {
+        let (cpu, mem) = match &self.utilization {
+            Some(profile) => (profile.cpu_p50, profile.mem_p50),
+            None => (self.avg_cpu_pct, self.avg_mem_pct),
+        };
+        cpu >= thresholds.cpu_pct || mem >= thresholds.mem_pct
+    }
+
+    /// `idle_days`, or 0 if `last_activity` is in the future (node clock skew) —
+    /// a skewed timestamp usually means `idle_days` was computed from a
+    /// negative duration and wrapped.
+    pub fn effective_idle_days(&self, now: DateTime<Utc>) -> u32 {
+        if self.last_activity > now {
+            warn!(
+                account_id = %self.account_id,
+                skew_secs = (self.last_activity - now).num_seconds(),
+                "last_activity is in the future; treating account as active"
+            );
+            return 0;
+        }
+        self.idle_days
+    }
 }
 
 // ─── Helpers ──────────────────────────────────────────────────────────────────
 
+/// Days per month when turning monthly prices into a daily rate.
+const DAYS_PER_MONTH: f64 = 30.0;
+
+/// Fleet spend per day: each account at its price from `prices` (its
+/// recorded cost when the tier is not priced) plus bandwidth overage.
+fn daily_run_rate_usd(accounts: &[AccountActivity], prices: &dyn PriceLookup) -> f64 {
+    accounts
+        .iter()
+        .map(|a| {
+            prices
+                .price(a.provider, a.current_tier, a.region.as_deref())
+                .unwrap_or(a.monthly_cost_usd)
+                + a.bandwidth_overage_usd
+        })
+        .sum::<f64>()
+        / DAYS_PER_MONTH
+}
+
+/// Pearson correlation of two equal-length series; `None` if either is flat.
+fn pearson(xs: &[f64], ys: &[f64]) -> Option<f64> {
+    if xs.len() < 2 || xs.len() != ys.len() {
+        return None;
+    }
+    let n = xs.len() as f64;
+    let mean_x = xs.iter().sum::<f64>() / n;
+    let mean_y = ys.iter().sum::<f64>() / n;
+    let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (x, y) in xs.iter().zip(ys) {
+        cov += (x - mean_x) * (y - mean_y);
+        var_x += (x - mean_x).powi(2);
+        var_y += (y - mean_y).powi(2);
+    }
+    if var_x == 0.0 || var_y == 0.0 {
+        return None;
+    }
+    Some(cov / (var_x.sqrt() * var_y.sqrt()))
+}
+
 fn downsize_tier(tier: &InstanceTier) -> InstanceTier {
     match tier {
         InstanceTier::Enterprise => InstanceTier::Pro,
@@ -411,6 +1708,15 @@ fn downsize_tier(tier: &InstanceTier) -> InstanceTier {
     }
 }
 
+fn upsize_tier(tier: &InstanceTier) -> InstanceTier {
+    match tier {
+        InstanceTier::Nano => InstanceTier::Standard,
+        InstanceTier::Standard => InstanceTier::Pro,
+        InstanceTier::Pro => InstanceTier::Enterprise,
+        InstanceTier::Enterprise => InstanceTier::Enterprise,
+    }
+}
+
 fn classify_trajectory(variance_pct: f64) -> CostTrajectory {
     if variance_pct < -5.0 {
         CostTrajectory::BelowBudget
@@ -426,13 +1732,18 @@ fn classify_trajectory(variance_pct: f64) -> CostTrajectory {
 /// Score a provider 0–100 based on health, speed, failure rate, and cost.
 fn compute_provider_score(p: &ProviderStats) -> f64 {
     let health_component = p.avg_health_score * 0.40;
-    let speed_component = (600.0_f64 - p.avg_provision_time_secs.min(600.0)) / 600.0 * 100.0 * 0.30;
+    let speed_component = provision_speed_score(p.avg_provision_time_secs) * 0.30;
     let reliability_component = (100.0 - p.provision_failure_rate_pct.min(100.0)) * 0.20;
     let cost_component = (20.0_f64 - p.cost_per_instance_usd.min(20.0)) / 20.0 * 100.0 * 0.10;
 
     (health_component + speed_component + reliability_component + cost_component).clamp(0.0, 100.0)
 }
 
+/// 100 for instant provisioning, 0 at ten minutes or more.
+fn provision_speed_score(avg_provision_secs: f64) -> f64 {
+    (600.0_f64 - avg_provision_secs.min(600.0)) / 600.0 * 100.0
+}
+
 fn classify_provider(score: f64, failure_rate_pct: f64) -> ProviderRecommendation {
     if failure_rate_pct > 10.0 {
         return ProviderRecommendation::Avoid;
@@ -485,6 +1796,11 @@ mod tests {
             avg_cpu_pct: cpu,
             avg_mem_pct: mem,
             monthly_cost_usd: cost,
+            observation_days: 30,
+            report_count: None,
+            utilization: None,
+            bandwidth_overage_usd: 0.0,
+            region: None,
         }
     }
 
@@ -517,6 +1833,226 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_account_activity_without_observation_days_loads_as_unobserved() {
+        let mut json =
+            serde_json::to_value(make_account(0, 10.0, 10.0, InstanceTier::Standard, 11.0))
+                .unwrap();
+        json.as_object_mut().unwrap().remove("observation_days");
+        let account: AccountActivity = serde_json::from_value(json).unwrap();
+        assert_eq!(account.observation_days, 0);
+
+        let report = CostEngine::analyze_waste(&make_fleet(), &[account]);
+        assert!(report.oversized_instances.is_empty());
+    }
+
+    #[test]
+    fn test_analyze_waste_requires_observation_history() {
+        let fleet = make_fleet();
+        let mut young = make_account(0, 10.0, 10.0, InstanceTier::Standard, 11.0);
+        young.instance_id = "i-young".to_string();
+        young.observation_days = 1;
+        let mut settled = make_account(0, 10.0, 10.0, InstanceTier::Standard, 11.0);
+        settled.instance_id = "i-settled".to_string();
+        settled.observation_days = 7;
+
+        let report = CostEngine::analyze_waste(&fleet, &[young.clone(), settled.clone()]);
+        assert_eq!(report.oversized_instances.len(), 1);
+        assert_eq!(report.oversized_instances[0].instance_id, "i-settled");
+        assert_eq!(
+            report.oversized_instances[0].confidence,
+            OptimizationConfidence::High
+        );
+
+        let opts = CostEngine::recommend_optimizations(&fleet, &[young, settled]);
+        assert_eq!(opts.len(), 1);
+        assert_eq!(opts[0].instance_id, "i-settled");
+        assert_eq!(opts[0].confidence, OptimizationConfidence::High);
+    }
+
+    #[test]
+    fn test_waste_policy_confidence_bands() {
+        let policy = WastePolicy::default();
+        assert_eq!(policy.confidence_for(1), None);
+        assert_eq!(
+            policy.confidence_for(3),
+            Some(OptimizationConfidence::Medium)
+        );
+        assert_eq!(policy.confidence_for(7), Some(OptimizationConfidence::High));
+
+        let strict = WastePolicy {
+            min_observation_days: 14,
+            high_confidence_observation_days: 30,
+            ..WastePolicy::default()
+        };
+        let fleet = make_fleet();
+        let mut account = make_account(0, 10.0, 10.0, InstanceTier::Standard, 11.0);
+        account.observation_days = 7;
+        let report = CostEngine::analyze_waste_with_policy(&fleet, &[account], &strict);
+        assert!(report.oversized_instances.is_empty());
+    }
+
+    #[test]
+    fn test_oversize_thresholds_are_configurable() {
+        let fleet = make_fleet();
+        let accounts = vec![make_account(0, 15.0, 15.0, InstanceTier::Standard, 11.0)];
+
+        let report = CostEngine::analyze_waste(&fleet, &accounts);
+        assert_eq!(report.oversized_instances.len(), 1);
+        assert_eq!(
+            CostEngine::recommend_optimizations(&fleet, &accounts).len(),
+            1
+        );
+
+        let memory_sensitive = WastePolicy {
+            oversize: OversizeThresholds {
+                cpu_pct: 20.0,
+                mem_pct: 10.0,
+            },
+            ..WastePolicy::default()
+        };
+        let report = CostEngine::analyze_waste_with_policy(&fleet, &accounts, &memory_sensitive);
+        assert!(report.oversized_instances.is_empty());
+        assert!(
+            CostEngine::recommend_optimizations_with_policy(&fleet, &accounts, &memory_sensitive)
+                .is_empty()
+        );
+    }
+
+    fn make_arbitrage(instance_id: &str, current: f64, alternative: f64) -> ProviderArbitrage {
+        ProviderArbitrage {
+            instance_id: instance_id.to_string(),
+            current_provider: VpsProvider::Vultr,
+            cheaper_provider: VpsProvider::Hetzner,
+            current_monthly_usd: current,
+            alternative_monthly_usd: alternative,
+            monthly_savings_usd: current - alternative,
+        }
+    }
+
+    #[test]
+    fn test_net_arbitrage_amortizes_migration_cost() {
+        let arb = make_arbitrage("i-1", 24.0, 12.0);
+        assert!((net_arbitrage(&arb, 30.0, 6) - 7.0).abs() < 1e-9);
+        assert!((net_arbitrage(&arb, 30.0, 0) - -18.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_analyze_waste_drops_arbitrage_that_does_not_pay_for_migration() {
+        let fleet = make_fleet();
+        let on_vultr = |tier, instance_id: &str| AccountActivity {
+            instance_id: instance_id.to_string(),
+            provider: VpsProvider::Vultr,
+            ..make_account(0, 50.0, 50.0, tier, 0.0)
+        };
+        let accounts = [
+            on_vultr(InstanceTier::Standard, "i-small"),
+            on_vultr(InstanceTier::Pro, "i-large"),
+        ];
+        // Standard: $2/mo gross vs $20 over 6 months (~$3.33/mo), not worth moving.
+        let prices = PriceBook::new()
+            .with_price(VpsProvider::Vultr, InstanceTier::Standard, 14.0)
+            .with_price(VpsProvider::Hetzner, InstanceTier::Standard, 12.0)
+            .with_price(VpsProvider::Vultr, InstanceTier::Pro, 48.0)
+            .with_price(VpsProvider::Hetzner, InstanceTier::Pro, 30.0);
+        let report = CostEngine::analyze_waste_with_arbitrage(
+            &fleet,
+            &accounts,
+            &WastePolicy::default(),
+            &prices,
+            &[VpsProvider::Hetzner],
+        );
+        assert_eq!(report.provider_arbitrage.len(), 1);
+        assert_eq!(report.provider_arbitrage[0].instance_id, "i-large");
+        let expected_net = 18.0 - 20.0 / 6.0;
+        assert!((report.total_recoverable_monthly_usd - expected_net).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_confidence_for_reports_slower_than_daily() {
+        let policy = WastePolicy::default();
+        // Every two days: 3 days needs 2 reports, 7 days needs 4.
+        assert_eq!(policy.required_reports(172_800), (2, 4));
+        assert_eq!(policy.confidence_for_reports(0, 172_800), None);
+        assert_eq!(
+            policy.confidence_for_reports(2, 172_800),
+            Some(OptimizationConfidence::Medium)
+        );
+        assert_eq!(
+            policy.confidence_for_reports(4, 172_800),
+            Some(OptimizationConfidence::High)
+        );
+    }
+
+    #[test]
+    fn test_confidence_for_reports_at_minute_cadence() {
+        let policy = WastePolicy::default();
+        let per_day = 24 * 60;
+        assert_eq!(
+            policy.required_reports(DEFAULT_REPORT_INTERVAL_SECS),
+            (3 * per_day, 7 * per_day)
+        );
+        // An hourly-cadence count (3×24) is only 72 minutes of data here.
+        assert_eq!(
+            policy.confidence_for_reports(72, DEFAULT_REPORT_INTERVAL_SECS),
+            None
+        );
+        assert_eq!(
+            policy.confidence_for_reports(3 * per_day, DEFAULT_REPORT_INTERVAL_SECS),
+            Some(OptimizationConfidence::Medium)
+        );
+
+        let three_day_high = WastePolicy {
+            min_observation_days: 1,
+            high_confidence_observation_days: 3,
+            ..WastePolicy::default()
+        };
+        assert_eq!(
+            three_day_high.confidence_for_reports(3 * per_day, DEFAULT_REPORT_INTERVAL_SECS),
+            Some(OptimizationConfidence::High)
+        );
+        // The same three days at an hourly cadence is only 72 reports.
+        assert_eq!(
+            three_day_high.confidence_for_reports(72, 3600),
+            Some(OptimizationConfidence::High)
+        );
+    }
+
+    #[test]
+    fn test_analyze_waste_uses_report_count_at_policy_cadence() {
+        let fleet = make_fleet();
+        let per_day = 24 * 60;
+        let mut account = make_account(0, 10.0, 10.0, InstanceTier::Standard, 11.0);
+        // Report count wins over observation_days when present.
+        account.observation_days = 0;
+        account.report_count = Some(3 * per_day);
+
+        let report = CostEngine::analyze_waste(&fleet, std::slice::from_ref(&account));
+        assert_eq!(report.oversized_instances.len(), 1);
+        assert_eq!(
+            report.oversized_instances[0].confidence,
+            OptimizationConfidence::Medium
+        );
+
+        let three_day_high = WastePolicy {
+            min_observation_days: 1,
+            high_confidence_observation_days: 3,
+            ..WastePolicy::default()
+        };
+        let opts = CostEngine::recommend_optimizations_with_policy(
+            &fleet,
+            std::slice::from_ref(&account),
+            &three_day_high,
+        );
+        assert_eq!(opts.len(), 1);
+        assert_eq!(opts[0].confidence, OptimizationConfidence::High);
+
+        // An hourly-cadence count is barely an hour of data at 60s.
+        account.report_count = Some(3 * 24);
+        let report = CostEngine::analyze_waste(&fleet, &[account]);
+        assert!(report.oversized_instances.is_empty());
+    }
+
     #[test]
     fn test_analyze_waste_nano_not_oversized() {
         let fleet = make_fleet();
@@ -548,6 +2084,103 @@ mod tests {
         assert!(summary.contains("oversized"));
     }
 
+    fn make_idle_report(idle_costs: &[f64]) -> WasteReport {
+        WasteReport {
+            generated_at: Utc::now(),
+            idle_accounts: idle_costs
+                .iter()
+                .enumerate()
+                .map(|(i, cost)| IdleAccount {
+                    account_id: format!("acc-{i}"),
+                    last_activity: Utc::now() - Duration::days(20),
+                    idle_days: 20,
+                    monthly_cost_usd: *cost,
+                })
+                .collect(),
+            oversized_instances: vec![],
+            provider_arbitrage: vec![],
+            total_recoverable_monthly_usd: idle_costs.iter().sum(),
+            currency: Currency::Usd,
+        }
+    }
+
+    #[test]
+    fn test_waste_summary_fmt_renders_cents() {
+        let report = make_idle_report(&[0.49, 0.25]);
+        assert!(report.summary().contains("— $1/month"));
+        let cents = report.summary_fmt(&CostFormat {
+            decimals: 2,
+            thousands_separator: false,
+        });
+        assert!(cents.contains("— $0.74/month"), "{cents}");
+        assert!(cents.contains("~$0.74/month"), "{cents}");
+    }
+
+    #[test]
+    fn test_waste_summary_fmt_separates_thousands() {
+        let report = make_idle_report(&[1000.0, 341.2]);
+        assert!(report.summary().contains("~$1341/month"));
+        let grouped = report.summary_fmt(&CostFormat {
+            decimals: 0,
+            thousands_separator: true,
+        });
+        assert!(grouped.contains("~$1,341/month"), "{grouped}");
+
+        let format = CostFormat {
+            decimals: 2,
+            thousands_separator: true,
+        };
+        assert_eq!(
+            format.render(Money::new(1234567.891, Currency::Eur)),
+            "€1,234,567.89"
+        );
+        assert_eq!(format.render(Money::new(-999.5, Currency::Usd)), "$-999.50");
+        assert_eq!(
+            format.render(Money::new(-1500.0, Currency::Usd)),
+            "$-1,500.00"
+        );
+    }
+
+    #[test]
+    fn test_waste_report_summary_in_eur() {
+        let fleet = make_fleet();
+        let accounts = vec![
+            make_account(20, 50.0, 50.0, InstanceTier::Standard, 100.0),
+            make_account(0, 10.0, 10.0, InstanceTier::Standard, 100.0),
+        ];
+        let report = CostEngine::analyze_waste(&fleet, &accounts);
+        let rates = ExchangeRates::new(Currency::Usd).with_rate(Currency::Eur, 0.9);
+
+        let usd = report.summary();
+        assert!(usd.contains("$100/month"));
+        assert!(usd.contains("~$140/month"));
+
+        let eur = report.summary_in(Currency::Eur, &rates);
+        assert!(eur.contains("€90/month"));
+        assert!(eur.contains("€36/month"));
+        assert!(eur.contains("~€126/month"));
+        assert!(!eur.contains('$'));
+
+        // Rendering never touches the base-currency totals.
+        assert_eq!(report.currency, Currency::Usd);
+        assert!((report.total_recoverable_monthly_usd - 140.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_exchange_rates_conversion() {
+        let rates = ExchangeRates::new(Currency::Usd)
+            .with_rate(Currency::Eur, 0.9)
+            .with_rate(Currency::Gbp, 0.75);
+        let eur = Money::new(90.0, Currency::Eur);
+        let gbp = eur.convert(Currency::Gbp, &rates).unwrap();
+        assert_eq!(gbp.currency, Currency::Gbp);
+        assert!((gbp.amount - 75.0).abs() < 0.001);
+        assert_eq!(format!("{gbp:.0}"), "£75");
+
+        let usd_only = ExchangeRates::new(Currency::Usd);
+        assert!(eur.convert(Currency::Usd, &usd_only).is_none());
+    }
+
     #[test]
     fn test_waste_report_total_recoverable() {
         let fleet = make_fleet();
@@ -562,6 +2195,126 @@ mod tests {
 
     // ─── CostProjection ─────────────────────────────────────────────────────
 
+    #[derive(Default)]
+    struct RecordingSink {
+        alerts: std::sync::Mutex<Vec<CostAnomalyAlert>>,
+    }
+
+    #[async_trait]
+    impl CostAlertSink for RecordingSink {
+        async fn emit(&self, alert: &CostAnomalyAlert) -> Result<(), String> {
+            self.alerts.lock().unwrap().push(alert.clone());
+            Ok(())
+        }
+    }
+
+    fn make_inputs<'a>(
+        fleet: &'a FleetStatus,
+        accounts: &'a [AccountActivity],
+        actual: f64,
+    ) -> CostProjectionInputs<'a> {
+        CostProjectionInputs {
+            fleet,
+            days: 30,
+            current_daily_usd: 40.0,
+            actual_to_date_usd: actual,
+            accounts,
+            alert_on_elevated: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_project_and_alert_anomaly_fires_once() {
+        let fleet = make_fleet();
+        let accounts: Vec<AccountActivity> = (0..8)
+            .map(|i| make_account(i, 50.0, 50.0, InstanceTier::Standard, 10.0 + i as f64))
+            .collect();
+        let sink = RecordingSink::default();
+        let (proj, alert) = CostEngine::project_and_alert(
+            make_inputs(&fleet, &accounts, 40.0 * 30.0 * 1.20),
+            &sink,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(proj.trajectory, CostTrajectory::Anomaly);
+        assert!(alert.is_some());
+        let alerts = sink.alerts.lock().unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert!((alerts[0].budget_usd - 1200.0).abs() < 0.01);
+        assert!((alerts[0].variance_pct - 20.0).abs() < 0.01);
+        assert_eq!(alerts[0].top_contributors.len(), TOP_CONTRIBUTOR_COUNT);
+        assert!((alerts[0].top_contributors[0].monthly_cost_usd - 17.0).abs() < 0.01);
+    }
+
+    #[tokio::test]
+    async fn test_bandwidth_overage_counts_toward_true_cost() {
+        let fleet = make_fleet();
+        let mut accounts: Vec<AccountActivity> = (0..6)
+            .map(|i| make_account(i, 50.0, 50.0, InstanceTier::Standard, 12.0))
+            .collect();
+        accounts[3].bandwidth_overage_usd = 25.0;
+        let sink = RecordingSink::default();
+        let (_, alert) = CostEngine::project_and_alert(
+            make_inputs(&fleet, &accounts, 40.0 * 30.0 * 1.20),
+            &sink,
+        )
+        .await
+        .unwrap();
+
+        let top = &alert.expect("anomaly alert").top_contributors[0];
+        assert_eq!(top.instance_id, "i-3");
+        assert!((top.monthly_cost_usd - 37.0).abs() < 0.01);
+
+        // 2.5 MB/s outbound ≈ 6.48 TB/month against a 4 TB allowance.
+        let network = claw_proto::NetworkMetrics {
+            bytes_sent: 0,
+            bytes_recv: 0,
+            bytes_sent_per_sec: 2.5e6,
+            bytes_recv_per_sec: 9.0e6,
+            tailscale_latency_ms: None,
+        };
+        let mut idle = make_account(20, 50.0, 50.0, InstanceTier::Standard, 12.0);
+        idle.apply_bandwidth(&network, 4.0, 10.0);
+        assert!((idle.bandwidth_overage_usd - 24.8).abs() < 0.01);
+        idle.bandwidth_overage_usd = 3.0;
+        let report = CostEngine::analyze_waste(&fleet, &[idle]);
+        assert!((report.idle_accounts[0].monthly_cost_usd - 15.0).abs() < 0.01);
+    }
+
+    #[tokio::test]
+    async fn test_project_and_alert_on_track_fires_none() {
+        let fleet = make_fleet();
+        let sink = RecordingSink::default();
+        let (proj, alert) =
+            CostEngine::project_and_alert(make_inputs(&fleet, &[], 40.0 * 30.0), &sink)
+                .await
+                .unwrap();
+
+        assert_eq!(proj.trajectory, CostTrajectory::OnTrack);
+        assert!(alert.is_none());
+        assert!(sink.alerts.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_project_and_alert_elevated_only_when_configured() {
+        let fleet = make_fleet();
+        let sink = RecordingSink::default();
+        let actual = 40.0 * 30.0 * 1.10;
+
+        CostEngine::project_and_alert(make_inputs(&fleet, &[], actual), &sink)
+            .await
+            .unwrap();
+        assert!(sink.alerts.lock().unwrap().is_empty());
+
+        let inputs = CostProjectionInputs {
+            alert_on_elevated: true,
+            ..make_inputs(&fleet, &[], actual)
+        };
+        CostEngine::project_and_alert(inputs, &sink).await.unwrap();
+        assert_eq!(sink.alerts.lock().unwrap().len(), 1);
+    }
+
     #[test]
     fn test_project_costs_on_track() {
         let fleet = make_fleet();
@@ -598,6 +2351,50 @@ mod tests {
         assert_eq!(proj.trajectory, CostTrajectory::Elevated);
     }
 
+    // ─── Cost efficiency ────────────────────────────────────────────────────
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, d).unwrap()
+    }
+
+    #[test]
+    fn test_efficiency_improves_as_fleet_grows() {
+        let mut history = CostEfficiencyHistory::new();
+        // Recorded out of order; the history keeps date order.
+        history.record_totals(day(15), 1_800.0, 200);
+        history.record_totals(day(1), 1_200.0, 100);
+        history.record_totals(day(8), 1_500.0, 150);
+        history.record_totals(day(22), 2_000.0, 250);
+        history.record_totals(day(29), 0.0, 0);
+
+        assert_eq!(history.len(), 4);
+        assert_eq!(history.samples()[0].cost_per_account_usd, 12.0);
+        let trend = history.trend();
+        assert_eq!(trend.direction, EfficiencyDirection::Improving);
+        assert!((trend.per_account_change_pct - -33.333).abs() < 0.01);
+        assert!(trend.fleet_size_correlation.unwrap() < -0.9);
+        assert!(trend.shows_economies_of_scale());
+    }
+
+    #[test]
+    fn test_efficiency_rising_cost_and_short_history() {
+        let mut history = CostEfficiencyHistory::new();
+        assert_eq!(history.trend().direction, EfficiencyDirection::Stable);
+
+        history.record(day(1), 10.0, 100);
+        assert_eq!(history.trend().fleet_size_correlation, None);
+        history.record(day(2), 12.0, 100);
+        // Same date replaces the earlier reading.
+        history.record(day(2), 13.0, 100);
+
+        let trend = history.trend();
+        assert_eq!(trend.samples, 2);
+        assert_eq!(trend.direction, EfficiencyDirection::Worsening);
+        // A flat fleet size gives no correlation.
+        assert_eq!(trend.fleet_size_correlation, None);
+        assert!(!trend.shows_economies_of_scale());
+    }
+
     // ─── Optimizations ──────────────────────────────────────────────────────
 
     #[test]
@@ -672,6 +2469,39 @@ mod tests {
         assert_eq!(cmp.entries[0].recommendation, ProviderRecommendation::Avoid);
     }
 
+    #[test]
+    fn test_compare_providers_for_tier_differs_by_tier() {
+        // Hetzner is cheaper; Vultr is more reliable.
+        let providers = vec![
+            make_provider(VpsProvider::Hetzner, 90.0, 300.0, 2.0, 5.0),
+            make_provider(VpsProvider::Vultr, 99.0, 300.0, 0.1, 7.0),
+            make_provider(VpsProvider::Contabo, 99.0, 300.0, 0.0, 3.0),
+        ];
+        // Contabo has no price for either tier, so it is never considered.
+        let prices = PriceBook::new()
+            .with_price(VpsProvider::Hetzner, InstanceTier::Nano, 4.0)
+            .with_price(VpsProvider::Hetzner, InstanceTier::Enterprise, 40.0)
+            .with_price(VpsProvider::Vultr, InstanceTier::Nano, 6.0)
+            .with_price(VpsProvider::Vultr, InstanceTier::Enterprise, 44.0);
+
+        let compare = |tier| {
+            CostEngine::compare_providers_for_tier(
+                &providers,
+                &prices,
+                tier,
+                &ComparisonWeights::for_tier(tier),
+            )
+        };
+        let nano = compare(InstanceTier::Nano);
+        let enterprise = compare(InstanceTier::Enterprise);
+
+        assert_eq!(nano.recommended_primary, VpsProvider::Hetzner);
+        assert_eq!(enterprise.recommended_primary, VpsProvider::Vultr);
+        assert_eq!(enterprise.recommended_standby, VpsProvider::Hetzner);
+        assert_eq!(nano.entries.len(), 2);
+        assert_eq!(nano.entries[0].cost_per_instance_usd, 4.0);
+    }
+
     #[test]
     fn test_downsize_tier_chain() {
         assert_eq!(downsize_tier(&InstanceTier::Enterprise), InstanceTier::Pro);
@@ -688,4 +2518,295 @@ mod tests {
         let back: ProviderComparison = serde_json::from_str(&json).expect("deserialize");
         assert_eq!(back.entries.len(), 1);
     }
+
+    #[test]
+    fn test_future_last_activity_is_not_idle() {
+        let fleet = make_fleet();
+        let mut skewed = make_account(0, 50.0, 50.0, InstanceTier::Standard, 10.0);
+        skewed.last_activity = Utc::now() + Duration::days(2);
+        // What `(now - last_activity).num_days() as u32` wraps to.
+        skewed.idle_days = -2_i64 as u32;
+
+        assert_eq!(skewed.effective_idle_days(Utc::now()), 0);
+        let report = CostEngine::analyze_waste(&fleet, std::slice::from_ref(&skewed));
+        assert!(report.idle_accounts.is_empty());
+        let opts = CostEngine::recommend_optimizations(&fleet, &[skewed]);
+        assert!(opts.is_empty());
+    }
+
+    #[test]
+    fn test_utilization_profile_percentiles() {
+        // Idle half the day, pegged the other half.
+        let cpu: Vec<f64> = (0..24).map(|h| if h < 12 { 2.0 } else { 95.0 }).collect();
+        let mem = vec![10.0; 24];
+        let profile = UtilizationProfile::from_samples(&cpu, &mem).unwrap();
+        assert_eq!(profile.cpu_p50, 2.0);
+        assert_eq!(profile.cpu_p95, 95.0);
+        assert_eq!(profile.mem_p95, 10.0);
+        assert!(UtilizationProfile::from_samples(&[], &mem).is_none());
+    }
+
+    #[test]
+    fn test_saturated_instance_upsized_on_p50_not_mean() {
+        let fleet = make_fleet();
+        let mem = vec![30.0; 20];
+
+        // Pegged all day: p50 is 90%.
+        let busy_cpu = vec![90.0; 20];
+        let mut busy = make_account(0, 90.0, 30.0, InstanceTier::Standard, 11.0);
+        busy.instance_id = "i-busy".to_string();
+        busy.utilization = UtilizationProfile::from_samples(&busy_cpu, &mem);
+
+        // Half the day at 65%, half pegged at 100%: mean 82.5% looks
+        // saturated, but the typical hour has headroom.
+        let bimodal_cpu: Vec<f64> = (0..20).map(|h| if h < 10 { 65.0 } else { 100.0 }).collect();
+        let mean = bimodal_cpu.iter().sum::<f64>() / bimodal_cpu.len() as f64;
+        assert!(mean >= 80.0);
+        let mut bimodal = make_account(0, mean, 30.0, InstanceTier::Standard, 11.0);
+        bimodal.instance_id = "i-bimodal".to_string();
+        bimodal.utilization = UtilizationProfile::from_samples(&bimodal_cpu, &mem);
+        assert!(bimodal.utilization.unwrap().cpu_p50 < 80.0);
+
+        let opts = CostEngine::recommend_optimizations(&fleet, &[busy, bimodal]);
+        assert_eq!(opts.len(), 1);
+        assert_eq!(opts[0].instance_id, "i-busy");
+        assert!(matches!(
+            opts[0].optimization_type,
+            OptimizationType::Upsize {
+                from_tier: InstanceTier::Standard,
+                to_tier: InstanceTier::Pro,
+            }
+        ));
+        assert!(opts[0].estimated_savings_monthly_usd < 0.0);
+        assert!(opts[0].requires_confirmation);
+
+        let mut top = make_account(0, 95.0, 95.0, InstanceTier::Enterprise, 90.0);
+        top.instance_id = "i-top".to_string();
+        assert!(CostEngine::recommend_optimizations(&fleet, &[top]).is_empty());
+    }
+
+    #[test]
+    fn test_spiky_instance_not_downsized_despite_low_mean() {
+        let fleet = make_fleet();
+        // 18 quiet hours at 5%, 2 batch-job hours at 95%: mean 14% looks oversized.
+        let cpu: Vec<f64> = (0..20).map(|h| if h < 18 { 5.0 } else { 95.0 }).collect();
+        let mem = vec![10.0; 20];
+        let mean = cpu.iter().sum::<f64>() / cpu.len() as f64;
+        assert!(mean < 20.0);
+
+        let mut spiky = make_account(0, mean, 10.0, InstanceTier::Standard, 11.0);
+        spiky.instance_id = "i-spiky".to_string();
+        spiky.utilization = UtilizationProfile::from_samples(&cpu, &mem);
+
+        let mut quiet = make_account(0, 5.0, 10.0, InstanceTier::Standard, 11.0);
+        quiet.instance_id = "i-quiet".to_string();
+        quiet.utilization = UtilizationProfile::from_samples(&[5.0; 20], &mem);
+
+        let report = CostEngine::analyze_waste(&fleet, &[spiky.clone(), quiet.clone()]);
+        let ids: Vec<&str> = report
+            .oversized_instances
+            .iter()
+            .map(|o| o.instance_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["i-quiet"]);
+
+        let opts = CostEngine::recommend_optimizations(&fleet, &[spiky, quiet]);
+        assert_eq!(opts.len(), 1);
+        assert_eq!(opts[0].instance_id, "i-quiet");
+    }
+
+    // ─── Live pricing ───────────────────────────────────────────────────────
+
+    struct FakeFetcher {
+        provider: VpsProvider,
+        prices: Vec<RegionalPrice>,
+        failing: std::sync::Arc<std::sync::atomic::AtomicBool>,
+        calls: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl PriceFetcher for FakeFetcher {
+        fn provider(&self) -> VpsProvider {
+            self.provider
+        }
+
+        async fn fetch(&self) -> Result<Vec<RegionalPrice>, String> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if self.failing.load(std::sync::atomic::Ordering::SeqCst) {
+                return Err("pricing API unavailable".to_string());
+            }
+            Ok(self.prices.clone())
+        }
+    }
+
+    fn regional(tier: InstanceTier, region: &str, monthly_usd: f64) -> RegionalPrice {
+        RegionalPrice {
+            tier,
+            region: region.to_string(),
+            monthly_usd,
+        }
+    }
+
+    fn make_live_book(ttl: chrono::Duration) -> (LivePriceBook, FakeFetcher) {
+        let fallback = PriceBook::new()
+            .with_price(VpsProvider::Hetzner, InstanceTier::Standard, 12.0)
+            .with_price(VpsProvider::Vultr, InstanceTier::Standard, 10.0);
+        // The test keeps a handle sharing the failure switch and call counter.
+        let handle = FakeFetcher {
+            provider: VpsProvider::Hetzner,
+            prices: vec![],
+            failing: Default::default(),
+            calls: Default::default(),
+        };
+        let fetcher = FakeFetcher {
+            provider: VpsProvider::Hetzner,
+            prices: vec![
+                regional(InstanceTier::Standard, "nbg1", 5.0),
+                regional(InstanceTier::Standard, "ash", 11.0),
+            ],
+            failing: handle.failing.clone(),
+            calls: handle.calls.clone(),
+        };
+        let book = LivePriceBook::new(fallback)
+            .with_fetcher(Box::new(fetcher))
+            .with_ttl(ttl);
+        (book, handle)
+    }
+
+    #[tokio::test]
+    async fn test_live_price_book_uses_regional_prices() {
+        let (book, handle) = make_live_book(chrono::Duration::hours(24));
+        // Before the first fetch everything comes from the static book.
+        assert_eq!(
+            book.price(VpsProvider::Hetzner, InstanceTier::Standard, Some("nbg1")),
+            Some(12.0)
+        );
+
+        book.refresh().await;
+        book.refresh().await;
+        // Cached for the TTL: the second refresh does not refetch.
+        assert_eq!(handle.calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        let hetzner = VpsProvider::Hetzner;
+        let standard = InstanceTier::Standard;
+        assert_eq!(book.price(hetzner, standard, Some("nbg1")), Some(5.0));
+        assert_eq!(book.price(hetzner, standard, Some("ash")), Some(11.0));
+        // No region, or one the provider did not price: the list price, never
+        // another region's.
+        assert_eq!(book.price(hetzner, standard, None), Some(12.0));
+        assert_eq!(book.price(hetzner, standard, Some("sin")), Some(12.0));
+        // Providers without a fetcher keep their static price.
+        assert_eq!(
+            book.price(VpsProvider::Vultr, standard, Some("ash")),
+            Some(10.0)
+        );
+
+        // Arbitrage is priced per region: cheaper than Vultr in Europe, not in the US.
+        let alternatives = [VpsProvider::Hetzner];
+        let eu = CostEngine::arbitrage_for(
+            "i-1",
+            VpsProvider::Vultr,
+            standard,
+            Some("nbg1"),
+            &alternatives,
+            &book,
+        )
+        .unwrap();
+        assert_eq!(eu.monthly_savings_usd, 5.0);
+        assert!(
+            CostEngine::arbitrage_for(
+                "i-2",
+                VpsProvider::Vultr,
+                standard,
+                Some("ash"),
+                &alternatives,
+                &book
+            )
+            .is_none()
+        );
+
+        // Projections price each account in its own region.
+        let account = |region: &str| AccountActivity {
+            region: Some(region.to_string()),
+            ..make_account(0, 50.0, 50.0, standard, 99.0)
+        };
+        let projection = CostEngine::project_costs_priced(
+            &make_fleet(),
+            &[account("nbg1"), account("ash")],
+            &book,
+            30,
+            0.0,
+        );
+        assert!((projection.current_daily_usd - 16.0 / 30.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_live_price_book_falls_back_on_fetch_failure() {
+        let (book, handle) = make_live_book(chrono::Duration::zero());
+        book.refresh().await;
+        assert_eq!(
+            book.price(VpsProvider::Hetzner, InstanceTier::Standard, Some("nbg1")),
+            Some(5.0)
+        );
+
+        handle
+            .failing
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+        book.refresh().await;
+        assert_eq!(handle.calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert_eq!(
+            book.price(VpsProvider::Hetzner, InstanceTier::Standard, Some("nbg1")),
+            Some(12.0)
+        );
+        assert_eq!(
+            book.price(VpsProvider::Hetzner, InstanceTier::Nano, Some("nbg1")),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hetzner_price_fetcher_reads_location_prices() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/pricing"))
+            .and(header("authorization", "Bearer tok"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "pricing": {
+                    "server_types": [
+                        {
+                            "name": "cx21",
+                            "prices": [
+                                { "location": "nbg1", "price_monthly": { "gross": "5.0000" } },
+                                { "location": "ash", "price_monthly": { "gross": "10.0000" } }
+                            ]
+                        },
+                        {
+                            "name": "ccx13",
+                            "prices": [
+                                { "location": "nbg1", "price_monthly": { "gross": "14.0000" } }
+                            ]
+                        }
+                    ]
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let rates = ExchangeRates::new(Currency::Usd).with_rate(Currency::Eur, 0.8);
+        let fetcher = HetznerPriceFetcher::new("tok", rates).with_base_url(server.uri());
+        let mut prices = fetcher.fetch().await.unwrap();
+        prices.sort_by(|a, b| a.region.cmp(&b.region));
+        // Server types outside the ClawOps tiers are skipped.
+        assert_eq!(prices.len(), 2);
+        assert_eq!(prices[0].region, "ash");
+        assert_eq!(prices[0].tier, InstanceTier::Standard);
+        assert!((prices[1].monthly_usd - 6.25).abs() < 1e-9);
+
+        let failing = HetznerPriceFetcher::new("tok", ExchangeRates::new(Currency::Usd))
+            .with_base_url(format!("{}/missing", server.uri()));
+        assert!(failing.fetch().await.is_err());
+    }
 }
@@ -6,9 +6,13 @@
 #![forbid(unsafe_code)]
 
 use chrono::{DateTime, Utc};
+use claw_proto::{
+    FleetStatus, InstanceRole, InstanceState, InstanceTier, ManagedInstance, StatusThresholds,
+    percentile,
+};
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::sync::Arc;
 use std::time::Duration;
 use tracing::warn;
@@ -16,12 +20,24 @@ use tracing::warn;
 pub use error::{MetricsError, Result};
 pub use types::{MetricName, MetricPoint, TimeRange};
 
+/// Upper bound on points held per series, whatever the retention window.
+pub const DEFAULT_MAX_POINTS_PER_SERIES: usize = 10_000;
+
+/// Width of the buckets disk samples evicted by the size cap are rolled into.
+pub const DISK_ROLLUP_BUCKET_SECS: i64 = 300;
+
 /// Central metric storage with retention policy.
 pub struct MetricStore {
     /// Per-metric time series (deque for efficient front-pop).
     series: RwLock<HashMap<String, VecDeque<MetricPoint>>>,
+    /// Points evicted by the size cap or rejected as already expired, per series.
+    dropped: RwLock<HashMap<String, u64>>,
+    /// Max disk usage per bucket start (unix secs) for samples the cap evicted.
+    disk_rollups: RwLock<HashMap<String, BTreeMap<i64, f64>>>,
     /// How long to retain data.
     retention: Duration,
+    /// Most points kept per series; the oldest is evicted beyond this.
+    max_points: usize,
 }
 
 impl MetricStore {
@@ -29,16 +45,32 @@ impl MetricStore {
     pub fn new(retention: Duration) -> Self {
         Self {
             series: RwLock::new(HashMap::new()),
+            dropped: RwLock::new(HashMap::new()),
+            disk_rollups: RwLock::new(HashMap::new()),
             retention,
+            max_points: DEFAULT_MAX_POINTS_PER_SERIES,
         }
     }
 
-    /// Push a metric point. Evicts expired data.
+    /// Override the per-series point cap (minimum 1).
+    pub fn with_max_points(mut self, max_points: usize) -> Self {
+        self.max_points = max_points.max(1);
+        self
+    }
+
+    /// Push a metric point. Evicts expired data, then the oldest points
+    /// beyond the per-series cap. A point already older than the retention
+    /// window is dropped rather than stored.
     pub fn push(&self, name: &MetricName, point: MetricPoint) -> Result<()> {
         let cutoff = Utc::now()
             - chrono::Duration::from_std(self.retention)
                 .map_err(|_| MetricsError::InvalidRetention)?;
 
+        if point.timestamp < cutoff {
+            self.record_dropped(&name.0, 1);
+            return Ok(());
+        }
+
         let mut series = self.series.write();
         let deque = series.entry(name.0.clone()).or_default();
 
@@ -48,9 +80,93 @@ impl MetricStore {
         }
 
         deque.push_back(point);
+        let overflow = deque.len().saturating_sub(self.max_points);
+        if overflow > 0 {
+            let evicted: Vec<MetricPoint> = deque.drain(..overflow).collect();
+            drop(series);
+            if name.0.ends_with(".disk") {
+                self.roll_up_disk(&name.0, &evicted, cutoff);
+            }
+            self.record_dropped(&name.0, overflow as u64);
+        }
         Ok(())
     }
 
+    /// Fold evicted disk samples into per-bucket maxima so the trend outlives the cap.
+    fn roll_up_disk(&self, name: &str, evicted: &[MetricPoint], cutoff: DateTime<Utc>) {
+        let mut rollups = self.disk_rollups.write();
+        let buckets = rollups.entry(name.to_string()).or_default();
+        for point in evicted {
+            let ts = point.timestamp.timestamp();
+            let bucket = buckets
+                .entry(ts - ts.rem_euclid(DISK_ROLLUP_BUCKET_SECS))
+                .or_insert(point.value);
+            *bucket = bucket.max(point.value);
+        }
+        let cutoff_bucket =
+            cutoff.timestamp() - cutoff.timestamp().rem_euclid(DISK_ROLLUP_BUCKET_SECS);
+        buckets.retain(|start, _| *start >= cutoff_bucket);
+    }
+
+    /// Disk usage for one instance over the whole retention window, oldest first.
+    ///
+    /// Samples the size cap evicted appear as one max-per-bucket point at the
+    /// bucket start, so a rising trend survives for disk-full prediction.
+    pub fn disk_history(&self, instance_id: &str) -> Vec<(DateTime<Utc>, f32)> {
+        let name = format!("{instance_id}.disk");
+        let Ok(cutoff) = chrono::Duration::from_std(self.retention).map(|r| Utc::now() - r) else {
+            return Vec::new();
+        };
+
+        let mut history: Vec<(DateTime<Utc>, f32)> = self
+            .disk_rollups
+            .read()
+            .get(&name)
+            .into_iter()
+            .flatten()
+            .filter_map(|(start, max)| {
+                let at = DateTime::from_timestamp(*start, 0)?;
+                (at >= cutoff - chrono::Duration::seconds(DISK_ROLLUP_BUCKET_SECS))
+                    .then_some((at, *max as f32))
+            })
+            .collect();
+        if let Some(raw) = self.series.read().get(&name) {
+            history.extend(
+                raw.iter()
+                    .filter(|p| p.timestamp >= cutoff)
+                    .map(|p| (p.timestamp, p.value as f32)),
+            );
+        }
+        history.sort_by_key(|(at, _)| *at);
+        history
+    }
+
+    fn record_dropped(&self, name: &str, n: u64) {
+        let mut dropped = self.dropped.write();
+        let total = dropped.entry(name.to_string()).or_default();
+        let before = *total;
+        *total += n;
+        // Log the first drop and then every 1000th, not every point of a flood.
+        if before == 0 || before / 1000 != *total / 1000 {
+            warn!(metric = %name, dropped = *total, "metric series over capacity; dropping samples");
+        }
+    }
+
+    /// Points dropped so far for one series.
+    pub fn dropped_points(&self, name: &MetricName) -> u64 {
+        self.dropped.read().get(&name.0).copied().unwrap_or(0)
+    }
+
+    /// Dropped points summed per instance (the series name up to its last `.`).
+    pub fn dropped_by_instance(&self) -> HashMap<String, u64> {
+        let mut by_instance: HashMap<String, u64> = HashMap::new();
+        for (name, n) in self.dropped.read().iter() {
+            let instance = name.rsplit_once('.').map_or(name.as_str(), |(i, _)| i);
+            *by_instance.entry(instance.to_string()).or_default() += n;
+        }
+        by_instance
+    }
+
     /// Query all points in the given time range.
     pub fn query(
         &self,
@@ -142,19 +258,48 @@ pub fn push_vps_snapshot(
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InstanceSnapshot {
     pub instance_id: String,
+    pub account_id: String,
     pub provider: String,
+    pub role: InstanceRole,
+    pub state: InstanceState,
     pub cpu_pct: f64,
     pub mem_pct: f64,
     pub disk_pct: f64,
     pub health_score: f64,
     pub monthly_cost_usd: f64,
     pub recorded_at: DateTime<Utc>,
+    /// Unknown for snapshots recorded before tiers were tracked here.
+    #[serde(default)]
+    pub tier: Option<InstanceTier>,
+    /// The provider's own id for the server, needed to rebuild fleet state.
+    #[serde(default)]
+    pub provider_instance_id: Option<String>,
+    /// Provider region the instance runs in, e.g. `eu-hetzner-nbg1`.
+    #[serde(default)]
+    pub region: Option<String>,
 }
 
+impl InstanceSnapshot {
+    /// The state this instance is counted under (see `classify_instance_status`).
+    pub fn status(&self, thresholds: &StatusThresholds) -> InstanceState {
+        let score = self.health_score.clamp(0.0, 100.0) as u8;
+        claw_proto::classify_instance_status(score, self.state, thresholds)
+    }
+}
+
+/// Health score at or above which an instance counts as healthy for provider SLA.
+pub const HEALTHY_INSTANCE_SCORE: f64 = 70.0;
+
 /// Fleet-wide aggregated metrics computed from a set of instance snapshots.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FleetMetrics {
     pub total_instances: u32,
+    pub active_instances: u32,
+    /// Accounts whose primary and standby are both active.
+    pub active_pairs: u32,
+    pub degraded_instances: u32,
+    pub failed_instances: u32,
+    pub bootstrapping_instances: u32,
     pub avg_cpu_pct: f64,
     pub avg_mem_pct: f64,
     pub avg_disk_pct: f64,
@@ -173,14 +318,35 @@ pub struct ProviderMetrics {
     pub avg_cpu_pct: f64,
     pub avg_mem_pct: f64,
     pub monthly_cost_usd: f64,
+    /// Percentage of instances scoring at least `HEALTHY_INSTANCE_SCORE`.
+    ///
+    /// Unlike the mean, one badly broken instance cannot drag this down much.
+    /// `None` in metrics saved before it was tracked.
+    #[serde(default)]
+    pub healthy_pct: Option<f64>,
+    /// Provision-to-healthy percentiles, once a `ProvisionLatencyTracker` has
+    /// been folded in with `FleetMetrics::with_time_to_healthy`.
+    #[serde(default)]
+    pub time_to_healthy: Option<LatencyPercentiles>,
 }
 
 impl FleetMetrics {
-    /// Compute fleet metrics from a slice of instance snapshots.
+    /// Compute fleet metrics from a slice of instance snapshots, classifying
+    /// each instance with the default status thresholds.
     pub fn compute(snapshots: &[InstanceSnapshot]) -> Self {
+        Self::compute_with(snapshots, &StatusThresholds::default())
+    }
+
+    /// Like `compute`, with the degraded/failed bands Health is configured with.
+    pub fn compute_with(snapshots: &[InstanceSnapshot], thresholds: &StatusThresholds) -> Self {
         if snapshots.is_empty() {
             return Self {
                 total_instances: 0,
+                active_instances: 0,
+                active_pairs: 0,
+                degraded_instances: 0,
+                failed_instances: 0,
+                bootstrapping_instances: 0,
                 avg_cpu_pct: 0.0,
                 avg_mem_pct: 0.0,
                 avg_disk_pct: 0.0,
@@ -197,6 +363,27 @@ impl FleetMetrics {
         let avg_disk = snapshots.iter().map(|s| s.disk_pct).sum::<f64>() / n;
         let avg_health = snapshots.iter().map(|s| s.health_score).sum::<f64>() / n;
         let total_cost = snapshots.iter().map(|s| s.monthly_cost_usd).sum::<f64>();
+        let statuses: Vec<InstanceState> = snapshots.iter().map(|s| s.status(thresholds)).collect();
+        let count_state =
+            |state: InstanceState| statuses.iter().filter(|s| **s == state).count() as u32;
+
+        // A pair is active when the account has both an active primary and an active standby
+        let mut active_roles: HashMap<&str, (bool, bool)> = HashMap::new();
+        for (snap, _) in snapshots
+            .iter()
+            .zip(&statuses)
+            .filter(|(_, status)| **status == InstanceState::Active)
+        {
+            let entry = active_roles.entry(snap.account_id.as_str()).or_default();
+            match snap.role {
+                InstanceRole::Primary => entry.0 = true,
+                InstanceRole::Standby => entry.1 = true,
+            }
+        }
+        let active_pairs = active_roles
+            .values()
+            .filter(|(primary, standby)| *primary && *standby)
+            .count() as u32;
 
         // Group by provider
         let mut by_provider: HashMap<String, Vec<&InstanceSnapshot>> = HashMap::new();
@@ -218,6 +405,15 @@ impl FleetMetrics {
                     avg_cpu_pct: instances.iter().map(|s| s.cpu_pct).sum::<f64>() / pn,
                     avg_mem_pct: instances.iter().map(|s| s.mem_pct).sum::<f64>() / pn,
                     monthly_cost_usd: instances.iter().map(|s| s.monthly_cost_usd).sum::<f64>(),
+                    healthy_pct: Some(
+                        instances
+                            .iter()
+                            .filter(|s| s.health_score >= HEALTHY_INSTANCE_SCORE)
+                            .count() as f64
+                            / pn
+                            * 100.0,
+                    ),
+                    time_to_healthy: None,
                 };
                 (provider.clone(), pm)
             })
@@ -225,6 +421,11 @@ impl FleetMetrics {
 
         Self {
             total_instances: snapshots.len() as u32,
+            active_instances: count_state(InstanceState::Active),
+            active_pairs,
+            degraded_instances: count_state(InstanceState::Degraded),
+            failed_instances: count_state(InstanceState::Failed),
+            bootstrapping_instances: count_state(InstanceState::Bootstrapping),
             avg_cpu_pct: avg_cpu,
             avg_mem_pct: avg_mem,
             avg_disk_pct: avg_disk,
@@ -234,6 +435,274 @@ impl FleetMetrics {
             computed_at: Utc::now(),
         }
     }
+
+    /// Attach each provider's provision-to-healthy percentiles from `tracker`.
+    pub fn with_time_to_healthy(mut self, tracker: &ProvisionLatencyTracker) -> Self {
+        for (provider, metrics) in &mut self.by_provider {
+            metrics.time_to_healthy = tracker.time_to_healthy(provider);
+        }
+        self
+    }
+
+    /// Build the Ledger-facing `FleetStatus` from the same counts as this aggregate.
+    pub fn to_fleet_status(&self) -> FleetStatus {
+        FleetStatus {
+            total_instances: self.total_instances,
+            active_pairs: self.active_pairs,
+            degraded_instances: self.degraded_instances,
+            failed_instances: self.failed_instances,
+            bootstrapping_instances: self.bootstrapping_instances,
+            generated_at: self.computed_at,
+        }
+    }
+
+    /// Per-provider cost changes between two aggregates, largest absolute delta first.
+    ///
+    /// A provider present in only one snapshot is treated as costing $0 in the other.
+    pub fn cost_movers(before: &FleetMetrics, after: &FleetMetrics) -> Vec<CostMover> {
+        let cost = |fm: &FleetMetrics, key: &str| {
+            fm.by_provider
+                .get(key)
+                .map(|p| p.monthly_cost_usd)
+                .unwrap_or(0.0)
+        };
+        let mut keys: Vec<&String> = before
+            .by_provider
+            .keys()
+            .chain(after.by_provider.keys())
+            .collect();
+        keys.sort();
+        keys.dedup();
+
+        let mut movers: Vec<CostMover> = keys
+            .into_iter()
+            .map(|key| {
+                let before_usd = cost(before, key);
+                let after_usd = cost(after, key);
+                let delta_usd = after_usd - before_usd;
+                CostMover {
+                    provider: key.clone(),
+                    before_usd,
+                    after_usd,
+                    delta_usd,
+                    delta_pct: (before_usd > 0.0).then(|| delta_usd / before_usd * 100.0),
+                }
+            })
+            .filter(|m| m.delta_usd != 0.0)
+            .collect();
+        movers.sort_by(|a, b| {
+            b.delta_usd
+                .abs()
+                .total_cmp(&a.delta_usd.abs())
+                .then_with(|| a.provider.cmp(&b.provider))
+        });
+        movers
+    }
+}
+
+/// One provider's monthly cost change between two fleet aggregates.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CostMover {
+    pub provider: String,
+    pub before_usd: f64,
+    pub after_usd: f64,
+    /// Signed change: positive means the provider got more expensive.
+    pub delta_usd: f64,
+    /// Percentage change, or `None` when the provider is new in `after`.
+    pub delta_pct: Option<f64>,
+}
+
+impl CostMover {
+    /// The mover with the largest relative change; new providers are skipped.
+    pub fn largest_pct(movers: &[CostMover]) -> Option<&CostMover> {
+        movers
+            .iter()
+            .filter(|m| m.delta_pct.is_some())
+            .max_by(|a, b| {
+                let pa = a.delta_pct.unwrap_or(0.0).abs();
+                let pb = b.delta_pct.unwrap_or(0.0).abs();
+                pa.total_cmp(&pb)
+            })
+    }
+}
+
+// ─── Fleet State Export ───────────────────────────────────────────────────────
+
+/// Version written into every `FleetStateExport`.
+pub const FLEET_STATE_EXPORT_VERSION: u32 = 1;
+
+/// Disaster-recovery snapshot of fleet membership and pair mappings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FleetStateExport {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    /// One entry per account, sorted by account id.
+    pub accounts: Vec<AccountPairExport>,
+}
+
+/// An account's primary/standby pair as recorded in an export.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountPairExport {
+    pub account_id: String,
+    pub primary: Option<ExportedInstance>,
+    pub standby: Option<ExportedInstance>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportedInstance {
+    pub instance_id: String,
+    pub provider: String,
+    pub provider_instance_id: Option<String>,
+    pub tier: Option<InstanceTier>,
+}
+
+/// An exported instance that still exists but no longer matches the export.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstanceDrift {
+    pub account_id: String,
+    pub expected: ExportedInstance,
+    pub live: ManagedInstance,
+}
+
+/// Outcome of rebuilding fleet state from an export and the providers' view.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReconcileReport {
+    /// The export with live provider ids and tiers; missing instances dropped.
+    pub state: FleetStateExport,
+    /// Instance ids found live exactly as exported.
+    pub matched: Vec<String>,
+    pub drifted: Vec<InstanceDrift>,
+    /// Exported instances no provider reports any more.
+    pub missing: Vec<ExportedInstance>,
+    /// Live instances the export knows nothing about.
+    pub unknown: Vec<ManagedInstance>,
+}
+
+impl ReconcileReport {
+    /// Every exported instance was found live, unchanged, and nothing extra exists.
+    pub fn is_clean(&self) -> bool {
+        self.drifted.is_empty() && self.missing.is_empty() && self.unknown.is_empty()
+    }
+}
+
+/// Export and rebuild of control-plane fleet state.
+pub struct FleetState;
+
+impl FleetState {
+    /// Capture account → pair → provider instance id mappings.
+    ///
+    /// When an instance has several snapshots the most recent one wins.
+    pub fn export(snapshots: &[InstanceSnapshot]) -> FleetStateExport {
+        let mut latest: HashMap<&str, &InstanceSnapshot> = HashMap::new();
+        for snap in snapshots {
+            let entry = latest.entry(snap.instance_id.as_str()).or_insert(snap);
+            if snap.recorded_at > entry.recorded_at {
+                *entry = snap;
+            }
+        }
+
+        let mut accounts: BTreeMap<&str, AccountPairExport> = BTreeMap::new();
+        let mut ordered: Vec<&InstanceSnapshot> = latest.into_values().collect();
+        ordered.sort_by(|a, b| a.instance_id.cmp(&b.instance_id));
+        for snap in ordered {
+            let pair =
+                accounts
+                    .entry(snap.account_id.as_str())
+                    .or_insert_with(|| AccountPairExport {
+                        account_id: snap.account_id.clone(),
+                        primary: None,
+                        standby: None,
+                    });
+            let slot = match snap.role {
+                InstanceRole::Primary => &mut pair.primary,
+                InstanceRole::Standby => &mut pair.standby,
+            };
+            slot.get_or_insert_with(|| ExportedInstance {
+                instance_id: snap.instance_id.clone(),
+                provider: snap.provider.clone(),
+                provider_instance_id: snap.provider_instance_id.clone(),
+                tier: snap.tier,
+            });
+        }
+
+        FleetStateExport {
+            version: FLEET_STATE_EXPORT_VERSION,
+            exported_at: Utc::now(),
+            accounts: accounts.into_values().collect(),
+        }
+    }
+
+    /// Rebuild state after a control-plane loss by matching the export
+    /// against what the providers report, by instance id.
+    pub fn reconcile_from_providers(
+        export: &FleetStateExport,
+        live: &[ManagedInstance],
+    ) -> ReconcileReport {
+        let mut by_id: HashMap<&str, &ManagedInstance> = live
+            .iter()
+            .filter_map(|m| Some((m.instance_id.as_deref()?, m)))
+            .collect();
+        let mut matched = Vec::new();
+        let mut drifted = Vec::new();
+        let mut missing = Vec::new();
+
+        let mut reconcile = |account_id: &str, slot: &Option<ExportedInstance>| {
+            let expected = slot.as_ref()?;
+            let Some(found) = by_id.remove(expected.instance_id.as_str()) else {
+                missing.push(expected.clone());
+                return None;
+            };
+            let current = ExportedInstance {
+                instance_id: expected.instance_id.clone(),
+                provider: found.provider.to_string(),
+                provider_instance_id: Some(found.provider_instance_id.clone()),
+                tier: found.tier.or(expected.tier),
+            };
+            if current == *expected {
+                matched.push(expected.instance_id.clone());
+            } else {
+                drifted.push(InstanceDrift {
+                    account_id: account_id.to_string(),
+                    expected: expected.clone(),
+                    live: found.clone(),
+                });
+            }
+            Some(current)
+        };
+
+        let accounts = export
+            .accounts
+            .iter()
+            .map(|pair| AccountPairExport {
+                account_id: pair.account_id.clone(),
+                primary: reconcile(&pair.account_id, &pair.primary),
+                standby: reconcile(&pair.account_id, &pair.standby),
+            })
+            .collect();
+
+        // Unlabelled servers cannot be matched and are always unknown.
+        let mut unknown: Vec<ManagedInstance> = by_id
+            .into_values()
+            .chain(live.iter().filter(|m| m.instance_id.is_none()))
+            .cloned()
+            .collect();
+        unknown.sort_by(|a, b| {
+            (&a.instance_id, &a.provider_instance_id)
+                .cmp(&(&b.instance_id, &b.provider_instance_id))
+        });
+
+        ReconcileReport {
+            state: FleetStateExport {
+                version: FLEET_STATE_EXPORT_VERSION,
+                exported_at: Utc::now(),
+                accounts,
+            },
+            matched,
+            drifted,
+            missing,
+            unknown,
+        }
+    }
 }
 
 // ─── Time-Series Ring Buffer ──────────────────────────────────────────────────
@@ -296,6 +765,153 @@ impl TimeSeriesBuffer {
     }
 }
 
+// ─── Provision-to-healthy latency ─────────────────────────────────────────────
+
+/// p50/p95 of one provider's provision-to-healthy times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LatencyPercentiles {
+    pub p50_ms: u64,
+    pub p95_ms: u64,
+    pub samples: usize,
+}
+
+#[derive(Debug, Clone)]
+struct PendingProvision {
+    provider: String,
+    provisioned_at: DateTime<Utc>,
+}
+
+/// Time from a provision completing until the node first reports healthy.
+///
+/// `provision_duration_ms` stops when the provider says "running"; this keeps
+/// going until clawnode is up and scoring at least degraded, so cloud-init
+/// and gateway start-up are counted too.
+///
+/// An instance that never reports healthy within the pending TTL is dropped
+/// and counted as timed out, so torn-down or dead instances do not pile up.
+/// Each provider keeps only its most recent [`MAX_TIME_TO_HEALTHY_SAMPLES`].
+#[derive(Debug, Clone)]
+pub struct ProvisionLatencyTracker {
+    pending: HashMap<String, PendingProvision>,
+    samples: HashMap<String, Vec<u64>>,
+    timed_out: HashMap<String, u32>,
+    thresholds: StatusThresholds,
+    pending_ttl: chrono::Duration,
+}
+
+/// How long an instance may take to report healthy before it stops being
+/// tracked.
+pub const DEFAULT_TIME_TO_HEALTHY_TTL_SECS: i64 = 3600;
+
+/// Time-to-healthy samples kept per provider; older ones are dropped first.
+pub const MAX_TIME_TO_HEALTHY_SAMPLES: usize = 1000;
+
+impl ProvisionLatencyTracker {
+    pub fn new() -> Self {
+        Self::with_thresholds(StatusThresholds::default())
+    }
+
+    /// Count a report as healthy once it scores at least `thresholds.failed_score`.
+    pub fn with_thresholds(thresholds: StatusThresholds) -> Self {
+        Self {
+            pending: HashMap::new(),
+            samples: HashMap::new(),
+            timed_out: HashMap::new(),
+            thresholds,
+            pending_ttl: chrono::Duration::seconds(DEFAULT_TIME_TO_HEALTHY_TTL_SECS),
+        }
+    }
+
+    /// Give up on an instance that has not reported healthy after `ttl`.
+    pub fn with_pending_ttl(mut self, ttl: chrono::Duration) -> Self {
+        self.pending_ttl = ttl;
+        self
+    }
+
+    /// Drop pending instances provisioned more than the TTL before `now`,
+    /// counting each as timed out for its provider.
+    pub fn expire_pending(&mut self, now: DateTime<Utc>) {
+        let ttl = self.pending_ttl;
+        let timed_out = &mut self.timed_out;
+        self.pending.retain(|_, pending| {
+            let live = now.signed_duration_since(pending.provisioned_at) <= ttl;
+            if !live {
+                *timed_out.entry(pending.provider.clone()).or_default() += 1;
+            }
+            live
+        });
+    }
+
+    /// Instances of `provider` that never reported healthy within the TTL.
+    pub fn timed_out(&self, provider: &str) -> u32 {
+        self.timed_out.get(provider).copied().unwrap_or(0)
+    }
+
+    /// Start the clock for a freshly provisioned instance.
+    pub fn record_provision(&mut self, instance_id: &str, provider: &str, at: DateTime<Utc>) {
+        self.expire_pending(at);
+        self.pending.insert(
+            instance_id.to_string(),
+            PendingProvision {
+                provider: provider.to_string(),
+                provisioned_at: at,
+            },
+        );
+    }
+
+    /// Feed a health report; returns the time-to-healthy if this is the
+    /// instance's first healthy report since provisioning.
+    pub fn record_health(
+        &mut self,
+        instance_id: &str,
+        health_score: u8,
+        at: DateTime<Utc>,
+    ) -> Option<u64> {
+        self.expire_pending(at);
+        if health_score < self.thresholds.failed_score {
+            return None;
+        }
+        let pending = self.pending.remove(instance_id)?;
+        let elapsed = at.signed_duration_since(pending.provisioned_at);
+        let ms = u64::try_from(elapsed.num_milliseconds()).unwrap_or(0);
+        let samples = self.samples.entry(pending.provider).or_default();
+        if samples.len() >= MAX_TIME_TO_HEALTHY_SAMPLES {
+            samples.remove(0);
+        }
+        samples.push(ms);
+        Some(ms)
+    }
+
+    /// Instances still waiting for their first healthy report.
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Percentiles for one provider, if any of its instances became healthy.
+    pub fn time_to_healthy(&self, provider: &str) -> Option<LatencyPercentiles> {
+        let samples = self.samples.get(provider)?;
+        Some(LatencyPercentiles {
+            p50_ms: percentile(samples, 50.0)?,
+            p95_ms: percentile(samples, 95.0)?,
+            samples: samples.len(),
+        })
+    }
+
+    /// Percentiles for every provider with at least one sample.
+    pub fn by_provider(&self) -> HashMap<String, LatencyPercentiles> {
+        self.samples
+            .keys()
+            .filter_map(|provider| Some((provider.clone(), self.time_to_healthy(provider)?)))
+            .collect()
+    }
+}
+
+impl Default for ProvisionLatencyTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // ─── Cost Tracker ─────────────────────────────────────────────────────────────
 
 /// Per-instance cost record.
@@ -376,6 +992,13 @@ pub mod error {
         InvalidRetention,
     }
 
+    /// Metric errors are caller mistakes; retrying cannot fix them.
+    impl From<MetricsError> for claw_proto::ClawError {
+        fn from(err: MetricsError) -> Self {
+            claw_proto::ClawError::Permanent(err.to_string())
+        }
+    }
+
     pub type Result<T> = std::result::Result<T, MetricsError>;
 }
 
@@ -472,6 +1095,13 @@ mod tests {
         assert!(MetricName::new("name with spaces").is_err());
     }
 
+    #[test]
+    fn test_metrics_error_is_permanent() {
+        let err = claw_proto::ClawError::from(MetricsError::InvalidRetention);
+        assert!(matches!(err, claw_proto::ClawError::Permanent(_)));
+        assert!(!err.is_retryable());
+    }
+
     #[test]
     fn test_push_and_query() {
         let store = MetricStore::new(Duration::from_secs(3600));
@@ -526,13 +1156,19 @@ mod tests {
     ) -> InstanceSnapshot {
         InstanceSnapshot {
             instance_id: instance_id.to_string(),
+            account_id: format!("acct-{instance_id}"),
             provider: provider.to_string(),
+            role: InstanceRole::Primary,
+            state: InstanceState::Active,
             cpu_pct: cpu,
             mem_pct: mem,
             disk_pct: 30.0,
             health_score: health,
             monthly_cost_usd: cost,
             recorded_at: Utc::now(),
+            tier: None,
+            provider_instance_id: None,
+            region: None,
         }
     }
 
@@ -576,6 +1212,68 @@ mod tests {
         assert!((hetzner.avg_health_score - 70.0).abs() < 0.001);
     }
 
+    fn make_member(
+        instance_id: &str,
+        account_id: &str,
+        role: InstanceRole,
+        state: InstanceState,
+    ) -> InstanceSnapshot {
+        InstanceSnapshot {
+            account_id: account_id.to_string(),
+            role,
+            state,
+            ..make_snapshot(instance_id, "hetzner", 10.0, 10.0, 90.0, 12.0)
+        }
+    }
+
+    #[test]
+    fn test_fleet_metrics_state_counts() {
+        let snapshots = vec![
+            make_member("i-1", "a-1", InstanceRole::Primary, InstanceState::Active),
+            make_member("i-2", "a-1", InstanceRole::Standby, InstanceState::Active),
+            make_member("i-3", "a-2", InstanceRole::Primary, InstanceState::Active),
+            make_member("i-4", "a-2", InstanceRole::Standby, InstanceState::Degraded),
+            make_member("i-5", "a-3", InstanceRole::Primary, InstanceState::Failed),
+            make_member(
+                "i-6",
+                "a-3",
+                InstanceRole::Standby,
+                InstanceState::Bootstrapping,
+            ),
+        ];
+        let fm = FleetMetrics::compute(&snapshots);
+        assert_eq!(fm.active_instances, 3);
+        assert_eq!(fm.active_pairs, 1);
+        assert_eq!(fm.degraded_instances, 1);
+        assert_eq!(fm.failed_instances, 1);
+        assert_eq!(fm.bootstrapping_instances, 1);
+    }
+
+    #[test]
+    fn test_to_fleet_status_matches_fleet_metrics() {
+        let snapshots = vec![
+            make_member("i-1", "a-1", InstanceRole::Primary, InstanceState::Active),
+            make_member("i-2", "a-1", InstanceRole::Standby, InstanceState::Active),
+            make_member("i-3", "a-2", InstanceRole::Primary, InstanceState::Degraded),
+            make_member("i-4", "a-2", InstanceRole::Standby, InstanceState::Active),
+            make_member("i-5", "a-3", InstanceRole::Primary, InstanceState::Failed),
+            make_member(
+                "i-6",
+                "a-4",
+                InstanceRole::Primary,
+                InstanceState::Bootstrapping,
+            ),
+        ];
+        let fm = FleetMetrics::compute(&snapshots);
+        let status = fm.to_fleet_status();
+        assert_eq!(status.total_instances, fm.total_instances);
+        assert_eq!(status.active_pairs, fm.active_pairs);
+        assert_eq!(status.degraded_instances, fm.degraded_instances);
+        assert_eq!(status.failed_instances, fm.failed_instances);
+        assert_eq!(status.bootstrapping_instances, fm.bootstrapping_instances);
+        assert_eq!(status.generated_at, fm.computed_at);
+    }
+
     // ─── TimeSeriesBuffer tests ───────────────────────────────────────────────
 
     #[test]
@@ -664,4 +1362,376 @@ mod tests {
         assert!(ct.get("i-1").is_some());
         assert!(ct.get("i-99").is_none());
     }
+
+    // ─── Cost mover tests ─────────────────────────────────────────────────────
+
+    #[test]
+    fn test_cost_movers_ranked_by_absolute_delta() {
+        let before = FleetMetrics::compute(&[
+            make_snapshot("i-1", "hetzner", 10.0, 10.0, 90.0, 12.0),
+            make_snapshot("i-2", "vultr", 10.0, 10.0, 90.0, 40.0),
+            make_snapshot("i-3", "contabo", 10.0, 10.0, 90.0, 8.0),
+        ]);
+        let after = FleetMetrics::compute(&[
+            make_snapshot("i-1", "hetzner", 10.0, 10.0, 90.0, 12.0),
+            make_snapshot("i-4", "hetzner", 10.0, 10.0, 90.0, 12.0),
+            make_snapshot("i-5", "hetzner", 10.0, 10.0, 90.0, 12.0),
+            make_snapshot("i-2", "vultr", 10.0, 10.0, 90.0, 10.0),
+            make_snapshot("i-3", "contabo", 10.0, 10.0, 90.0, 8.0),
+            make_snapshot("i-6", "digitalocean", 10.0, 10.0, 90.0, 6.0),
+        ]);
+
+        let movers = FleetMetrics::cost_movers(&before, &after);
+        let order: Vec<&str> = movers.iter().map(|m| m.provider.as_str()).collect();
+        // vultr -30, hetzner +24, digitalocean +6; contabo is unchanged.
+        assert_eq!(order, vec!["vultr", "hetzner", "digitalocean"]);
+        assert!((movers[0].delta_usd + 30.0).abs() < 0.001);
+        assert!((movers[1].delta_usd - 24.0).abs() < 0.001);
+        assert_eq!(movers[2].delta_pct, None);
+
+        let top_pct = CostMover::largest_pct(&movers).expect("has pct movers");
+        assert_eq!(top_pct.provider, "hetzner");
+        assert!((top_pct.delta_pct.unwrap() - 200.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_cost_movers_provider_removed() {
+        let before = FleetMetrics::compute(&[make_snapshot("i-1", "vultr", 1.0, 1.0, 90.0, 15.0)]);
+        let after = FleetMetrics::compute(&[]);
+        let movers = FleetMetrics::cost_movers(&before, &after);
+        assert_eq!(movers.len(), 1);
+        assert_eq!(movers[0].after_usd, 0.0);
+        assert_eq!(movers[0].delta_pct, Some(-100.0));
+    }
+
+    #[test]
+    fn test_provider_healthy_pct_resists_one_bad_instance() {
+        let mut snapshots: Vec<InstanceSnapshot> = (0..9)
+            .map(|i| make_snapshot(&format!("i-{i}"), "hetzner", 10.0, 10.0, 90.0, 12.0))
+            .collect();
+        snapshots.push(make_snapshot("i-bad", "hetzner", 10.0, 10.0, 0.0, 12.0));
+        let fm = FleetMetrics::compute(&snapshots);
+        let hetzner = &fm.by_provider["hetzner"];
+        assert!((hetzner.avg_health_score - 81.0).abs() < 0.001);
+        assert!((hetzner.healthy_pct.unwrap() - 90.0).abs() < 0.001);
+
+        // Metrics saved before healthy_pct existed load as unknown, not 0%.
+        let mut json = serde_json::to_value(hetzner).unwrap();
+        json.as_object_mut().unwrap().remove("healthy_pct");
+        let old: ProviderMetrics = serde_json::from_value(json).unwrap();
+        assert_eq!(old.healthy_pct, None);
+    }
+
+    // ─── Bounded ingestion tests ──────────────────────────────────────────────
+
+    #[test]
+    fn test_flooding_instance_is_capped() {
+        let store = MetricStore::new(Duration::from_secs(3600)).with_max_points(100);
+        for i in 0..1000 {
+            push_vps_snapshot(&store, "i-noisy", i as f64, 10.0, 10.0, 90.0);
+        }
+        push_vps_snapshot(&store, "i-calm", 5.0, 10.0, 10.0, 90.0);
+
+        let noisy = MetricName::new("i-noisy.cpu").unwrap();
+        let points = store
+            .query(&noisy, TimeRange::last_minutes(5), None)
+            .unwrap();
+        assert_eq!(points.len(), 100);
+        // The newest samples are the ones kept.
+        assert_eq!(points.last().unwrap().value, 999.0);
+        assert_eq!(points[0].value, 900.0);
+        assert_eq!(store.dropped_points(&noisy), 900);
+
+        let calm = MetricName::new("i-calm.cpu").unwrap();
+        assert_eq!(store.last_value(&calm), Some(5.0));
+        assert_eq!(store.dropped_points(&calm), 0);
+
+        let by_instance = store.dropped_by_instance();
+        assert_eq!(by_instance.get("i-noisy"), Some(&3600));
+        assert!(!by_instance.contains_key("i-calm"));
+    }
+
+    fn push_disk_at(store: &MetricStore, instance_id: &str, minutes_ago: i64, pct: f64) {
+        let name = MetricName::new(&format!("{instance_id}.disk")).unwrap();
+        let mut point = MetricPoint::now(pct);
+        point.timestamp = Utc::now() - chrono::Duration::minutes(minutes_ago);
+        store.push(&name, point).unwrap();
+    }
+
+    #[test]
+    fn test_disk_history_is_time_ordered_across_rollup() {
+        let store = MetricStore::new(Duration::from_secs(86_400)).with_max_points(5);
+        for i in 0..40 {
+            push_disk_at(&store, "i-fill", 40 - i, 50.0 + i as f64);
+        }
+
+        let history = store.disk_history("i-fill");
+        // Evicted samples were rolled up rather than lost.
+        assert!(history.len() > 5 && history.len() < 40, "{}", history.len());
+        assert!(history.windows(2).all(|w| w[0].0 <= w[1].0));
+        // The rising trend survives: each point is at least the one before.
+        assert!(history.windows(2).all(|w| w[0].1 <= w[1].1));
+        assert_eq!(history.last().unwrap().1, 89.0);
+        assert!(store.disk_history("i-unknown").is_empty());
+    }
+
+    #[test]
+    fn test_disk_rollup_keeps_bucket_max() {
+        let store = MetricStore::new(Duration::from_secs(86_400)).with_max_points(1);
+        let name = MetricName::new("i-spiky.disk").unwrap();
+        let bucket_start = Utc::now().timestamp() - 3600;
+        let bucket_start = bucket_start - bucket_start.rem_euclid(DISK_ROLLUP_BUCKET_SECS);
+        for (offset, pct) in [(0, 50.0), (60, 70.0), (120, 60.0)] {
+            let mut point = MetricPoint::now(pct);
+            point.timestamp = DateTime::from_timestamp(bucket_start + offset, 0).unwrap();
+            store.push(&name, point).unwrap();
+        }
+        store.push(&name, MetricPoint::now(65.0)).unwrap();
+
+        let history = store.disk_history("i-spiky");
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].0.timestamp(), bucket_start);
+        assert_eq!(history[0].1, 70.0);
+        assert_eq!(history[1].1, 65.0);
+    }
+
+    #[test]
+    fn test_point_older_than_retention_is_dropped() {
+        let store = MetricStore::new(Duration::from_secs(3600));
+        let name = MetricName::new("i-skewed.cpu").unwrap();
+        let mut point = MetricPoint::now(1.0);
+        point.timestamp = Utc::now() - chrono::Duration::days(365);
+        store.push(&name, point).unwrap();
+
+        assert_eq!(store.last_value(&name), None);
+        assert_eq!(store.dropped_points(&name), 1);
+    }
+
+    #[test]
+    fn test_fleet_metrics_classifies_by_health_score() {
+        let snapshots = vec![
+            make_snapshot("i-1", "hetzner", 10.0, 10.0, 95.0, 12.0),
+            make_snapshot("i-2", "hetzner", 10.0, 10.0, 50.0, 12.0),
+            make_snapshot("i-3", "hetzner", 10.0, 10.0, 10.0, 12.0),
+        ];
+        // All three were reported Active; the score decides how they count.
+        let fm = FleetMetrics::compute(&snapshots);
+        assert_eq!(fm.active_instances, 1);
+        assert_eq!(fm.degraded_instances, 1);
+        assert_eq!(fm.failed_instances, 1);
+
+        let strict = StatusThresholds {
+            degraded_score: 96,
+            failed_score: 60,
+        };
+        let fm = FleetMetrics::compute_with(&snapshots, &strict);
+        assert_eq!(fm.active_instances, 0);
+        assert_eq!(fm.degraded_instances, 1);
+        assert_eq!(fm.failed_instances, 2);
+    }
+
+    // ─── Fleet state export ─────────────────────────────────────────────────
+
+    fn make_exported_member(
+        instance_id: &str,
+        account_id: &str,
+        role: InstanceRole,
+        server_id: &str,
+    ) -> InstanceSnapshot {
+        InstanceSnapshot {
+            tier: Some(InstanceTier::Standard),
+            provider_instance_id: Some(server_id.to_string()),
+            ..make_member(instance_id, account_id, role, InstanceState::Active)
+        }
+    }
+
+    fn make_live(instance_id: &str, server_id: &str, tier: InstanceTier) -> ManagedInstance {
+        ManagedInstance {
+            instance_id: Some(instance_id.to_string()),
+            provider: claw_proto::VpsProvider::Hetzner,
+            provider_instance_id: server_id.to_string(),
+            name: format!("co-{instance_id}"),
+            ip: None,
+            tier: Some(tier),
+            labels: HashMap::new(),
+            status: "running".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_fleet_state_export_round_trips_through_json() {
+        let snapshots = vec![
+            make_exported_member("i-2", "a-1", InstanceRole::Standby, "srv-2"),
+            make_exported_member("i-1", "a-1", InstanceRole::Primary, "srv-1"),
+            make_exported_member("i-3", "a-2", InstanceRole::Primary, "srv-3"),
+        ];
+        let export = FleetState::export(&snapshots);
+        assert_eq!(export.accounts.len(), 2);
+        let pair = &export.accounts[0];
+        assert_eq!(pair.account_id, "a-1");
+        assert_eq!(pair.primary.as_ref().unwrap().instance_id, "i-1");
+        let standby = pair.standby.as_ref().unwrap();
+        assert_eq!(standby.provider_instance_id.as_deref(), Some("srv-2"));
+        assert_eq!(standby.tier, Some(InstanceTier::Standard));
+        assert!(export.accounts[1].standby.is_none());
+
+        let json = serde_json::to_string(&export).unwrap();
+        let restored: FleetStateExport = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, export);
+    }
+
+    #[test]
+    fn test_fleet_state_reconcile_flags_drifted_instance() {
+        let export = FleetState::export(&[
+            make_exported_member("i-1", "a-1", InstanceRole::Primary, "srv-1"),
+            make_exported_member("i-2", "a-1", InstanceRole::Standby, "srv-2"),
+            make_exported_member("i-3", "a-2", InstanceRole::Primary, "srv-3"),
+        ]);
+        let live = vec![
+            make_live("i-1", "srv-1", InstanceTier::Standard),
+            // Resized outside the control plane.
+            make_live("i-2", "srv-2", InstanceTier::Pro),
+            make_live("i-9", "srv-9", InstanceTier::Nano),
+            // Created before servers carried an instance id label.
+            ManagedInstance {
+                instance_id: None,
+                ..make_live("", "srv-0", InstanceTier::Nano)
+            },
+        ];
+
+        let report = FleetState::reconcile_from_providers(&export, &live);
+        assert!(!report.is_clean());
+        assert_eq!(report.matched, vec!["i-1"]);
+        assert_eq!(report.drifted.len(), 1);
+        assert_eq!(report.drifted[0].expected.instance_id, "i-2");
+        assert_eq!(report.drifted[0].live.tier, Some(InstanceTier::Pro));
+        assert_eq!(report.missing.len(), 1);
+        assert_eq!(report.missing[0].instance_id, "i-3");
+        let unknown: Vec<&str> = report
+            .unknown
+            .iter()
+            .map(|m| m.provider_instance_id.as_str())
+            .collect();
+        assert_eq!(unknown, vec!["srv-0", "srv-9"]);
+
+        // The rebuilt state carries the live tier and drops the lost primary.
+        let rebuilt = &report.state.accounts;
+        assert_eq!(
+            rebuilt[0].standby.as_ref().unwrap().tier,
+            Some(InstanceTier::Pro)
+        );
+        assert!(rebuilt[1].primary.is_none());
+    }
+
+    // ─── Provision-to-healthy latency ───────────────────────────────────────
+
+    #[test]
+    fn test_time_to_healthy_waits_for_first_healthy_report() {
+        let mut tracker = ProvisionLatencyTracker::new();
+        let t0 = Utc::now();
+        tracker.record_provision("i-1", "hetzner", t0);
+
+        // Running but still in cloud-init: not healthy yet.
+        assert_eq!(
+            tracker.record_health("i-1", 10, t0 + chrono::Duration::seconds(30)),
+            None
+        );
+        assert_eq!(
+            tracker.record_health("i-1", 60, t0 + chrono::Duration::seconds(150)),
+            Some(150_000)
+        );
+        // Only the first healthy report counts.
+        assert_eq!(
+            tracker.record_health("i-1", 95, t0 + chrono::Duration::seconds(200)),
+            None
+        );
+        assert_eq!(tracker.pending_count(), 0);
+
+        let hetzner = tracker.time_to_healthy("hetzner").unwrap();
+        assert_eq!(hetzner.p50_ms, 150_000);
+        assert_eq!(hetzner.samples, 1);
+        assert!(tracker.time_to_healthy("vultr").is_none());
+    }
+
+    #[test]
+    fn test_time_to_healthy_percentiles_per_provider() {
+        let mut tracker = ProvisionLatencyTracker::new();
+        let t0 = Utc::now();
+        for n in 1..=20 {
+            let id = format!("h-{n}");
+            tracker.record_provision(&id, "hetzner", t0);
+            tracker.record_health(&id, 90, t0 + chrono::Duration::seconds(n * 10));
+        }
+        tracker.record_provision("v-1", "vultr", t0);
+        tracker.record_health("v-1", 90, t0 + chrono::Duration::seconds(40));
+        // A health report for an instance never seen provisioning is ignored.
+        assert_eq!(tracker.record_health("i-unknown", 90, t0), None);
+
+        let by_provider = tracker.by_provider();
+        assert_eq!(
+            by_provider["hetzner"],
+            LatencyPercentiles {
+                p50_ms: 100_000,
+                p95_ms: 190_000,
+                samples: 20
+            }
+        );
+        assert_eq!(by_provider["vultr"].p95_ms, 40_000);
+    }
+
+    #[test]
+    fn test_time_to_healthy_keeps_only_recent_samples() {
+        let mut tracker = ProvisionLatencyTracker::new();
+        let t0 = Utc::now();
+        let total = MAX_TIME_TO_HEALTHY_SAMPLES + 10;
+        for n in 0..total {
+            let id = format!("h-{n}");
+            tracker.record_provision(&id, "hetzner", t0);
+            // The first ten are slow outliers that should age out.
+            let secs = if n < 10 { 3000 } else { 60 };
+            tracker.record_health(&id, 90, t0 + chrono::Duration::seconds(secs));
+        }
+
+        let latency = tracker.time_to_healthy("hetzner").unwrap();
+        assert_eq!(latency.samples, MAX_TIME_TO_HEALTHY_SAMPLES);
+        assert_eq!(latency.p95_ms, 60_000);
+    }
+
+    #[test]
+    fn test_time_to_healthy_drops_instances_past_the_ttl() {
+        let mut tracker =
+            ProvisionLatencyTracker::new().with_pending_ttl(chrono::Duration::minutes(30));
+        let t0 = Utc::now();
+        tracker.record_provision("i-dead", "hetzner", t0);
+        tracker.record_provision("i-slow", "hetzner", t0 + chrono::Duration::minutes(20));
+
+        // 45 minutes in, i-dead has expired; i-slow is still within its window.
+        let later = t0 + chrono::Duration::minutes(45);
+        assert_eq!(tracker.record_health("i-dead", 90, later), None);
+        assert_eq!(tracker.timed_out("hetzner"), 1);
+        assert_eq!(
+            tracker.record_health("i-slow", 90, later),
+            Some(25 * 60_000)
+        );
+        assert_eq!(tracker.pending_count(), 0);
+    }
+
+    #[test]
+    fn test_fleet_metrics_carry_time_to_healthy_per_provider() {
+        let mut tracker = ProvisionLatencyTracker::new();
+        let t0 = Utc::now();
+        tracker.record_provision("i-1", "hetzner", t0);
+        tracker.record_health("i-1", 90, t0 + chrono::Duration::seconds(240));
+
+        let fm = FleetMetrics::compute(&[
+            make_snapshot("i-1", "hetzner", 10.0, 10.0, 90.0, 10.0),
+            make_snapshot("i-2", "vultr", 10.0, 10.0, 90.0, 10.0),
+        ])
+        .with_time_to_healthy(&tracker);
+        assert_eq!(
+            fm.by_provider["hetzner"].time_to_healthy.map(|p| p.p50_ms),
+            Some(240_000)
+        );
+        assert_eq!(fm.by_provider["vultr"].time_to_healthy, None);
+    }
 }
@@ -2,11 +2,16 @@
 //!
 //! Provides [`JsonStore`], a generic key-value store that keeps data in memory
 //! and snapshots to a JSON file on every write.
+//!
+//! Snapshots are written atomically (temp file + rename) and the previous good
+//! snapshot is kept alongside as `{domain}.json.bak`, so a crash mid-write never
+//! leaves the store unloadable.
 
 #![forbid(unsafe_code)]
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 use tracing::{debug, warn};
 
@@ -24,27 +29,77 @@ impl JsonStore {
         Self { path }
     }
 
+    /// Path of the current snapshot file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Path of the last good snapshot kept as a fallback.
+    pub fn backup_path(&self) -> PathBuf {
+        self.path.with_extension("json.bak")
+    }
+
+    fn temp_path(&self) -> PathBuf {
+        self.path.with_extension("json.tmp")
+    }
+
     /// Load data from disk. Returns empty map if file doesn't exist.
+    ///
+    /// Falls back to the last good snapshot if the current file is corrupt.
     pub fn load<T: for<'de> Deserialize<'de>>(&self) -> HashMap<String, T> {
         match std::fs::read_to_string(&self.path) {
+            Ok(content) => match serde_json::from_str(&content) {
+                Ok(data) => data,
+                Err(e) => {
+                    warn!(path = %self.path.display(), error = %e, "corrupt state file, trying last good snapshot");
+                    self.load_backup()
+                }
+            },
+            Err(_) => {
+                debug!(path = %self.path.display(), "no state file, trying last good snapshot");
+                self.load_backup()
+            }
+        }
+    }
+
+    fn load_backup<T: for<'de> Deserialize<'de>>(&self) -> HashMap<String, T> {
+        let backup = self.backup_path();
+        match std::fs::read_to_string(&backup) {
             Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
-                warn!(path = %self.path.display(), error = %e, "corrupt state file, starting fresh");
+                warn!(path = %backup.display(), error = %e, "corrupt backup snapshot, starting fresh");
                 HashMap::new()
             }),
             Err(_) => {
-                debug!(path = %self.path.display(), "no state file, starting fresh");
+                debug!(path = %backup.display(), "no backup snapshot, starting fresh");
                 HashMap::new()
             }
         }
     }
 
-    /// Save data to disk. Creates directories as needed.
+    /// Save data to disk atomically. Creates directories as needed.
+    ///
+    /// Writes to a temp file, fsyncs it, keeps the current snapshot as the
+    /// backup (if it is valid JSON), then renames the temp file into place.
     pub fn save<T: Serialize>(&self, data: &HashMap<String, T>) -> std::io::Result<()> {
         if let Some(parent) = self.path.parent() {
             std::fs::create_dir_all(parent)?;
         }
         let content = serde_json::to_string_pretty(data).map_err(std::io::Error::other)?;
-        std::fs::write(&self.path, content)
+
+        let tmp = self.temp_path();
+        {
+            let mut file = std::fs::File::create(&tmp)?;
+            file.write_all(content.as_bytes())?;
+            file.sync_all()?;
+        }
+
+        if let Ok(current) = std::fs::read_to_string(&self.path)
+            && serde_json::from_str::<serde_json::Value>(&current).is_ok()
+        {
+            std::fs::write(self.backup_path(), current)?;
+        }
+
+        std::fs::rename(&tmp, &self.path)
     }
 }
 
@@ -116,4 +171,78 @@ mod tests {
         let loaded: HashMap<String, String> = store.load();
         assert_eq!(loaded.get("key").unwrap(), "second");
     }
+
+    #[test]
+    fn test_json_store_atomic_save_leaves_no_temp_file() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let store = JsonStore::new(dir.path(), "atomic");
+
+        let mut data = HashMap::new();
+        data.insert("key".to_string(), "value".to_string());
+        store.save(&data).expect("save");
+
+        assert!(store.path().exists());
+        assert!(!store.path().with_extension("json.tmp").exists());
+    }
+
+    #[test]
+    fn test_json_store_partial_write_falls_back_to_last_good() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let store = JsonStore::new(dir.path(), "partial");
+
+        let mut data = HashMap::new();
+        data.insert("key".to_string(), "first".to_string());
+        store.save(&data).expect("save1");
+        data.insert("key".to_string(), "second".to_string());
+        store.save(&data).expect("save2");
+
+        // Simulate a process killed mid-write of the live file.
+        std::fs::write(store.path(), r#"{"key": "thi"#).expect("truncate");
+
+        let loaded: HashMap<String, String> = store.load();
+        assert_eq!(loaded.get("key").unwrap(), "first");
+    }
+
+    #[test]
+    fn test_json_store_interrupted_save_keeps_current_state() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let store = JsonStore::new(dir.path(), "interrupted");
+
+        let mut data = HashMap::new();
+        data.insert("key".to_string(), "committed".to_string());
+        store.save(&data).expect("save");
+
+        // A crash before rename leaves only a partial temp file behind.
+        std::fs::write(store.path().with_extension("json.tmp"), "{\"key\": ").expect("tmp");
+
+        let loaded: HashMap<String, String> = store.load();
+        assert_eq!(loaded.get("key").unwrap(), "committed");
+
+        data.insert("key".to_string(), "next".to_string());
+        store.save(&data).expect("save over stale temp");
+        let loaded: HashMap<String, String> = store.load();
+        assert_eq!(loaded.get("key").unwrap(), "next");
+    }
+
+    #[test]
+    fn test_json_store_corrupt_current_not_backed_up() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let store = JsonStore::new(dir.path(), "keepgood");
+
+        let mut data = HashMap::new();
+        data.insert("key".to_string(), "good".to_string());
+        store.save(&data).expect("save1");
+        data.insert("key".to_string(), "newer".to_string());
+        store.save(&data).expect("save2");
+
+        std::fs::write(store.path(), "garbage").expect("corrupt");
+        data.insert("key".to_string(), "latest".to_string());
+        store.save(&data).expect("save3");
+
+        // Backup still holds a valid snapshot rather than the garbage.
+        let backup: HashMap<String, String> =
+            serde_json::from_str(&std::fs::read_to_string(store.backup_path()).unwrap())
+                .expect("backup is valid json");
+        assert_eq!(backup.get("key").unwrap(), "good");
+    }
 }
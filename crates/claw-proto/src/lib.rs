@@ -53,6 +53,25 @@ impl std::fmt::Display for InstanceTier {
     }
 }
 
+impl InstanceTier {
+    /// Hetzner server type each tier is provisioned and priced as.
+    pub fn hetzner_server_type(self) -> &'static str {
+        match self {
+            Self::Nano => "cx11",
+            Self::Standard => "cx21",
+            Self::Pro => "cx31",
+            Self::Enterprise => "cx41",
+        }
+    }
+
+    /// Tier provisioned as the Hetzner `server_type`, if any.
+    pub fn from_hetzner_server_type(server_type: &str) -> Option<Self> {
+        [Self::Nano, Self::Standard, Self::Pro, Self::Enterprise]
+            .into_iter()
+            .find(|tier| tier.hetzner_server_type() == server_type)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum InstanceRole {
@@ -69,6 +88,26 @@ impl std::fmt::Display for InstanceRole {
     }
 }
 
+/// Operating system image an instance is provisioned with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OsImage {
+    #[default]
+    Ubuntu2204,
+    Ubuntu2404,
+    Debian12,
+}
+
+impl std::fmt::Display for OsImage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ubuntu2204 => write!(f, "ubuntu-22.04"),
+            Self::Ubuntu2404 => write!(f, "ubuntu-24.04"),
+            Self::Debian12 => write!(f, "debian-12"),
+        }
+    }
+}
+
 // ─── Instance State ───────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -83,6 +122,52 @@ pub enum InstanceState {
     Maintenance,
 }
 
+/// Health score below which an instance counts as degraded.
+pub const DEGRADED_HEALTH_SCORE: u8 = 70;
+/// Health score below which an instance counts as failed (and Health escalates).
+pub const FAILED_HEALTH_SCORE: u8 = 20;
+
+/// Score bands used by `classify_instance_status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusThresholds {
+    pub degraded_score: u8,
+    pub failed_score: u8,
+}
+
+impl Default for StatusThresholds {
+    fn default() -> Self {
+        Self {
+            degraded_score: DEGRADED_HEALTH_SCORE,
+            failed_score: FAILED_HEALTH_SCORE,
+        }
+    }
+}
+
+/// The state an instance should be counted under, given its health score.
+///
+/// Bootstrapping and maintenance instances keep their state, and a score
+/// never upgrades a state the caller already reported as degraded or failed.
+pub fn classify_instance_status(
+    health_score: u8,
+    state: InstanceState,
+    thresholds: &StatusThresholds,
+) -> InstanceState {
+    let by_score = if health_score < thresholds.failed_score {
+        InstanceState::Failed
+    } else if health_score < thresholds.degraded_score {
+        InstanceState::Degraded
+    } else {
+        InstanceState::Active
+    };
+    match (state, by_score) {
+        (InstanceState::Bootstrapping | InstanceState::Maintenance | InstanceState::Failed, _) => {
+            state
+        }
+        (InstanceState::Degraded, InstanceState::Active) => InstanceState::Degraded,
+        _ => by_score,
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ServiceStatus {
@@ -115,6 +200,9 @@ pub struct InstanceMetadata {
 /// Health report sent periodically from clawnode to gateway.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthReport {
+    /// Schema version the sending node speaks; see `HEALTH_REPORT_VERSION`.
+    #[serde(default = "legacy_report_version")]
+    pub report_version: u32,
     pub instance_id: String,
     pub account_id: String,
     pub provider: VpsProvider,
@@ -135,9 +223,14 @@ pub struct HealthReport {
     pub cpu_usage_1m: f32,
     pub mem_usage_pct: f32,
     pub disk_usage_pct: f32,
+    // Swap and load were added in v2.
+    #[serde(default)]
     pub swap_usage_pct: f32,
+    #[serde(default)]
     pub load_avg_1m: f32,
+    #[serde(default)]
     pub load_avg_5m: f32,
+    #[serde(default)]
     pub load_avg_15m: f32,
     pub uptime_secs: u64,
 
@@ -145,9 +238,54 @@ pub struct HealthReport {
     pub bytes_sent_per_sec: f64,
     pub bytes_recv_per_sec: f64,
 
+    /// How often this node sends heartbeats; `None` for nodes that predate it.
+    #[serde(default)]
+    pub heartbeat_interval_secs: Option<u64>,
+    /// Services the node must run, as its `critical_services` label lists
+    /// them ("openclaw,docker"); `None` when unset, meaning all of them.
+    #[serde(default)]
+    pub critical_services: Option<String>,
     pub reported_at: DateTime<Utc>,
 }
 
+/// Current `HealthReport` schema version, sent by up-to-date nodes.
+pub const HEALTH_REPORT_VERSION: u32 = 2;
+
+/// Reports without a `report_version` come from nodes that predate versioning.
+fn legacy_report_version() -> u32 {
+    1
+}
+
+/// Fields added after v1; older reports get their serde defaults.
+const HEALTH_REPORT_V2_FIELDS: &[&str] = &[
+    "swap_usage_pct",
+    "load_avg_1m",
+    "load_avg_5m",
+    "load_avg_15m",
+    "heartbeat_interval_secs",
+    "critical_services",
+];
+
+/// A `HealthReport` accepted at the proto boundary, with what had to be filled in.
+#[derive(Debug, Clone)]
+pub struct UpgradedHealthReport {
+    pub report: HealthReport,
+    /// Version the node actually sent.
+    pub source_version: u32,
+    /// Fields the node omitted and that were given their default.
+    pub defaulted_fields: Vec<&'static str>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReportSchemaError {
+    #[error("health report is not a JSON object")]
+    NotAnObject,
+    #[error("health score {0} is out of range 0-100")]
+    HealthScoreOutOfRange(u8),
+    #[error("invalid health report: {0}")]
+    Invalid(#[from] serde_json::Error),
+}
+
 /// CPU/memory/disk metrics from a VPS instance.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetricsReport {
@@ -163,8 +301,11 @@ pub struct MetricsReport {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CpuMetrics {
     pub usage_pct: f32,
+    #[serde(default)]
     pub load_1m: f32,
+    #[serde(default)]
     pub load_5m: f32,
+    #[serde(default)]
     pub load_15m: f32,
     pub core_count: u32,
 }
@@ -174,7 +315,9 @@ pub struct MemoryMetrics {
     pub total_mb: u64,
     pub used_mb: u64,
     pub available_mb: u64,
+    #[serde(default)]
     pub swap_total_mb: u64,
+    #[serde(default)]
     pub swap_used_mb: u64,
 }
 
@@ -195,6 +338,16 @@ pub struct NetworkMetrics {
     pub tailscale_latency_ms: Option<f32>,
 }
 
+impl NetworkMetrics {
+    /// Outbound traffic for a 30-day month at the current send rate, in TB.
+    ///
+    /// Providers bill overage on egress, so inbound bytes are not counted.
+    pub fn projected_monthly_egress_tb(&self) -> f64 {
+        const SECS_PER_MONTH: f64 = 30.0 * 86_400.0;
+        self.bytes_sent_per_sec.max(0.0) * SECS_PER_MONTH / 1e12
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenClawMetrics {
     pub http_status: Option<u16>,
@@ -203,6 +356,17 @@ pub struct OpenClawMetrics {
     pub uptime_secs: Option<u64>,
 }
 
+/// Nearest-rank percentile (`pct` in 0–100) of `samples`; `None` if empty.
+pub fn percentile<T: Copy + PartialOrd>(samples: &[T], pct: f64) -> Option<T> {
+    if samples.is_empty() {
+        return None;
+    }
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    Some(sorted[rank.clamp(1, sorted.len()) - 1])
+}
+
 // ─── Heartbeat ────────────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -261,6 +425,9 @@ pub struct ProvisionRequest {
     pub role: InstanceRole,
     pub provider: VpsProvider,
     pub region: String,
+    /// Defaults to Ubuntu 22.04 for requests serialized before this field existed.
+    #[serde(default)]
+    pub os_image: OsImage,
     pub pair_instance_id: Option<String>,
     pub openclaw_config: Option<serde_json::Value>,
     pub requested_by: String,
@@ -279,6 +446,26 @@ pub struct ProvisionResult {
     pub provider_instance_id: Option<String>,
 }
 
+/// Label carrying our instance id, written on every server at create time.
+pub const INSTANCE_ID_LABEL: &str = "instance_id";
+
+/// A ClawOps-managed instance as reported by its provider.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManagedInstance {
+    /// From the `INSTANCE_ID_LABEL` label; `None` for servers created
+    /// before the label was written.
+    pub instance_id: Option<String>,
+    pub provider: VpsProvider,
+    pub provider_instance_id: String,
+    pub name: String,
+    pub ip: Option<String>,
+    /// From the instance's `tier` label, if present and recognised.
+    pub tier: Option<InstanceTier>,
+    pub labels: std::collections::HashMap<String, String>,
+    /// Provider-reported status, e.g. `running`.
+    pub status: String,
+}
+
 // ─── Fleet Status ─────────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -293,6 +480,20 @@ pub struct FleetStatus {
 
 // ─── Command Protocol ─────────────────────────────────────────────────────────
 
+/// Namespaces of the commands a clawnode accepts from the gateway.
+pub const NODE_COMMAND_PREFIXES: &[&str] = &[
+    "system.",
+    "vps.",
+    "openclaw.",
+    "docker.",
+    "health.",
+    "node.",
+    "config.",
+    "secret.",
+    "auth.",
+    "audit.",
+];
+
 /// A command request from the gateway to a clawnode instance.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommandRequest {
@@ -418,6 +619,17 @@ impl InstancePairStatus {
     }
 }
 
+/// Why a pair can be taken out by a single failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpofKind {
+    NoStandby,
+    /// Primary and standby share a provider region.
+    SameRegion,
+    /// Different regions, but one provider outage takes both.
+    SameProvider,
+}
+
 // ─── Teardown Types ───────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -471,6 +683,62 @@ pub struct ConfigPushFailure {
     pub error: String,
 }
 
+// ─── Error taxonomy ───────────────────────────────────────────────────────────
+
+/// Failure categories shared by every agent crate, so the Commander can
+/// decide whether to retry, escalate or give up without knowing which
+/// specialist failed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+#[serde(tag = "category", content = "message", rename_all = "snake_case")]
+pub enum ClawError {
+    /// Likely to succeed if tried again (timeouts, rate limits, 5xx).
+    #[error("transient: {0}")]
+    Transient(String),
+    /// Will fail the same way every time.
+    #[error("permanent: {0}")]
+    Permanent(String),
+    /// Refused by a safety rule; needs an operator, never a retry.
+    #[error("safety: {0}")]
+    Safety(String),
+    #[error("not found: {0}")]
+    NotFound(String),
+    #[error("unauthorized: {0}")]
+    Unauthorized(String),
+}
+
+impl ClawError {
+    /// Only transient failures are worth retrying.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, ClawError::Transient(_))
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            ClawError::Transient(m)
+            | ClawError::Permanent(m)
+            | ClawError::Safety(m)
+            | ClawError::NotFound(m)
+            | ClawError::Unauthorized(m) => m,
+        }
+    }
+}
+
+/// For crates that report errors as plain strings (Ledger sinks).
+///
+/// A bare message carries no category, so it is always permanent; callers
+/// that know better construct the variant directly.
+impl From<String> for ClawError {
+    fn from(message: String) -> Self {
+        ClawError::Permanent(message)
+    }
+}
+
+impl From<ReportSchemaError> for ClawError {
+    fn from(err: ReportSchemaError) -> Self {
+        ClawError::Permanent(err.to_string())
+    }
+}
+
 // ─── Validation ───────────────────────────────────────────────────────────────
 
 /// Validate an instance ID format.
@@ -487,6 +755,40 @@ pub fn validate_account_id(id: &str) -> bool {
     !id.is_empty() && id.len() <= 64
 }
 
+/// Parse a health report from any node version, upgrading it to the current schema.
+///
+/// Fields added since the sender's version get their defaults and are listed
+/// in `defaulted_fields`; anything else missing or malformed is still an error.
+pub fn validate_and_upgrade(
+    mut raw: serde_json::Value,
+) -> Result<UpgradedHealthReport, ReportSchemaError> {
+    let fields = raw.as_object_mut().ok_or(ReportSchemaError::NotAnObject)?;
+    let source_version = fields
+        .get("report_version")
+        .and_then(|v| v.as_u64())
+        .map_or(legacy_report_version(), |v| v as u32);
+
+    let defaulted_fields: Vec<&'static str> = HEALTH_REPORT_V2_FIELDS
+        .iter()
+        .copied()
+        .filter(|field| !fields.contains_key(*field))
+        .collect();
+
+    let mut report: HealthReport = serde_json::from_value(raw)?;
+    if report.health_score > 100 {
+        return Err(ReportSchemaError::HealthScoreOutOfRange(
+            report.health_score,
+        ));
+    }
+    report.report_version = report.report_version.max(HEALTH_REPORT_VERSION);
+
+    Ok(UpgradedHealthReport {
+        report,
+        source_version,
+        defaulted_fields,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -540,6 +842,7 @@ mod tests {
     #[test]
     fn test_health_report_serialization() {
         let report = HealthReport {
+            report_version: HEALTH_REPORT_VERSION,
             instance_id: "i-test".to_string(),
             account_id: "acc-1".to_string(),
             provider: VpsProvider::Hetzner,
@@ -563,6 +866,8 @@ mod tests {
             uptime_secs: 86400,
             bytes_sent_per_sec: 1024.0,
             bytes_recv_per_sec: 2048.0,
+            heartbeat_interval_secs: Some(30),
+            critical_services: None,
             reported_at: Utc::now(),
         };
 
@@ -571,4 +876,143 @@ mod tests {
         assert_eq!(back.instance_id, "i-test");
         assert_eq!(back.health_score, 95);
     }
+
+    fn v1_report_json() -> serde_json::Value {
+        serde_json::json!({
+            "instance_id": "i-old",
+            "account_id": "acc-1",
+            "provider": "hetzner",
+            "region": "eu-hetzner-nbg1",
+            "tier": "standard",
+            "role": "primary",
+            "state": "ACTIVE",
+            "health_score": 88,
+            "openclaw_status": "healthy",
+            "openclaw_http_status": 200,
+            "docker_running": true,
+            "tailscale_connected": true,
+            "tailscale_latency_ms": null,
+            "cpu_usage_1m": 12.0,
+            "mem_usage_pct": 40.0,
+            "disk_usage_pct": 55.0,
+            "uptime_secs": 3600,
+            "bytes_sent_per_sec": 10.0,
+            "bytes_recv_per_sec": 20.0,
+            "reported_at": "2026-03-01T07:00:00Z"
+        })
+    }
+
+    #[test]
+    fn test_v1_health_report_upgrades_with_defaults() {
+        let upgraded = validate_and_upgrade(v1_report_json()).expect("v1 report accepted");
+        assert_eq!(upgraded.source_version, 1);
+        assert_eq!(upgraded.report.report_version, HEALTH_REPORT_VERSION);
+        assert_eq!(upgraded.report.health_score, 88);
+        assert_eq!(upgraded.report.swap_usage_pct, 0.0);
+        assert_eq!(upgraded.report.load_avg_15m, 0.0);
+        assert_eq!(
+            upgraded.defaulted_fields,
+            vec![
+                "swap_usage_pct",
+                "load_avg_1m",
+                "load_avg_5m",
+                "load_avg_15m",
+                "heartbeat_interval_secs",
+                "critical_services",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_and_upgrade_rejects_bad_reports() {
+        let mut missing = v1_report_json();
+        missing.as_object_mut().unwrap().remove("instance_id");
+        assert!(matches!(
+            validate_and_upgrade(missing),
+            Err(ReportSchemaError::Invalid(_))
+        ));
+
+        let mut out_of_range = v1_report_json();
+        out_of_range["health_score"] = 140.into();
+        assert!(matches!(
+            validate_and_upgrade(out_of_range),
+            Err(ReportSchemaError::HealthScoreOutOfRange(140))
+        ));
+
+        assert!(matches!(
+            validate_and_upgrade(serde_json::json!([1, 2])),
+            Err(ReportSchemaError::NotAnObject)
+        ));
+    }
+
+    #[test]
+    fn test_classify_instance_status_bands() {
+        let t = StatusThresholds::default();
+        assert_eq!(
+            classify_instance_status(95, InstanceState::Active, &t),
+            InstanceState::Active
+        );
+        assert_eq!(
+            classify_instance_status(50, InstanceState::Active, &t),
+            InstanceState::Degraded
+        );
+        assert_eq!(
+            classify_instance_status(10, InstanceState::Unknown, &t),
+            InstanceState::Failed
+        );
+        // Reported states are never upgraded, and lifecycle states are kept.
+        assert_eq!(
+            classify_instance_status(95, InstanceState::Degraded, &t),
+            InstanceState::Degraded
+        );
+        assert_eq!(
+            classify_instance_status(95, InstanceState::Failed, &t),
+            InstanceState::Failed
+        );
+        assert_eq!(
+            classify_instance_status(0, InstanceState::Bootstrapping, &t),
+            InstanceState::Bootstrapping
+        );
+    }
+
+    #[test]
+    fn test_hetzner_server_type_round_trips() {
+        assert_eq!(InstanceTier::Nano.hetzner_server_type(), "cx11");
+        assert_eq!(InstanceTier::Enterprise.hetzner_server_type(), "cx41");
+        for tier in [
+            InstanceTier::Nano,
+            InstanceTier::Standard,
+            InstanceTier::Pro,
+            InstanceTier::Enterprise,
+        ] {
+            assert_eq!(
+                InstanceTier::from_hetzner_server_type(tier.hetzner_server_type()),
+                Some(tier)
+            );
+        }
+        assert_eq!(InstanceTier::from_hetzner_server_type("cpx51"), None);
+    }
+
+    #[test]
+    fn test_percentile_nearest_rank() {
+        let samples = [40u64, 10, 30, 20, 50];
+        assert_eq!(percentile(&samples, 50.0), Some(30));
+        assert_eq!(percentile(&samples, 95.0), Some(50));
+        assert_eq!(percentile(&samples, 0.0), Some(10));
+        assert_eq!(percentile(&[2.5, 0.5, 1.5], 50.0), Some(1.5));
+        assert_eq!(percentile::<u64>(&[], 50.0), None);
+    }
+
+    #[test]
+    fn test_claw_error_from_string_and_schema_error() {
+        let other = ClawError::from("webhook returned 400".to_string());
+        assert!(matches!(other, ClawError::Permanent(_)));
+        assert_eq!(other.message(), "webhook returned 400");
+        assert!(!other.is_retryable());
+
+        let schema = ClawError::from(ReportSchemaError::HealthScoreOutOfRange(120));
+        assert!(matches!(schema, ClawError::Permanent(_)));
+        assert!(ClawError::Transient("timeout".to_string()).is_retryable());
+        assert!(!ClawError::Safety("blocked".to_string()).is_retryable());
+    }
 }
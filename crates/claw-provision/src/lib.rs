@@ -9,10 +9,16 @@
 use anyhow::{Context, Result, bail};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use claw_proto::{InstanceRole, InstanceTier, ProvisionRequest, ProvisionResult, VpsProvider};
+use claw_auth::{AuditLogEntry, AuditLogStore};
+use claw_proto::{
+    ClawError, INSTANCE_ID_LABEL, InstanceRole, InstanceTier, ManagedInstance, OsImage,
+    ProvisionRequest, ProvisionResult, VpsProvider,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use tracing::{info, warn};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::{debug, info, warn};
 use uuid::Uuid;
 
 // ─── Retry Policy ─────────────────────────────────────────────────────────────
@@ -32,7 +38,7 @@ pub struct RetryPolicy {
     pub base_delay_ms: u64,
     /// Maximum delay in milliseconds (caps exponential growth).
     pub max_delay_ms: u64,
-    /// Whether to apply ±25% random jitter to avoid thundering-herd.
+    /// Whether to apply ±20% random jitter to avoid thundering-herd.
     pub jitter: bool,
 }
 
@@ -50,22 +56,32 @@ impl Default for RetryPolicy {
 impl RetryPolicy {
     /// Calculate the delay for attempt `n` (0-indexed, 0 = first retry).
     ///
-    /// Uses exponential backoff: `min(base * 2^n, max)` with optional jitter.
+    /// Uses exponential backoff: `base * 2^n` with optional jitter, capped at `max`.
+    /// The jitter offset is derived from `n`, so the sequence is fixed; use
+    /// [`delay_for_attempt_with`](Self::delay_for_attempt_with) to spread
+    /// concurrent callers.
     pub fn delay_for_attempt(&self, n: u32) -> std::time::Duration {
+        self.backoff(n, u64::from(n) * 7 + 13)
+    }
+
+    /// Like `delay_for_attempt`, but draws the ±20% jitter from `rng` so a
+    /// pinned seed reproduces the exact delay sequence.
+    pub fn delay_for_attempt_with(&self, n: u32, rng: &dyn RandomSource) -> std::time::Duration {
+        self.backoff(n, rng.next_u64())
+    }
+
+    /// `base * 2^n`, shifted within ±20% by `draw` when jitter is on, then
+    /// capped at `max` so jitter never pushes a delay past it.
+    fn backoff(&self, n: u32, draw: u64) -> std::time::Duration {
         let exp = self.base_delay_ms.saturating_mul(1u64 << n.min(10));
-        let capped = exp.min(self.max_delay_ms);
         let ms = if self.jitter {
-            // ±25% jitter using a deterministic but varied offset
-            let jitter_range = capped / 4;
-            // Use attempt number as a simple jitter seed
-            let jitter_offset = (n as u64 * 7 + 13) % (jitter_range.max(1) * 2);
-            capped
-                .saturating_sub(jitter_range)
-                .saturating_add(jitter_offset)
+            let jitter_range = exp / 5;
+            exp.saturating_sub(jitter_range)
+                .saturating_add(draw % jitter_range.saturating_mul(2).saturating_add(1))
         } else {
-            capped
+            exp
         };
-        std::time::Duration::from_millis(ms)
+        std::time::Duration::from_millis(ms.min(self.max_delay_ms))
     }
 
     /// Returns `true` if attempt `n` (0-indexed) is within the retry budget.
@@ -74,6 +90,103 @@ impl RetryPolicy {
     }
 }
 
+// ─── Randomness ───────────────────────────────────────────────────────────────
+
+/// Source of randomness for retry jitter and provider spread.
+///
+/// Injected so tests and incident replays can pin a seed; production uses
+/// `SeededRng::from_entropy()`.
+pub trait RandomSource: Send + Sync + std::fmt::Debug {
+    fn next_u64(&self) -> u64;
+}
+
+/// SplitMix64 generator: small, fast and fully determined by its seed.
+#[derive(Debug)]
+pub struct SeededRng {
+    state: AtomicU64,
+}
+
+impl SeededRng {
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            state: AtomicU64::new(seed),
+        }
+    }
+
+    /// Seed from the clock and the process's hash randomization.
+    pub fn from_entropy() -> Self {
+        use std::hash::{BuildHasher, Hasher};
+        let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+        hasher.write_u128(
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos(),
+        );
+        Self::from_seed(hasher.finish())
+    }
+}
+
+impl RandomSource for SeededRng {
+    fn next_u64(&self) -> u64 {
+        let mut z = self
+            .state
+            .fetch_add(0x9E37_79B9_7F4A_7C15, Ordering::Relaxed)
+            .wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+// ─── Provision gate ───────────────────────────────────────────────────────────
+
+/// Admission check `FleetProvisioner` runs before every provision, e.g. the
+/// Commander's per-actor rate limit and fleet size cap.
+pub trait ProvisionGate: Send + Sync {
+    /// Admit `instances` new instances for `requested_by`, or refuse with an error.
+    fn admit(&self, requested_by: &str, instances: u32) -> Result<()>;
+}
+
+/// Told about every instance `FleetProvisioner` brings up, e.g. to start
+/// the provision-to-healthy clock. Not called in dry-run mode.
+pub trait ProvisionObserver: Send + Sync {
+    fn provisioned(&self, provider: &str, result: &ProvisionResult);
+}
+
+/// Receives an entry for every resize and teardown `FleetProvisioner` sends
+/// to a provider, so direct calls are on record as well as ones made
+/// through the Commander. Not called in dry-run mode.
+pub trait OperationAuditSink: Send + Sync {
+    fn record(&self, entry: AuditLogEntry);
+}
+
+impl OperationAuditSink for parking_lot::Mutex<AuditLogStore> {
+    fn record(&self, entry: AuditLogEntry) {
+        self.lock().append(entry);
+    }
+}
+
+// ─── Sleep hook ───────────────────────────────────────────────────────────────
+
+/// Waits between retries. Injected so tests can record backoff delays
+/// instead of sleeping through them.
+#[async_trait]
+pub trait Sleeper: Send + Sync + std::fmt::Debug {
+    async fn sleep(&self, duration: std::time::Duration);
+}
+
+/// Production sleeper backed by `tokio::time::sleep`.
+#[derive(Debug, Default)]
+pub struct TokioSleeper;
+
+#[async_trait]
+impl Sleeper for TokioSleeper {
+    async fn sleep(&self, duration: std::time::Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
+
 // ─── Provider trait ───────────────────────────────────────────────────────────
 
 /// All provider implementations must implement this trait.
@@ -90,10 +203,64 @@ pub trait Provider: Send + Sync + std::fmt::Debug {
     async fn provider_health(&self) -> Result<ProviderHealth>;
     fn supported_regions(&self) -> Vec<Region>;
     fn supports_live_resize(&self) -> bool;
+
+    /// OS images this provider can boot. Defaults to the historical Ubuntu 22.04.
+    fn supported_images(&self) -> Vec<OsImage> {
+        vec![OsImage::Ubuntu2204]
+    }
+
+    /// Monthly price of `tier` on this provider. Defaults to the list price
+    /// in `TierSpec`.
+    fn monthly_cost(&self, tier: &InstanceTier) -> f32 {
+        TierSpec::monthly_cost(tier)
+    }
+
+    /// Optional features this provider supports. Conservative by default:
+    /// only `live_resize` is reported, from `supports_live_resize`.
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            live_resize: self.supports_live_resize(),
+            ..ProviderCapabilities::default()
+        }
+    }
+
+    /// Every ClawOps-managed instance on this provider, for reconciliation.
+    /// Providers without a listing API report none.
+    async fn list_managed_instances(&self) -> Result<Vec<ManagedInstance>> {
+        Ok(Vec::new())
+    }
+
+    /// Power the instance off but keep its disk (archive). Unsupported by default.
+    async fn hibernate(&self, provider_instance_id: &str) -> Result<()> {
+        bail!(
+            "{} does not support hibernating instance {provider_instance_id}",
+            self.name()
+        )
+    }
+
+    /// Snapshot the instance's disk and return the snapshot id. Only called
+    /// when `capabilities().snapshot` is set.
+    async fn snapshot(&self, provider_instance_id: &str) -> Result<String> {
+        bail!(
+            "{} does not support snapshotting instance {provider_instance_id}",
+            self.name()
+        )
+    }
 }
 
 // ─── Core types ───────────────────────────────────────────────────────────────
 
+/// Optional provider features, checked before an operation is attempted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProviderCapabilities {
+    pub live_resize: bool,
+    pub snapshot: bool,
+    pub firewall: bool,
+    pub floating_ip: bool,
+    pub hibernate: bool,
+    pub ipv6: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Region {
     pub id: String,
@@ -106,7 +273,7 @@ pub struct Region {
     pub latency_class: LatencyClass,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Continent {
     EU,
@@ -117,11 +284,23 @@ pub enum Continent {
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum LatencyClass {
+    /// RTT under 10ms.
     Low,
+    /// RTT 10–50ms.
     Medium,
+    /// RTT over 50ms.
     High,
 }
 
+/// Bucket a measured round-trip time into a `LatencyClass`.
+pub fn classify_latency(rtt: std::time::Duration) -> LatencyClass {
+    match rtt.as_millis() {
+        0..10 => LatencyClass::Low,
+        10..=50 => LatencyClass::Medium,
+        _ => LatencyClass::High,
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TierSpec {
     pub tier: InstanceTier,
@@ -194,6 +373,25 @@ impl TierSpec {
     }
 }
 
+/// Monthly cost of `tier` including overage for traffic beyond its included bandwidth.
+pub fn estimate_monthly_cost(
+    tier: &TierSpec,
+    projected_bandwidth_tb: f32,
+    overage_per_tb_usd: f32,
+) -> f64 {
+    let overage_tb = (projected_bandwidth_tb - tier.bandwidth_tb).max(0.0);
+    f64::from(tier.monthly_cost_usd) + f64::from(overage_tb) * f64::from(overage_per_tb_usd)
+}
+
+/// Health points a fallback provider gives up per latency class step
+/// (Low → Medium → High) when ranking selection candidates.
+pub const DEFAULT_LATENCY_WEIGHT: u8 = 10;
+
+/// Expected downtime of a resize on a provider that supports live resize.
+pub const LIVE_RESIZE_DOWNTIME_SECS: u32 = 0;
+/// Expected downtime of a power-off / change-type / power-on resize.
+pub const OFFLINE_RESIZE_DOWNTIME_SECS: u32 = 20;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResizeResult {
     pub instance_id: String,
@@ -216,30 +414,217 @@ pub struct ProviderHealth {
     pub checked_at: DateTime<Utc>,
 }
 
+/// Per-provider outcome of a fleet-wide health poll.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ProviderHealthStatus {
+    Reachable(ProviderHealth),
+    /// The health call errored or did not answer within the registry's timeout.
+    Unreachable {
+        provider: String,
+        error: String,
+    },
+}
+
+impl ProviderHealthStatus {
+    pub fn health(&self) -> Option<&ProviderHealth> {
+        match self {
+            Self::Reachable(h) => Some(h),
+            Self::Unreachable { .. } => None,
+        }
+    }
+}
+
+/// How long `all_health` waits for any single provider before marking it unreachable.
+pub const DEFAULT_HEALTH_TIMEOUT_SECS: u64 = 10;
+
+/// Quota usage (percent) above which new provisions to a provider are paused.
+pub const PAUSE_QUOTA_PCT: f32 = 95.0;
+
+/// Quota usage (percent) above which a provider stops being preferred.
+pub const QUOTA_PRESSURE_PCT: f32 = 90.0;
+
+/// Fold quota usage into a health score: a provider past
+/// [`QUOTA_PRESSURE_PCT`] is capped at the fallback threshold, so selection
+/// moves new work elsewhere before the account hits its cap.
+pub fn quota_adjusted_health(health_score: u8, quota_used_pct: f32) -> u8 {
+    if quota_used_pct > QUOTA_PRESSURE_PCT {
+        health_score.min(FALLBACK_MIN_HEALTH)
+    } else {
+        health_score
+    }
+}
+
+// ─── Region blocklist ─────────────────────────────────────────────────────────
+
+/// Providers, regions, and countries we must never place instances in
+/// (compliance / sanctions). Each entry carries the reason it was blocked.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RegionBlocklist {
+    providers: HashMap<VpsProvider, String>,
+    regions: HashMap<String, String>,
+    /// Keyed by upper-case ISO country code.
+    countries: HashMap<String, String>,
+}
+
+impl RegionBlocklist {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn block_provider(mut self, provider: VpsProvider, reason: &str) -> Self {
+        self.providers.insert(provider, reason.to_string());
+        self
+    }
+
+    pub fn block_region(mut self, region_id: &str, reason: &str) -> Self {
+        self.regions
+            .insert(region_id.to_string(), reason.to_string());
+        self
+    }
+
+    pub fn block_country(mut self, country: &str, reason: &str) -> Self {
+        self.countries
+            .insert(country.to_uppercase(), reason.to_string());
+        self
+    }
+
+    /// Why `region` is blocked, or `None` if it may be used.
+    pub fn blocked_reason(&self, region: &Region) -> Option<&str> {
+        self.providers
+            .get(&region.provider)
+            .or_else(|| self.regions.get(&region.id))
+            .or_else(|| self.countries.get(&region.country.to_uppercase()))
+            .map(String::as_str)
+    }
+
+    pub fn is_blocked(&self, region: &Region) -> bool {
+        self.blocked_reason(region).is_some()
+    }
+}
+
 // ─── Provider registry ────────────────────────────────────────────────────────
 
+/// Minimum health score for the preferred provider to be used as-is.
+const PREFERRED_MIN_HEALTH: u8 = 75;
+/// Minimum health score for a provider to be considered as a fallback.
+pub const FALLBACK_MIN_HEALTH: u8 = 65;
+
+/// One provider considered by `select_provider_explained`, and why it was
+/// accepted or skipped.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CandidateDecision {
+    pub provider: String,
+    pub selected: bool,
+    pub reason: String,
+}
+
+impl CandidateDecision {
+    fn skipped(provider: &str, reason: impl Into<String>) -> Self {
+        Self {
+            provider: provider.to_string(),
+            selected: false,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for CandidateDecision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let verdict = if self.selected { "selected" } else { "skipped" };
+        write!(f, "{}: {verdict} — {}", self.provider, self.reason)
+    }
+}
+
+/// Result of provider selection with the trace of every candidate considered,
+/// in the order they were evaluated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelectionOutcome {
+    /// Chosen provider name and region, if any candidate qualified.
+    pub selected: Option<(String, Region)>,
+    pub trace: Vec<CandidateDecision>,
+}
+
 pub struct ProviderRegistry {
     providers: HashMap<String, Box<dyn Provider>>,
+    /// Latency classes measured by the prober, keyed by region id.
+    measured_latency: HashMap<String, LatencyClass>,
+    blocklist: RegionBlocklist,
+    /// Breaks ties when spreading across equally healthy fallback providers.
+    rng: Arc<dyn RandomSource>,
+    health_timeout: std::time::Duration,
+    /// Providers paused by their own health reports; cleared when health recovers.
+    provisioning_paused: parking_lot::RwLock<HashSet<VpsProvider>>,
+    /// Providers paused by an operator; only `resume` clears these.
+    manually_paused: HashSet<VpsProvider>,
+    /// Health points each latency class step costs a fallback candidate.
+    latency_weight: u8,
+    /// provider name → continent → available regions, lowest latency first.
+    /// Built lazily; cleared whenever region data may have changed.
+    region_index: parking_lot::RwLock<HashMap<String, HashMap<Continent, Vec<Region>>>>,
 }
 
 impl ProviderRegistry {
     pub fn new() -> Self {
         Self {
             providers: HashMap::new(),
+            measured_latency: HashMap::new(),
+            blocklist: RegionBlocklist::default(),
+            rng: Arc::new(SeededRng::from_entropy()),
+            health_timeout: std::time::Duration::from_secs(DEFAULT_HEALTH_TIMEOUT_SECS),
+            provisioning_paused: parking_lot::RwLock::new(HashSet::new()),
+            manually_paused: HashSet::new(),
+            latency_weight: DEFAULT_LATENCY_WEIGHT,
+            region_index: parking_lot::RwLock::new(HashMap::new()),
         }
     }
 
+    /// How many health points a region one latency class slower must be
+    /// worth before it wins; 0 ranks fallback providers on health alone.
+    pub fn set_latency_weight(&mut self, weight: u8) {
+        self.latency_weight = weight;
+    }
+
+    /// Per-provider deadline applied by `all_health`.
+    pub fn set_health_timeout(&mut self, timeout: std::time::Duration) {
+        self.health_timeout = timeout;
+    }
+
+    /// Replace the randomness used for provider spread (e.g. a fixed seed in tests).
+    pub fn set_rng(&mut self, rng: Arc<dyn RandomSource>) {
+        self.rng = rng;
+    }
+
+    /// Replace the compliance blocklist applied to every region lookup.
+    pub fn set_blocklist(&mut self, blocklist: RegionBlocklist) {
+        self.blocklist = blocklist;
+        self.refresh_regions();
+    }
+
+    /// Drop the cached region index so the next selection re-reads every
+    /// provider's regions (e.g. after an availability change).
+    pub fn refresh_regions(&self) {
+        self.region_index.write().clear();
+    }
+
     pub fn from_env() -> Self {
         let mut registry = Self::new();
+        let trace_http = std::env::var("CLAWOPS_TRACE_HTTP").is_ok_and(|v| v == "1" || v == "true");
 
         if let Ok(token) = std::env::var("HETZNER_API_TOKEN") {
-            registry.register(Box::new(HetznerProvider::new(token)));
+            let mut hetzner = HetznerProvider::new(token).with_http_tracing(trace_http);
+            if let Some(secs) = std::env::var("CLAWOPS_PROVISION_TIMEOUT_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+            {
+                hetzner = hetzner.with_provision_timeout(std::time::Duration::from_secs(secs));
+            }
+            registry.register(Box::new(hetzner));
         }
         if let Ok(key) = std::env::var("VULTR_API_KEY") {
-            registry.register(Box::new(VultrProvider {
-                api_key: key,
-                client: build_client(),
-            }));
+            registry.register(Box::new(
+                VultrProvider::new(key).with_http_tracing(trace_http),
+            ));
         }
         if let Ok(key) = std::env::var("CONTABO_API_KEY") {
             registry.register(Box::new(ContaboProvider { api_key: key }));
@@ -254,8 +639,67 @@ impl ProviderRegistry {
         registry
     }
 
+    /// Stop routing new provisions to `provider` until `resume` is called.
+    pub fn pause(&mut self, provider: VpsProvider) {
+        info!(
+            provider = provider_name(&provider),
+            "provisioning paused by operator"
+        );
+        self.manually_paused.insert(provider);
+    }
+
+    /// Lift a manual pause and any health-driven pause; the latter is
+    /// re-applied on the next health check if the provider is still unhealthy.
+    pub fn resume(&mut self, provider: VpsProvider) {
+        self.manually_paused.remove(&provider);
+        self.provisioning_paused.write().remove(&provider);
+        info!(provider = provider_name(&provider), "provisioning resumed");
+    }
+
+    pub fn is_paused(&self, provider: &VpsProvider) -> bool {
+        self.manually_paused.contains(provider)
+            || self.provisioning_paused.read().contains(provider)
+    }
+
+    /// Every paused provider, manual or health-driven, sorted by name.
+    pub fn paused_providers(&self) -> Vec<VpsProvider> {
+        let mut paused: Vec<VpsProvider> = self
+            .manually_paused
+            .iter()
+            .chain(self.provisioning_paused.read().iter())
+            .copied()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        paused.sort_by_key(provider_name);
+        paused
+    }
+
+    /// Pause or un-pause the provider registered as `name` from a fresh health report.
+    fn observe_health(&self, name: &str, health: &ProviderHealth) {
+        let Some(provider) = provider_from_name(name) else {
+            return;
+        };
+        let unhealthy = health.active_incident || health.quota_used_pct > PAUSE_QUOTA_PCT;
+        let mut paused = self.provisioning_paused.write();
+        if unhealthy && paused.insert(provider) {
+            warn!(
+                provider = name,
+                active_incident = health.active_incident,
+                quota_used_pct = health.quota_used_pct,
+                "provider unhealthy; pausing new provisions"
+            );
+        } else if !unhealthy && paused.remove(&provider) {
+            info!(
+                provider = name,
+                "provider recovered; provisioning un-paused"
+            );
+        }
+    }
+
     pub fn register(&mut self, provider: Box<dyn Provider>) {
         info!(name = provider.name(), "registering provider");
+        self.region_index.get_mut().remove(provider.name());
         self.providers.insert(provider.name().to_string(), provider);
     }
 
@@ -263,66 +707,333 @@ impl ProviderRegistry {
         self.providers.get(name).map(|p| p.as_ref())
     }
 
+    /// Recompute region latency classes from measured RTTs (region id → RTT).
+    ///
+    /// Measurements replace the static class a provider reports for that region.
+    pub fn apply_latency_measurements(&mut self, rtts: &HashMap<String, std::time::Duration>) {
+        for (region_id, rtt) in rtts {
+            let class = classify_latency(*rtt);
+            if let Some(previous) = self
+                .measured_latency
+                .insert(region_id.clone(), class.clone())
+                && previous != class
+            {
+                info!(region = %region_id, ?previous, current = ?class, "region latency class changed");
+            }
+        }
+        self.refresh_regions();
+    }
+
+    /// Why `region_id` on `provider` may not be used, if it is blocklisted.
+    ///
+    /// A region the provider does not list has no known country, so only
+    /// provider and region-id entries apply to it.
+    pub fn blocked_reason(&self, provider: &dyn Provider, region_id: &str) -> Option<&str> {
+        match provider
+            .supported_regions()
+            .into_iter()
+            .find(|region| region.id == region_id)
+        {
+            Some(region) => self.blocklist.blocked_reason(&region),
+            None => provider_from_name(provider.name())
+                .and_then(|p| self.blocklist.providers.get(&p))
+                .or_else(|| self.blocklist.regions.get(region_id))
+                .map(String::as_str),
+        }
+    }
+
+    /// A provider's usable regions: blocklisted regions removed, measured
+    /// latency classes applied.
+    pub fn regions_for(&self, provider: &dyn Provider) -> Vec<Region> {
+        provider
+            .supported_regions()
+            .into_iter()
+            .filter(|region| match self.blocklist.blocked_reason(region) {
+                Some(reason) => {
+                    info!(region = %region.id, provider = provider.name(), reason, "region blocklisted; skipping");
+                    false
+                }
+                None => true,
+            })
+            .map(|mut region| {
+                if let Some(class) = self.measured_latency.get(&region.id) {
+                    region.latency_class = class.clone();
+                }
+                region
+            })
+            .collect()
+    }
+
+    /// Lowest-latency available region of `provider` on `continent`.
+    fn best_region(&self, provider: &dyn Provider, continent: &Continent) -> Option<Region> {
+        if let Some(by_continent) = self.region_index.read().get(provider.name()) {
+            return by_continent.get(continent)?.first().cloned();
+        }
+
+        let mut by_continent: HashMap<Continent, Vec<Region>> = HashMap::new();
+        for region in self.regions_for(provider) {
+            if region.available {
+                by_continent
+                    .entry(region.continent.clone())
+                    .or_default()
+                    .push(region);
+            }
+        }
+        for regions in by_continent.values_mut() {
+            // Stable sort keeps the provider's own order among equal classes.
+            regions.sort_by_key(|r| r.latency_class.clone());
+        }
+        let best = by_continent.get(continent).and_then(|r| r.first().cloned());
+        self.region_index
+            .write()
+            .insert(provider.name().to_string(), by_continent);
+        best
+    }
+
     pub async fn select_provider(
         &self,
         preferred: &VpsProvider,
         continent: Continent,
     ) -> Option<(&dyn Provider, Region)> {
-        let preferred_name = provider_name(preferred);
+        let outcome = self.select_provider_explained(preferred, continent).await;
+        let (name, region) = outcome.selected?;
+        Some((self.get(&name)?, region))
+    }
 
-        if let Some(provider) = self.providers.get(preferred_name)
-            && let Ok(health) = provider.provider_health().await
-            && health.health_score >= 75
-            && !health.active_incident
-            && let Some(region) = provider
-                .supported_regions()
-                .into_iter()
-                .find(|r| r.continent == continent && r.available)
-        {
-            return Some((provider.as_ref(), region));
+    /// Like `select_provider`, but fallbacks are also ranked on the provider's
+    /// price for `tier` and its provision success rate (see `ranking_score`),
+    /// so equally healthy providers tie-break toward the cheaper and more
+    /// reliable one.
+    pub async fn select_provider_for_tier(
+        &self,
+        preferred: &VpsProvider,
+        continent: Continent,
+        tier: &InstanceTier,
+    ) -> Option<(&dyn Provider, Region)> {
+        let outcome = self.select(preferred, continent, Some(tier)).await;
+        let (name, region) = outcome.selected?;
+        Some((self.get(&name)?, region))
+    }
+
+    /// Same selection as `select_provider`, but records why each candidate
+    /// was accepted or skipped.
+    pub async fn select_provider_explained(
+        &self,
+        preferred: &VpsProvider,
+        continent: Continent,
+    ) -> SelectionOutcome {
+        self.select(preferred, continent, None).await
+    }
+
+    /// Shared selection: the preferred provider if it qualifies, else the
+    /// best-ranked fallback by `ranking_score`, priced for `tier` if given.
+    async fn select(
+        &self,
+        preferred: &VpsProvider,
+        continent: Continent,
+        tier: Option<&InstanceTier>,
+    ) -> SelectionOutcome {
+        let preferred_name = provider_name(preferred);
+        let mut trace = Vec::new();
+
+        match self.providers.get(preferred_name) {
+            None => trace.push(CandidateDecision::skipped(preferred_name, "not registered")),
+            Some(provider) => match self.qualify(provider.as_ref(), PREFERRED_MIN_HEALTH).await {
+                Err(reason) => trace.push(CandidateDecision::skipped(preferred_name, reason)),
+                Ok(health) => match self.best_region(provider.as_ref(), &continent) {
+                    Some(region) => {
+                        let score = health.health_score;
+                        trace.push(Self::accepted(preferred_name, score, &continent, &region));
+                        return SelectionOutcome {
+                            selected: Some((preferred_name.to_string(), region)),
+                            trace,
+                        };
+                    }
+                    None => trace.push(Self::no_region(preferred_name, &continent)),
+                },
+            },
         }
 
-        // Fallback: find best available provider
-        let mut candidates: Vec<(u8, &str)> = Vec::new();
-        for (name, provider) in &self.providers {
-            if name == preferred_name {
-                continue;
-            }
-            if let Ok(health) = provider.provider_health().await
-                && !health.active_incident
-                && health.health_score >= 65
-            {
-                candidates.push((health.health_score, name.as_str()));
+        // Fallback: rank providers by health, less a penalty for slower regions.
+        // Health checks run concurrently so one slow API does not stall selection.
+        let mut names: Vec<&String> = self
+            .providers
+            .keys()
+            .filter(|name| *name != preferred_name)
+            .collect();
+        names.sort();
+        let checks = names
+            .iter()
+            .map(|name| self.qualify(self.providers[*name].as_ref(), FALLBACK_MIN_HEALTH));
+        let outcomes = futures_util::future::join_all(checks).await;
+        let mut qualified: Vec<(&str, ProviderHealth, Region)> = Vec::new();
+        for (name, outcome) in names.into_iter().zip(outcomes) {
+            let provider = self.providers[name].as_ref();
+            match outcome {
+                Ok(health) => match self.best_region(provider, &continent) {
+                    Some(region) => qualified.push((name.as_str(), health, region)),
+                    None => trace.push(Self::no_region(name, &continent)),
+                },
+                Err(reason) => trace.push(CandidateDecision::skipped(name, reason)),
             }
         }
-        candidates.sort_by_key(|b| std::cmp::Reverse(b.0));
+        let cheapest = tier.map(|tier| {
+            qualified
+                .iter()
+                .map(|(name, ..)| self.providers[*name].monthly_cost(tier))
+                .fold(f32::INFINITY, f32::min)
+        });
+        let mut candidates: Vec<(i32, &str)> = Vec::new();
+        let mut placements: HashMap<&str, (u8, Region)> = HashMap::new();
+        for (name, health, region) in qualified {
+            let cost_score = tier.zip(cheapest).map(|(tier, cheapest)| {
+                let cost = self.providers[name].monthly_cost(tier);
+                if cost > 0.0 { cheapest / cost } else { 1.0 }
+            });
+            let key = self.ranking_score(&health, &region, cost_score);
+            candidates.push((key, name));
+            placements.insert(name, (health.health_score, region));
+        }
+        let selected = self.spread(candidates).into_iter().next().and_then(|name| {
+            let (score, region) = placements.remove(name)?;
+            trace.push(Self::accepted(name, score, &continent, &region));
+            Some((name.to_string(), region))
+        });
 
-        for (_, name) in candidates {
-            if let Some(provider) = self.providers.get(name)
-                && let Some(region) = provider
-                    .supported_regions()
-                    .into_iter()
-                    .find(|r| r.continent == continent && r.available)
-            {
-                return Some((provider.as_ref(), region));
+        SelectionOutcome { selected, trace }
+    }
+
+    /// Fallback ranking key, in hundredths of a health point: the health
+    /// score, less `latency_weight` per latency class step above `Low`.
+    ///
+    /// With a `cost_score` (cheapest candidate's price over this one's), the
+    /// health score is first blended with cost and provision success rate
+    /// in `score_provider`'s proportions, so price breaks ties between
+    /// equally healthy providers.
+    fn ranking_score(
+        &self,
+        health: &ProviderHealth,
+        region: &Region,
+        cost_score: Option<f32>,
+    ) -> i32 {
+        let steps = match region.latency_class {
+            LatencyClass::Low => 0,
+            LatencyClass::Medium => 1,
+            LatencyClass::High => 2,
+        };
+        let points = match cost_score {
+            None => f32::from(health.health_score),
+            Some(cost_score) => {
+                let reliability = health.provision_success_rate_7d.clamp(0.0, 1.0) * 100.0;
+                let cost = cost_score.clamp(0.0, 1.0) * 100.0;
+                (f32::from(health.health_score) * 0.4 + cost * 0.3 + reliability * 0.1) / 0.8
             }
+        };
+        (points * 100.0).round() as i32 - i32::from(self.latency_weight) * steps * 100
+    }
+
+    /// Health of `provider` if it is reachable, incident-free and scores at
+    /// least `min_score`; otherwise the reason it is not.
+    async fn qualify(
+        &self,
+        provider: &dyn Provider,
+        min_score: u8,
+    ) -> Result<ProviderHealth, String> {
+        if let Some(kind) = provider_from_name(provider.name())
+            && self.manually_paused.contains(&kind)
+        {
+            return Err("provisioning paused by operator".to_string());
+        }
+        let health = provider
+            .provider_health()
+            .await
+            .map_err(|e| format!("health check failed: {e}"))?;
+        self.observe_health(provider.name(), &health);
+        if health.active_incident {
+            return Err("active_incident".to_string());
+        }
+        if health.quota_used_pct > PAUSE_QUOTA_PCT {
+            return Err(format!("quota {:.0}% used", health.quota_used_pct));
+        }
+        if health.health_score < min_score {
+            return Err(format!(
+                "health score {} below {min_score}",
+                health.health_score
+            ));
+        }
+        Ok(health)
+    }
+
+    fn accepted(
+        name: &str,
+        score: u8,
+        continent: &Continent,
+        region: &Region,
+    ) -> CandidateDecision {
+        CandidateDecision {
+            provider: name.to_string(),
+            selected: true,
+            reason: format!(
+                "score {score}, {continent:?} region {} available",
+                region.id
+            ),
         }
+    }
 
-        None
+    fn no_region(name: &str, continent: &Continent) -> CandidateDecision {
+        CandidateDecision::skipped(name, format!("no available {continent:?} region"))
     }
 
-    pub async fn all_health(&self) -> Vec<ProviderHealth> {
-        let mut results = Vec::new();
-        for provider in self.providers.values() {
-            match provider.provider_health().await {
-                Ok(health) => results.push(health),
-                Err(e) => warn!(
-                    provider = provider.name(),
-                    "provider health check failed: {e}"
-                ),
+    /// Order fallback candidates by health, spreading load randomly across ties.
+    ///
+    /// Candidates are sorted by name before drawing, so the order depends only
+    /// on the rng seed and not on map iteration order.
+    fn spread<'r>(&self, mut candidates: Vec<(i32, &'r str)>) -> Vec<&'r str> {
+        candidates.sort_by_key(|c| c.1);
+        let mut keyed: Vec<(i32, u64, &str)> = candidates
+            .into_iter()
+            .map(|(score, name)| (score, self.rng.next_u64(), name))
+            .collect();
+        keyed.sort_by_key(|c| (std::cmp::Reverse(c.0), c.1));
+        keyed.into_iter().map(|c| c.2).collect()
+    }
+
+    /// Poll every provider concurrently, sorted by provider name.
+    ///
+    /// A provider that errors or exceeds the health timeout is reported as
+    /// `Unreachable` rather than dropped.
+    pub async fn all_health(&self) -> Vec<ProviderHealthStatus> {
+        let mut providers: Vec<&dyn Provider> =
+            self.providers.values().map(|p| p.as_ref()).collect();
+        providers.sort_by_key(|p| p.name());
+
+        let checks = providers.into_iter().map(|provider| async move {
+            let outcome = tokio::time::timeout(self.health_timeout, provider.provider_health())
+                .await
+                .unwrap_or_else(|_| {
+                    Err(anyhow::anyhow!(
+                        "no response within {}ms",
+                        self.health_timeout.as_millis()
+                    ))
+                });
+            match outcome {
+                Ok(health) => {
+                    self.observe_health(provider.name(), &health);
+                    ProviderHealthStatus::Reachable(health)
+                }
+                Err(e) => {
+                    warn!(
+                        provider = provider.name(),
+                        "provider health check failed: {e}"
+                    );
+                    ProviderHealthStatus::Unreachable {
+                        provider: provider.name().to_string(),
+                        error: e.to_string(),
+                    }
+                }
             }
-        }
-        results
+        });
+        futures_util::future::join_all(checks).await
     }
 }
 
@@ -342,6 +1053,19 @@ fn provider_name(provider: &VpsProvider) -> &'static str {
     }
 }
 
+/// The provider a registry name ("hetzner") refers to.
+pub fn provider_from_name(name: &str) -> Option<VpsProvider> {
+    [
+        VpsProvider::Hetzner,
+        VpsProvider::Vultr,
+        VpsProvider::Contabo,
+        VpsProvider::Hostinger,
+        VpsProvider::DigitalOcean,
+    ]
+    .into_iter()
+    .find(|p| provider_name(p) == name)
+}
+
 fn build_client() -> reqwest::Client {
     reqwest::Client::builder()
         .timeout(std::time::Duration::from_secs(30))
@@ -349,37 +1073,378 @@ fn build_client() -> reqwest::Client {
         .expect("failed to build HTTP client")
 }
 
-// ─── Instance Pair ────────────────────────────────────────────────────────────
+// ─── HTTP tracing ─────────────────────────────────────────────────────────────
+
+/// Environment variables whose values must never appear in logs.
+const SECRET_ENV_VARS: &[&str] = &[
+    "HETZNER_API_TOKEN",
+    "VULTR_API_KEY",
+    "CONTABO_API_KEY",
+    "HOSTINGER_API_KEY",
+    "DO_API_TOKEN",
+    "TAILSCALE_AUTH_KEY",
+    "CLAWOPS_API_KEY",
+];
+
+/// JSON keys whose values are replaced outright.
+const SECRET_KEYS: &[&str] = &[
+    "api_key",
+    "api_token",
+    "token",
+    "auth_key",
+    "password",
+    "secret",
+];
+
+const REDACTED: &str = "****";
+
+/// Opt-in request/response logging for provider HTTP clients.
+trait SendTraced {
+    /// `send()`, logging the redacted exchange at debug level when `enabled`.
+    async fn send_traced(self, enabled: bool) -> reqwest::Result<reqwest::Response>;
+}
 
-/// A provisioned primary+standby pair on (ideally) different providers.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct InstancePair {
-    pub pair_id: String,
-    pub account_id: String,
-    pub tier: InstanceTier,
-    pub primary: ProvisionResult,
-    pub primary_provider: VpsProvider,
-    pub primary_region: String,
-    pub standby: ProvisionResult,
-    pub standby_provider: VpsProvider,
-    pub standby_region: String,
-    pub provisioned_at: DateTime<Utc>,
+impl SendTraced for reqwest::RequestBuilder {
+    async fn send_traced(self, enabled: bool) -> reqwest::Result<reqwest::Response> {
+        let (client, request) = self.build_split();
+        execute_traced(&client, request?, enabled).await
+    }
 }
 
-/// Result of a bulk provisioning operation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BulkProvisionResult {
-    pub requested: u32,
-    pub succeeded: u32,
-    pub failed: u32,
-    pub results: Vec<ProvisionResult>,
-    pub completed_at: DateTime<Utc>,
+/// `client.execute(request)`, logging the redacted exchange when `enabled`.
+async fn execute_traced(
+    client: &reqwest::Client,
+    request: reqwest::Request,
+    enabled: bool,
+) -> reqwest::Result<reqwest::Response> {
+    if !enabled {
+        return client.execute(request).await;
+    }
+    debug!(request = %describe_request(&request, &secret_env_values()), "provider API request");
+    let response = client.execute(request).await;
+    match &response {
+        Ok(resp) => debug!(status = %resp.status(), url = %resp.url(), "provider API response"),
+        Err(e) => debug!(error = %e, "provider API request failed"),
+    }
+    response
 }
 
-/// Score a provider for selection using weighted factors.
-/// Returns a score in [0.0, 1.0].
-pub fn score_provider(
-    health_score: u8,
+fn secret_env_values() -> Vec<String> {
+    SECRET_ENV_VARS
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .filter(|v| !v.is_empty())
+        .collect()
+}
+
+/// Render a request for logging: credentials masked, cloud-init elided.
+pub fn describe_request(request: &reqwest::Request, secrets: &[String]) -> String {
+    let mut out = format!("{} {}", request.method(), request.url());
+    for (name, value) in request.headers() {
+        let value = value.to_str().unwrap_or("<binary>");
+        out.push_str(&format!(
+            "\n{name}: {}",
+            redact_header(name.as_str(), value)
+        ));
+    }
+    if let Some(bytes) = request.body().and_then(|b| b.as_bytes()) {
+        let body = match serde_json::from_slice::<serde_json::Value>(bytes) {
+            Ok(json) => redact_json(&json, secrets).to_string(),
+            Err(_) => format!("<{} bytes>", bytes.len()),
+        };
+        out.push_str(&format!("\n\n{body}"));
+    }
+    out
+}
+
+/// Mask credential headers, keeping the auth scheme (`Bearer ****`).
+pub fn redact_header(name: &str, value: &str) -> String {
+    let name = name.to_ascii_lowercase();
+    if name == "authorization" || name == "proxy-authorization" {
+        return match value.split_once(' ') {
+            Some((scheme, _)) => format!("{scheme} {REDACTED}"),
+            None => REDACTED.to_string(),
+        };
+    }
+    if name.contains("key") || name.contains("token") {
+        return REDACTED.to_string();
+    }
+    value.to_string()
+}
+
+/// Copy of `body` with `user_data` elided, secret keys masked and any known
+/// secret value scrubbed from remaining strings.
+pub fn redact_json(body: &serde_json::Value, secrets: &[String]) -> serde_json::Value {
+    use serde_json::Value;
+    match body {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| {
+                    let value = if key == "user_data" {
+                        let len = value.as_str().map_or(0, str::len);
+                        Value::String(format!("<cloud-init elided, {len} bytes>"))
+                    } else if SECRET_KEYS.contains(&key.as_str()) && !value.is_null() {
+                        Value::String(REDACTED.to_string())
+                    } else {
+                        redact_json(value, secrets)
+                    };
+                    (key.clone(), value)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => {
+            Value::Array(items.iter().map(|v| redact_json(v, secrets)).collect())
+        }
+        Value::String(s) => Value::String(secrets.iter().fold(s.clone(), |acc, secret| {
+            acc.replace(secret.as_str(), REDACTED)
+        })),
+        other => other.clone(),
+    }
+}
+
+// ─── Rate-limit handling ──────────────────────────────────────────────────────
+
+/// Parse a `Retry-After` value: delay seconds or an HTTP-date.
+///
+/// A date in the past means "retry now".
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<std::time::Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(std::time::Duration::from_secs(secs));
+    }
+    let at = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        at.with_timezone(&Utc)
+            .signed_duration_since(now)
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+/// Send a request, backing off while the provider answers 429, or 503 for
+/// idempotent methods.
+///
+/// `build` is called for a fresh request on every attempt. A non-idempotent
+/// request (`POST`) is only retried on 429, which the provider rejects
+/// before acting on; a 503 may have created the resource. Each wait honours
+/// `Retry-After` when present (otherwise `policy`'s backoff, jittered by
+/// `rng`), is capped at `policy.max_delay_ms` and goes through `sleeper`.
+/// Once `policy.max_retries` is spent the throttled response is returned
+/// as-is, for [`check_provider_status`] to report.
+pub async fn send_with_rate_limit(
+    policy: &RetryPolicy,
+    rng: &dyn RandomSource,
+    sleeper: &dyn Sleeper,
+    trace_http: bool,
+    build: impl Fn() -> reqwest::RequestBuilder,
+) -> reqwest::Result<reqwest::Response> {
+    let mut attempt = 0u32;
+    loop {
+        let (client, request) = build().build_split();
+        let request = request?;
+        let idempotent = request.method().is_idempotent();
+        let resp = execute_traced(&client, request, trace_http).await?;
+        let status = resp.status();
+        let throttled = status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            || (status == reqwest::StatusCode::SERVICE_UNAVAILABLE && idempotent);
+        if !throttled || !policy.should_retry(attempt) {
+            return Ok(resp);
+        }
+        let wait = resp
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| parse_retry_after(v, Utc::now()))
+            .unwrap_or_else(|| policy.delay_for_attempt_with(attempt, rng))
+            .min(std::time::Duration::from_millis(policy.max_delay_ms));
+        warn!(
+            %status,
+            url = %resp.url(),
+            attempt,
+            wait_ms = wait.as_millis() as u64,
+            "provider API rate limited; backing off"
+        );
+        sleeper.sleep(wait).await;
+        attempt += 1;
+    }
+}
+
+/// How a provider API call failed, for callers deciding whether to retry.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ProviderError {
+    /// HTTP 429; wait `retry_after` (from the `Retry-After` header) if given.
+    #[error("provider rate limited the request (retry after {retry_after:?})")]
+    RateLimited {
+        retry_after: Option<std::time::Duration>,
+    },
+    /// 408 or 5xx: the same request may succeed later.
+    #[error("transient provider error: HTTP {0}")]
+    Transient(u16),
+    /// Any other 4xx (bad request, bad credentials): retrying cannot help.
+    #[error("provider rejected the request: HTTP {0}")]
+    Fatal(u16),
+}
+
+/// How long to wait before retrying a failed provider call, or `None` if it
+/// must not be retried.
+///
+/// A fatal provider error (400, 401, 403, ...) is never retried; a rate limit
+/// waits out its `Retry-After`, capped at `policy.max_delay_ms`; anything else
+/// takes the policy's backoff.
+fn provider_retry_delay(
+    policy: &RetryPolicy,
+    attempt: u32,
+    rng: &dyn RandomSource,
+    err: &anyhow::Error,
+) -> Option<std::time::Duration> {
+    match err.chain().find_map(|c| c.downcast_ref::<ProviderError>()) {
+        Some(ProviderError::Fatal(_)) => None,
+        Some(ProviderError::RateLimited {
+            retry_after: Some(wait),
+        }) => Some((*wait).min(std::time::Duration::from_millis(policy.max_delay_ms))),
+        _ => Some(policy.delay_for_attempt_with(attempt, rng)),
+    }
+}
+
+/// True if `err` is a provider's 401 or 403.
+fn is_auth_failure(err: &anyhow::Error) -> bool {
+    matches!(
+        err.chain().find_map(|c| c.downcast_ref::<ProviderError>()),
+        Some(ProviderError::Fatal(401 | 403))
+    )
+}
+
+/// A provisioning request the forge itself rejected, before or instead of a
+/// provider call.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ProvisionError {
+    /// No provider client is registered under this name.
+    #[error("provider '{0}' not registered")]
+    NotRegistered(String),
+    /// A safety rule refused the operation.
+    #[error("refusing to {0}")]
+    Refused(String),
+}
+
+/// Like `error_for_status`, but keeps a 429's `Retry-After` and separates
+/// transient from fatal failures.
+pub fn check_provider_status(
+    resp: reqwest::Response,
+) -> std::result::Result<reqwest::Response, ProviderError> {
+    let status = resp.status();
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = resp
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| parse_retry_after(v, Utc::now()));
+        return Err(ProviderError::RateLimited { retry_after });
+    }
+    if status.is_server_error() || status == reqwest::StatusCode::REQUEST_TIMEOUT {
+        return Err(ProviderError::Transient(status.as_u16()));
+    }
+    if status.is_client_error() {
+        return Err(ProviderError::Fatal(status.as_u16()));
+    }
+    Ok(resp)
+}
+
+/// Classify a provisioning failure into the shared [`ClawError`] taxonomy.
+///
+/// Provider HTTP failures are categorised by status code; timeouts, connect
+/// errors, 429 and 5xx are transient. A [`ProvisionError::Refused`] is a safety
+/// stop and an unregistered provider is not found. A [`ClawError`] already in
+/// the chain keeps its category. Anything else is permanent.
+pub fn classify_error(err: &anyhow::Error) -> ClawError {
+    let message = format!("{err:#}");
+    if let Some(provider) = err.chain().find_map(|e| e.downcast_ref::<ProviderError>()) {
+        return match provider {
+            ProviderError::RateLimited { .. } | ProviderError::Transient(_) => {
+                ClawError::Transient(message)
+            }
+            ProviderError::Fatal(401 | 403) => ClawError::Unauthorized(message),
+            ProviderError::Fatal(404) => ClawError::NotFound(message),
+            ProviderError::Fatal(_) => ClawError::Permanent(message),
+        };
+    }
+    if let Some(http) = err.chain().find_map(|e| e.downcast_ref::<reqwest::Error>()) {
+        if http.is_timeout() || http.is_connect() {
+            return ClawError::Transient(message);
+        }
+        if let Some(status) = http.status() {
+            return match status.as_u16() {
+                429 | 500..=599 => ClawError::Transient(message),
+                404 => ClawError::NotFound(message),
+                401 | 403 => ClawError::Unauthorized(message),
+                _ => ClawError::Permanent(message),
+            };
+        }
+    }
+    if let Some(forge) = err.chain().find_map(|e| e.downcast_ref::<ProvisionError>()) {
+        return match forge {
+            ProvisionError::NotRegistered(_) => ClawError::NotFound(message),
+            ProvisionError::Refused(_) => ClawError::Safety(message),
+        };
+    }
+    if let Some(claw) = err.chain().find_map(|e| e.downcast_ref::<ClawError>()) {
+        return claw.clone();
+    }
+    ClawError::Permanent(message)
+}
+
+// ─── Instance Pair ────────────────────────────────────────────────────────────
+
+/// A provisioned primary+standby pair on (ideally) different providers.
+///
+/// `standby` is `None` when standby provisioning failed; see
+/// `FleetProvisioner::complete_pair` to recover.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstancePair {
+    pub pair_id: String,
+    pub account_id: String,
+    pub tier: InstanceTier,
+    pub primary: ProvisionResult,
+    pub primary_provider: VpsProvider,
+    pub primary_region: String,
+    pub standby: Option<ProvisionResult>,
+    pub standby_provider: VpsProvider,
+    pub standby_region: String,
+    /// Image both instances run; a recovered standby reuses it.
+    #[serde(default)]
+    pub os_image: OsImage,
+    /// Error from the most recent failed standby attempt, if any.
+    pub error: Option<String>,
+    pub provisioned_at: DateTime<Utc>,
+    /// Produced by a dry-run provisioner: no instance exists.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+impl InstancePair {
+    /// Both the primary and the standby are up.
+    pub fn is_complete(&self) -> bool {
+        is_provisioned(&self.primary) && self.standby.as_ref().is_some_and(is_provisioned)
+    }
+}
+
+fn is_provisioned(result: &ProvisionResult) -> bool {
+    result.success && result.instance_id.is_some()
+}
+
+/// Result of a bulk provisioning operation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkProvisionResult {
+    pub requested: u32,
+    pub succeeded: u32,
+    pub failed: u32,
+    pub results: Vec<ProvisionResult>,
+    pub completed_at: DateTime<Utc>,
+}
+
+/// Score a provider for selection using weighted factors.
+/// Returns a score in [0.0, 1.0].
+pub fn score_provider(
+    health_score: u8,
     cost_score: f32,
     latency_class: &LatencyClass,
     provision_success_rate: f32,
@@ -396,6 +1461,84 @@ pub fn score_provider(
     (health * 0.4) + (cost * 0.3) + (latency * 0.2) + (reliability * 0.1)
 }
 
+/// Ranking key for standby candidates; larger is more independent.
+type PlacementKey = (bool, u8, bool, std::cmp::Reverse<LatencyClass>);
+
+/// Pick the standby location least likely to fail together with `primary`.
+///
+/// Candidates are ranked by, in order: a different provider, a different site
+/// (country, then city, then datacenter), the same continent (for replication
+/// latency), and lower latency class. Paused providers and those with a
+/// health score under 65 or an unreachable health endpoint are skipped, as
+/// are unavailable and blocklisted regions.
+pub async fn recommend_standby_placement(
+    primary: &Region,
+    registry: &ProviderRegistry,
+) -> Option<(VpsProvider, Region)> {
+    let mut names: Vec<&String> = registry.providers.keys().collect();
+    names.sort();
+
+    let mut best: Option<(PlacementKey, Region)> = None;
+    for name in names {
+        let provider = registry.providers[name].as_ref();
+        if registry
+            .qualify(provider, FALLBACK_MIN_HEALTH)
+            .await
+            .is_err()
+        {
+            continue;
+        }
+        for region in registry.regions_for(provider) {
+            if !region.available {
+                continue;
+            }
+            let site = if region.country != primary.country {
+                3
+            } else if region.city != primary.city {
+                2
+            } else if region.id != primary.id {
+                1
+            } else {
+                0
+            };
+            let key = (
+                region.provider != primary.provider,
+                site,
+                region.continent == primary.continent,
+                std::cmp::Reverse(region.latency_class.clone()),
+            );
+            if best.as_ref().is_none_or(|(k, _)| key > *k) {
+                best = Some((key, region));
+            }
+        }
+    }
+    best.map(|(_, region)| (region.provider, region))
+}
+
+/// Settings every instance of an account boots with, whatever its role.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AccountNodeConfig {
+    pub gateway_url: String,
+    pub auth_token: String,
+    /// Break-glass keys appended to root's `authorized_keys`.
+    #[serde(default)]
+    pub ssh_authorized_keys: Vec<String>,
+}
+
+impl AccountNodeConfig {
+    /// `openclaw_config` for an instance in `role`; `ha` is false for an
+    /// account that runs without a standby.
+    pub fn openclaw_config(&self, role: InstanceRole, ha: bool) -> serde_json::Value {
+        serde_json::json!({
+            "gateway_url": self.gateway_url,
+            "auth_token": self.auth_token,
+            "ssh_authorized_keys": self.ssh_authorized_keys,
+            "role": role,
+            "ha": ha,
+        })
+    }
+}
+
 /// Parameters for provisioning a primary+standby pair.
 #[derive(Debug, Clone)]
 pub struct PairProvisionRequest {
@@ -405,25 +1548,276 @@ pub struct PairProvisionRequest {
     pub primary_region: String,
     pub standby_provider: VpsProvider,
     pub standby_region: String,
-    pub gateway_url: String,
-    pub auth_token: String,
+    pub os_image: OsImage,
+    pub node: AccountNodeConfig,
     pub requested_by: String,
 }
 
+/// Default retry policy for `provision_pair_with_retry`: two retries after
+/// the first attempt, starting at 10s and capped at 2 minutes.
+pub fn default_provision_retry_policy() -> RetryPolicy {
+    RetryPolicy {
+        max_retries: 2,
+        base_delay_ms: 10_000,
+        max_delay_ms: 120_000,
+        jitter: true,
+    }
+}
+
 /// High-level fleet operations built on the ProviderRegistry.
+#[derive(Clone)]
 pub struct FleetProvisioner<'a> {
     registry: &'a ProviderRegistry,
+    /// Backoff between attempts in `provision_pair_with_retry`.
+    retry_policy: RetryPolicy,
+    rng: Arc<dyn RandomSource>,
+    sleeper: Arc<dyn Sleeper>,
+    /// Tear the primary down when its standby cannot be provisioned, so a
+    /// failed pair never leaves an unprotected instance running.
+    rollback_on_partial_failure: bool,
+    /// Run every check but never call a provider's `provision`, `teardown`,
+    /// `resize` or `hibernate`; results carry deterministic fake ids.
+    dry_run: bool,
+    /// Consulted before every provision; `None` admits everything.
+    gate: Option<Arc<dyn ProvisionGate>>,
+    /// Told about every successful provision.
+    observer: Option<Arc<dyn ProvisionObserver>>,
+    /// Records every provider resize and teardown.
+    audit: Option<Arc<dyn OperationAuditSink>>,
 }
 
 impl<'a> FleetProvisioner<'a> {
     pub fn new(registry: &'a ProviderRegistry) -> Self {
-        Self { registry }
+        Self {
+            registry,
+            retry_policy: default_provision_retry_policy(),
+            rng: Arc::new(SeededRng::from_entropy()),
+            sleeper: Arc::new(TokioSleeper),
+            rollback_on_partial_failure: true,
+            dry_run: false,
+            gate: None,
+            observer: None,
+            audit: None,
+        }
+    }
+
+    /// Check every provision (pair, single, standby, bulk, migration) with
+    /// `gate` before any provider is called.
+    pub fn with_gate(mut self, gate: Arc<dyn ProvisionGate>) -> Self {
+        self.gate = Some(gate);
+        self
+    }
+
+    /// Report every successfully provisioned instance to `observer`.
+    pub fn with_observer(mut self, observer: Arc<dyn ProvisionObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Record every provider resize and teardown in `audit`.
+    pub fn with_audit_sink(mut self, audit: Arc<dyn OperationAuditSink>) -> Self {
+        self.audit = Some(audit);
+        self
+    }
+
+    /// Hand the outcome of a provider resize or teardown to the audit sink.
+    fn record_operation(
+        &self,
+        action: &str,
+        provider: &VpsProvider,
+        provider_instance_id: &str,
+        requested_by: &str,
+        mut details: serde_json::Value,
+        error: Option<&anyhow::Error>,
+    ) {
+        let Some(audit) = &self.audit else {
+            return;
+        };
+        details["provider"] = provider.to_string().into();
+        if let Some(e) = error {
+            details["error"] = format!("{e:#}").into();
+        }
+        audit.record(AuditLogEntry {
+            id: Uuid::new_v4().to_string(),
+            timestamp: Utc::now(),
+            actor: requested_by.to_string(),
+            action: action.to_string(),
+            resource: "provider_instance".to_string(),
+            resource_id: Some(provider_instance_id.to_string()),
+            result: if error.is_none() {
+                "success"
+            } else {
+                "failure"
+            }
+            .to_string(),
+            details: Some(details.to_string()),
+        });
+    }
+
+    fn admit(&self, requested_by: &str, instances: u32) -> Result<()> {
+        match &self.gate {
+            Some(gate) => gate.admit(requested_by, instances),
+            None => Ok(()),
+        }
+    }
+
+    /// Override the backoff used by `provision_pair_with_retry`.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Override the randomness used for retry jitter.
+    pub fn with_rng(mut self, rng: Arc<dyn RandomSource>) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    /// Override the sleeper used between retries.
+    pub fn with_sleeper(mut self, sleeper: Arc<dyn Sleeper>) -> Self {
+        self.sleeper = sleeper;
+        self
+    }
+
+    /// Keep (`false`) or tear down (`true`, the default) a primary whose
+    /// standby cannot be provisioned.
+    pub fn with_rollback_on_partial_failure(mut self, enabled: bool) -> Self {
+        self.rollback_on_partial_failure = enabled;
+        self
+    }
+
+    /// Run every check without calling any mutating provider method.
+    pub fn with_dry_run(mut self, enabled: bool) -> Self {
+        self.dry_run = enabled;
+        self
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// `provision_one` for a standalone instance, after the gate admits it.
+    async fn admit_and_provision(
+        &self,
+        provider: &dyn Provider,
+        req: &ProvisionRequest,
+    ) -> Result<ProvisionResult> {
+        self.admit(&req.requested_by, 1)?;
+        self.provision_one(provider, req).await
+    }
+
+    /// Refuse `region` on `provider` if the registry's blocklist covers it.
+    fn ensure_region_allowed(&self, provider: &dyn Provider, region: &str) -> Result<()> {
+        match self.registry.blocked_reason(provider, region) {
+            Some(reason) => bail!(ProvisionError::Refused(format!(
+                "provision in blocklisted region {region} on {} ({reason})",
+                provider.name()
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// `provision_checked`, or a synthetic result in dry-run mode. A
+    /// blocklisted region is refused either way.
+    async fn provision_one(
+        &self,
+        provider: &dyn Provider,
+        req: &ProvisionRequest,
+    ) -> Result<ProvisionResult> {
+        self.ensure_region_allowed(provider, &req.region)?;
+        if !self.dry_run {
+            let result = provision_checked(provider, req).await?;
+            if let Some(observer) = &self.observer
+                && result.success
+            {
+                observer.provisioned(provider.name(), &result);
+            }
+            return Ok(result);
+        }
+        ensure_image_supported(provider, req)?;
+        let id = format!("dryrun-{}-{}-{}", provider.name(), req.account_id, req.role);
+        info!(instance = %id, "dry run: skipping provider provision");
+        Ok(ProvisionResult {
+            request_id: req.request_id,
+            instance_id: Some(id.clone()),
+            success: true,
+            error: None,
+            provision_duration_ms: 0,
+            instance_ip: None,
+            tailscale_ip: None,
+            provider_instance_id: Some(id),
+        })
+    }
+
+    /// `provision_pair`, retried under the retry policy when the primary
+    /// fails or was rolled back. Without rollback, a pair missing only one
+    /// side is returned as-is rather than re-provisioned, so the running
+    /// side is never abandoned; `complete_pair` finishes a missing standby.
+    ///
+    /// A rate-limited primary waits out the provider's `Retry-After` (capped
+    /// at the policy's `max_delay_ms`) instead of the backoff; a fatal provider error is
+    /// returned without retrying.
+    pub async fn provision_pair_with_retry(
+        &self,
+        req: PairProvisionRequest,
+    ) -> Result<InstancePair> {
+        let mut attempt = 0;
+        loop {
+            match self.provision_pair(req.clone()).await {
+                Ok(pair)
+                    if is_provisioned(&pair.primary)
+                        || pair.standby.as_ref().is_some_and(is_provisioned)
+                        || !self.retry_policy.should_retry(attempt) =>
+                {
+                    return Ok(pair);
+                }
+                Ok(pair) => {
+                    let delay = self
+                        .retry_policy
+                        .delay_for_attempt_with(attempt, &*self.rng);
+                    warn!(
+                        account_id = %req.account_id,
+                        attempt,
+                        delay_ms = delay.as_millis() as u64,
+                        error = ?pair.error,
+                        "pair rolled back, retrying"
+                    );
+                    self.sleeper.sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(e) if self.retry_policy.should_retry(attempt) => {
+                    let Some(delay) =
+                        provider_retry_delay(&self.retry_policy, attempt, &*self.rng, &e)
+                    else {
+                        return Err(e);
+                    };
+                    warn!(
+                        account_id = %req.account_id,
+                        attempt,
+                        delay_ms = delay.as_millis() as u64,
+                        "pair provisioning failed, retrying: {e:#}"
+                    );
+                    self.sleeper.sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
     }
 
     /// Provision a primary+standby pair.
     ///
     /// Primary goes on `primary_provider`; standby goes on a different provider
     /// where possible (for fault isolation). Both are provisioned concurrently.
+    ///
+    /// If the standby fails and `rollback_on_partial_failure` is set, it is
+    /// retried once through `complete_pair`; if that also fails, the primary
+    /// is torn down and returned unprovisioned, with both the standby error
+    /// and the rollback recorded in `error`. If the rollback itself fails,
+    /// the pair is returned with the still-running primary filled in so the
+    /// caller can clean it up. A standby whose primary failed is rolled back
+    /// the same way, or torn down unconditionally if the primary errored,
+    /// since the error cannot carry it back.
     pub async fn provision_pair(&self, req: PairProvisionRequest) -> Result<InstancePair> {
         let account_id = req.account_id.as_str();
         let tier = req.tier;
@@ -431,8 +1825,8 @@ impl<'a> FleetProvisioner<'a> {
         let primary_region = req.primary_region.as_str();
         let standby_provider = req.standby_provider;
         let standby_region = req.standby_region.as_str();
-        let gateway_url = req.gateway_url.as_str();
-        let auth_token = req.auth_token.as_str();
+        let os_image = req.os_image;
+        let node = &req.node;
         let requested_by = req.requested_by.as_str();
         let pair_id = Uuid::new_v4().to_string();
         let now = Utc::now();
@@ -444,12 +1838,9 @@ impl<'a> FleetProvisioner<'a> {
             role: InstanceRole::Primary,
             provider: primary_provider,
             region: primary_region.to_string(),
+            os_image,
             pair_instance_id: None, // will be updated after standby is known
-            openclaw_config: Some(serde_json::json!({
-                "gateway_url": gateway_url,
-                "auth_token": auth_token,
-                "role": "primary",
-            })),
+            openclaw_config: Some(node.openclaw_config(InstanceRole::Primary, true)),
             requested_by: requested_by.to_string(),
             requested_at: now,
         };
@@ -461,44 +1852,162 @@ impl<'a> FleetProvisioner<'a> {
             role: InstanceRole::Standby,
             provider: standby_provider,
             region: standby_region.to_string(),
+            os_image,
             pair_instance_id: None,
-            openclaw_config: Some(serde_json::json!({
-                "gateway_url": gateway_url,
-                "auth_token": auth_token,
-                "role": "standby",
-            })),
+            openclaw_config: Some(node.openclaw_config(InstanceRole::Standby, true)),
             requested_by: requested_by.to_string(),
             requested_at: now,
         };
 
         let primary_prov = self
-            .registry
-            .get(provider_name(&primary_provider))
-            .ok_or_else(|| {
-                anyhow::anyhow!("primary provider '{}' not registered", primary_provider)
-            })?;
+            .provider(&primary_provider)
+            .context("primary provider")?;
         let standby_prov = self
-            .registry
-            .get(provider_name(&standby_provider))
-            .ok_or_else(|| {
-                anyhow::anyhow!("standby provider '{}' not registered", standby_provider)
-            })?;
+            .provider(&standby_provider)
+            .context("standby provider")?;
+
+        // Refuse a blocklisted side before either is provisioned.
+        self.ensure_region_allowed(primary_prov, primary_region)?;
+        self.ensure_region_allowed(standby_prov, standby_region)?;
+        self.admit(requested_by, 2)?;
 
         // Provision concurrently
         let (primary_result, standby_result) = tokio::join!(
-            primary_prov.provision(&primary_req),
-            standby_prov.provision(&standby_req),
+            self.provision_one(primary_prov, &primary_req),
+            self.provision_one(standby_prov, &standby_req),
         );
 
-        let primary_result = primary_result.context("primary provisioning failed")?;
-        let standby_result = standby_result.context("standby provisioning failed")?;
+        let mut primary_result = match primary_result {
+            Ok(result) => result,
+            // The error cannot carry the standby back, so never leave it running.
+            Err(e) => {
+                let e = e.context("primary provisioning failed");
+                let Some(standby) = standby_result.ok().filter(is_provisioned) else {
+                    return Err(e);
+                };
+                return Err(
+                    match self
+                        .rollback_instance(standby_prov, &standby, account_id)
+                        .await
+                    {
+                        Ok(instance) => e.context(format!("standby {instance} rolled back")),
+                        Err(rollback) => e.context(format!(
+                            "rollback of standby on {standby_provider} failed: {rollback:#}"
+                        )),
+                    },
+                );
+            }
+        };
+        let (mut standby_result, mut error) = match standby_result {
+            Ok(result) if is_provisioned(&result) => (Some(result), None),
+            Ok(result) => {
+                let e = result
+                    .error
+                    .unwrap_or_else(|| "standby provisioning failed".to_string());
+                (None, Some(e))
+            }
+            Err(e) => (None, Some(format!("standby provisioning failed: {e:#}"))),
+        };
 
-        info!(
-            pair_id = %pair_id,
-            primary = ?primary_result.instance_id,
-            standby = ?standby_result.instance_id,
-            "pair provisioned"
-        );
+        if let Some(standby_error) = error
+            .clone()
+            .filter(|_| self.rollback_on_partial_failure && is_provisioned(&primary_result))
+        {
+            let partial = InstancePair {
+                pair_id: pair_id.clone(),
+                account_id: account_id.to_string(),
+                tier,
+                primary: primary_result.clone(),
+                primary_provider,
+                primary_region: primary_region.to_string(),
+                standby: None,
+                standby_provider,
+                standby_region: standby_region.to_string(),
+                os_image,
+                error: Some(standby_error.clone()),
+                provisioned_at: now,
+                dry_run: self.dry_run,
+            };
+            match self
+                .provision_standby(&partial, standby_provider, standby_region, node)
+                .await
+            {
+                Ok(completed) => {
+                    standby_result = completed.standby;
+                    error = None;
+                }
+                Err(retry_error) => {
+                    let standby_error = format!("{standby_error}; retry failed: {retry_error:#}");
+                    let rollback = self
+                        .rollback_instance(primary_prov, &primary_result, account_id)
+                        .await;
+                    let message = match &rollback {
+                        Ok(instance) => format!("{standby_error}; primary {instance} rolled back"),
+                        Err(e) => format!(
+                            "{standby_error}; rollback of primary on {primary_provider} failed: {e:#}"
+                        ),
+                    };
+                    if rollback.is_ok() {
+                        primary_result = ProvisionResult {
+                            instance_id: None,
+                            success: false,
+                            error: Some(message.clone()),
+                            instance_ip: None,
+                            tailscale_ip: None,
+                            provider_instance_id: None,
+                            ..primary_result
+                        };
+                    }
+                    error = Some(message);
+                }
+            }
+        }
+
+        // The mirror case: the standby is up but the primary is not.
+        if !is_provisioned(&primary_result)
+            && let Some(standby) = standby_result.clone().filter(is_provisioned)
+        {
+            let primary_error = primary_result
+                .error
+                .clone()
+                .unwrap_or_else(|| "primary provisioning failed".to_string());
+            error = Some(if !self.rollback_on_partial_failure {
+                primary_error
+            } else {
+                match self
+                    .rollback_instance(standby_prov, &standby, account_id)
+                    .await
+                {
+                    Ok(instance) => {
+                        standby_result = None;
+                        format!("{primary_error}; standby {instance} rolled back")
+                    }
+                    Err(e) => format!(
+                        "{primary_error}; rollback of standby on {standby_provider} failed: {e:#}"
+                    ),
+                }
+            });
+        }
+
+        match &error {
+            None => info!(
+                pair_id = %pair_id,
+                primary = ?primary_result.instance_id,
+                standby = ?standby_result.as_ref().and_then(|s| s.instance_id.as_ref()),
+                "pair provisioned"
+            ),
+            Some(e) if !is_provisioned(&primary_result) => warn!(
+                pair_id = %pair_id,
+                error = %e,
+                "pair not provisioned"
+            ),
+            Some(e) => warn!(
+                pair_id = %pair_id,
+                primary = ?primary_result.instance_id,
+                error = %e,
+                "pair provisioned without standby"
+            ),
+        }
 
         Ok(InstancePair {
             pair_id,
@@ -510,38 +2019,179 @@ impl<'a> FleetProvisioner<'a> {
             standby: standby_result,
             standby_provider,
             standby_region: standby_region.to_string(),
+            os_image,
+            error,
             provisioned_at: now,
+            dry_run: self.dry_run,
         })
     }
 
-    /// Provision multiple standalone instances (not paired).
+    /// Tear down one half of a pair whose other half could not be
+    /// provisioned, returning its provider instance id.
+    async fn rollback_instance(
+        &self,
+        provider: &dyn Provider,
+        result: &ProvisionResult,
+        account_id: &str,
+    ) -> Result<String> {
+        let Some(instance) = result.provider_instance_id.clone() else {
+            bail!("no provider instance id for {:?}", result.instance_id);
+        };
+        if !self.dry_run {
+            provider
+                .teardown(&instance, account_id)
+                .await
+                .with_context(|| format!("teardown of {instance} failed"))?;
+        }
+        Ok(instance)
+    }
+
+    /// Provision a lone primary with no standby, for accounts (trial, nano)
+    /// that do not warrant paying for HA.
+    pub async fn provision_single(
+        &self,
+        account_id: &str,
+        tier: InstanceTier,
+        provider: VpsProvider,
+        region: &str,
+        os_image: OsImage,
+        node: &AccountNodeConfig,
+    ) -> Result<ProvisionResult> {
+        let prov = self.provider(&provider)?;
+
+        let req = ProvisionRequest {
+            request_id: Uuid::new_v4(),
+            account_id: account_id.to_string(),
+            tier,
+            role: InstanceRole::Primary,
+            provider,
+            region: region.to_string(),
+            os_image,
+            pair_instance_id: None,
+            openclaw_config: Some(node.openclaw_config(InstanceRole::Primary, false)),
+            requested_by: "forge:provision_single".to_string(),
+            requested_at: Utc::now(),
+        };
+        self.admit(&req.requested_by, 1)?;
+
+        let result = self
+            .provision_one(prov, &req)
+            .await
+            .context("single-instance provisioning failed")?;
+        info!(
+            account_id,
+            instance = ?result.instance_id,
+            "single instance provisioned (no standby)"
+        );
+        Ok(result)
+    }
+
+    /// Provision the missing standby for a pair whose primary is up.
     ///
-    /// PRD safety: no more than 100 without rolling validation.
-    pub async fn bulk_provision(
+    /// The standby is linked to the existing primary via `pair_instance_id`
+    /// and boots with the same `node` settings as the primary. Calling this on
+    /// an already-complete pair returns it unchanged.
+    pub async fn complete_pair(
         &self,
-        requests: Vec<ProvisionRequest>,
-    ) -> Result<BulkProvisionResult> {
-        if requests.len() > 100 {
+        partial: &InstancePair,
+        standby_provider: VpsProvider,
+        standby_region: &str,
+        node: &AccountNodeConfig,
+    ) -> Result<InstancePair> {
+        if partial.is_complete() {
+            return Ok(partial.clone());
+        }
+        if !is_provisioned(&partial.primary) {
             bail!(
-                "bulk_provision: refusing to provision {} instances at once (max 100 per PRD safety rule)",
-                requests.len()
+                "cannot complete pair {}: primary is not provisioned",
+                partial.pair_id
             );
         }
+        self.admit("forge:complete_pair", 1)?;
+        self.provision_standby(partial, standby_provider, standby_region, node)
+            .await
+    }
 
-        let total = requests.len() as u32;
-        let mut results = Vec::with_capacity(requests.len());
-        let mut succeeded = 0u32;
-        let mut failed = 0u32;
+    /// The standby half of `complete_pair`, without the admission check.
+    async fn provision_standby(
+        &self,
+        partial: &InstancePair,
+        standby_provider: VpsProvider,
+        standby_region: &str,
+        node: &AccountNodeConfig,
+    ) -> Result<InstancePair> {
+        let provider = self
+            .provider(&standby_provider)
+            .context("standby provider")?;
 
-        for req in &requests {
-            let provider_name_str = provider_name(&req.provider);
-            match self.registry.get(provider_name_str) {
-                Some(provider) => match provider.provision(req).await {
-                    Ok(result) => {
-                        if result.success {
-                            succeeded += 1;
-                        } else {
-                            failed += 1;
+        let standby_req = ProvisionRequest {
+            request_id: Uuid::new_v4(),
+            account_id: partial.account_id.clone(),
+            tier: partial.tier,
+            role: InstanceRole::Standby,
+            provider: standby_provider,
+            region: standby_region.to_string(),
+            os_image: partial.os_image,
+            pair_instance_id: partial.primary.instance_id.clone(),
+            openclaw_config: Some(node.openclaw_config(InstanceRole::Standby, true)),
+            requested_by: "forge:complete_pair".to_string(),
+            requested_at: Utc::now(),
+        };
+
+        let standby = self
+            .provision_one(provider, &standby_req)
+            .await
+            .context("standby provisioning failed")?;
+        if !is_provisioned(&standby) {
+            bail!(
+                "standby provisioning failed: {}",
+                standby.error.as_deref().unwrap_or("no instance returned")
+            );
+        }
+
+        info!(
+            pair_id = %partial.pair_id,
+            standby = ?standby.instance_id,
+            "pair completed with new standby"
+        );
+
+        Ok(InstancePair {
+            standby: Some(standby),
+            standby_provider,
+            standby_region: standby_region.to_string(),
+            error: None,
+            ..partial.clone()
+        })
+    }
+
+    /// Provision multiple standalone instances (not paired).
+    ///
+    /// PRD safety: no more than 100 without rolling validation.
+    pub async fn bulk_provision(
+        &self,
+        requests: Vec<ProvisionRequest>,
+    ) -> Result<BulkProvisionResult> {
+        if requests.len() > 100 {
+            bail!(ProvisionError::Refused(format!(
+                "provision {} instances at once (max 100 per PRD safety rule)",
+                requests.len()
+            )));
+        }
+
+        let total = requests.len() as u32;
+        let mut results = Vec::with_capacity(requests.len());
+        let mut succeeded = 0u32;
+        let mut failed = 0u32;
+
+        for req in &requests {
+            let provider_name_str = provider_name(&req.provider);
+            match self.registry.get(provider_name_str) {
+                Some(provider) => match self.admit_and_provision(provider, req).await {
+                    Ok(result) => {
+                        if result.success {
+                            succeeded += 1;
+                        } else {
+                            failed += 1;
                         }
                         results.push(result);
                     }
@@ -591,7 +2241,15 @@ impl<'a> FleetProvisioner<'a> {
     ///
     /// PRD safety: both teardowns are attempted; errors are collected.
     /// NEVER called without an audit record having been written first.
-    pub async fn teardown_pair(&self, pair: &InstancePair) -> Result<(bool, Vec<String>)> {
+    pub async fn teardown_pair(
+        &self,
+        pair: &InstancePair,
+        requested_by: &str,
+    ) -> Result<(bool, Vec<String>)> {
+        if self.dry_run {
+            info!(pair_id = %pair.pair_id, "dry run: skipping pair teardown");
+            return Ok((true, Vec::new()));
+        }
         let mut errors = Vec::new();
 
         // Teardown primary
@@ -601,7 +2259,15 @@ impl<'a> FleetProvisioner<'a> {
             .as_deref()
             .unwrap_or_default();
         if let Some(provider) = self.registry.get(provider_name(&pair.primary_provider)) {
-            if let Err(e) = provider.teardown(primary_id, &pair.account_id).await {
+            let outcome = provider.teardown(primary_id, &pair.account_id).await;
+            self.record_teardown(
+                &pair.primary_provider,
+                primary_id,
+                &pair.account_id,
+                requested_by,
+                outcome.as_ref().err(),
+            );
+            if let Err(e) = outcome {
                 errors.push(format!("primary teardown failed: {e}"));
             }
         } else {
@@ -611,21 +2277,27 @@ impl<'a> FleetProvisioner<'a> {
             ));
         }
 
-        // Teardown standby
-        let standby_id = pair
-            .standby
-            .provider_instance_id
-            .as_deref()
-            .unwrap_or_default();
-        if let Some(provider) = self.registry.get(provider_name(&pair.standby_provider)) {
-            if let Err(e) = provider.teardown(standby_id, &pair.account_id).await {
-                errors.push(format!("standby teardown failed: {e}"));
+        // Teardown standby (absent if standby provisioning never succeeded)
+        if let Some(standby) = &pair.standby {
+            let standby_id = standby.provider_instance_id.as_deref().unwrap_or_default();
+            if let Some(provider) = self.registry.get(provider_name(&pair.standby_provider)) {
+                let outcome = provider.teardown(standby_id, &pair.account_id).await;
+                self.record_teardown(
+                    &pair.standby_provider,
+                    standby_id,
+                    &pair.account_id,
+                    requested_by,
+                    outcome.as_ref().err(),
+                );
+                if let Err(e) = outcome {
+                    errors.push(format!("standby teardown failed: {e}"));
+                }
+            } else {
+                errors.push(format!(
+                    "standby provider '{}' not registered",
+                    pair.standby_provider
+                ));
             }
-        } else {
-            errors.push(format!(
-                "standby provider '{}' not registered",
-                pair.standby_provider
-            ));
         }
 
         let success = errors.is_empty();
@@ -637,10 +2309,347 @@ impl<'a> FleetProvisioner<'a> {
 
         Ok((success, errors))
     }
+
+    fn provider(&self, provider: &VpsProvider) -> Result<&dyn Provider> {
+        self.registry
+            .get(provider_name(provider))
+            .ok_or_else(|| ProvisionError::NotRegistered(provider.to_string()).into())
+    }
+
+    fn record_teardown(
+        &self,
+        provider: &VpsProvider,
+        provider_instance_id: &str,
+        account_id: &str,
+        requested_by: &str,
+        error: Option<&anyhow::Error>,
+    ) {
+        self.record_operation(
+            "teardown",
+            provider,
+            provider_instance_id,
+            requested_by,
+            serde_json::json!({ "account_id": account_id }),
+            error,
+        );
+    }
+
+    /// Resize a single instance in place, on behalf of `requested_by`.
+    pub async fn resize_instance(
+        &self,
+        provider: VpsProvider,
+        provider_instance_id: &str,
+        new_tier: &InstanceTier,
+        requested_by: &str,
+    ) -> Result<ResizeResult> {
+        let backend = self.provider(&provider)?;
+        if self.dry_run {
+            info!(instance = %provider_instance_id, "dry run: skipping provider resize");
+            return Ok(ResizeResult {
+                instance_id: provider_instance_id.to_string(),
+                old_tier: *new_tier,
+                new_tier: *new_tier,
+                downtime_seconds: 0,
+                completed_at: Utc::now(),
+            });
+        }
+        let outcome = backend
+            .resize(provider_instance_id, new_tier)
+            .await
+            .with_context(|| format!("resize of {provider_instance_id} on {provider} failed"));
+        let details = match &outcome {
+            Ok(r) => serde_json::json!({
+                "old_tier": r.old_tier,
+                "new_tier": r.new_tier,
+                "downtime_seconds": r.downtime_seconds,
+            }),
+            Err(_) => serde_json::json!({ "new_tier": new_tier }),
+        };
+        self.record_operation(
+            "resize",
+            &provider,
+            provider_instance_id,
+            requested_by,
+            details,
+            outcome.as_ref().err(),
+        );
+        outcome
+    }
+
+    /// Expected downtime of resizing one instance on `provider`.
+    ///
+    /// Unregistered providers get the offline estimate.
+    pub fn estimated_resize_downtime_secs(&self, provider: VpsProvider) -> u32 {
+        match self.provider(&provider) {
+            Ok(p) if p.supports_live_resize() => LIVE_RESIZE_DOWNTIME_SECS,
+            _ => OFFLINE_RESIZE_DOWNTIME_SECS,
+        }
+    }
+
+    /// Teardown a single instance on behalf of `requested_by`.
+    ///
+    /// NEVER called without an audit record having been written first.
+    pub async fn teardown_instance(
+        &self,
+        provider: VpsProvider,
+        provider_instance_id: &str,
+        account_id: &str,
+        requested_by: &str,
+    ) -> Result<()> {
+        let backend = self.provider(&provider)?;
+        if self.dry_run {
+            info!(instance = %provider_instance_id, "dry run: skipping provider teardown");
+            return Ok(());
+        }
+        let outcome = backend
+            .teardown(provider_instance_id, account_id)
+            .await
+            .with_context(|| format!("teardown of {provider_instance_id} on {provider} failed"));
+        self.record_teardown(
+            &provider,
+            provider_instance_id,
+            account_id,
+            requested_by,
+            outcome.as_ref().err(),
+        );
+        outcome
+    }
+
+    /// Power off a single instance, keeping its disk.
+    ///
+    /// Rejected up front if the provider does not advertise `hibernate`.
+    pub async fn hibernate_instance(
+        &self,
+        provider: VpsProvider,
+        provider_instance_id: &str,
+    ) -> Result<()> {
+        let backend = self.provider(&provider)?;
+        if !backend.capabilities().hibernate {
+            bail!("hibernate is not supported by provider '{provider}'");
+        }
+        if self.dry_run {
+            info!(instance = %provider_instance_id, "dry run: skipping provider hibernate");
+            return Ok(());
+        }
+        backend
+            .hibernate(provider_instance_id)
+            .await
+            .with_context(|| format!("hibernate of {provider_instance_id} on {provider} failed"))
+    }
+
+    /// Move an instance to `req.provider`: provision the replacement,
+    /// snapshot the original where its provider supports it, then tear the
+    /// original down. The original is left untouched if the replacement or
+    /// the snapshot fails.
+    pub async fn migrate_instance(
+        &self,
+        req: &ProvisionRequest,
+        from_provider: VpsProvider,
+        from_provider_instance_id: &str,
+    ) -> Result<MigrationResult> {
+        let source = self.provider(&from_provider)?;
+        let replacement = self
+            .admit_and_provision(self.provider(&req.provider)?, req)
+            .await
+            .context("migration target provisioning failed")?;
+        if !is_provisioned(&replacement) {
+            bail!(
+                "migration target provisioning failed: {}",
+                replacement
+                    .error
+                    .as_deref()
+                    .unwrap_or("no instance returned")
+            );
+        }
+
+        let snapshot_id = if source.capabilities().snapshot && !self.dry_run {
+            let id = source
+                .snapshot(from_provider_instance_id)
+                .await
+                .with_context(|| {
+                    format!(
+                        "replacement {} is up but the original could not be snapshotted",
+                        replacement.instance_id.as_deref().unwrap_or_default()
+                    )
+                })?;
+            Some(id)
+        } else {
+            None
+        };
+
+        self.teardown_instance(
+            from_provider,
+            from_provider_instance_id,
+            &req.account_id,
+            &req.requested_by,
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "replacement {} is up but the original was not removed",
+                replacement.instance_id.as_deref().unwrap_or_default()
+            )
+        })?;
+
+        info!(
+            account_id = %req.account_id,
+            from = %from_provider,
+            to = %req.provider,
+            snapshot = ?snapshot_id,
+            "instance migrated"
+        );
+        Ok(MigrationResult {
+            replacement,
+            snapshot_id,
+        })
+    }
+}
+
+/// Outcome of `FleetProvisioner::migrate_instance`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationResult {
+    pub replacement: ProvisionResult,
+    /// Snapshot of the original taken before teardown; `None` when its
+    /// provider cannot snapshot (or in a dry run).
+    pub snapshot_id: Option<String>,
+}
+
+/// Provision after checking the provider offers the requested OS image.
+async fn provision_checked(
+    provider: &dyn Provider,
+    req: &ProvisionRequest,
+) -> Result<ProvisionResult> {
+    ensure_image_supported(provider, req)?;
+    provider.provision(req).await
+}
+
+fn ensure_image_supported(provider: &dyn Provider, req: &ProvisionRequest) -> Result<()> {
+    if !provider.supported_images().contains(&req.os_image) {
+        bail!(
+            "OS image {} is not offered by provider '{}'",
+            req.os_image,
+            provider.name()
+        );
+    }
+    Ok(())
 }
 
 // ─── Cloud-init bootstrap script ──────────────────────────────────────────────
 
+/// Serialization format for a config embedded in a bootstrap script.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigFormat {
+    /// Read by clawnode from `/etc/clawnode/config.json`.
+    #[default]
+    Json,
+}
+
+/// The clawnode config written to a freshly provisioned instance.
+///
+/// Field names match clawnode's own `NodeConfig`, so the file it boots with
+/// parses without translation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeConfig {
+    pub instance_id: String,
+    pub account_id: String,
+    pub gateway: String,
+    pub token: String,
+    pub hostname: String,
+    pub provider: String,
+    pub region: String,
+    pub tier: String,
+    pub role: InstanceRole,
+    pub pair_instance_id: Option<String>,
+    pub state_path: String,
+    pub heartbeat_interval_secs: u64,
+}
+
+impl NodeConfig {
+    /// Serialize for embedding in a quoted heredoc. Every value is escaped by
+    /// the serializer, so no field can break out of the document.
+    pub fn to_embedded(&self, format: ConfigFormat) -> Result<String> {
+        match format {
+            ConfigFormat::Json => {
+                serde_json::to_string_pretty(self).context("failed to serialize node config")
+            }
+        }
+    }
+
+    pub fn from_embedded(data: &str, format: ConfigFormat) -> Result<Self> {
+        match format {
+            ConfigFormat::Json => {
+                serde_json::from_str(data).context("failed to parse embedded node config")
+            }
+        }
+    }
+}
+
+/// Break-glass SSH keys listed under `ssh_authorized_keys` in an account's
+/// `openclaw_config`.
+fn ssh_keys_from_config(config: Option<&serde_json::Value>) -> Vec<String> {
+    config
+        .and_then(|c| c["ssh_authorized_keys"].as_array())
+        .map(|keys| {
+            keys.iter()
+                .filter_map(|k| k.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// True for a single-line OpenSSH public key: a known key type, a base64
+/// body and an optional plain comment.
+fn is_valid_ssh_public_key(key: &str) -> bool {
+    let mut parts = key.split(' ').filter(|p| !p.is_empty());
+    let (Some(key_type), Some(body)) = (parts.next(), parts.next()) else {
+        return false;
+    };
+    let known_type = matches!(key_type, "ssh-ed25519" | "ssh-rsa")
+        || key_type.starts_with("ecdsa-sha2-")
+            && key_type["ecdsa-sha2-".len()..]
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric());
+    let base64_body = body
+        .trim_end_matches('=')
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '/');
+    let plain_comment = parts.all(|word| {
+        word.chars()
+            .all(|c| c.is_ascii_alphanumeric() || "@._+-:".contains(c))
+    });
+    known_type && base64_body && plain_comment
+}
+
+/// Shell block appending `keys` to root's `authorized_keys`; empty when there
+/// are no usable keys. Anything that is not a well-formed public key is
+/// dropped, so no entry can end the heredoc early or smuggle in a command.
+fn ssh_keys_block(keys: &[String]) -> String {
+    let keys: Vec<&str> = keys
+        .iter()
+        .map(|k| k.trim())
+        .filter(|k| {
+            let valid = is_valid_ssh_public_key(k);
+            if !valid && !k.is_empty() {
+                warn!("dropping malformed SSH key from account config");
+            }
+            valid
+        })
+        .collect();
+    if keys.is_empty() {
+        return String::new();
+    }
+    format!(
+        "# Break-glass SSH access\n\
+         install -d -m 700 /root/.ssh\n\
+         cat >> /root/.ssh/authorized_keys << 'SSH_KEYS_EOF'\n\
+         {}\n\
+         SSH_KEYS_EOF\n\
+         chmod 600 /root/.ssh/authorized_keys\n",
+        keys.join("\n")
+    )
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn cloud_init_script(
     instance_id: &str,
@@ -652,21 +2661,36 @@ pub fn cloud_init_script(
     tier: &str,
     provider: &str,
     region: &str,
+    ssh_authorized_keys: &[String],
 ) -> String {
-    let role_str = match role {
-        InstanceRole::Primary => "primary",
-        InstanceRole::Standby => "standby",
+    let node_config = NodeConfig {
+        instance_id: instance_id.to_string(),
+        account_id: account_id.to_string(),
+        gateway: gateway_url.to_string(),
+        token: api_key.to_string(),
+        hostname: format!("co-{instance_id}"),
+        provider: provider.to_string(),
+        region: region.to_string(),
+        tier: tier.to_string(),
+        role: *role,
+        pair_instance_id: pair_instance_id.map(str::to_string),
+        state_path: "/var/lib/clawnode".to_string(),
+        heartbeat_interval_secs: 30,
     };
-    let pair_id = pair_instance_id.unwrap_or("");
+    // Serializing a struct of plain strings and integers cannot fail.
+    let config_json = node_config
+        .to_embedded(ConfigFormat::Json)
+        .unwrap_or_default();
     let tailscale_auth_key = std::env::var("TAILSCALE_AUTH_KEY").unwrap_or_default();
     let clawnode_url = std::env::var("CLAWNODE_BINARY_URL").unwrap_or_else(|_| {
         "https://releases.clawops.io/clawnode/latest/clawnode-linux-amd64".to_string()
     });
+    let ssh_keys = ssh_keys_block(ssh_authorized_keys);
 
     format!(
         r#"#!/bin/bash
 set -euo pipefail
-export DEBIAN_FRONTEND=noninteractive
+{ssh_keys}export DEBIAN_FRONTEND=noninteractive
 apt-get update -qq
 apt-get install -y -qq curl wget ca-certificates gnupg lsb-release
 # Docker
@@ -683,18 +2707,7 @@ mkdir -p /usr/local/bin /etc/clawnode /var/log/clawnode
 wget -q -O /usr/local/bin/clawnode "{clawnode_url}"
 chmod +x /usr/local/bin/clawnode
 cat > /etc/clawnode/config.json << 'CONFIG_EOF'
-{{
-  "instance_id": "{instance_id}",
-  "account_id": "{account_id}",
-  "gateway": "{gateway_url}",
-  "token": "{api_key}",
-  "provider": "{provider}",
-  "region": "{region}",
-  "tier": "{tier}",
-  "role": "{role_str}",
-  "pair_instance_id": "{pair_id}",
-  "heartbeat_interval_secs": 30
-}}
+{config_json}
 CONFIG_EOF
 cat > /etc/systemd/system/clawnode.service << 'SERVICE_EOF'
 [Unit]
@@ -720,12 +2733,11 @@ echo "clawnode bootstrap complete - instance {instance_id}"
 
 // ─── Hetzner provider ─────────────────────────────────────────────────────────
 
-fn hetzner_server_type(tier: &InstanceTier) -> &'static str {
-    match tier {
-        InstanceTier::Nano => "cx11",
-        InstanceTier::Standard => "cx21",
-        InstanceTier::Pro => "cx31",
-        InstanceTier::Enterprise => "cx41",
+fn hetzner_image(os: OsImage) -> &'static str {
+    match os {
+        OsImage::Ubuntu2204 => "ubuntu-22.04",
+        OsImage::Ubuntu2404 => "ubuntu-24.04",
+        OsImage::Debian12 => "debian-12",
     }
 }
 
@@ -735,15 +2747,50 @@ fn hetzner_location(region_id: &str) -> &'static str {
         "eu-hetzner-hel1" => "hel1",
         "eu-hetzner-fsn1" => "fsn1",
         "us-hetzner-ash" => "ash",
+        "ap-hetzner-sin" => "sin",
         _ => "nbg1",
     }
 }
 
+/// Ownership of a server id, checked before it is deleted.
+enum Ownership {
+    Gone,
+    Ours,
+    /// Exists but is not a ClawOps server for this account.
+    Foreign(String),
+}
+
+/// Default time a new server may take to reach `running`.
+pub const DEFAULT_PROVISION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(600);
+/// Longest gap between boot status polls.
+const MAX_BOOT_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Delay before boot status poll `attempt`: 1s, 2s, 4s, 8s, then every 10s,
+/// so fast boots are seen quickly without hammering the API on slow ones.
+fn boot_poll_interval(attempt: u32) -> std::time::Duration {
+    std::time::Duration::from_secs(1u64 << attempt.min(4)).min(MAX_BOOT_POLL_INTERVAL)
+}
+
+/// Servers a Hetzner project may run unless the account's limit was raised.
+pub const HETZNER_DEFAULT_SERVER_LIMIT: u32 = 100;
+
+/// How long a Hetzner quota reading is reused by `provider_health`.
+const HETZNER_QUOTA_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(60);
+
 #[derive(Debug)]
 pub struct HetznerProvider {
     api_token: String,
     base_url: String,
     client: reqwest::Client,
+    retry_policy: RetryPolicy,
+    rng: Arc<dyn RandomSource>,
+    trace_http: bool,
+    server_limit: u32,
+    /// Last quota reading and when it was taken.
+    quota_cache: parking_lot::Mutex<Option<(std::time::Instant, f32)>>,
+    /// How long a new server may take to reach `running`.
+    provision_timeout: std::time::Duration,
+    sleeper: Arc<dyn Sleeper>,
 }
 
 impl HetznerProvider {
@@ -752,7 +2799,119 @@ impl HetznerProvider {
             api_token,
             base_url: "https://api.hetzner.cloud/v1".to_string(),
             client: build_client(),
+            retry_policy: RetryPolicy::default(),
+            rng: Arc::new(SeededRng::from_entropy()),
+            trace_http: false,
+            server_limit: HETZNER_DEFAULT_SERVER_LIMIT,
+            quota_cache: parking_lot::Mutex::new(None),
+            provision_timeout: DEFAULT_PROVISION_TIMEOUT,
+            sleeper: Arc::new(TokioSleeper),
+        }
+    }
+
+    /// Override how long `provision` waits for the server to boot.
+    pub fn with_provision_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.provision_timeout = timeout;
+        self
+    }
+
+    /// Override the sleeper used between boot status polls and rate-limit
+    /// retries.
+    pub fn with_sleeper(mut self, sleeper: Arc<dyn Sleeper>) -> Self {
+        self.sleeper = sleeper;
+        self
+    }
+
+    /// Set the account's server limit used to compute `quota_used_pct`.
+    pub fn with_server_limit(mut self, server_limit: u32) -> Self {
+        self.server_limit = server_limit;
+        self
+    }
+
+    /// Log every API request and response, with secrets redacted.
+    pub fn with_http_tracing(mut self, enabled: bool) -> Self {
+        self.trace_http = enabled;
+        self
+    }
+
+    /// Override the randomness used for retry jitter.
+    pub fn with_rng(mut self, rng: Arc<dyn RandomSource>) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    /// Override the retry policy used for teardown and rate-limit backoff.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Send a request built by `build`, rebuilding it after each 429 (or 503,
+    /// if idempotent).
+    async fn send(
+        &self,
+        build: impl Fn() -> reqwest::RequestBuilder,
+    ) -> reqwest::Result<reqwest::Response> {
+        send_with_rate_limit(
+            &self.retry_policy,
+            self.rng.as_ref(),
+            self.sleeper.as_ref(),
+            self.trace_http,
+            build,
+        )
+        .await
+    }
+
+    /// Send once, without the rate-limit retry, for callers that run their own
+    /// retry loop.
+    async fn send_once(
+        &self,
+        build: impl Fn() -> reqwest::RequestBuilder,
+    ) -> reqwest::Result<reqwest::Response> {
+        build().send_traced(self.trace_http).await
+    }
+
+    /// Send via [`Self::send`] and map a failed status to a [`ProviderError`].
+    async fn send_checked(
+        &self,
+        what: &str,
+        build: impl Fn() -> reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response> {
+        let resp = self
+            .send(build)
+            .await
+            .with_context(|| format!("Hetzner {what} request failed"))?;
+        check_provider_status(resp).with_context(|| format!("Hetzner {what} returned error status"))
+    }
+
+    /// Percentage of the server limit in use, cached for
+    /// `HETZNER_QUOTA_CACHE_TTL`. `None` if the server list could not be read.
+    async fn quota_used_pct(&self) -> Option<f32> {
+        if let Some((at, pct)) = *self.quota_cache.lock()
+            && at.elapsed() < HETZNER_QUOTA_CACHE_TTL
+        {
+            return Some(pct);
         }
+        let url = format!("{}/servers", self.base_url);
+        let body: serde_json::Value = self
+            .send_checked("GET /servers", || {
+                self.client
+                    .get(&url)
+                    .query(&[("per_page", "1")])
+                    .bearer_auth(&self.api_token)
+                    .timeout(std::time::Duration::from_secs(5))
+            })
+            .await
+            .ok()?
+            .json()
+            .await
+            .ok()?;
+        let servers = body["meta"]["pagination"]["total_entries"]
+            .as_u64()
+            .or_else(|| body["servers"].as_array().map(|s| s.len() as u64))?;
+        let pct = servers as f32 / self.server_limit.max(1) as f32 * 100.0;
+        *self.quota_cache.lock() = Some((std::time::Instant::now(), pct));
+        Some(pct)
     }
 
     fn regions() -> Vec<Region> {
@@ -797,20 +2956,33 @@ impl HetznerProvider {
                 available: true,
                 latency_class: LatencyClass::Medium,
             },
+            Region {
+                id: "ap-hetzner-sin".to_string(),
+                display_name: "Hetzner Singapore".to_string(),
+                city: "Singapore".to_string(),
+                country: "SG".to_string(),
+                continent: Continent::APAC,
+                provider: VpsProvider::Hetzner,
+                available: true,
+                latency_class: LatencyClass::High,
+            },
         ]
     }
 
     async fn wait_for_server_running(&self, server_id: u64) -> Result<()> {
         let url = format!("{}/servers/{}", self.base_url, server_id);
-        for attempt in 0..120 {
-            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+        let mut waited = std::time::Duration::ZERO;
+        let mut attempt = 0;
+        while waited < self.provision_timeout {
+            let delay = boot_poll_interval(attempt).min(self.provision_timeout - waited);
+            self.sleeper.sleep(delay).await;
+            waited += delay;
+            attempt += 1;
             let resp: serde_json::Value = self
-                .client
-                .get(&url)
-                .bearer_auth(&self.api_token)
-                .send()
+                .send_checked("GET /servers/{id}", || {
+                    self.client.get(&url).bearer_auth(&self.api_token)
+                })
                 .await?
-                .error_for_status()?
                 .json()
                 .await?;
 
@@ -829,7 +3001,8 @@ impl HetznerProvider {
             }
         }
         bail!(
-            "timeout waiting for Hetzner server {} to reach running state",
+            "timeout after {}s waiting for Hetzner server {} to reach running state",
+            self.provision_timeout.as_secs(),
             server_id
         )
     }
@@ -845,14 +3018,10 @@ impl HetznerProvider {
                 self.base_url, page
             );
             let resp: HetznerListServersResponse = self
-                .client
-                .get(&url)
-                .bearer_auth(&self.api_token)
-                .send()
-                .await
-                .context("Hetzner GET /servers request failed")?
-                .error_for_status()
-                .context("Hetzner GET /servers returned error status")?
+                .send_checked("GET /servers", || {
+                    self.client.get(&url).bearer_auth(&self.api_token)
+                })
+                .await?
                 .json()
                 .await
                 .context("Failed to parse Hetzner server list")?;
@@ -874,18 +3043,77 @@ impl HetznerProvider {
     pub async fn get_server(&self, server_id: u64) -> Result<HetznerServer> {
         let url = format!("{}/servers/{}", self.base_url, server_id);
         let resp: serde_json::Value = self
-            .client
-            .get(&url)
-            .bearer_auth(&self.api_token)
-            .send()
+            .send_checked("GET /servers/{id}", || {
+                self.client.get(&url).bearer_auth(&self.api_token)
+            })
             .await?
-            .error_for_status()?
             .json()
             .await?;
 
         serde_json::from_value(resp["server"].clone())
             .context("failed to deserialize Hetzner server")
     }
+
+    /// Check whether a server still exists (`false` on 404).
+    async fn server_exists(&self, server_id: u64) -> Result<bool> {
+        let url = format!("{}/servers/{}", self.base_url, server_id);
+        let resp = self
+            .send(|| self.client.get(&url).bearer_auth(&self.api_token))
+            .await
+            .context("Hetzner GET /servers/{id} request failed")?;
+        if resp.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        check_provider_status(resp).context("Hetzner GET /servers/{id} returned error status")?;
+        Ok(true)
+    }
+
+    /// Who a server id currently belongs to, from its labels (`Gone` on 404).
+    /// Sent once: the teardown loop does the retrying.
+    async fn server_ownership(&self, server_id: u64, account_id: &str) -> Result<Ownership> {
+        let url = format!("{}/servers/{}", self.base_url, server_id);
+        let resp = self
+            .send_once(|| self.client.get(&url).bearer_auth(&self.api_token))
+            .await
+            .context("Hetzner GET /servers/{id} request failed")?;
+        if resp.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(Ownership::Gone);
+        }
+        let body: serde_json::Value = check_provider_status(resp)
+            .context("Hetzner GET /servers/{id} returned error status")?
+            .json()
+            .await
+            .context("failed to parse Hetzner server")?;
+        let labels = &body["server"]["labels"];
+        let label = |key: &str| labels[key].as_str().unwrap_or("<unset>").to_string();
+        Ok(if label("managed_by") != "clawops" {
+            Ownership::Foreign(format!(
+                "managed_by label is '{}', not 'clawops'",
+                label("managed_by")
+            ))
+        } else if label("account_id") != account_id {
+            Ownership::Foreign(format!(
+                "account_id label is '{}', expected '{account_id}'",
+                label("account_id")
+            ))
+        } else {
+            Ownership::Ours
+        })
+    }
+
+    /// Issue a single DELETE; a 404 means the server is already gone.
+    async fn delete_server(&self, provider_instance_id: &str) -> Result<()> {
+        let url = format!("{}/servers/{}", self.base_url, provider_instance_id);
+        let resp = self
+            .send_once(|| self.client.delete(&url).bearer_auth(&self.api_token))
+            .await
+            .context("Hetzner DELETE /servers request failed")?;
+        if resp.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(());
+        }
+        check_provider_status(resp).context("Hetzner DELETE /servers returned error status")?;
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -898,30 +3126,42 @@ impl Provider for HetznerProvider {
         let start = std::time::Instant::now();
         info!(account_id = %req.account_id, region = %req.region, tier = ?req.tier, "provisioning Hetzner instance");
 
-        let server_type = hetzner_server_type(&req.tier);
+        let server_type = req.tier.hetzner_server_type();
         let location = hetzner_location(&req.region);
         let server_name = format!("co-{}-{}", req.account_id, &req.request_id.to_string()[..8]);
         let instance_id = Uuid::new_v4().to_string();
 
+        // The account's own settings win; the environment covers requests
+        // built without them.
+        let config = req.openclaw_config.as_ref();
+        let config_or_env = |key: &str, var: &str| {
+            config
+                .and_then(|c| c[key].as_str())
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+                .unwrap_or_else(|| std::env::var(var).unwrap_or_default())
+        };
         let user_data = cloud_init_script(
             &instance_id,
             &req.account_id,
-            &std::env::var("CLAWOPS_GATEWAY_URL").unwrap_or_default(),
-            &std::env::var("CLAWOPS_API_KEY").unwrap_or_default(),
+            &config_or_env("gateway_url", "CLAWOPS_GATEWAY_URL"),
+            &config_or_env("auth_token", "CLAWOPS_API_KEY"),
             &req.role,
             req.pair_instance_id.as_deref(),
             &req.tier.to_string(),
             "hetzner",
             &req.region,
+            &ssh_keys_from_config(config),
         );
 
         let body = serde_json::json!({
             "name": server_name,
             "server_type": server_type,
             "location": location,
-            "image": "ubuntu-22.04",
+            "image": hetzner_image(req.os_image),
             "user_data": user_data,
             "labels": {
+                INSTANCE_ID_LABEL: instance_id,
                 "account_id": req.account_id,
                 "tier": req.tier.to_string(),
                 "role": req.role.to_string(),
@@ -930,16 +3170,21 @@ impl Provider for HetznerProvider {
             "start_after_create": true,
         });
 
+        // Sent once: `provision_pair_with_retry` is the retry layer for
+        // provisioning and waits out a 429's Retry-After itself.
+        let url = format!("{}/servers", self.base_url);
         let resp: serde_json::Value = self
-            .client
-            .post(format!("{}/servers", self.base_url))
-            .bearer_auth(&self.api_token)
-            .json(&body)
-            .send()
-            .await
-            .context("Hetzner POST /servers request failed")?
-            .error_for_status()
-            .context("Hetzner POST /servers returned error status")?
+            .send_once(|| {
+                self.client
+                    .post(&url)
+                    .bearer_auth(&self.api_token)
+                    .json(&body)
+            })
+            .await
+            .context("Hetzner POST /servers request failed")
+            .and_then(|resp| {
+                check_provider_status(resp).context("Hetzner POST /servers returned error status")
+            })?
             .json()
             .await
             .context("failed to parse Hetzner server creation response")?;
@@ -973,19 +3218,74 @@ impl Provider for HetznerProvider {
             provider_instance_id,
             account_id, "tearing down Hetzner instance"
         );
-        self.client
-            .delete(format!(
-                "{}/servers/{}",
-                self.base_url, provider_instance_id
-            ))
-            .bearer_auth(&self.api_token)
-            .send()
-            .await
-            .context("Hetzner DELETE /servers request failed")?
-            .error_for_status()
-            .context("Hetzner DELETE /servers returned error status")?;
-        info!(provider_instance_id, "Hetzner instance deleted");
-        Ok(())
+
+        let server_id: u64 = provider_instance_id
+            .parse()
+            .with_context(|| format!("invalid Hetzner server id '{provider_instance_id}'"))?;
+
+        // Re-check ownership before every DELETE: after a dropped response the
+        // id may already be gone, or recycled for a server that is not ours.
+        let mut attempt = 0u32;
+        let last_err = loop {
+            let result = match self.server_ownership(server_id, account_id).await {
+                Ok(Ownership::Gone) => {
+                    info!(provider_instance_id, "Hetzner instance already gone");
+                    return Ok(());
+                }
+                Ok(Ownership::Foreign(reason)) => {
+                    bail!(ProvisionError::Refused(format!(
+                        "delete Hetzner server {provider_instance_id}: {reason}"
+                    )))
+                }
+                Ok(Ownership::Ours) => self.delete_server(provider_instance_id).await,
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(()) => {
+                    info!(provider_instance_id, "Hetzner instance deleted");
+                    return Ok(());
+                }
+                // Bad credentials: neither a retry nor the existence check can succeed.
+                Err(e) if is_auth_failure(&e) => {
+                    return Err(e.context(format!(
+                        "Hetzner rejected the credentials for deleting server {provider_instance_id}"
+                    )));
+                }
+                Err(e) if self.retry_policy.should_retry(attempt) => {
+                    let Some(delay) =
+                        provider_retry_delay(&self.retry_policy, attempt, self.rng.as_ref(), &e)
+                    else {
+                        break e;
+                    };
+                    warn!(
+                        provider_instance_id,
+                        attempt, "Hetzner delete failed, retrying: {e:#}"
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(e) => break e,
+            }
+        };
+
+        // Retries exhausted: the DELETE may still have gone through, so check
+        // whether the server is actually there before reporting failure.
+        match self.server_exists(server_id).await {
+            Ok(false) => {
+                info!(
+                    provider_instance_id,
+                    "Hetzner instance already gone after failed deletes"
+                );
+                Ok(())
+            }
+            Ok(true) => Err(last_err.context(format!(
+                "Hetzner server {provider_instance_id} still exists after {} delete attempts",
+                attempt + 1
+            ))),
+            Err(verify_err) => Err(last_err.context(format!(
+                "Hetzner delete failed and existence check also failed: {verify_err:#}"
+            ))),
+        }
     }
 
     async fn resize(
@@ -995,65 +3295,106 @@ impl Provider for HetznerProvider {
     ) -> Result<ResizeResult> {
         info!(provider_instance_id, tier = ?new_tier, "resizing Hetzner instance");
 
-        // Power off
-        self.client
-            .post(format!(
-                "{}/servers/{}/actions/poweroff",
+        let action_url = |action: &str| {
+            format!(
+                "{}/servers/{}/actions/{action}",
                 self.base_url, provider_instance_id
-            ))
-            .bearer_auth(&self.api_token)
-            .send()
-            .await?
-            .error_for_status()?;
+            )
+        };
+
+        // Power off
+        let url = action_url("poweroff");
+        self.send_checked("POST /servers/{id}/actions/poweroff", || {
+            self.client.post(&url).bearer_auth(&self.api_token)
+        })
+        .await?;
 
         tokio::time::sleep(tokio::time::Duration::from_secs(15)).await;
 
         // Change type
         let body = serde_json::json!({
-            "server_type": hetzner_server_type(new_tier),
+            "server_type": new_tier.hetzner_server_type(),
             "upgrade_disk": false,
         });
-        self.client
-            .post(format!(
-                "{}/servers/{}/actions/change_type",
-                self.base_url, provider_instance_id
-            ))
-            .bearer_auth(&self.api_token)
-            .json(&body)
-            .send()
-            .await?
-            .error_for_status()?;
+        let url = action_url("change_type");
+        self.send_checked("POST /servers/{id}/actions/change_type", || {
+            self.client
+                .post(&url)
+                .bearer_auth(&self.api_token)
+                .json(&body)
+        })
+        .await?;
 
         tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
 
         // Power back on
-        self.client
-            .post(format!(
-                "{}/servers/{}/actions/poweron",
-                self.base_url, provider_instance_id
-            ))
-            .bearer_auth(&self.api_token)
-            .send()
-            .await?
-            .error_for_status()?;
+        let url = action_url("poweron");
+        self.send_checked("POST /servers/{id}/actions/poweron", || {
+            self.client.post(&url).bearer_auth(&self.api_token)
+        })
+        .await?;
 
         Ok(ResizeResult {
             instance_id: provider_instance_id.to_string(),
             old_tier: InstanceTier::Standard, // Would be fetched from API in production
             new_tier: *new_tier,
-            downtime_seconds: 20,
+            downtime_seconds: OFFLINE_RESIZE_DOWNTIME_SECS,
             completed_at: Utc::now(),
         })
     }
 
+    async fn hibernate(&self, provider_instance_id: &str) -> Result<()> {
+        info!(
+            provider_instance_id,
+            "powering off Hetzner instance for archive"
+        );
+        let url = format!(
+            "{}/servers/{}/actions/poweroff",
+            self.base_url, provider_instance_id
+        );
+        self.send_checked("POST /servers/{id}/actions/poweroff", || {
+            self.client.post(&url).bearer_auth(&self.api_token)
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn snapshot(&self, provider_instance_id: &str) -> Result<String> {
+        info!(provider_instance_id, "snapshotting Hetzner instance");
+        let url = format!(
+            "{}/servers/{}/actions/create_image",
+            self.base_url, provider_instance_id
+        );
+        let body: serde_json::Value = self
+            .send_checked("POST /servers/{id}/actions/create_image", || {
+                self.client
+                    .post(&url)
+                    .bearer_auth(&self.api_token)
+                    .json(&serde_json::json!({
+                        "type": "snapshot",
+                        "description": format!("clawops {provider_instance_id}"),
+                    }))
+            })
+            .await?
+            .json()
+            .await
+            .context("failed to parse Hetzner create_image response")?;
+        body["image"]["id"]
+            .as_u64()
+            .map(|id| id.to_string())
+            .context("Hetzner create_image response has no image id")
+    }
+
     async fn provider_health(&self) -> Result<ProviderHealth> {
         let start = std::time::Instant::now();
+        let url = format!("{}/datacenters", self.base_url);
         let api_resp = self
-            .client
-            .get(format!("{}/datacenters", self.base_url))
-            .bearer_auth(&self.api_token)
-            .timeout(std::time::Duration::from_secs(5))
-            .send()
+            .send(|| {
+                self.client
+                    .get(&url)
+                    .bearer_auth(&self.api_token)
+                    .timeout(std::time::Duration::from_secs(5))
+            })
             .await;
 
         let api_reachable = api_resp.is_ok() && api_resp.as_ref().unwrap().status().is_success();
@@ -1068,6 +3409,23 @@ impl Provider for HetznerProvider {
         } else {
             50
         };
+        // An unreadable quota may mean the account is full, so the provider
+        // is degraded to fallback-only rather than assumed empty.
+        let quota = if api_reachable {
+            self.quota_used_pct().await
+        } else {
+            Some(0.0)
+        };
+        let (health_score, incident_description) = match quota {
+            Some(pct) => (quota_adjusted_health(health_score, pct), None),
+            None => {
+                warn!("Hetzner server quota could not be read; degrading provider health");
+                (
+                    health_score.min(FALLBACK_MIN_HEALTH),
+                    Some("server quota could not be read".to_string()),
+                )
+            }
+        };
 
         Ok(ProviderHealth {
             provider: VpsProvider::Hetzner,
@@ -1076,22 +3434,71 @@ impl Provider for HetznerProvider {
             provision_avg_ms: 252_000,
             provision_success_rate_7d: 0.99,
             active_incident: false,
-            incident_description: None,
-            quota_used_pct: 0.0,
+            incident_description,
+            quota_used_pct: quota.unwrap_or(0.0),
             checked_at: Utc::now(),
         })
     }
 
+    async fn list_managed_instances(&self) -> Result<Vec<ManagedInstance>> {
+        // `list_servers` already asks for `managed_by=clawops`; the mapping
+        // re-checks the label in case the selector is ever dropped.
+        Ok(self
+            .list_servers()
+            .await?
+            .iter()
+            .filter_map(HetznerServer::to_managed_instance)
+            .collect())
+    }
+
     fn supported_regions(&self) -> Vec<Region> {
         Self::regions()
     }
     fn supports_live_resize(&self) -> bool {
         false
     }
+
+    fn supported_images(&self) -> Vec<OsImage> {
+        vec![OsImage::Ubuntu2204, OsImage::Ubuntu2404, OsImage::Debian12]
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            live_resize: self.supports_live_resize(),
+            snapshot: true,
+            firewall: true,
+            floating_ip: true,
+            hibernate: true,
+            ipv6: true,
+        }
+    }
 }
 
 // ─── Hetzner API types ───────────────────────────────────────────────────────
 
+impl HetznerServer {
+    /// Provider-neutral view, or `None` if the server is not labelled
+    /// `managed_by=clawops`.
+    pub fn to_managed_instance(&self) -> Option<ManagedInstance> {
+        if self.labels.get("managed_by").map(String::as_str) != Some("clawops") {
+            return None;
+        }
+        Some(ManagedInstance {
+            instance_id: self.labels.get(INSTANCE_ID_LABEL).cloned(),
+            provider: VpsProvider::Hetzner,
+            provider_instance_id: self.id.to_string(),
+            name: self.name.clone(),
+            ip: self.public_net.ipv4.as_ref().map(|v4| v4.ip.clone()),
+            tier: self
+                .labels
+                .get("tier")
+                .and_then(|t| serde_json::from_value(serde_json::Value::String(t.clone())).ok()),
+            labels: self.labels.clone(),
+            status: self.status.clone(),
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HetznerServer {
     pub id: u64,
@@ -1143,10 +3550,63 @@ pub struct HetznerPagination {
 
 // ─── Vultr provider (stub) ────────────────────────────────────────────────────
 
+fn vultr_region(region_id: &str) -> &'static str {
+    match region_id {
+        "eu-vultr-ams" => "ams",
+        "us-vultr-ewr" => "ewr",
+        "ap-vultr-sgp" => "sgp",
+        "ap-vultr-nrt" => "nrt",
+        _ => "ams",
+    }
+}
+
 #[derive(Debug)]
 pub struct VultrProvider {
     api_key: String,
+    base_url: String,
     client: reqwest::Client,
+    retry_policy: RetryPolicy,
+    rng: Arc<dyn RandomSource>,
+    trace_http: bool,
+    sleeper: Arc<dyn Sleeper>,
+}
+
+impl VultrProvider {
+    pub fn new(api_key: String) -> Self {
+        Self {
+            api_key,
+            base_url: "https://api.vultr.com/v2".to_string(),
+            client: build_client(),
+            retry_policy: RetryPolicy::default(),
+            rng: Arc::new(SeededRng::from_entropy()),
+            trace_http: false,
+            sleeper: Arc::new(TokioSleeper),
+        }
+    }
+
+    /// Log every API request and response, with secrets redacted.
+    pub fn with_http_tracing(mut self, enabled: bool) -> Self {
+        self.trace_http = enabled;
+        self
+    }
+
+    /// Override the randomness used for retry jitter.
+    pub fn with_rng(mut self, rng: Arc<dyn RandomSource>) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    /// Override the retry policy used for rate-limit backoff.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Override the sleeper used between rate-limit retries.
+    pub fn with_sleeper(mut self, sleeper: Arc<dyn Sleeper>) -> Self {
+        self.sleeper = sleeper;
+        self
+    }
 }
 
 #[async_trait]
@@ -1156,20 +3616,32 @@ impl Provider for VultrProvider {
     }
 
     async fn provision(&self, req: &ProvisionRequest) -> Result<ProvisionResult> {
-        info!(account_id = %req.account_id, "provisioning Vultr instance (stub)");
+        info!(
+            account_id = %req.account_id,
+            region = vultr_region(&req.region),
+            "provisioning Vultr instance (stub)"
+        );
         bail!("Vultr provisioning not yet fully implemented")
     }
 
     async fn teardown(&self, provider_instance_id: &str, _account_id: &str) -> Result<()> {
-        self.client
-            .delete(format!(
-                "https://api.vultr.com/v2/instances/{}",
-                provider_instance_id
-            ))
-            .header("Authorization", format!("Bearer {}", self.api_key))
-            .send()
-            .await?
-            .error_for_status()?;
+        let url = format!("{}/instances/{}", self.base_url, provider_instance_id);
+        send_with_rate_limit(
+            &self.retry_policy,
+            self.rng.as_ref(),
+            self.sleeper.as_ref(),
+            self.trace_http,
+            || {
+                self.client
+                    .delete(&url)
+                    .header("Authorization", format!("Bearer {}", self.api_key))
+            },
+        )
+        .await
+        .map_err(anyhow::Error::from)
+        .and_then(|resp| {
+            check_provider_status(resp).context("Vultr DELETE /instances returned error status")
+        })?;
         Ok(())
     }
 
@@ -1185,10 +3657,10 @@ impl Provider for VultrProvider {
         let start = std::time::Instant::now();
         let ok = self
             .client
-            .get("https://api.vultr.com/v2/regions")
+            .get(format!("{}/regions", self.base_url))
             .header("Authorization", format!("Bearer {}", self.api_key))
             .timeout(std::time::Duration::from_secs(5))
-            .send()
+            .send_traced(self.trace_http)
             .await
             .is_ok();
         let ms = start.elapsed().as_millis() as u64;
@@ -1233,6 +3705,26 @@ impl Provider for VultrProvider {
                 available: true,
                 latency_class: LatencyClass::Medium,
             },
+            Region {
+                id: "ap-vultr-sgp".to_string(),
+                display_name: "Vultr Singapore".to_string(),
+                city: "Singapore".to_string(),
+                country: "SG".to_string(),
+                continent: Continent::APAC,
+                provider: VpsProvider::Vultr,
+                available: true,
+                latency_class: LatencyClass::High,
+            },
+            Region {
+                id: "ap-vultr-nrt".to_string(),
+                display_name: "Vultr Tokyo".to_string(),
+                city: "Tokyo".to_string(),
+                country: "JP".to_string(),
+                continent: Continent::APAC,
+                provider: VpsProvider::Vultr,
+                available: true,
+                latency_class: LatencyClass::High,
+            },
         ]
     }
 
@@ -1276,7 +3768,28 @@ impl Provider for ContaboProvider {
         })
     }
     fn supported_regions(&self) -> Vec<Region> {
-        vec![]
+        vec![
+            Region {
+                id: "ap-contabo-sin".to_string(),
+                display_name: "Contabo Singapore".to_string(),
+                city: "Singapore".to_string(),
+                country: "SG".to_string(),
+                continent: Continent::APAC,
+                provider: VpsProvider::Contabo,
+                available: true,
+                latency_class: LatencyClass::High,
+            },
+            Region {
+                id: "ap-contabo-tyo".to_string(),
+                display_name: "Contabo Tokyo".to_string(),
+                city: "Tokyo".to_string(),
+                country: "JP".to_string(),
+                continent: Continent::APAC,
+                provider: VpsProvider::Contabo,
+                available: true,
+                latency_class: LatencyClass::High,
+            },
+        ]
     }
     fn supports_live_resize(&self) -> bool {
         false
@@ -1318,7 +3831,16 @@ impl Provider for HostingerProvider {
         })
     }
     fn supported_regions(&self) -> Vec<Region> {
-        vec![]
+        vec![Region {
+            id: "ap-hostinger-bom".to_string(),
+            display_name: "Hostinger Mumbai".to_string(),
+            city: "Mumbai".to_string(),
+            country: "IN".to_string(),
+            continent: Continent::APAC,
+            provider: VpsProvider::Hostinger,
+            available: true,
+            latency_class: LatencyClass::High,
+        }]
     }
     fn supports_live_resize(&self) -> bool {
         false
@@ -1360,13 +3882,181 @@ impl Provider for DigitalOceanProvider {
         })
     }
     fn supported_regions(&self) -> Vec<Region> {
-        vec![]
+        vec![Region {
+            id: "ap-digitalocean-sgp1".to_string(),
+            display_name: "DigitalOcean Singapore 1".to_string(),
+            city: "Singapore".to_string(),
+            country: "SG".to_string(),
+            continent: Continent::APAC,
+            provider: VpsProvider::DigitalOcean,
+            available: true,
+            latency_class: LatencyClass::High,
+        }]
     }
     fn supports_live_resize(&self) -> bool {
         false
     }
 }
 
+// ─── Chaos provider ───────────────────────────────────────────────────────────
+
+/// Faults a `ChaosProvider` injects into the provider it wraps.
+///
+/// Injection is deterministic: the same profile and call sequence always
+/// fail the same calls.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FaultProfile {
+    /// Fail every nth provision (1 = all of them); `None` never fails.
+    pub fail_provision_every: Option<u32>,
+    pub fail_teardown: bool,
+    /// Cap on the reported health score.
+    pub health_score: Option<u8>,
+    pub active_incident: bool,
+    /// The health endpoint errors instead of answering.
+    pub health_unreachable: bool,
+    /// Delay added before every call.
+    pub latency_ms: u64,
+}
+
+impl FaultProfile {
+    /// The whole provider is down: provisions fail and health reports an incident.
+    pub fn outage() -> Self {
+        Self {
+            fail_provision_every: Some(1),
+            fail_teardown: true,
+            health_score: Some(0),
+            active_incident: true,
+            ..Self::default()
+        }
+    }
+}
+
+/// Wraps a provider and injects the faults in a `FaultProfile`, for
+/// exercising detection, failover and incident handling end to end.
+#[derive(Debug)]
+pub struct ChaosProvider {
+    inner: Box<dyn Provider>,
+    profile: FaultProfile,
+    provision_calls: AtomicU64,
+}
+
+impl ChaosProvider {
+    pub fn new(inner: Box<dyn Provider>, profile: FaultProfile) -> Self {
+        Self {
+            inner,
+            profile,
+            provision_calls: AtomicU64::new(0),
+        }
+    }
+
+    pub fn profile(&self) -> &FaultProfile {
+        &self.profile
+    }
+
+    /// Provisions attempted through this wrapper, failed ones included.
+    pub fn provision_calls(&self) -> u64 {
+        self.provision_calls.load(Ordering::Relaxed)
+    }
+
+    async fn delay(&self) {
+        if self.profile.latency_ms > 0 {
+            tokio::time::sleep(std::time::Duration::from_millis(self.profile.latency_ms)).await;
+        }
+    }
+}
+
+#[async_trait]
+impl Provider for ChaosProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    async fn provision(&self, req: &ProvisionRequest) -> Result<ProvisionResult> {
+        self.delay().await;
+        let n = self.provision_calls.fetch_add(1, Ordering::Relaxed) + 1;
+        if let Some(every) = self.profile.fail_provision_every
+            && n.is_multiple_of(u64::from(every))
+        {
+            bail!(
+                "chaos: injected provision failure on {} (call {n})",
+                self.name()
+            );
+        }
+        self.inner.provision(req).await
+    }
+
+    async fn teardown(&self, provider_instance_id: &str, account_id: &str) -> Result<()> {
+        self.delay().await;
+        if self.profile.fail_teardown {
+            bail!(
+                "chaos: injected teardown failure on {} for {provider_instance_id}",
+                self.name()
+            );
+        }
+        self.inner.teardown(provider_instance_id, account_id).await
+    }
+
+    async fn resize(
+        &self,
+        provider_instance_id: &str,
+        new_tier: &InstanceTier,
+    ) -> Result<ResizeResult> {
+        self.delay().await;
+        self.inner.resize(provider_instance_id, new_tier).await
+    }
+
+    async fn provider_health(&self) -> Result<ProviderHealth> {
+        self.delay().await;
+        if self.profile.health_unreachable {
+            bail!("chaos: {} health endpoint unreachable", self.name());
+        }
+        let mut health = self.inner.provider_health().await?;
+        if let Some(cap) = self.profile.health_score {
+            health.health_score = health.health_score.min(cap);
+        }
+        if self.profile.active_incident {
+            health.active_incident = true;
+            health.incident_description = Some("chaos: injected outage".to_string());
+        }
+        Ok(health)
+    }
+
+    fn supported_regions(&self) -> Vec<Region> {
+        self.inner.supported_regions()
+    }
+
+    fn supports_live_resize(&self) -> bool {
+        self.inner.supports_live_resize()
+    }
+
+    fn supported_images(&self) -> Vec<OsImage> {
+        self.inner.supported_images()
+    }
+
+    fn monthly_cost(&self, tier: &InstanceTier) -> f32 {
+        self.inner.monthly_cost(tier)
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        self.inner.capabilities()
+    }
+
+    async fn list_managed_instances(&self) -> Result<Vec<ManagedInstance>> {
+        self.delay().await;
+        self.inner.list_managed_instances().await
+    }
+
+    async fn hibernate(&self, provider_instance_id: &str) -> Result<()> {
+        self.delay().await;
+        self.inner.hibernate(provider_instance_id).await
+    }
+
+    async fn snapshot(&self, provider_instance_id: &str) -> Result<String> {
+        self.delay().await;
+        self.inner.snapshot(provider_instance_id).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1406,19 +4096,21 @@ mod tests {
 
     #[test]
     fn test_retry_policy_delay_capped() {
-        let p = RetryPolicy {
-            max_retries: 3,
-            base_delay_ms: 500,
-            max_delay_ms: 1_000,
-            jitter: false,
-        };
-        // After many attempts, delay must not exceed max
-        for n in 0..20 {
-            let d = p.delay_for_attempt(n).as_millis();
-            assert!(
-                d <= p.max_delay_ms as u128,
-                "delay must not exceed max_delay_ms at attempt {n}"
-            );
+        for jitter in [false, true] {
+            let p = RetryPolicy {
+                max_retries: 3,
+                base_delay_ms: 500,
+                max_delay_ms: 1_000,
+                jitter,
+            };
+            // After many attempts, delay must not exceed max, jitter included
+            for n in 0..20 {
+                let d = p.delay_for_attempt(n).as_millis();
+                assert!(
+                    d <= p.max_delay_ms as u128,
+                    "delay must not exceed max_delay_ms at attempt {n}"
+                );
+            }
         }
     }
 
@@ -1431,11 +4123,42 @@ mod tests {
     }
 
     #[test]
-    fn test_hetzner_server_type_mapping() {
-        assert_eq!(hetzner_server_type(&InstanceTier::Nano), "cx11");
-        assert_eq!(hetzner_server_type(&InstanceTier::Standard), "cx21");
-        assert_eq!(hetzner_server_type(&InstanceTier::Pro), "cx31");
-        assert_eq!(hetzner_server_type(&InstanceTier::Enterprise), "cx41");
+    fn test_estimate_monthly_cost_bandwidth_overage() {
+        let standard = TierSpec::all().remove("standard").unwrap();
+        // 3 TB of a 4 TB allowance: base price only.
+        assert_eq!(estimate_monthly_cost(&standard, 3.0, 10.0), 12.0);
+        // 6.5 TB: 2.5 TB over at $10/TB.
+        assert!((estimate_monthly_cost(&standard, 6.5, 10.0) - 37.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_hetzner_image_mapping() {
+        assert_eq!(hetzner_image(OsImage::Ubuntu2204), "ubuntu-22.04");
+        assert_eq!(hetzner_image(OsImage::Ubuntu2404), "ubuntu-24.04");
+        assert_eq!(hetzner_image(OsImage::Debian12), "debian-12");
+        let hetzner = HetznerProvider::new("token".to_string());
+        assert!(hetzner.supported_images().contains(&OsImage::Debian12));
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_os_image_rejected() {
+        let mut registry = ProviderRegistry::new();
+        let hetzner = MockProvider::new("hetzner");
+        let provisioned = std::sync::Arc::clone(&hetzner.provisioned);
+        registry.register(Box::new(hetzner));
+        registry.register(Box::new(MockProvider::new("vultr")));
+
+        // The mock only offers the default Ubuntu 22.04 image.
+        let err = FleetProvisioner::new(&registry)
+            .provision_pair(PairProvisionRequest {
+                os_image: OsImage::Debian12,
+                ..make_pair_request()
+            })
+            .await
+            .unwrap_err();
+        assert!(format!("{err:#}").contains("debian-12 is not offered"));
+
+        assert!(provisioned.lock().unwrap().is_empty());
     }
 
     #[test]
@@ -1450,9 +4173,69 @@ mod tests {
     fn test_hetzner_location_mapping() {
         assert_eq!(hetzner_location("eu-hetzner-nbg1"), "nbg1");
         assert_eq!(hetzner_location("eu-hetzner-hel1"), "hel1");
+        assert_eq!(hetzner_location("ap-hetzner-sin"), "sin");
         assert_eq!(hetzner_location("unknown"), "nbg1");
     }
 
+    #[test]
+    fn test_vultr_region_mapping() {
+        assert_eq!(vultr_region("ap-vultr-sgp"), "sgp");
+        assert_eq!(vultr_region("ap-vultr-nrt"), "nrt");
+        assert_eq!(vultr_region("us-vultr-ewr"), "ewr");
+    }
+
+    #[test]
+    fn test_every_provider_lists_an_apac_region() {
+        let providers: Vec<Box<dyn Provider>> = vec![
+            Box::new(HetznerProvider::new("token".to_string())),
+            Box::new(VultrProvider::new("key".to_string())),
+            Box::new(ContaboProvider {
+                api_key: "key".to_string(),
+            }),
+            Box::new(HostingerProvider {
+                api_key: "key".to_string(),
+            }),
+            Box::new(DigitalOceanProvider {
+                api_token: "token".to_string(),
+            }),
+        ];
+        for provider in providers {
+            let apac: Vec<_> = provider
+                .supported_regions()
+                .into_iter()
+                .filter(|r| r.continent == Continent::APAC)
+                .collect();
+            assert!(!apac.is_empty(), "{} has no APAC region", provider.name());
+            assert!(apac.iter().all(|r| r.latency_class == LatencyClass::High));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_select_provider_places_hetzner_in_apac() {
+        use wiremock::matchers::path;
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(path("/datacenters"))
+            .respond_with(wiremock::ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        wiremock::Mock::given(path("/servers"))
+            .respond_with(wiremock::ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({ "servers": [], "meta": { "pagination": { "total_entries": 0 } } }),
+            ))
+            .mount(&server)
+            .await;
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(mock_hetzner(&server)));
+
+        let (provider, region) = registry
+            .select_provider(&VpsProvider::Hetzner, Continent::APAC)
+            .await
+            .expect("Hetzner serves APAC from Singapore");
+        assert_eq!(provider.name(), "hetzner");
+        assert_eq!(region.continent, Continent::APAC);
+        assert_eq!(region.id, "ap-hetzner-sin");
+    }
+
     #[test]
     fn test_tier_spec_all() {
         let all = TierSpec::all();
@@ -1505,6 +4288,7 @@ mod tests {
             "standard",
             "hetzner",
             "eu-hetzner-nbg1",
+            &[],
         );
 
         assert!(script.contains("i-test"));
@@ -1514,4 +4298,2538 @@ mod tests {
         assert!(script.contains("Docker"));
         assert!(script.contains("Tailscale"));
     }
+
+    #[test]
+    fn test_cloud_init_script_ssh_keys() {
+        let script = |keys: &[String]| {
+            cloud_init_script(
+                "i-test",
+                "acc-1",
+                "wss://gateway.example.com",
+                "api-key-123",
+                &InstanceRole::Primary,
+                None,
+                "standard",
+                "hetzner",
+                "eu-hetzner-nbg1",
+                keys,
+            )
+        };
+        let key = "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIOps ops@clawops".to_string();
+        let with_key = script(std::slice::from_ref(&key));
+        assert!(with_key.contains("install -d -m 700 /root/.ssh"));
+        assert!(with_key.contains(&format!("\n{key}\nSSH_KEYS_EOF\n")));
+        assert!(with_key.contains("chmod 600 /root/.ssh/authorized_keys"));
+        // Keys go in before anything that can fail, so a broken package
+        // install or Tailscale join still leaves break-glass access.
+        assert!(with_key.starts_with("#!/bin/bash\nset -euo pipefail\n# Break-glass SSH access\n"));
+
+        let without = script(&[]);
+        assert!(!without.contains("authorized_keys"));
+        assert!(without.contains("set -euo pipefail\nexport DEBIAN_FRONTEND"));
+
+        // A key that could close the heredoc is dropped, not embedded.
+        let hostile = script(&["ssh-rsa AAA\nSSH_KEYS_EOF\nrm -rf /".to_string()]);
+        assert!(!hostile.contains("authorized_keys"));
+
+        // So is a bare terminator line followed by a command entry.
+        let terminator = script(&[
+            key.clone(),
+            "SSH_KEYS_EOF".to_string(),
+            "rm -rf /".to_string(),
+        ]);
+        assert!(terminator.contains(&format!("\n{key}\nSSH_KEYS_EOF\n")));
+        assert_eq!(terminator.matches("SSH_KEYS_EOF").count(), 2);
+        assert!(!terminator.contains("rm -rf"));
+    }
+
+    #[test]
+    fn test_ssh_public_key_validation() {
+        for key in [
+            "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIOps",
+            "ssh-rsa AAAAB3NzaC1yc2E= ops@clawops",
+            "ecdsa-sha2-nistp256 AAAAE2VjZHNh bob laptop",
+        ] {
+            assert!(is_valid_ssh_public_key(key), "{key}");
+        }
+        for key in [
+            "SSH_KEYS_EOF",
+            "rm -rf /",
+            "ssh-dss AAAAB3NzaC1kc3M=",
+            "ssh-ed25519",
+            "ssh-ed25519 AAAA$(reboot)",
+            "ssh-ed25519 AAAA ops;reboot",
+            "ecdsa-sha2-nistp256;x AAAA",
+        ] {
+            assert!(!is_valid_ssh_public_key(key), "{key}");
+        }
+    }
+
+    #[test]
+    fn test_ssh_keys_from_account_config() {
+        let config = serde_json::json!({ "ssh_authorized_keys": ["ssh-ed25519 AAAA a@b"] });
+        assert_eq!(
+            ssh_keys_from_config(Some(&config)),
+            vec!["ssh-ed25519 AAAA a@b".to_string()]
+        );
+        assert!(ssh_keys_from_config(None).is_empty());
+    }
+
+    #[test]
+    fn test_embedded_node_config_round_trips() {
+        let script = cloud_init_script(
+            "i-test",
+            "acc-1",
+            "wss://gateway.example.com",
+            "api-key-\"quoted\"\nCONFIG_EOF",
+            &InstanceRole::Standby,
+            Some("i-primary"),
+            "standard",
+            "hetzner",
+            "eu-hetzner-nbg1",
+            &[],
+        );
+        let start = script.find("<< 'CONFIG_EOF'\n").unwrap() + "<< 'CONFIG_EOF'\n".len();
+        let end = start + script[start..].find("\nCONFIG_EOF\n").unwrap();
+        let parsed = NodeConfig::from_embedded(&script[start..end], ConfigFormat::Json).unwrap();
+
+        assert_eq!(parsed.token, "api-key-\"quoted\"\nCONFIG_EOF");
+        assert_eq!(parsed.role, InstanceRole::Standby);
+        assert_eq!(parsed.pair_instance_id.as_deref(), Some("i-primary"));
+        assert_eq!(parsed.hostname, "co-i-test");
+
+        let embedded = parsed.to_embedded(ConfigFormat::Json).unwrap();
+        assert_eq!(
+            NodeConfig::from_embedded(&embedded, ConfigFormat::Json).unwrap(),
+            parsed
+        );
+    }
+
+    // ─── Pair provisioning ──────────────────────────────────────────────────
+
+    /// Test double that records provision requests and can be told to fail.
+    #[derive(Debug)]
+    struct MockProvider {
+        name: &'static str,
+        fail_provision: bool,
+        provisioned: std::sync::Arc<std::sync::Mutex<Vec<ProvisionRequest>>>,
+        regions: Vec<Region>,
+        fail_health: bool,
+        health_delay: Option<std::time::Duration>,
+        active_incident: bool,
+        health_score: u8,
+        region_calls: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+        monthly_cost: Option<f32>,
+        /// Fail this many provisions before succeeding.
+        fail_first: usize,
+        /// Answer provisions with `success: false` instead of an error.
+        reject_provision: bool,
+        mutated: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    impl MockProvider {
+        fn new(name: &'static str) -> Self {
+            Self {
+                name,
+                fail_provision: false,
+                provisioned: Default::default(),
+                regions: Vec::new(),
+                fail_health: false,
+                health_delay: None,
+                active_incident: false,
+                health_score: 95,
+                region_calls: Default::default(),
+                monthly_cost: None,
+                fail_first: 0,
+                reject_provision: false,
+                mutated: Default::default(),
+            }
+        }
+
+        fn failing(name: &'static str) -> Self {
+            Self {
+                fail_provision: true,
+                ..Self::new(name)
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Provider for MockProvider {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        async fn provision(&self, req: &ProvisionRequest) -> Result<ProvisionResult> {
+            self.provisioned.lock().unwrap().push(req.clone());
+            if self.fail_provision || self.provisioned.lock().unwrap().len() <= self.fail_first {
+                bail!("{} provisioning unavailable", self.name);
+            }
+            let n = self.provisioned.lock().unwrap().len();
+            if self.reject_provision {
+                return Ok(ProvisionResult {
+                    request_id: req.request_id,
+                    instance_id: None,
+                    success: false,
+                    error: Some(format!("{} quota exceeded", self.name)),
+                    provision_duration_ms: 1,
+                    instance_ip: None,
+                    tailscale_ip: None,
+                    provider_instance_id: None,
+                });
+            }
+            Ok(ProvisionResult {
+                request_id: req.request_id,
+                instance_id: Some(format!("{}-{}-{n}", self.name, req.role)),
+                success: true,
+                error: None,
+                provision_duration_ms: 1,
+                instance_ip: Some("203.0.113.10".to_string()),
+                tailscale_ip: None,
+                provider_instance_id: Some(format!("{}-srv-{n}", self.name)),
+            })
+        }
+
+        async fn teardown(&self, id: &str, _account_id: &str) -> Result<()> {
+            self.mutated.lock().unwrap().push(format!("teardown {id}"));
+            Ok(())
+        }
+
+        async fn resize(&self, id: &str, tier: &InstanceTier) -> Result<ResizeResult> {
+            self.mutated.lock().unwrap().push(format!("resize {id}"));
+            Ok(ResizeResult {
+                instance_id: id.to_string(),
+                old_tier: InstanceTier::Standard,
+                new_tier: *tier,
+                downtime_seconds: 0,
+                completed_at: Utc::now(),
+            })
+        }
+
+        async fn provider_health(&self) -> Result<ProviderHealth> {
+            if let Some(delay) = self.health_delay {
+                tokio::time::sleep(delay).await;
+            }
+            if self.fail_health {
+                bail!("{} status endpoint returned 503", self.name);
+            }
+            Ok(ProviderHealth {
+                provider: VpsProvider::Hetzner,
+                api_reachable: true,
+                health_score: self.health_score,
+                provision_avg_ms: 1,
+                provision_success_rate_7d: 1.0,
+                active_incident: self.active_incident,
+                incident_description: None,
+                quota_used_pct: 0.0,
+                checked_at: Utc::now(),
+            })
+        }
+
+        fn supported_regions(&self) -> Vec<Region> {
+            self.region_calls.fetch_add(1, Ordering::Relaxed);
+            self.regions.clone()
+        }
+
+        fn supports_live_resize(&self) -> bool {
+            false
+        }
+
+        fn monthly_cost(&self, tier: &InstanceTier) -> f32 {
+            self.monthly_cost
+                .unwrap_or_else(|| TierSpec::monthly_cost(tier))
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingSleeper {
+        slept: std::sync::Mutex<Vec<std::time::Duration>>,
+    }
+
+    #[async_trait]
+    impl Sleeper for RecordingSleeper {
+        async fn sleep(&self, duration: std::time::Duration) {
+            self.slept.lock().unwrap().push(duration);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_provision_pair_with_retry_backs_off_exponentially() {
+        let primary = ChaosProvider::new(
+            Box::new(MockProvider::new("hetzner")),
+            FaultProfile {
+                fail_provision_every: Some(1),
+                ..FaultProfile::default()
+            },
+        );
+        let standby = MockProvider::new("vultr");
+        let standby_calls = standby.mutated.clone();
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(primary));
+        registry.register(Box::new(standby));
+
+        let sleeper = Arc::new(RecordingSleeper::default());
+        let provisioner = FleetProvisioner::new(&registry)
+            .with_retry_policy(RetryPolicy {
+                max_retries: 4,
+                base_delay_ms: 100,
+                max_delay_ms: 5_000,
+                jitter: true,
+            })
+            .with_rng(Arc::new(SeededRng::from_seed(42)))
+            .with_sleeper(sleeper.clone());
+        assert!(
+            provisioner
+                .provision_pair_with_retry(make_pair_request())
+                .await
+                .is_err()
+        );
+
+        // Every attempt's running standby is torn down, not stranded.
+        assert_eq!(
+            *standby_calls.lock().unwrap(),
+            (1..=5)
+                .map(|n| format!("teardown vultr-srv-{n}"))
+                .collect::<Vec<_>>()
+        );
+
+        let slept = sleeper.slept.lock().unwrap().clone();
+        assert_eq!(slept.len(), 4, "one sleep between each of 5 attempts");
+        for (attempt, delay) in slept.iter().enumerate() {
+            let nominal = 100u128 << attempt;
+            let ms = delay.as_millis();
+            assert!(
+                ms >= nominal * 4 / 5 && ms <= nominal * 6 / 5,
+                "{attempt}: {ms}ms"
+            );
+        }
+        assert!(slept.windows(2).all(|w| w[1] > w[0]));
+    }
+
+    fn make_node_config() -> AccountNodeConfig {
+        AccountNodeConfig {
+            gateway_url: "wss://gateway.example.com".to_string(),
+            auth_token: "token".to_string(),
+            ssh_authorized_keys: vec!["ssh-ed25519 AAAA ops@example".to_string()],
+        }
+    }
+
+    fn make_pair_request() -> PairProvisionRequest {
+        PairProvisionRequest {
+            account_id: "acc-1".to_string(),
+            tier: InstanceTier::Standard,
+            primary_provider: VpsProvider::Hetzner,
+            primary_region: "eu-hetzner-nbg1".to_string(),
+            standby_provider: VpsProvider::Vultr,
+            standby_region: "eu-vultr-ams".to_string(),
+            os_image: OsImage::default(),
+            node: make_node_config(),
+            requested_by: "test".to_string(),
+        }
+    }
+
+    /// A provisioner that keeps a primary whose standby failed.
+    fn without_rollback(registry: &ProviderRegistry) -> FleetProvisioner<'_> {
+        FleetProvisioner::new(registry).with_rollback_on_partial_failure(false)
+    }
+
+    #[tokio::test]
+    async fn test_provision_pair_keeps_primary_when_standby_fails() {
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider::new("hetzner")));
+        registry.register(Box::new(MockProvider::failing("vultr")));
+
+        let pair = without_rollback(&registry)
+            .provision_pair(make_pair_request())
+            .await
+            .unwrap();
+
+        assert!(pair.primary.success);
+        assert!(pair.standby.is_none());
+        assert!(pair.error.is_some());
+        assert!(!pair.is_complete());
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_pair_never_calls_providers() {
+        let primary = MockProvider::new("hetzner");
+        let standby = MockProvider::failing("vultr");
+        let primary_calls = primary.provisioned.clone();
+        let standby_calls = standby.provisioned.clone();
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(primary));
+        registry.register(Box::new(standby));
+        let provisioner = FleetProvisioner::new(&registry).with_dry_run(true);
+
+        let pair = provisioner
+            .provision_pair(make_pair_request())
+            .await
+            .unwrap();
+        assert!(pair.dry_run);
+        assert!(pair.is_complete());
+        assert_eq!(
+            pair.primary.instance_id.as_deref(),
+            Some("dryrun-hetzner-acc-1-primary")
+        );
+        assert_eq!(
+            pair.standby
+                .as_ref()
+                .and_then(|s| s.provider_instance_id.as_deref()),
+            Some("dryrun-vultr-acc-1-standby")
+        );
+        assert_eq!(pair.primary_provider, VpsProvider::Hetzner);
+        assert_eq!(pair.standby_region, "eu-vultr-ams");
+
+        let again = provisioner
+            .provision_pair(make_pair_request())
+            .await
+            .unwrap();
+        assert_eq!(again.primary.instance_id, pair.primary.instance_id);
+        assert!(primary_calls.lock().unwrap().is_empty());
+        assert!(standby_calls.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_teardown_and_resize_never_call_providers() {
+        let primary = MockProvider::new("hetzner");
+        let standby = MockProvider::new("vultr");
+        let primary_calls = primary.mutated.clone();
+        let standby_calls = standby.mutated.clone();
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(primary));
+        registry.register(Box::new(standby));
+        let live = FleetProvisioner::new(&registry);
+        let pair = live.provision_pair(make_pair_request()).await.unwrap();
+        let provisioner = FleetProvisioner::new(&registry).with_dry_run(true);
+
+        provisioner
+            .teardown_instance(VpsProvider::Hetzner, "hetzner-srv-1", "acc-1", "test")
+            .await
+            .unwrap();
+        let (ok, errors) = provisioner.teardown_pair(&pair, "test").await.unwrap();
+        assert!(ok && errors.is_empty());
+        let resized = provisioner
+            .resize_instance(
+                VpsProvider::Hetzner,
+                "hetzner-srv-1",
+                &InstanceTier::Pro,
+                "test",
+            )
+            .await
+            .unwrap();
+        assert_eq!(resized.new_tier, InstanceTier::Pro);
+        provisioner
+            .migrate_instance(
+                &ProvisionRequest {
+                    request_id: Uuid::new_v4(),
+                    account_id: "acc-1".to_string(),
+                    tier: InstanceTier::Standard,
+                    role: InstanceRole::Primary,
+                    provider: VpsProvider::Hetzner,
+                    region: "eu-hetzner-nbg1".to_string(),
+                    os_image: OsImage::default(),
+                    pair_instance_id: None,
+                    openclaw_config: None,
+                    requested_by: "test".to_string(),
+                    requested_at: Utc::now(),
+                },
+                VpsProvider::Vultr,
+                "vultr-srv-1",
+            )
+            .await
+            .unwrap();
+        assert!(
+            provisioner
+                .teardown_instance(VpsProvider::Contabo, "x", "acc-1", "test")
+                .await
+                .is_err()
+        );
+
+        assert!(primary_calls.lock().unwrap().is_empty());
+        assert!(standby_calls.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_direct_resize_and_teardown_are_audited() {
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider::new("hetzner")));
+        let dir = tempfile::tempdir().unwrap();
+        let history = Arc::new(parking_lot::Mutex::new(AuditLogStore::new(dir.path())));
+        let provisioner = FleetProvisioner::new(&registry).with_audit_sink(history.clone());
+
+        provisioner
+            .resize_instance(
+                VpsProvider::Hetzner,
+                "hetzner-srv-1",
+                &InstanceTier::Pro,
+                "forge",
+            )
+            .await
+            .unwrap();
+        provisioner
+            .teardown_instance(VpsProvider::Hetzner, "hetzner-srv-1", "acc-1", "forge")
+            .await
+            .unwrap();
+
+        let history = history.lock();
+        let entries = history.history_for_instance("hetzner-srv-1");
+        let mut actions: Vec<_> = entries.iter().map(|e| e.action.as_str()).collect();
+        actions.sort_unstable();
+        assert_eq!(actions, ["resize", "teardown"]);
+        assert!(
+            entries
+                .iter()
+                .all(|e| e.actor == "forge" && e.result == "success")
+        );
+        let resize = entries.iter().find(|e| e.action == "resize").unwrap();
+        let resize: serde_json::Value =
+            serde_json::from_str(resize.details.as_deref().unwrap()).unwrap();
+        assert_eq!(resize["new_tier"], "pro");
+        assert_eq!(resize["provider"], "hetzner");
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_still_rejects_unsupported_image() {
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider::new("hetzner")));
+        registry.register(Box::new(MockProvider::new("vultr")));
+        let provisioner = FleetProvisioner::new(&registry).with_dry_run(true);
+
+        let err = provisioner
+            .provision_pair(PairProvisionRequest {
+                os_image: OsImage::Debian12,
+                ..make_pair_request()
+            })
+            .await
+            .unwrap_err();
+        assert!(format!("{err:#}").contains("debian-12 is not offered"));
+    }
+
+    #[tokio::test]
+    async fn test_provision_pair_rolls_back_primary_when_standby_fails() {
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider::new("hetzner")));
+        registry.register(Box::new(MockProvider::failing("vultr")));
+
+        let pair = FleetProvisioner::new(&registry)
+            .provision_pair(make_pair_request())
+            .await
+            .unwrap();
+
+        assert!(!pair.primary.success);
+        assert!(pair.primary.instance_id.is_none());
+        assert!(pair.primary.provider_instance_id.is_none());
+        assert!(pair.standby.is_none());
+        let error = pair.error.unwrap();
+        assert!(error.contains("vultr provisioning unavailable"), "{error}");
+        assert!(
+            error.contains("primary hetzner-srv-1 rolled back"),
+            "{error}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_provision_pair_rolls_back_standby_when_primary_fails() {
+        let standby = MockProvider::new("vultr");
+        let standby_calls = standby.mutated.clone();
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider {
+            reject_provision: true,
+            ..MockProvider::new("hetzner")
+        }));
+        registry.register(Box::new(standby));
+
+        let pair = FleetProvisioner::new(&registry)
+            .provision_pair(make_pair_request())
+            .await
+            .unwrap();
+        assert!(!pair.primary.success);
+        assert!(pair.standby.is_none());
+        let error = pair.error.unwrap();
+        assert!(error.contains("hetzner quota exceeded"), "{error}");
+        assert!(error.contains("standby vultr-srv-1 rolled back"), "{error}");
+        assert_eq!(*standby_calls.lock().unwrap(), vec!["teardown vultr-srv-1"]);
+
+        // Without rollback the standby is handed back with the primary error.
+        let pair = without_rollback(&registry)
+            .provision_pair_with_retry(make_pair_request())
+            .await
+            .unwrap();
+        assert!(pair.standby.as_ref().is_some_and(is_provisioned));
+        assert_eq!(pair.error.as_deref(), Some("hetzner quota exceeded"));
+        assert_eq!(standby_calls.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_provision_pair_retries_standby_before_rolling_back() {
+        let standby = MockProvider {
+            fail_first: 1,
+            ..MockProvider::new("vultr")
+        };
+        let standby_calls = standby.provisioned.clone();
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider::new("hetzner")));
+        registry.register(Box::new(standby));
+
+        let pair = FleetProvisioner::new(&registry)
+            .provision_pair(make_pair_request())
+            .await
+            .unwrap();
+
+        assert!(pair.is_complete());
+        assert!(pair.error.is_none());
+        assert_eq!(
+            pair.primary.instance_id.as_deref(),
+            Some("hetzner-primary-1")
+        );
+        let calls = standby_calls.lock().unwrap();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[1].pair_instance_id, pair.primary.instance_id);
+    }
+
+    #[tokio::test]
+    async fn test_provision_pair_surfaces_failed_rollback() {
+        let primary = ChaosProvider::new(
+            Box::new(MockProvider::new("hetzner")),
+            FaultProfile {
+                fail_teardown: true,
+                ..FaultProfile::default()
+            },
+        );
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(primary));
+        registry.register(Box::new(MockProvider::failing("vultr")));
+
+        let pair = FleetProvisioner::new(&registry)
+            .provision_pair(make_pair_request())
+            .await
+            .unwrap();
+        assert!(pair.primary.success);
+        assert_eq!(
+            pair.primary.provider_instance_id.as_deref(),
+            Some("hetzner-srv-1")
+        );
+        assert!(pair.standby.is_none());
+        let message = pair.error.unwrap();
+        assert!(
+            message.contains("vultr provisioning unavailable"),
+            "{message}"
+        );
+        assert!(
+            message.contains("rollback of primary on hetzner failed"),
+            "{message}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_complete_pair_provisions_missing_standby() {
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider::new("hetzner")));
+        registry.register(Box::new(MockProvider::failing("vultr")));
+        registry.register(Box::new(MockProvider::new("digitalocean")));
+        let provisioner = without_rollback(&registry);
+
+        let partial = provisioner
+            .provision_pair(make_pair_request())
+            .await
+            .unwrap();
+        let completed = provisioner
+            .complete_pair(
+                &partial,
+                VpsProvider::DigitalOcean,
+                "eu-do-ams3",
+                &make_node_config(),
+            )
+            .await
+            .unwrap();
+
+        assert!(completed.is_complete());
+        assert!(completed.error.is_none());
+        assert_eq!(completed.pair_id, partial.pair_id);
+        assert_eq!(completed.primary.instance_id, partial.primary.instance_id);
+        assert_eq!(completed.standby_provider, VpsProvider::DigitalOcean);
+        assert_eq!(completed.standby_region, "eu-do-ams3");
+    }
+
+    #[tokio::test]
+    async fn test_complete_pair_links_standby_to_primary() {
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider::new("hetzner")));
+        registry.register(Box::new(MockProvider::failing("vultr")));
+        let standby = MockProvider::new("digitalocean");
+        let provisioned = standby.provisioned.clone();
+        registry.register(Box::new(standby));
+        let provisioner = without_rollback(&registry);
+
+        let partial = provisioner
+            .provision_pair(make_pair_request())
+            .await
+            .unwrap();
+        provisioner
+            .complete_pair(
+                &partial,
+                VpsProvider::DigitalOcean,
+                "eu-do-ams3",
+                &make_node_config(),
+            )
+            .await
+            .unwrap();
+
+        let reqs = provisioned.lock().unwrap();
+        assert_eq!(reqs.len(), 1);
+        assert_eq!(reqs[0].role, InstanceRole::Standby);
+        assert_eq!(reqs[0].pair_instance_id, partial.primary.instance_id);
+        // The recovered standby gets the same gateway, token and keys.
+        assert_eq!(
+            reqs[0].openclaw_config,
+            Some(make_node_config().openclaw_config(InstanceRole::Standby, true))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_complete_pair_on_complete_pair_is_noop() {
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider::new("hetzner")));
+        registry.register(Box::new(MockProvider::new("vultr")));
+        let provisioner = FleetProvisioner::new(&registry);
+
+        let pair = provisioner
+            .provision_pair(make_pair_request())
+            .await
+            .unwrap();
+        assert!(pair.is_complete());
+
+        // Digitalocean is not registered: any provisioning attempt would error.
+        let same = provisioner
+            .complete_pair(
+                &pair,
+                VpsProvider::DigitalOcean,
+                "eu-do-ams3",
+                &make_node_config(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(same.standby_provider, VpsProvider::Vultr);
+        assert_eq!(
+            same.standby.as_ref().and_then(|s| s.instance_id.clone()),
+            pair.standby.as_ref().and_then(|s| s.instance_id.clone())
+        );
+    }
+
+    // ─── Hetzner boot polling ───────────────────────────────────────────────
+
+    #[test]
+    fn test_boot_poll_interval_backs_off_to_cap() {
+        let secs: Vec<u64> = (0..7).map(|a| boot_poll_interval(a).as_secs()).collect();
+        assert_eq!(secs, vec![1, 2, 4, 8, 10, 10, 10]);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_server_running_respects_custom_timeout() {
+        use wiremock::matchers::{method, path};
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(method("GET"))
+            .and(path("/servers/42"))
+            .respond_with(wiremock::ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({ "server": { "id": 42, "status": "initializing" } }),
+            ))
+            .mount(&server)
+            .await;
+
+        let sleeper = Arc::new(RecordingSleeper::default());
+        let provider = mock_hetzner(&server)
+            .with_provision_timeout(std::time::Duration::from_secs(20))
+            .with_sleeper(sleeper.clone());
+        let err = provider.wait_for_server_running(42).await.unwrap_err();
+        assert!(err.to_string().contains("timeout after 20s"), "{err}");
+
+        let slept = sleeper.slept.lock().unwrap().clone();
+        let secs: Vec<u64> = slept.iter().map(|d| d.as_secs()).collect();
+        // 1+2+4+8 = 15s, then the last poll is clipped to the 5s left.
+        assert_eq!(secs, vec![1, 2, 4, 8, 5]);
+        assert_eq!(
+            slept.iter().sum::<std::time::Duration>(),
+            std::time::Duration::from_secs(20)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_server_running_detects_fast_boot() {
+        use wiremock::matchers::{method, path};
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(method("GET"))
+            .and(path("/servers/42"))
+            .respond_with(
+                wiremock::ResponseTemplate::new(200).set_body_json(
+                    serde_json::json!({ "server": { "id": 42, "status": "running" } }),
+                ),
+            )
+            .mount(&server)
+            .await;
+
+        let sleeper = Arc::new(RecordingSleeper::default());
+        let provider = mock_hetzner(&server).with_sleeper(sleeper.clone());
+        provider.wait_for_server_running(42).await.unwrap();
+        assert_eq!(
+            *sleeper.slept.lock().unwrap(),
+            vec![std::time::Duration::from_secs(1)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hetzner_provision_labels_server_with_instance_id() {
+        use wiremock::matchers::{method, path};
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(method("POST"))
+            .and(path("/servers"))
+            .respond_with(wiremock::ResponseTemplate::new(201).set_body_json(
+                serde_json::json!({ "server": { "id": 42, "public_net": { "ipv4": { "ip": "203.0.113.42" } } } }),
+            ))
+            .mount(&server)
+            .await;
+        wiremock::Mock::given(method("GET"))
+            .and(path("/servers/42"))
+            .respond_with(
+                wiremock::ResponseTemplate::new(200).set_body_json(
+                    serde_json::json!({ "server": { "id": 42, "status": "running" } }),
+                ),
+            )
+            .mount(&server)
+            .await;
+
+        let provider = mock_hetzner(&server).with_sleeper(Arc::new(RecordingSleeper::default()));
+        let result = provider
+            .provision(&ProvisionRequest {
+                request_id: Uuid::new_v4(),
+                account_id: "acc-1".to_string(),
+                tier: InstanceTier::Standard,
+                role: InstanceRole::Primary,
+                provider: VpsProvider::Hetzner,
+                region: "eu-hetzner-nbg1".to_string(),
+                os_image: OsImage::default(),
+                pair_instance_id: None,
+                openclaw_config: None,
+                requested_by: "test".to_string(),
+                requested_at: Utc::now(),
+            })
+            .await
+            .unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        let body: serde_json::Value = requests
+            .iter()
+            .find(|r| r.method.as_str() == "POST")
+            .unwrap()
+            .body_json()
+            .unwrap();
+        let labels = body["labels"].clone();
+        assert_eq!(
+            labels[INSTANCE_ID_LABEL].as_str(),
+            result.instance_id.as_deref()
+        );
+
+        // The label round-trips into the listing used for reconciliation.
+        let listed: HetznerServer =
+            serde_json::from_value(hetzner_server_json(42, labels)).unwrap();
+        assert_eq!(
+            listed.to_managed_instance().unwrap().instance_id,
+            result.instance_id
+        );
+    }
+
+    // ─── Managed instance listing ───────────────────────────────────────────
+
+    fn hetzner_server_json(id: u64, labels: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "name": format!("co-acc-{id}"),
+            "status": "running",
+            "created": "2026-01-01T00:00:00+00:00",
+            "public_net": { "ipv4": { "ip": format!("203.0.113.{id}") } },
+            "server_type": { "id": 1, "name": "cx21", "cores": 2, "memory": 4.0, "disk": 40 },
+            "labels": labels,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_hetzner_list_managed_instances_keeps_clawops_filter() {
+        use wiremock::matchers::{method, path, query_param};
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(method("GET"))
+            .and(path("/servers"))
+            .and(query_param("label_selector", "managed_by=clawops"))
+            .respond_with(
+                wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "servers": [
+                        hetzner_server_json(7, serde_json::json!({
+                            "managed_by": "clawops", "tier": "standard", "account_id": "acc-7",
+                            "instance_id": "i-7"
+                        })),
+                        // Would only appear if the selector were ignored.
+                        hetzner_server_json(8, serde_json::json!({ "managed_by": "someone-else" })),
+                    ],
+                    "meta": { "pagination": {
+                        "page": 1, "per_page": 25, "next_page": null, "total_entries": 2
+                    } }
+                })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let instances = mock_hetzner(&server)
+            .list_managed_instances()
+            .await
+            .unwrap();
+        assert_eq!(instances.len(), 1);
+        let instance = &instances[0];
+        assert_eq!(instance.instance_id.as_deref(), Some("i-7"));
+        assert_eq!(instance.provider, VpsProvider::Hetzner);
+        assert_eq!(instance.provider_instance_id, "7");
+        assert_eq!(instance.ip.as_deref(), Some("203.0.113.7"));
+        assert_eq!(instance.tier, Some(InstanceTier::Standard));
+        assert_eq!(instance.status, "running");
+        assert_eq!(instance.labels["managed_by"], "clawops");
+        assert_eq!(instance.labels["account_id"], "acc-7");
+    }
+
+    #[tokio::test]
+    async fn test_list_managed_instances_defaults_to_empty() {
+        let stub = ContaboProvider {
+            api_key: "key".to_string(),
+        };
+        assert!(stub.list_managed_instances().await.unwrap().is_empty());
+    }
+
+    // ─── Hetzner quota ──────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_hetzner_health_reports_quota_usage() {
+        use wiremock::matchers::{method, path};
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(method("GET"))
+            .and(path("/datacenters"))
+            .respond_with(
+                wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "datacenters": []
+                })),
+            )
+            .mount(&server)
+            .await;
+        let servers: Vec<_> = (0..95).map(|id| serde_json::json!({ "id": id })).collect();
+        wiremock::Mock::given(method("GET"))
+            .and(path("/servers"))
+            .respond_with(
+                wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "servers": servers
+                })),
+            )
+            // The second health check must be served from the cache.
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let provider = mock_hetzner(&server).with_server_limit(100);
+        let health = provider.provider_health().await.unwrap();
+        assert!((health.quota_used_pct - 95.0).abs() < 0.01);
+        assert!(health.health_score < PREFERRED_MIN_HEALTH);
+
+        let cached = provider.provider_health().await.unwrap();
+        assert!((cached.quota_used_pct - 95.0).abs() < 0.01);
+    }
+
+    #[tokio::test]
+    async fn test_hetzner_quota_counts_pagination_total_not_page() {
+        use wiremock::matchers::{method, path};
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(method("GET"))
+            .and(path("/datacenters"))
+            .respond_with(wiremock::ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        // `per_page=1` returns one server; the total lives in the pagination meta.
+        wiremock::Mock::given(method("GET"))
+            .and(path("/servers"))
+            .respond_with(
+                wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "servers": [{ "id": 1 }],
+                    "meta": { "pagination": { "page": 1, "per_page": 1, "total_entries": 92 } }
+                })),
+            )
+            .mount(&server)
+            .await;
+
+        let health = mock_hetzner(&server)
+            .with_server_limit(100)
+            .provider_health()
+            .await
+            .unwrap();
+        assert!((health.quota_used_pct - 92.0).abs() < 0.01);
+        assert_eq!(health.health_score, FALLBACK_MIN_HEALTH);
+    }
+
+    #[tokio::test]
+    async fn test_hetzner_unreadable_quota_degrades_health() {
+        use wiremock::matchers::{method, path};
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(method("GET"))
+            .and(path("/datacenters"))
+            .respond_with(wiremock::ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        wiremock::Mock::given(method("GET"))
+            .and(path("/servers"))
+            .respond_with(wiremock::ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        let health = mock_hetzner(&server).provider_health().await.unwrap();
+        assert!(health.api_reachable);
+        assert!(health.health_score < PREFERRED_MIN_HEALTH);
+        assert_eq!(
+            health.incident_description.as_deref(),
+            Some("server quota could not be read")
+        );
+    }
+
+    #[test]
+    fn test_quota_adjusted_health_caps_near_full_accounts() {
+        assert_eq!(quota_adjusted_health(95, 50.0), 95);
+        assert_eq!(quota_adjusted_health(95, 90.0), 95);
+        assert_eq!(quota_adjusted_health(95, 91.0), FALLBACK_MIN_HEALTH);
+        assert_eq!(quota_adjusted_health(40, 99.0), 40);
+    }
+
+    // ─── Hetzner teardown ───────────────────────────────────────────────────
+
+    fn mock_hetzner(server: &wiremock::MockServer) -> HetznerProvider {
+        HetznerProvider {
+            base_url: server.uri(),
+            ..HetznerProvider::new("test-token".to_string())
+        }
+        .with_retry_policy(RetryPolicy {
+            max_retries: 2,
+            base_delay_ms: 1,
+            max_delay_ms: 1,
+            jitter: false,
+        })
+    }
+
+    /// GET /servers/42 response for a server labelled as ours.
+    fn owned_server(account_id: &str, managed_by: &str) -> wiremock::ResponseTemplate {
+        wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "server": {
+                "id": 42,
+                "status": "running",
+                "labels": { "managed_by": managed_by, "account_id": account_id }
+            }
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_hetzner_teardown_retries_transient_failure() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/servers/42"))
+            .respond_with(owned_server("acc-1", "clawops"))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/servers/42"))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/servers/42"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        mock_hetzner(&server)
+            .teardown("42", "acc-1")
+            .await
+            .expect("teardown succeeds after retry");
+    }
+
+    #[tokio::test]
+    async fn test_hetzner_teardown_persistent_failure_server_gone() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/servers/42"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(3)
+            .mount(&server)
+            .await;
+        // Ours before each of the three attempts, gone at the final check.
+        Mock::given(method("GET"))
+            .and(path("/servers/42"))
+            .respond_with(owned_server("acc-1", "clawops"))
+            .up_to_n_times(3)
+            .expect(3)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/servers/42"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        mock_hetzner(&server)
+            .teardown("42", "acc-1")
+            .await
+            .expect("server confirmed gone counts as success");
+    }
+
+    #[tokio::test]
+    async fn test_hetzner_teardown_persistent_failure_server_present() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/servers/42"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(3)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/servers/42"))
+            .respond_with(owned_server("acc-1", "clawops"))
+            .expect(4)
+            .mount(&server)
+            .await;
+
+        let err = mock_hetzner(&server)
+            .teardown("42", "acc-1")
+            .await
+            .expect_err("server still present must be an error");
+        assert!(format!("{err:#}").contains("still exists"));
+    }
+
+    #[tokio::test]
+    async fn test_hetzner_snapshot_returns_image_id() {
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/servers/42/actions/create_image"))
+            .and(body_partial_json(serde_json::json!({ "type": "snapshot" })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "image": { "id": 77, "type": "snapshot" },
+                "action": { "id": 1, "status": "running" }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        assert_eq!(mock_hetzner(&server).snapshot("42").await.unwrap(), "77");
+    }
+
+    #[tokio::test]
+    async fn test_hetzner_teardown_auth_failure_returns_immediately() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        for status in [401, 403] {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path("/servers/42"))
+                .respond_with(owned_server("acc-1", "clawops"))
+                .expect(1)
+                .mount(&server)
+                .await;
+            Mock::given(method("DELETE"))
+                .and(path("/servers/42"))
+                .respond_with(ResponseTemplate::new(status))
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let err = mock_hetzner(&server)
+                .teardown("42", "acc-1")
+                .await
+                .expect_err("rejected credentials must fail");
+            assert_eq!(
+                err.chain().find_map(|c| c.downcast_ref::<ProviderError>()),
+                Some(&ProviderError::Fatal(status))
+            );
+            assert!(format!("{err:#}").contains("credentials"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hetzner_teardown_already_gone_skips_delete() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/servers/42"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        mock_hetzner(&server)
+            .teardown("42", "acc-1")
+            .await
+            .expect("already gone is success");
+    }
+
+    #[tokio::test]
+    async fn test_hetzner_teardown_deletes_owned_server() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/servers/42"))
+            .respond_with(owned_server("acc-1", "clawops"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/servers/42"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        mock_hetzner(&server)
+            .teardown("42", "acc-1")
+            .await
+            .expect("owned server is deleted");
+    }
+
+    #[tokio::test]
+    async fn test_hetzner_teardown_refuses_foreign_server() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/servers/42"))
+            .respond_with(owned_server("acc-1", "terraform"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        // A recycled id now owned by another ClawOps account is not ours either.
+        Mock::given(method("GET"))
+            .and(path("/servers/42"))
+            .respond_with(owned_server("acc-2", "clawops"))
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let hetzner = mock_hetzner(&server);
+        let err = hetzner.teardown("42", "acc-1").await.unwrap_err();
+        assert!(format!("{err:#}").contains("managed_by label is 'terraform'"));
+        let err = hetzner.teardown("42", "acc-1").await.unwrap_err();
+        assert!(format!("{err:#}").contains("account_id label is 'acc-2'"));
+    }
+
+    #[test]
+    fn test_classify_latency_buckets() {
+        use std::time::Duration;
+        assert_eq!(
+            classify_latency(Duration::from_millis(2)),
+            LatencyClass::Low
+        );
+        assert_eq!(
+            classify_latency(Duration::from_micros(9_999)),
+            LatencyClass::Low
+        );
+        assert_eq!(
+            classify_latency(Duration::from_millis(10)),
+            LatencyClass::Medium
+        );
+        assert_eq!(
+            classify_latency(Duration::from_millis(50)),
+            LatencyClass::Medium
+        );
+        assert_eq!(
+            classify_latency(Duration::from_millis(51)),
+            LatencyClass::High
+        );
+        assert_eq!(classify_latency(Duration::from_secs(1)), LatencyClass::High);
+    }
+
+    #[tokio::test]
+    async fn test_select_provider_for_tier_prefers_cheaper_of_equal_health() {
+        for seed in 0..20 {
+            let mut registry = ProviderRegistry::new();
+            registry.set_rng(Arc::new(SeededRng::from_seed(seed)));
+            registry.register(Box::new(MockProvider {
+                regions: vec![make_region("nbg1")],
+                monthly_cost: Some(18.0),
+                ..MockProvider::new("hetzner")
+            }));
+            registry.register(Box::new(MockProvider {
+                regions: vec![make_region("ams")],
+                monthly_cost: Some(12.0),
+                ..MockProvider::new("vultr")
+            }));
+
+            // DigitalOcean is preferred but not registered: both are fallbacks.
+            let (provider, region) = registry
+                .select_provider_for_tier(
+                    &VpsProvider::DigitalOcean,
+                    Continent::EU,
+                    &InstanceTier::Standard,
+                )
+                .await
+                .unwrap();
+            assert_eq!(provider.name(), "vultr", "seed {seed}");
+            assert_eq!(region.id, "ams");
+        }
+    }
+
+    #[test]
+    fn test_ranking_score_rewards_cost_and_reliability_and_penalises_latency() {
+        let health = |rate: f32| ProviderHealth {
+            provider: VpsProvider::Hetzner,
+            api_reachable: true,
+            health_score: 90,
+            provision_avg_ms: 1,
+            provision_success_rate_7d: rate,
+            active_incident: false,
+            incident_description: None,
+            quota_used_pct: 0.0,
+            checked_at: Utc::now(),
+        };
+        let registry = ProviderRegistry::new();
+        let region = make_region("nbg1");
+        let cheap = registry.ranking_score(&health(0.99), &region, Some(1.0));
+        let pricey = registry.ranking_score(&health(0.99), &region, Some(0.5));
+        let flaky = registry.ranking_score(&health(0.80), &region, Some(1.0));
+        assert!(cheap > pricey);
+        assert!(cheap > flaky);
+
+        // The configured latency penalty applies whether or not a tier is priced.
+        let far = Region {
+            latency_class: LatencyClass::High,
+            ..region.clone()
+        };
+        let penalty = 2 * i32::from(DEFAULT_LATENCY_WEIGHT) * 100;
+        assert_eq!(
+            registry.ranking_score(&health(0.99), &far, Some(1.0)),
+            cheap - penalty
+        );
+        assert_eq!(
+            registry.ranking_score(&health(0.99), &region, None)
+                - registry.ranking_score(&health(0.99), &far, None),
+            penalty
+        );
+    }
+
+    fn make_region(id: &str) -> Region {
+        Region {
+            id: id.to_string(),
+            display_name: id.to_string(),
+            city: "Test".to_string(),
+            country: "DE".to_string(),
+            continent: Continent::EU,
+            provider: VpsProvider::Hetzner,
+            available: true,
+            latency_class: LatencyClass::Low,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_measured_high_latency_region_is_deprioritized() {
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider {
+            regions: vec![make_region("eu-slow"), make_region("eu-fast")],
+            ..MockProvider::new("hetzner")
+        }));
+
+        // Statically both are Low; the first listed wins.
+        let (_, region) = registry
+            .select_provider(&VpsProvider::Hetzner, Continent::EU)
+            .await
+            .unwrap();
+        assert_eq!(region.id, "eu-slow");
+
+        let rtts = HashMap::from([
+            ("eu-slow".to_string(), std::time::Duration::from_millis(120)),
+            ("eu-fast".to_string(), std::time::Duration::from_millis(4)),
+        ]);
+        registry.apply_latency_measurements(&rtts);
+
+        let (_, region) = registry
+            .select_provider(&VpsProvider::Hetzner, Continent::EU)
+            .await
+            .unwrap();
+        assert_eq!(region.id, "eu-fast");
+        assert_eq!(region.latency_class, LatencyClass::Low);
+
+        let provider = registry.get("hetzner").unwrap();
+        let slow = registry
+            .regions_for(provider)
+            .into_iter()
+            .find(|r| r.id == "eu-slow")
+            .unwrap();
+        assert_eq!(slow.latency_class, LatencyClass::High);
+    }
+
+    #[test]
+    fn test_hetzner_capabilities() {
+        let caps = HetznerProvider::new("token".to_string()).capabilities();
+        assert_eq!(
+            caps,
+            ProviderCapabilities {
+                live_resize: false,
+                snapshot: true,
+                firewall: true,
+                floating_ip: true,
+                hibernate: true,
+                ipv6: true,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_operation_rejected_up_front() {
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider::new("vultr")));
+        let provisioner = FleetProvisioner::new(&registry);
+
+        assert_eq!(
+            registry.get("vultr").unwrap().capabilities(),
+            ProviderCapabilities::default()
+        );
+        let err = provisioner
+            .hibernate_instance(VpsProvider::Vultr, "srv-1")
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "hibernate is not supported by provider 'vultr'"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_blocklisted_country_excluded_from_selection() {
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider {
+            regions: vec![make_region("eu-de-1"), make_region("eu-de-2")],
+            ..MockProvider::new("hetzner")
+        }));
+        registry.register(Box::new(MockProvider {
+            regions: vec![Region {
+                country: "NL".to_string(),
+                provider: VpsProvider::Vultr,
+                ..make_region("eu-nl-1")
+            }],
+            ..MockProvider::new("vultr")
+        }));
+        registry.set_blocklist(RegionBlocklist::new().block_country("de", "export control"));
+
+        // Preferred provider only has blocked regions: falls back to an allowed one.
+        let (provider, region) = registry
+            .select_provider(&VpsProvider::Hetzner, Continent::EU)
+            .await
+            .unwrap();
+        assert_eq!(provider.name(), "vultr");
+        assert_eq!(region.id, "eu-nl-1");
+
+        let hetzner = registry.get("hetzner").unwrap();
+        assert!(registry.regions_for(hetzner).is_empty());
+
+        // Blocking the last allowed region leaves nothing to select.
+        registry.set_blocklist(
+            RegionBlocklist::new()
+                .block_country("DE", "export control")
+                .block_region("eu-nl-1", "data residency"),
+        );
+        assert!(
+            registry
+                .select_provider(&VpsProvider::Vultr, Continent::EU)
+                .await
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_provision_refuses_blocklisted_region() {
+        let mut registry = ProviderRegistry::new();
+        let hetzner = MockProvider {
+            regions: vec![make_region("eu-hetzner-nbg1")],
+            ..MockProvider::new("hetzner")
+        };
+        let provisioned = hetzner.provisioned.clone();
+        registry.register(Box::new(hetzner));
+        registry.register(Box::new(MockProvider::new("vultr")));
+        registry.set_blocklist(RegionBlocklist::new().block_country("de", "export control"));
+
+        for dry_run in [false, true] {
+            let err = FleetProvisioner::new(&registry)
+                .with_dry_run(dry_run)
+                .provision_pair(make_pair_request())
+                .await
+                .unwrap_err();
+            assert!(
+                matches!(
+                    err.downcast_ref::<ProvisionError>(),
+                    Some(ProvisionError::Refused(msg)) if msg.contains("export control")
+                ),
+                "{err:#}"
+            );
+        }
+
+        // A region the provider does not list is still refused by id.
+        registry.set_blocklist(RegionBlocklist::new().block_region("eu-vultr-ams", "residency"));
+        let err = FleetProvisioner::new(&registry)
+            .provision_pair(make_pair_request())
+            .await
+            .unwrap_err();
+        assert!(format!("{err:#}").contains("residency"), "{err:#}");
+        assert!(provisioned.lock().unwrap().is_empty());
+
+        // Standalone provisions go through the same check.
+        let err = FleetProvisioner::new(&registry)
+            .provision_single(
+                "acc-1",
+                InstanceTier::Nano,
+                VpsProvider::Vultr,
+                "eu-vultr-ams",
+                OsImage::default(),
+                &make_node_config(),
+            )
+            .await
+            .unwrap_err();
+        assert!(format!("{err:#}").contains("residency"), "{err:#}");
+    }
+
+    #[test]
+    fn test_blocklist_reasons() {
+        let blocklist = RegionBlocklist::new()
+            .block_provider(VpsProvider::Contabo, "vendor review")
+            .block_region("eu-de-2", "sanctions");
+        let allowed = make_region("eu-de-1");
+        let by_region = make_region("eu-de-2");
+        let by_provider = Region {
+            provider: VpsProvider::Contabo,
+            ..make_region("eu-contabo-1")
+        };
+        assert_eq!(blocklist.blocked_reason(&allowed), None);
+        assert_eq!(blocklist.blocked_reason(&by_region), Some("sanctions"));
+        assert_eq!(
+            blocklist.blocked_reason(&by_provider),
+            Some("vendor review")
+        );
+    }
+
+    // ─── Seeded randomness ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_same_seed_reproduces_jitter() {
+        let policy = RetryPolicy::default();
+        let a = SeededRng::from_seed(7);
+        let b = SeededRng::from_seed(7);
+        let run = |rng: &SeededRng| -> Vec<_> {
+            (0..6)
+                .map(|n| policy.delay_for_attempt_with(n, rng))
+                .collect()
+        };
+        let first = run(&a);
+        assert_eq!(first, run(&b));
+        for (n, delay) in first.iter().enumerate() {
+            let nominal = 500u64 << n;
+            let band = (nominal - nominal / 5).min(30_000)..=(nominal + nominal / 5).min(30_000);
+            assert!(band.contains(&(delay.as_millis() as u64)));
+            // The unseeded variant jitters within the same band.
+            let fixed = policy.delay_for_attempt(n as u32);
+            assert!(band.contains(&(fixed.as_millis() as u64)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_same_seed_reproduces_spread() {
+        let build = |seed| {
+            let mut registry = ProviderRegistry::new();
+            for name in ["vultr", "contabo", "digitalocean", "hostinger"] {
+                registry.register(Box::new(MockProvider {
+                    regions: vec![make_region(&format!("eu-{name}"))],
+                    ..MockProvider::new(name)
+                }));
+            }
+            registry.set_rng(Arc::new(SeededRng::from_seed(seed)));
+            registry
+        };
+        let placements = |registry: ProviderRegistry| async move {
+            let mut picked = Vec::new();
+            for _ in 0..12 {
+                // Preferred provider is not registered, so every pick is a spread.
+                let (provider, _) = registry
+                    .select_provider(&VpsProvider::Hetzner, Continent::EU)
+                    .await
+                    .unwrap();
+                picked.push(provider.name().to_string());
+            }
+            picked
+        };
+
+        let first = placements(build(42)).await;
+        assert_eq!(first, placements(build(42)).await);
+        let distinct: std::collections::HashSet<_> = first.iter().collect();
+        assert!(distinct.len() > 1, "ties should spread across providers");
+    }
+
+    // ─── HTTP tracing ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_traced_request_masks_bearer_token() {
+        let request = reqwest::Client::new()
+            .get("https://api.hetzner.cloud/v1/servers/42")
+            .bearer_auth("hcloud-secret-token")
+            .build()
+            .unwrap();
+        let logged = describe_request(&request, &[]);
+        assert!(logged.contains("authorization: Bearer ****"), "{logged}");
+        assert!(!logged.contains("hcloud-secret-token"));
+    }
+
+    #[test]
+    fn test_traced_request_elides_cloud_init() {
+        let user_data = cloud_init_script(
+            "i-1",
+            "acc-1",
+            "wss://gateway.example.com",
+            "clawops-api-key",
+            &InstanceRole::Primary,
+            None,
+            "standard",
+            "hetzner",
+            "eu-hetzner-nbg1",
+            &[],
+        );
+        let request = reqwest::Client::new()
+            .post("https://api.hetzner.cloud/v1/servers")
+            .json(&serde_json::json!({
+                "name": "co-acc-1",
+                "user_data": user_data,
+                "labels": { "note": "rotated ts-authkey-123" },
+            }))
+            .build()
+            .unwrap();
+        let logged = describe_request(&request, &["ts-authkey-123".to_string()]);
+        assert!(logged.contains("<cloud-init elided"), "{logged}");
+        assert!(!logged.contains("clawops-api-key"));
+        assert!(!logged.contains("ts-authkey-123"));
+        assert!(logged.contains("co-acc-1"));
+    }
+
+    // ─── Fleet health poll ──────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_select_provider_checks_fallbacks_concurrently() {
+        let mut registry = ProviderRegistry::new();
+        for (name, region) in [("vultr", "ams"), ("digitalocean", "ams3")] {
+            registry.register(Box::new(MockProvider {
+                regions: vec![make_region(region)],
+                health_delay: Some(std::time::Duration::from_millis(300)),
+                ..MockProvider::new(name)
+            }));
+        }
+
+        let started = std::time::Instant::now();
+        let outcome = registry
+            .select_provider_explained(&VpsProvider::Hetzner, Continent::EU)
+            .await;
+        let elapsed = started.elapsed();
+        assert!(outcome.selected.is_some());
+        // Bounded by the slowest check (300ms), not the sum (600ms).
+        assert!(elapsed >= std::time::Duration::from_millis(300));
+        assert!(
+            elapsed < std::time::Duration::from_millis(550),
+            "{elapsed:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_all_health_slow_provider_does_not_block_others() {
+        let mut registry = ProviderRegistry::new();
+        registry.set_health_timeout(std::time::Duration::from_millis(200));
+        registry.register(Box::new(MockProvider {
+            health_delay: Some(std::time::Duration::from_secs(30)),
+            ..MockProvider::new("contabo")
+        }));
+        for name in ["hetzner", "vultr"] {
+            registry.register(Box::new(MockProvider {
+                health_delay: Some(std::time::Duration::from_millis(100)),
+                ..MockProvider::new(name)
+            }));
+        }
+
+        let started = std::time::Instant::now();
+        let statuses = registry.all_health().await;
+        // Concurrent: bounded by the timeout, not the sum of the delays.
+        assert!(started.elapsed() < std::time::Duration::from_secs(2));
+
+        assert_eq!(statuses.len(), 3);
+        match &statuses[0] {
+            ProviderHealthStatus::Unreachable { provider, error } => {
+                assert_eq!(provider, "contabo");
+                assert!(error.contains("no response"));
+            }
+            other => panic!("expected contabo to time out, got {other:?}"),
+        }
+        assert!(statuses[1..].iter().all(|s| s.health().is_some()));
+    }
+
+    #[tokio::test]
+    async fn test_all_health_reports_failing_provider_as_unreachable() {
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider::new("hetzner")));
+        registry.register(Box::new(MockProvider {
+            fail_health: true,
+            ..MockProvider::new("vultr")
+        }));
+
+        let statuses = registry.all_health().await;
+        assert_eq!(statuses.len(), 2);
+        assert!(statuses[0].health().is_some());
+        match &statuses[1] {
+            ProviderHealthStatus::Unreachable { provider, error } => {
+                assert_eq!(provider, "vultr");
+                assert!(error.contains("503"));
+            }
+            other => panic!("expected vultr unreachable, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_provision_single_creates_one_unpaired_primary() {
+        let mut registry = ProviderRegistry::new();
+        let mock = MockProvider::new("hetzner");
+        let provisioned = mock.provisioned.clone();
+        registry.register(Box::new(mock));
+        let forge = FleetProvisioner::new(&registry);
+
+        let result = forge
+            .provision_single(
+                "acc-trial",
+                InstanceTier::Nano,
+                VpsProvider::Hetzner,
+                "eu-hetzner-nbg1",
+                OsImage::Ubuntu2204,
+                &make_node_config(),
+            )
+            .await
+            .unwrap();
+        assert!(result.success);
+
+        // The requested image is passed through, not replaced by the default.
+        let err = forge
+            .provision_single(
+                "acc-trial",
+                InstanceTier::Nano,
+                VpsProvider::Hetzner,
+                "eu-hetzner-nbg1",
+                OsImage::Debian12,
+                &make_node_config(),
+            )
+            .await
+            .unwrap_err();
+        assert!(format!("{err:#}").contains("debian-12"), "{err:#}");
+
+        let requests = provisioned.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].role, InstanceRole::Primary);
+        assert!(requests[0].pair_instance_id.is_none());
+        assert_eq!(requests[0].tier, InstanceTier::Nano);
+        let config = requests[0].openclaw_config.as_ref().unwrap();
+        assert_eq!(config["gateway_url"], "wss://gateway.example.com");
+        assert_eq!(config["auth_token"], "token");
+        assert_eq!(config["ha"], false);
+        assert_eq!(
+            ssh_keys_from_config(Some(config)),
+            make_node_config().ssh_authorized_keys
+        );
+    }
+
+    // ─── Standby placement ──────────────────────────────────────────────────
+
+    fn sited_region(id: &str, provider: VpsProvider, city: &str, country: &str) -> Region {
+        Region {
+            city: city.to_string(),
+            country: country.to_string(),
+            provider,
+            ..make_region(id)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_standby_placement_prefers_other_provider() {
+        let primary = sited_region("nbg1-a", VpsProvider::Hetzner, "Nuremberg", "DE");
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider {
+            regions: vec![
+                primary.clone(),
+                sited_region("nbg1-b", VpsProvider::Hetzner, "Nuremberg", "DE"),
+            ],
+            ..MockProvider::new("hetzner")
+        }));
+        registry.register(Box::new(MockProvider {
+            regions: vec![
+                sited_region("ams", VpsProvider::Vultr, "Amsterdam", "NL"),
+                Region {
+                    continent: Continent::US,
+                    ..sited_region("ewr", VpsProvider::Vultr, "New Jersey", "US")
+                },
+            ],
+            ..MockProvider::new("vultr")
+        }));
+
+        let (provider, region) = recommend_standby_placement(&primary, &registry)
+            .await
+            .unwrap();
+        assert_eq!(provider, VpsProvider::Vultr);
+        // Same continent beats the farther US site.
+        assert_eq!(region.id, "ams");
+    }
+
+    #[tokio::test]
+    async fn test_standby_placement_skips_unhealthy_provider() {
+        let primary = sited_region("nbg1-a", VpsProvider::Hetzner, "Nuremberg", "DE");
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider {
+            regions: vec![
+                sited_region("nbg1-b", VpsProvider::Hetzner, "Nuremberg", "DE"),
+                sited_region("fsn1", VpsProvider::Hetzner, "Falkenstein", "DE"),
+            ],
+            ..MockProvider::new("hetzner")
+        }));
+        registry.register(Box::new(MockProvider {
+            regions: vec![sited_region("ams", VpsProvider::Vultr, "Amsterdam", "NL")],
+            fail_health: true,
+            ..MockProvider::new("vultr")
+        }));
+
+        let (provider, region) = recommend_standby_placement(&primary, &registry)
+            .await
+            .unwrap();
+        assert_eq!(provider, VpsProvider::Hetzner);
+        assert_eq!(region.id, "fsn1");
+    }
+
+    // ─── Explained selection ────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_select_provider_explained_records_rejection_and_fallback() {
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider {
+            regions: vec![make_region("nbg1")],
+            active_incident: true,
+            ..MockProvider::new("hetzner")
+        }));
+        registry.register(Box::new(MockProvider {
+            regions: vec![Region {
+                provider: VpsProvider::Vultr,
+                ..make_region("ams")
+            }],
+            ..MockProvider::new("vultr")
+        }));
+
+        let outcome = registry
+            .select_provider_explained(&VpsProvider::Hetzner, Continent::EU)
+            .await;
+        let (name, region) = outcome.selected.unwrap();
+        assert_eq!(name, "vultr");
+        assert_eq!(region.id, "ams");
+
+        let trace: Vec<String> = outcome.trace.iter().map(|d| d.to_string()).collect();
+        assert_eq!(
+            trace,
+            vec![
+                "hetzner: skipped — active_incident",
+                "vultr: selected — score 95, EU region ams available",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_provider_explained_reports_missing_region() {
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider {
+            regions: vec![Region {
+                continent: Continent::US,
+                ..make_region("ash")
+            }],
+            ..MockProvider::new("hetzner")
+        }));
+        registry.register(Box::new(MockProvider {
+            fail_health: true,
+            ..MockProvider::new("vultr")
+        }));
+
+        let outcome = registry
+            .select_provider_explained(&VpsProvider::Hetzner, Continent::EU)
+            .await;
+        assert!(outcome.selected.is_none());
+        assert_eq!(outcome.trace[0].reason, "no available EU region");
+        assert!(!outcome.trace[1].selected);
+        assert!(outcome.trace[1].reason.starts_with("health check failed"));
+        assert!(
+            registry
+                .select_provider(&VpsProvider::Hetzner, Continent::EU)
+                .await
+                .is_none()
+        );
+    }
+
+    // ─── Provisioning pause ─────────────────────────────────────────────────
+
+    fn make_pause_registry(hetzner_incident: bool) -> ProviderRegistry {
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider {
+            regions: vec![make_region("nbg1")],
+            active_incident: hetzner_incident,
+            ..MockProvider::new("hetzner")
+        }));
+        registry.register(Box::new(MockProvider {
+            regions: vec![Region {
+                provider: VpsProvider::Vultr,
+                ..make_region("ams")
+            }],
+            ..MockProvider::new("vultr")
+        }));
+        registry
+    }
+
+    #[tokio::test]
+    async fn test_incident_pauses_provider_until_health_recovers() {
+        let mut registry = make_pause_registry(true);
+
+        let (provider, _) = registry
+            .select_provider(&VpsProvider::Hetzner, Continent::EU)
+            .await
+            .unwrap();
+        assert_eq!(provider.name(), "vultr");
+        assert!(registry.is_paused(&VpsProvider::Hetzner));
+        assert_eq!(registry.paused_providers(), vec![VpsProvider::Hetzner]);
+
+        // Incident clears: the next health check lifts the pause.
+        registry.register(Box::new(MockProvider {
+            regions: vec![make_region("nbg1")],
+            ..MockProvider::new("hetzner")
+        }));
+        let (provider, _) = registry
+            .select_provider(&VpsProvider::Hetzner, Continent::EU)
+            .await
+            .unwrap();
+        assert_eq!(provider.name(), "hetzner");
+        assert!(!registry.is_paused(&VpsProvider::Hetzner));
+    }
+
+    #[tokio::test]
+    async fn test_manual_pause_overrides_healthy_provider() {
+        let mut registry = make_pause_registry(false);
+        registry.pause(VpsProvider::Hetzner);
+
+        let outcome = registry
+            .select_provider_explained(&VpsProvider::Hetzner, Continent::EU)
+            .await;
+        assert_eq!(outcome.selected.unwrap().0, "vultr");
+        assert_eq!(outcome.trace[0].reason, "provisioning paused by operator");
+        // A healthy report does not lift an operator pause.
+        registry.all_health().await;
+        assert!(registry.is_paused(&VpsProvider::Hetzner));
+
+        registry.resume(VpsProvider::Hetzner);
+        let (provider, _) = registry
+            .select_provider(&VpsProvider::Hetzner, Continent::EU)
+            .await
+            .unwrap();
+        assert_eq!(provider.name(), "hetzner");
+    }
+
+    // ─── Region index ───────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_repeated_selection_reads_regions_once() {
+        let provider = MockProvider {
+            regions: vec![make_region("nbg1"), make_region("fsn1")],
+            ..MockProvider::new("hetzner")
+        };
+        let calls = provider.region_calls.clone();
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(provider));
+
+        for _ in 0..500 {
+            let (_, region) = registry
+                .select_provider(&VpsProvider::Hetzner, Continent::EU)
+                .await
+                .unwrap();
+            assert_eq!(region.id, "nbg1");
+        }
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+        // A continent the provider does not serve is answered from the index too.
+        assert!(
+            registry
+                .select_provider(&VpsProvider::Hetzner, Continent::APAC)
+                .await
+                .is_none()
+        );
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+        registry.refresh_regions();
+        registry
+            .select_provider(&VpsProvider::Hetzner, Continent::EU)
+            .await
+            .unwrap();
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+    }
+
+    fn latency_region(id: &str, provider: VpsProvider, latency_class: LatencyClass) -> Region {
+        Region {
+            provider,
+            latency_class,
+            ..make_region(id)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_low_latency_region_wins_between_equal_providers() {
+        for seed in 0..8 {
+            let mut registry = ProviderRegistry::new();
+            registry.set_rng(Arc::new(SeededRng::from_seed(seed)));
+            registry.register(Box::new(MockProvider {
+                regions: vec![latency_region(
+                    "ams",
+                    VpsProvider::Vultr,
+                    LatencyClass::Medium,
+                )],
+                ..MockProvider::new("vultr")
+            }));
+            registry.register(Box::new(MockProvider {
+                regions: vec![latency_region(
+                    "nue",
+                    VpsProvider::Contabo,
+                    LatencyClass::Low,
+                )],
+                ..MockProvider::new("contabo")
+            }));
+
+            let outcome = registry
+                .select_provider_explained(&VpsProvider::Hetzner, Continent::EU)
+                .await;
+            let (name, region) = outcome.selected.unwrap();
+            assert_eq!(
+                (name.as_str(), region.id.as_str()),
+                ("contabo", "nue"),
+                "seed {seed}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_latency_weight_is_configurable() {
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(MockProvider {
+            regions: vec![latency_region(
+                "ams",
+                VpsProvider::Vultr,
+                LatencyClass::Medium,
+            )],
+            ..MockProvider::new("vultr")
+        }));
+        registry.register(Box::new(MockProvider {
+            regions: vec![latency_region(
+                "nue",
+                VpsProvider::Contabo,
+                LatencyClass::Low,
+            )],
+            health_score: 90,
+            ..MockProvider::new("contabo")
+        }));
+
+        // 90 with a Low region beats 95 with a Medium one at the default weight...
+        let selected = registry
+            .select_provider_explained(&VpsProvider::Hetzner, Continent::EU)
+            .await
+            .selected
+            .unwrap();
+        assert_eq!(selected.0, "contabo");
+
+        // ...but health alone decides once latency is weighted at zero.
+        registry.set_latency_weight(0);
+        let selected = registry
+            .select_provider_explained(&VpsProvider::Hetzner, Continent::EU)
+            .await
+            .selected
+            .unwrap();
+        assert_eq!(selected.0, "vultr");
+    }
+
+    // ─── Error classification ───────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_classify_error_maps_http_status_to_category() {
+        let server = wiremock::MockServer::start().await;
+        for (status, path) in [
+            (503, "/busy"),
+            (404, "/gone"),
+            (401, "/auth"),
+            (422, "/bad"),
+        ] {
+            wiremock::Mock::given(wiremock::matchers::path(path))
+                .respond_with(wiremock::ResponseTemplate::new(status))
+                .mount(&server)
+                .await;
+        }
+        let client = reqwest::Client::new();
+        let fail = |path: &'static str| {
+            let req = client.get(format!("{}{path}", server.uri()));
+            async move {
+                let err: anyhow::Error = req
+                    .send()
+                    .await
+                    .unwrap()
+                    .error_for_status()
+                    .unwrap_err()
+                    .into();
+                classify_error(&err.context("Hetzner API call failed"))
+            }
+        };
+
+        let busy = fail("/busy").await;
+        assert!(matches!(busy, ClawError::Transient(_)));
+        assert!(busy.is_retryable());
+        assert!(matches!(fail("/gone").await, ClawError::NotFound(_)));
+        assert!(matches!(fail("/auth").await, ClawError::Unauthorized(_)));
+        assert!(matches!(fail("/bad").await, ClawError::Permanent(_)));
+    }
+
+    #[test]
+    fn test_classify_error_non_http_failures() {
+        let refused = anyhow::Error::new(ProvisionError::Refused(
+            "delete Hetzner server 42: not ours".into(),
+        ));
+        assert!(matches!(classify_error(&refused), ClawError::Safety(_)));
+        let missing = anyhow::Error::new(ProvisionError::NotRegistered("ovh".into()))
+            .context("standby provider");
+        assert!(matches!(classify_error(&missing), ClawError::NotFound(_)));
+        // Wording alone no longer decides the category.
+        let worded = anyhow::anyhow!("refusing to continue: provider not registered");
+        assert!(matches!(classify_error(&worded), ClawError::Permanent(_)));
+        let typed = anyhow::Error::new(ClawError::Transient("gateway busy".into()));
+        assert!(matches!(classify_error(&typed), ClawError::Transient(_)));
+        let other = anyhow::anyhow!("Vultr resize not yet implemented");
+        let classified = classify_error(&other);
+        assert!(matches!(classified, ClawError::Permanent(_)));
+        assert!(!classified.is_retryable());
+    }
+
+    // ─── Rate-limit handling ────────────────────────────────────────────────
+
+    /// A provisioner whose primary is a Hetzner API on `server` that
+    /// answers POST /servers with `response`. The provider keeps its default
+    /// retry policy: the pair retry loop must be the only retry layer.
+    async fn hetzner_pair_registry(
+        server: &wiremock::MockServer,
+        response: wiremock::ResponseTemplate,
+        expected_calls: u64,
+    ) -> ProviderRegistry {
+        use wiremock::matchers::{method, path};
+        wiremock::Mock::given(method("POST"))
+            .and(path("/servers"))
+            .respond_with(response)
+            .expect(expected_calls)
+            .mount(server)
+            .await;
+        let hetzner = mock_hetzner(server).with_sleeper(Arc::new(RecordingSleeper::default()));
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(hetzner));
+        registry.register(Box::new(MockProvider::new("vultr")));
+        registry
+    }
+
+    #[tokio::test]
+    async fn test_provision_retry_waits_for_retry_after_on_429() {
+        let server = wiremock::MockServer::start().await;
+        let registry = hetzner_pair_registry(
+            &server,
+            wiremock::ResponseTemplate::new(429).insert_header("Retry-After", "30"),
+            3,
+        )
+        .await;
+        let sleeper = Arc::new(RecordingSleeper::default());
+        let provisioner = FleetProvisioner::new(&registry).with_sleeper(sleeper.clone());
+
+        let err = provisioner
+            .provision_pair_with_retry(make_pair_request())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.chain().find_map(|c| c.downcast_ref::<ProviderError>()),
+            Some(ProviderError::RateLimited {
+                retry_after: Some(d)
+            }) if *d == std::time::Duration::from_secs(30)
+        ));
+        assert!(matches!(classify_error(&err), ClawError::Transient(_)));
+        assert_eq!(
+            *sleeper.slept.lock().unwrap(),
+            vec![std::time::Duration::from_secs(30); 2]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_provision_retry_gives_up_on_fatal_status() {
+        let server = wiremock::MockServer::start().await;
+        let registry =
+            hetzner_pair_registry(&server, wiremock::ResponseTemplate::new(401), 1).await;
+        let sleeper = Arc::new(RecordingSleeper::default());
+        let provisioner = FleetProvisioner::new(&registry).with_sleeper(sleeper.clone());
+
+        let err = provisioner
+            .provision_pair_with_retry(make_pair_request())
+            .await
+            .unwrap_err();
+        assert!(matches!(classify_error(&err), ClawError::Unauthorized(_)));
+        assert!(sleeper.slept.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_provider_error_display() {
+        assert_eq!(
+            ProviderError::Fatal(400).to_string(),
+            "provider rejected the request: HTTP 400"
+        );
+        assert!(
+            ProviderError::RateLimited {
+                retry_after: Some(std::time::Duration::from_secs(30))
+            }
+            .to_string()
+            .contains("30s")
+        );
+    }
+
+    #[test]
+    fn test_parse_retry_after_seconds_and_http_date() {
+        let now = DateTime::parse_from_rfc3339("2015-10-21T07:28:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(std::time::Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now),
+            Some(std::time::Duration::from_secs(30))
+        );
+        // Already passed: retry immediately.
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:00:00 GMT", now),
+            Some(std::time::Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+
+    #[tokio::test]
+    async fn test_send_with_rate_limit_waits_then_succeeds() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let policy = RetryPolicy {
+            max_retries: 3,
+            base_delay_ms: 1,
+            max_delay_ms: 50,
+            jitter: false,
+        };
+        let client = reqwest::Client::new();
+        let rng = SeededRng::from_seed(7);
+        let sleeper = RecordingSleeper::default();
+        let resp =
+            send_with_rate_limit(&policy, &rng, &sleeper, false, || client.get(server.uri()))
+                .await
+                .unwrap();
+        assert_eq!(resp.status(), reqwest::StatusCode::OK);
+        // Retry-After of 1s, capped at max_delay_ms.
+        assert_eq!(
+            *sleeper.slept.lock().unwrap(),
+            vec![std::time::Duration::from_millis(50)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_vultr_teardown_uses_configured_retry_policy() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/instances/vm-1"))
+            .respond_with(ResponseTemplate::new(429))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let vultr = VultrProvider {
+            base_url: server.uri(),
+            ..VultrProvider::new("key".to_string())
+        }
+        .with_retry_policy(RetryPolicy {
+            max_retries: 1,
+            base_delay_ms: 1,
+            max_delay_ms: 1,
+            jitter: false,
+        })
+        .with_sleeper(Arc::new(RecordingSleeper::default()));
+        let err = vultr.teardown("vm-1", "acc-1").await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ProviderError>(),
+            Some(ProviderError::RateLimited { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_send_with_rate_limit_gives_up_after_max_retries() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let policy = RetryPolicy {
+            max_retries: 2,
+            base_delay_ms: 1,
+            max_delay_ms: 1,
+            jitter: false,
+        };
+        let client = reqwest::Client::new();
+        let rng = SeededRng::from_seed(7);
+        let sleeper = RecordingSleeper::default();
+        let resp =
+            send_with_rate_limit(&policy, &rng, &sleeper, false, || client.get(server.uri()))
+                .await
+                .unwrap();
+        assert_eq!(resp.status(), reqwest::StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(sleeper.slept.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_send_with_rate_limit_retries_post_only_on_429() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let policy = RetryPolicy {
+            max_retries: 2,
+            base_delay_ms: 1,
+            max_delay_ms: 1,
+            jitter: false,
+        };
+        let client = reqwest::Client::new();
+        let rng = SeededRng::from_seed(7);
+
+        // A 503 may already have created the server: no second POST.
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let sleeper = RecordingSleeper::default();
+        let resp =
+            send_with_rate_limit(&policy, &rng, &sleeper, false, || client.post(server.uri()))
+                .await
+                .unwrap();
+        assert_eq!(resp.status(), reqwest::StatusCode::SERVICE_UNAVAILABLE);
+        assert!(sleeper.slept.lock().unwrap().is_empty());
+
+        // A 429 was refused outright, so it is safe to send again.
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(429))
+            .expect(3)
+            .mount(&server)
+            .await;
+        let resp =
+            send_with_rate_limit(&policy, &rng, &sleeper, false, || client.post(server.uri()))
+                .await
+                .unwrap();
+        assert_eq!(resp.status(), reqwest::StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(sleeper.slept.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_hetzner_teardown_honours_retry_after_on_delete() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        // The teardown loop is the only retry layer, so the 429 costs one more
+        // ownership check before the second DELETE.
+        Mock::given(method("GET"))
+            .and(path("/servers/42"))
+            .respond_with(owned_server("acc-1", "clawops"))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/servers/42"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/servers/42"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        mock_hetzner(&server)
+            .teardown("42", "acc-1")
+            .await
+            .expect("teardown succeeds once the rate limit lifts");
+    }
+
+    #[tokio::test]
+    async fn test_hetzner_helpers_report_typed_provider_errors() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/servers/42"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/servers/42/actions/poweroff"))
+            .respond_with(ResponseTemplate::new(400))
+            .mount(&server)
+            .await;
+
+        let hetzner = mock_hetzner(&server);
+        let err = hetzner.get_server(42).await.unwrap_err();
+        assert_eq!(
+            err.chain().find_map(|c| c.downcast_ref::<ProviderError>()),
+            Some(&ProviderError::Fatal(401))
+        );
+        assert!(matches!(classify_error(&err), ClawError::Unauthorized(_)));
+        let err = hetzner.hibernate("42").await.unwrap_err();
+        assert!(matches!(classify_error(&err), ClawError::Permanent(_)));
+    }
+
+    // ─── Chaos provider ─────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_chaos_provider_injects_configured_faults() {
+        let chaos = ChaosProvider::new(
+            Box::new(MockProvider::new("hetzner")),
+            FaultProfile {
+                fail_provision_every: Some(2),
+                health_score: Some(30),
+                active_incident: true,
+                latency_ms: 5,
+                ..FaultProfile::default()
+            },
+        );
+        assert_eq!(chaos.name(), "hetzner");
+
+        let req = ProvisionRequest {
+            request_id: Uuid::new_v4(),
+            account_id: "acc-1".to_string(),
+            tier: InstanceTier::Standard,
+            role: InstanceRole::Primary,
+            provider: VpsProvider::Hetzner,
+            region: "nbg1".to_string(),
+            os_image: OsImage::default(),
+            pair_instance_id: None,
+            openclaw_config: None,
+            requested_by: "test".to_string(),
+            requested_at: Utc::now(),
+        };
+        let started = std::time::Instant::now();
+        assert!(chaos.provision(&req).await.is_ok());
+        assert!(started.elapsed() >= std::time::Duration::from_millis(5));
+        let err = chaos.provision(&req).await.unwrap_err();
+        assert!(err.to_string().contains("injected provision failure"));
+        assert!(chaos.provision(&req).await.is_ok());
+        assert_eq!(chaos.provision_calls(), 3);
+
+        let health = chaos.provider_health().await.unwrap();
+        assert_eq!(health.health_score, 30);
+        assert!(health.active_incident);
+        assert!(chaos.teardown("srv-1", "acc-1").await.is_ok());
+
+        let down = ChaosProvider::new(
+            Box::new(MockProvider::new("hetzner")),
+            FaultProfile {
+                health_unreachable: true,
+                ..FaultProfile::outage()
+            },
+        );
+        assert!(down.provider_health().await.is_err());
+        assert!(down.teardown("srv-1", "acc-1").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_selection_routes_around_chaos_outage() {
+        let mut registry = ProviderRegistry::new();
+        registry.register(Box::new(ChaosProvider::new(
+            Box::new(MockProvider {
+                regions: vec![make_region("nbg1")],
+                ..MockProvider::new("hetzner")
+            }),
+            FaultProfile::outage(),
+        )));
+        registry.register(Box::new(MockProvider {
+            regions: vec![Region {
+                provider: VpsProvider::Vultr,
+                ..make_region("ams")
+            }],
+            ..MockProvider::new("vultr")
+        }));
+
+        let outcome = registry
+            .select_provider_explained(&VpsProvider::Hetzner, Continent::EU)
+            .await;
+        assert_eq!(outcome.selected.unwrap().0, "vultr");
+        assert_eq!(outcome.trace[0].reason, "active_incident");
+        assert!(registry.is_paused(&VpsProvider::Hetzner));
+    }
 }
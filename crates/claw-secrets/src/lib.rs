@@ -178,13 +178,24 @@ impl SecretStore {
             .collect()
     }
 
+    /// Write the current state to disk. Also runs on drop.
+    pub fn flush(&self) -> std::io::Result<()> {
+        self.store.save(&self.secrets)
+    }
+
     fn snapshot(&self) {
-        if let Err(e) = self.store.save(&self.secrets) {
+        if let Err(e) = self.flush() {
             warn!(error = %e, "failed to snapshot secret store");
         }
     }
 }
 
+impl Drop for SecretStore {
+    fn drop(&mut self) {
+        self.snapshot();
+    }
+}
+
 // ─────────────────────────────────────────────────────────────
 // Tests
 // ─────────────────────────────────────────────────────────────
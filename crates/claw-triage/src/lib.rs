@@ -8,8 +8,10 @@
 #![forbid(unsafe_code)]
 
 use chrono::{DateTime, Utc};
-use claw_proto::{HealthCheck, VpsProvider};
+use claw_proto::{ClawError, HealthCheck, SpofKind, VpsProvider};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use tracing::warn;
 use uuid::Uuid;
 
 // ─── Severity ─────────────────────────────────────────────────────────────────
@@ -77,6 +79,19 @@ pub struct TimelineEntry {
     pub actor: String,
     pub action: String,
     pub outcome: String,
+    /// What happened, for analysis; `action` and `outcome` are for people.
+    #[serde(default)]
+    pub event: Option<TimelineEvent>,
+}
+
+/// Structured timeline events that feed post-mortem analysis.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TimelineEvent {
+    /// An affected account can be taken out by a single failure.
+    SinglePointOfFailure { account_id: String, kind: SpofKind },
+    /// A failover was attempted.
+    Failover { succeeded: bool },
 }
 
 // ─── Root cause ───────────────────────────────────────────────────────────────
@@ -125,6 +140,19 @@ pub struct HealthEvent {
     pub detected_at: DateTime<Utc>,
 }
 
+// ─── User impact ──────────────────────────────────────────────────────────────
+
+/// Maps instances and regions to the number of users actually served by them.
+///
+/// Lets Triage derive impact from fleet state instead of trusting the
+/// `affected_users` figure supplied with a trigger.
+pub trait UserImpactResolver: Send + Sync {
+    /// Distinct users served by the given instances.
+    fn users_for_instances(&self, instance_ids: &[String]) -> u32;
+    /// Users served by all instances in a region.
+    fn users_for_region(&self, region: &str) -> u32;
+}
+
 // ─── Incident ─────────────────────────────────────────────────────────────────
 
 /// A fully structured incident record.
@@ -144,14 +172,36 @@ pub struct Incident {
     pub actions_taken: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub resolved_at: Option<DateTime<Utc>>,
+    /// First time `auto_resolve` saw every affected instance healthy.
+    #[serde(default)]
+    pub recovered_since: Option<DateTime<Utc>>,
+    /// Operator who acknowledged the incident; required to auto-resolve a P1.
+    #[serde(default)]
+    pub acknowledged_by: Option<String>,
 }
 
 impl Incident {
     /// Duration from creation to now (or to resolution).
+    ///
+    /// Zero if `created_at` is later than the end time (clock skew).
     pub fn duration_mins(&self) -> u64 {
-        let end = self.resolved_at.unwrap_or_else(Utc::now);
+        self.duration_mins_at(Utc::now())
+    }
+
+    /// Like [`duration_mins`](Self::duration_mins), for an open incident
+    /// measured at `now`.
+    pub fn duration_mins_at(&self, now: DateTime<Utc>) -> u64 {
+        let end = self.resolved_at.unwrap_or(now);
         let diff = end.signed_duration_since(self.created_at);
-        diff.num_minutes().unsigned_abs()
+        if diff < chrono::TimeDelta::zero() {
+            warn!(
+                incident_id = %self.id,
+                skew_secs = -diff.num_seconds(),
+                "incident ends before it was created; reporting zero duration"
+            );
+            return 0;
+        }
+        u64::try_from(diff.num_minutes()).unwrap_or(0)
     }
 }
 
@@ -172,22 +222,94 @@ pub struct IncidentReport {
     pub generated_at: DateTime<Utc>,
 }
 
+// ─── Post-mortem ──────────────────────────────────────────────────────────────
+
+/// Conditions that made an incident worse or longer than it had to be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContributingFactor {
+    /// An affected account had no standby outside the failing region.
+    SinglePointOfFailure,
+    /// A recorded failover attempt did not succeed.
+    FailedFailover,
+    ProviderOutage,
+    ResourceExhaustion,
+}
+
+/// What an incident touched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlastRadius {
+    pub instances: Vec<String>,
+    pub affected_users: u32,
+    pub provider: VpsProvider,
+    pub region: String,
+}
+
+/// Structured post-mortem for a resolved incident.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostMortem {
+    pub incident_id: String,
+    pub severity: Severity,
+    pub title: String,
+    pub timeline: Vec<TimelineEntry>,
+    pub root_cause: Option<RootCause>,
+    /// Minutes from detection to resolution.
+    pub mttr_mins: u64,
+    pub blast_radius: BlastRadius,
+    pub contributing_factors: Vec<ContributingFactor>,
+    pub action_items: Vec<String>,
+    pub generated_at: DateTime<Utc>,
+}
+
 // ─── Incident Manager ─────────────────────────────────────────────────────────
 
+/// How long affected instances must stay healthy before `auto_resolve` acts.
+pub const DEFAULT_RECOVERY_WINDOW_MINS: i64 = 15;
+
 /// The Triage agent's incident lifecycle manager.
 pub struct IncidentManager {
     incidents: Vec<Incident>,
+    resolver: Option<Box<dyn UserImpactResolver>>,
+    recovery_window: chrono::Duration,
 }
 
 impl IncidentManager {
     pub fn new() -> Self {
         Self {
             incidents: Vec::new(),
+            resolver: None,
+            recovery_window: chrono::Duration::minutes(DEFAULT_RECOVERY_WINDOW_MINS),
+        }
+    }
+
+    /// Resolve affected-user counts through `resolver` instead of the trigger.
+    pub fn with_resolver(resolver: Box<dyn UserImpactResolver>) -> Self {
+        Self {
+            resolver: Some(resolver),
+            ..Self::new()
         }
     }
 
+    /// Override how long recovery must be sustained before `auto_resolve` acts.
+    pub fn with_recovery_window(mut self, window: chrono::Duration) -> Self {
+        self.recovery_window = window;
+        self
+    }
+
+    /// Users served by `region`, if a resolver is configured.
+    pub fn region_impact(&self, region: &str) -> Option<u32> {
+        self.resolver.as_ref().map(|r| r.users_for_region(region))
+    }
+
     /// Create a new incident from a health event.
-    pub fn create_incident(&mut self, trigger: HealthEvent) -> &Incident {
+    ///
+    /// With a resolver configured, `affected_users` (and so severity) is derived
+    /// from the affected instance rather than taken from the trigger.
+    pub fn create_incident(&mut self, mut trigger: HealthEvent) -> &Incident {
+        if let Some(resolver) = &self.resolver {
+            trigger.affected_users =
+                resolver.users_for_instances(std::slice::from_ref(&trigger.instance_id));
+        }
         let severity = classify_severity(trigger.affected_users, trigger.data_loss_risk);
         let id = Uuid::new_v4().to_string();
 
@@ -201,6 +323,7 @@ impl IncidentManager {
                 "Health event detected: {} — score {}",
                 trigger.description, trigger.health_score
             ),
+            event: None,
         };
 
         let incident = Incident {
@@ -218,6 +341,8 @@ impl IncidentManager {
             actions_taken: Vec::new(),
             created_at: trigger.detected_at,
             resolved_at: None,
+            recovered_since: None,
+            acknowledged_by: None,
         };
 
         self.incidents.push(incident);
@@ -229,13 +354,13 @@ impl IncidentManager {
         &mut self,
         incident_id: &str,
         entry: TimelineEntry,
-    ) -> Result<(), String> {
+    ) -> Result<(), ClawError> {
         match self.incidents.iter_mut().find(|i| i.id == incident_id) {
             Some(inc) => {
                 inc.timeline.push(entry);
                 Ok(())
             }
-            None => Err(format!("Incident {} not found", incident_id)),
+            None => Err(incident_not_found(incident_id)),
         }
     }
 
@@ -244,7 +369,7 @@ impl IncidentManager {
         &mut self,
         incident_id: &str,
         status: IncidentStatus,
-    ) -> Result<(), String> {
+    ) -> Result<(), ClawError> {
         match self.incidents.iter_mut().find(|i| i.id == incident_id) {
             Some(inc) => {
                 inc.status = status;
@@ -253,25 +378,184 @@ impl IncidentManager {
                 }
                 Ok(())
             }
-            None => Err(format!("Incident {} not found", incident_id)),
+            None => Err(incident_not_found(incident_id)),
         }
     }
 
+    /// Record operator acknowledgment. Lets `auto_resolve` close a P1.
+    pub fn acknowledge(&mut self, incident_id: &str, operator: &str) -> Result<(), ClawError> {
+        match self.incidents.iter_mut().find(|i| i.id == incident_id) {
+            Some(inc) => {
+                inc.acknowledged_by = Some(operator.to_string());
+                inc.timeline.push(TimelineEntry {
+                    timestamp: Utc::now(),
+                    actor: operator.to_string(),
+                    action: "acknowledged".to_string(),
+                    outcome: "Operator acknowledged incident".to_string(),
+                    event: None,
+                });
+                Ok(())
+            }
+            None => Err(incident_not_found(incident_id)),
+        }
+    }
+
+    /// Close out incidents whose affected instances have all stayed at or
+    /// above `recovery_threshold` for the recovery window.
+    ///
+    /// Instances missing from `current_health` count as not recovered. P1s
+    /// are only moved to `Mitigated` until an operator has acknowledged them.
+    /// Returns the ids of incidents whose status changed.
+    pub fn auto_resolve(
+        &mut self,
+        current_health: &HashMap<String, u8>,
+        recovery_threshold: u8,
+    ) -> Vec<String> {
+        let now = Utc::now();
+        let mut changed = Vec::new();
+
+        for inc in self
+            .incidents
+            .iter_mut()
+            .filter(|i| i.status != IncidentStatus::Resolved)
+        {
+            let recovered = inc.affected_instances.iter().all(|id| {
+                current_health
+                    .get(id)
+                    .is_some_and(|score| *score >= recovery_threshold)
+            });
+            if !recovered {
+                inc.recovered_since = None;
+                continue;
+            }
+            let since = *inc.recovered_since.get_or_insert(now);
+            if now - since < self.recovery_window {
+                continue;
+            }
+
+            let mins = (now - since).num_minutes();
+            if inc.severity == Severity::P1 && inc.acknowledged_by.is_none() {
+                if inc.status == IncidentStatus::Mitigated {
+                    continue;
+                }
+                inc.status = IncidentStatus::Mitigated;
+                inc.timeline.push(TimelineEntry {
+                    timestamp: now,
+                    actor: "triage".to_string(),
+                    action: "auto_mitigated".to_string(),
+                    outcome: format!(
+                        "All affected instances healthy for {mins} min — awaiting operator acknowledgment to resolve"
+                    ),
+                    event: None,
+                });
+            } else {
+                inc.status = IncidentStatus::Resolved;
+                inc.resolved_at = Some(now);
+                inc.timeline.push(TimelineEntry {
+                    timestamp: now,
+                    actor: "triage".to_string(),
+                    action: "auto_resolved".to_string(),
+                    outcome: format!("All affected instances healthy for {mins} min"),
+                    event: None,
+                });
+            }
+            changed.push(inc.id.clone());
+        }
+
+        changed
+    }
+
     /// Add an instance to the affected list.
     pub fn add_affected_instance(
         &mut self,
         incident_id: &str,
         instance_id: String,
-    ) -> Result<(), String> {
+    ) -> Result<(), ClawError> {
         match self.incidents.iter_mut().find(|i| i.id == incident_id) {
             Some(inc) => {
                 if !inc.affected_instances.contains(&instance_id) {
                     inc.affected_instances.push(instance_id);
+                    if let Some(resolver) = &self.resolver {
+                        // Severity only escalates: data-loss P1s stay P1.
+                        inc.affected_users = resolver.users_for_instances(&inc.affected_instances);
+                        inc.severity = inc
+                            .severity
+                            .max(classify_severity(inc.affected_users, false));
+                    }
                 }
                 Ok(())
             }
-            None => Err(format!("Incident {} not found", incident_id)),
+            None => Err(incident_not_found(incident_id)),
+        }
+    }
+
+    /// Fold incident `from` into `into`: instances, timeline, and actions are
+    /// combined, severity is recomputed, and `from` is resolved with a note.
+    ///
+    /// Both incidents must exist, differ, and still be unresolved.
+    pub fn merge(&mut self, into: &str, from: &str) -> Result<&Incident, ClawError> {
+        if into == from {
+            return Err(ClawError::Permanent(format!(
+                "Cannot merge incident {into} into itself"
+            )));
+        }
+        let find = |id: &str| {
+            self.incidents
+                .iter()
+                .position(|i| i.id == id)
+                .ok_or_else(|| incident_not_found(id))
+        };
+        let (into_idx, from_idx) = (find(into)?, find(from)?);
+        for idx in [into_idx, from_idx] {
+            if self.incidents[idx].status == IncidentStatus::Resolved {
+                return Err(ClawError::Permanent(format!(
+                    "Incident {} is already resolved",
+                    self.incidents[idx].id
+                )));
+            }
+        }
+
+        let now = Utc::now();
+        let source = self.incidents[from_idx].clone();
+        let target = &mut self.incidents[into_idx];
+        for instance in &source.affected_instances {
+            if !target.affected_instances.contains(instance) {
+                target.affected_instances.push(instance.clone());
+            }
         }
+        target.affected_users = match &self.resolver {
+            Some(resolver) => resolver.users_for_instances(&target.affected_instances),
+            None => target.affected_users + source.affected_users,
+        };
+        target.severity = target
+            .severity
+            .max(source.severity)
+            .max(classify_severity(target.affected_users, false));
+        target.timeline.extend(source.timeline);
+        target.timeline.sort_by_key(|e| e.timestamp);
+        target.timeline.push(TimelineEntry {
+            timestamp: now,
+            actor: "triage".to_string(),
+            action: "merged".to_string(),
+            outcome: format!("Merged incident {from}"),
+            event: None,
+        });
+        target.actions_taken.extend(source.actions_taken);
+        target.created_at = target.created_at.min(source.created_at);
+        target.recovered_since = None;
+
+        let merged = &mut self.incidents[from_idx];
+        merged.status = IncidentStatus::Resolved;
+        merged.resolved_at = Some(now);
+        merged.timeline.push(TimelineEntry {
+            timestamp: now,
+            actor: "triage".to_string(),
+            action: "merged".to_string(),
+            outcome: format!("merged into {into}"),
+            event: None,
+        });
+
+        Ok(&self.incidents[into_idx])
     }
 
     /// Determine root cause from health check data.
@@ -302,6 +586,37 @@ impl IncidentManager {
         }
     }
 
+    /// Generate a post-mortem. Only resolved incidents have one.
+    pub fn generate_postmortem(&self, incident: &Incident) -> Result<PostMortem, ClawError> {
+        if incident.status != IncidentStatus::Resolved {
+            return Err(ClawError::Permanent(format!(
+                "Incident {} is not resolved (status: {:?})",
+                incident.id, incident.status
+            )));
+        }
+
+        let contributing_factors = contributing_factors(incident);
+        let action_items = build_action_items(incident, &contributing_factors);
+
+        Ok(PostMortem {
+            incident_id: incident.id.clone(),
+            severity: incident.severity,
+            title: incident.title.clone(),
+            timeline: incident.timeline.clone(),
+            root_cause: incident.root_cause.clone(),
+            mttr_mins: incident.duration_mins(),
+            blast_radius: BlastRadius {
+                instances: incident.affected_instances.clone(),
+                affected_users: incident.affected_users,
+                provider: incident.provider,
+                region: incident.region.clone(),
+            },
+            contributing_factors,
+            action_items,
+            generated_at: Utc::now(),
+        })
+    }
+
     /// Find an incident by ID.
     pub fn get(&self, incident_id: &str) -> Option<&Incident> {
         self.incidents.iter().find(|i| i.id == incident_id)
@@ -324,12 +639,171 @@ impl IncidentManager {
     }
 }
 
+fn incident_not_found(incident_id: &str) -> ClawError {
+    ClawError::NotFound(format!("Incident {incident_id} not found"))
+}
+
 impl Default for IncidentManager {
     fn default() -> Self {
         Self::new()
     }
 }
 
+// ─── Notification digest ──────────────────────────────────────────────────────
+
+/// Default batching window for non-P1 incident notifications.
+pub const DEFAULT_DIGEST_WINDOW_SECS: i64 = 120;
+
+/// The part of an incident a notification carries.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IncidentNotice {
+    pub incident_id: String,
+    pub severity: Severity,
+    pub title: String,
+    pub region: String,
+}
+
+impl IncidentNotice {
+    pub fn from_incident(incident: &Incident) -> Self {
+        Self {
+            incident_id: incident.id.clone(),
+            severity: incident.severity,
+            title: incident.title.clone(),
+            region: incident.region.clone(),
+        }
+    }
+}
+
+/// A batch of notifications collected over one window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncidentDigest {
+    pub window_start: DateTime<Utc>,
+    pub window_end: DateTime<Utc>,
+    /// P1s in the window, listed individually (they were also sent immediately).
+    pub p1s: Vec<IncidentNotice>,
+    /// Lower-severity incidents counted per (severity, region), most severe first.
+    pub counts: Vec<(Severity, String, usize)>,
+}
+
+impl IncidentDigest {
+    pub fn total(&self) -> usize {
+        self.p1s.len() + self.counts.iter().map(|(_, _, n)| n).sum::<usize>()
+    }
+
+    /// One-line digest, e.g. "Incident digest: 4 incidents. P1 inc-1 (...); 3 P3 in eu-hetzner-nbg1."
+    pub fn summary(&self) -> String {
+        let mut parts: Vec<String> = self
+            .p1s
+            .iter()
+            .map(|n| format!("P1 {} ({})", n.incident_id, n.title))
+            .collect();
+        parts.extend(
+            self.counts
+                .iter()
+                .map(|(severity, region, n)| format!("{n} {severity} in {region}")),
+        );
+        format!(
+            "Incident digest: {} incidents. {}.",
+            self.total(),
+            parts.join("; ")
+        )
+    }
+}
+
+/// A notification ready to send.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Notification {
+    /// A P1, sent as soon as it is raised.
+    Immediate(IncidentNotice),
+    Digest(IncidentDigest),
+}
+
+/// Batches incident notifications so a burst becomes one digest.
+///
+/// P1s bypass the window and are returned immediately from `push`; everything
+/// else waits until `flush_due` is called after the window has elapsed.
+#[derive(Debug, Clone)]
+pub struct NotificationDigest {
+    window: chrono::Duration,
+    window_start: Option<DateTime<Utc>>,
+    pending: Vec<IncidentNotice>,
+}
+
+impl NotificationDigest {
+    pub fn new() -> Self {
+        Self::with_window(chrono::Duration::seconds(DEFAULT_DIGEST_WINDOW_SECS))
+    }
+
+    pub fn with_window(window: chrono::Duration) -> Self {
+        Self {
+            window,
+            window_start: None,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Queue a notification. Returns it at once if it is a P1.
+    pub fn push(&mut self, incident: &Incident, now: DateTime<Utc>) -> Option<Notification> {
+        let notice = IncidentNotice::from_incident(incident);
+        self.window_start.get_or_insert(now);
+        self.pending.push(notice.clone());
+        (notice.severity == Severity::P1).then_some(Notification::Immediate(notice))
+    }
+
+    /// Emit the digest if the window opened at least `window` ago.
+    pub fn flush_due(&mut self, now: DateTime<Utc>) -> Option<Notification> {
+        let start = self.window_start?;
+        if now - start < self.window {
+            return None;
+        }
+        self.flush(now)
+    }
+
+    /// Emit whatever is pending regardless of the window.
+    ///
+    /// Returns `None` when only P1s are pending: they have already been sent.
+    pub fn flush(&mut self, now: DateTime<Utc>) -> Option<Notification> {
+        let window_start = self.window_start.take()?;
+        let pending = std::mem::take(&mut self.pending);
+
+        let mut p1s = Vec::new();
+        let mut counts: BTreeMap<(std::cmp::Reverse<Severity>, String), usize> = BTreeMap::new();
+        for notice in pending {
+            if notice.severity == Severity::P1 {
+                p1s.push(notice);
+            } else {
+                *counts
+                    .entry((std::cmp::Reverse(notice.severity), notice.region))
+                    .or_insert(0) += 1;
+            }
+        }
+        if counts.is_empty() {
+            return None;
+        }
+
+        Some(Notification::Digest(IncidentDigest {
+            window_start,
+            window_end: now,
+            p1s,
+            counts: counts
+                .into_iter()
+                .map(|((severity, region), n)| (severity.0, region, n))
+                .collect(),
+        }))
+    }
+
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+impl Default for NotificationDigest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // ─── Helpers ──────────────────────────────────────────────────────────────────
 
 fn build_incident_title(trigger: &HealthEvent, severity: Severity) -> String {
@@ -406,6 +880,69 @@ fn build_next_steps(incident: &Incident) -> Vec<String> {
     steps
 }
 
+fn contributing_factors(incident: &Incident) -> Vec<ContributingFactor> {
+    let mut factors = Vec::new();
+    let events: Vec<&TimelineEvent> = incident
+        .timeline
+        .iter()
+        .filter_map(|e| e.event.as_ref())
+        .collect();
+
+    if events
+        .iter()
+        .any(|e| matches!(e, TimelineEvent::SinglePointOfFailure { .. }))
+    {
+        factors.push(ContributingFactor::SinglePointOfFailure);
+    }
+    if events
+        .iter()
+        .any(|e| matches!(e, TimelineEvent::Failover { succeeded: false }))
+    {
+        factors.push(ContributingFactor::FailedFailover);
+    }
+    match incident.root_cause.as_ref().map(|rc| &rc.category) {
+        Some(RootCauseCategory::ProviderOutage) => factors.push(ContributingFactor::ProviderOutage),
+        Some(RootCauseCategory::ResourceExhaustion) => {
+            factors.push(ContributingFactor::ResourceExhaustion)
+        }
+        _ => {}
+    }
+
+    factors
+}
+
+fn build_action_items(incident: &Incident, factors: &[ContributingFactor]) -> Vec<String> {
+    let mut items: Vec<String> = factors
+        .iter()
+        .map(|factor| match factor {
+            ContributingFactor::SinglePointOfFailure => format!(
+                "Add cross-region standby for affected accounts ({})",
+                incident.affected_instances.join(", ")
+            ),
+            ContributingFactor::FailedFailover => {
+                "Verify standby readiness and rehearse failover".to_string()
+            }
+            ContributingFactor::ProviderOutage => format!(
+                "Spread capacity away from {} {}",
+                incident.provider, incident.region
+            ),
+            ContributingFactor::ResourceExhaustion => {
+                "Right-size affected instances and lower resource alert thresholds".to_string()
+            }
+        })
+        .collect();
+
+    let unexplained = incident
+        .root_cause
+        .as_ref()
+        .is_none_or(|rc| rc.category == RootCauseCategory::Unknown);
+    if unexplained {
+        items.push("Complete root cause analysis".to_string());
+    }
+
+    items
+}
+
 fn determine_root_cause_from_data(incident: &Incident, health_data: &[HealthCheck]) -> RootCause {
     // Analyse health check patterns to classify root cause
     let failing_checks: Vec<&HealthCheck> = health_data
@@ -561,6 +1098,7 @@ mod tests {
             actor: "guardian".to_string(),
             action: "failover triggered".to_string(),
             outcome: "Standby promoted to primary".to_string(),
+            event: Some(TimelineEvent::Failover { succeeded: true }),
         };
 
         mgr.add_timeline_entry(&id, entry).unwrap();
@@ -576,9 +1114,19 @@ mod tests {
             actor: "guardian".to_string(),
             action: "test".to_string(),
             outcome: "test".to_string(),
+            event: None,
         };
-        let result = mgr.add_timeline_entry("nonexistent-id", entry);
-        assert!(result.is_err());
+        let err = mgr.add_timeline_entry("nonexistent-id", entry).unwrap_err();
+        assert!(matches!(err, ClawError::NotFound(_)), "{err:?}");
+        assert!(!err.is_retryable());
+        assert!(matches!(
+            mgr.update_status("nonexistent-id", IncidentStatus::Resolved),
+            Err(ClawError::NotFound(_))
+        ));
+        assert!(matches!(
+            mgr.acknowledge("nonexistent-id", "ops"),
+            Err(ClawError::NotFound(_))
+        ));
     }
 
     #[test]
@@ -635,6 +1183,28 @@ mod tests {
         assert_eq!(inc.affected_instances.len(), 2);
     }
 
+    #[test]
+    fn test_duration_clamps_skewed_timestamps_to_zero() {
+        let mut mgr = IncidentManager::new();
+        let mut inc = mgr.create_incident(make_trigger(5, false)).clone();
+        let created = inc.created_at;
+
+        assert_eq!(
+            inc.duration_mins_at(created + chrono::Duration::minutes(42)),
+            42
+        );
+        // Measured before creation, e.g. a node clock running ahead.
+        assert_eq!(
+            inc.duration_mins_at(created - chrono::Duration::minutes(5)),
+            0
+        );
+
+        inc.resolved_at = Some(created + chrono::Duration::minutes(17));
+        assert_eq!(inc.duration_mins(), 17);
+        inc.resolved_at = Some(created - chrono::Duration::hours(2));
+        assert_eq!(inc.duration_mins(), 0);
+    }
+
     // ─── Root cause ─────────────────────────────────────────────────────────
 
     #[test]
@@ -675,6 +1245,8 @@ mod tests {
             actions_taken: vec![],
             created_at: Utc::now(),
             resolved_at: None,
+            recovered_since: None,
+            acknowledged_by: None,
         };
         let health_data = vec![HealthCheck {
             name: "tailscale".to_string(),
@@ -743,4 +1315,324 @@ mod tests {
         assert_eq!(Severity::P1.to_string(), "P1");
         assert_eq!(Severity::P4.to_string(), "P4");
     }
+
+    // ─── User impact resolution ─────────────────────────────────────────────
+
+    struct FakeResolver {
+        users_by_instance: std::collections::HashMap<String, u32>,
+    }
+
+    impl FakeResolver {
+        fn new(entries: &[(&str, u32)]) -> Self {
+            Self {
+                users_by_instance: entries.iter().map(|(id, n)| (id.to_string(), *n)).collect(),
+            }
+        }
+    }
+
+    impl UserImpactResolver for FakeResolver {
+        fn users_for_instances(&self, instance_ids: &[String]) -> u32 {
+            instance_ids
+                .iter()
+                .filter_map(|id| self.users_by_instance.get(id))
+                .sum()
+        }
+
+        fn users_for_region(&self, _region: &str) -> u32 {
+            self.users_by_instance.values().sum()
+        }
+    }
+
+    #[test]
+    fn test_create_incident_uses_resolved_users() {
+        let resolver = FakeResolver::new(&[("i-test", 80)]);
+        let mut mgr = IncidentManager::with_resolver(Box::new(resolver));
+        // Trigger under-reports impact; resolver knows i-test serves 80 users.
+        let inc = mgr.create_incident(make_trigger(2, false));
+        assert_eq!(inc.affected_users, 80);
+        assert_eq!(inc.severity, Severity::P1);
+    }
+
+    #[test]
+    fn test_create_incident_resolved_users_can_lower_severity() {
+        let resolver = FakeResolver::new(&[("i-test", 3)]);
+        let mut mgr = IncidentManager::with_resolver(Box::new(resolver));
+        let inc = mgr.create_incident(make_trigger(100, false));
+        assert_eq!(inc.affected_users, 3);
+        assert_eq!(inc.severity, Severity::P3);
+    }
+
+    #[test]
+    fn test_add_affected_instance_recomputes_severity() {
+        let resolver = FakeResolver::new(&[("i-test", 5), ("i-2", 10), ("i-3", 40)]);
+        let mut mgr = IncidentManager::with_resolver(Box::new(resolver));
+        let id = mgr.create_incident(make_trigger(0, false)).id.clone();
+        assert_eq!(mgr.get(&id).unwrap().severity, Severity::P3);
+
+        mgr.add_affected_instance(&id, "i-2".to_string()).unwrap();
+        assert_eq!(mgr.get(&id).unwrap().affected_users, 15);
+        assert_eq!(mgr.get(&id).unwrap().severity, Severity::P2);
+
+        mgr.add_affected_instance(&id, "i-3".to_string()).unwrap();
+        assert_eq!(mgr.get(&id).unwrap().affected_users, 55);
+        assert_eq!(mgr.get(&id).unwrap().severity, Severity::P1);
+        assert_eq!(mgr.region_impact("eu-hetzner-nbg1"), Some(55));
+    }
+
+    // ─── Auto-resolve ────────────────────────────────────────────────────────
+
+    fn healthy(ids: &[&str]) -> HashMap<String, u8> {
+        ids.iter().map(|id| (id.to_string(), 95)).collect()
+    }
+
+    #[test]
+    fn test_auto_resolve_recovered_p3() {
+        let mut mgr = IncidentManager::new().with_recovery_window(chrono::Duration::zero());
+        let id = mgr.create_incident(make_trigger(3, false)).id.clone();
+
+        // Still unhealthy: nothing happens.
+        let sick = HashMap::from([("i-test".to_string(), 30)]);
+        assert!(mgr.auto_resolve(&sick, 70).is_empty());
+
+        assert_eq!(
+            mgr.auto_resolve(&healthy(&["i-test"]), 70),
+            vec![id.clone()]
+        );
+        let inc = mgr.get(&id).unwrap();
+        assert_eq!(inc.status, IncidentStatus::Resolved);
+        assert!(inc.resolved_at.is_some());
+        assert_eq!(inc.timeline.last().unwrap().action, "auto_resolved");
+    }
+
+    #[test]
+    fn test_auto_resolve_p1_waits_for_ack() {
+        let mut mgr = IncidentManager::new().with_recovery_window(chrono::Duration::zero());
+        let id = mgr.create_incident(make_trigger(80, false)).id.clone();
+
+        mgr.auto_resolve(&healthy(&["i-test"]), 70);
+        assert_eq!(mgr.get(&id).unwrap().status, IncidentStatus::Mitigated);
+        // Repeated sweeps don't re-log.
+        assert!(mgr.auto_resolve(&healthy(&["i-test"]), 70).is_empty());
+
+        mgr.acknowledge(&id, "ops-alice").unwrap();
+        mgr.auto_resolve(&healthy(&["i-test"]), 70);
+        assert_eq!(mgr.get(&id).unwrap().status, IncidentStatus::Resolved);
+    }
+
+    #[test]
+    fn test_auto_resolve_requires_sustained_recovery() {
+        let mut mgr = IncidentManager::new();
+        let id = mgr.create_incident(make_trigger(3, false)).id.clone();
+
+        // Default window has not elapsed yet.
+        assert!(mgr.auto_resolve(&healthy(&["i-test"]), 70).is_empty());
+        assert_eq!(mgr.get(&id).unwrap().status, IncidentStatus::Open);
+        assert!(mgr.get(&id).unwrap().recovered_since.is_some());
+
+        // A relapse restarts the clock.
+        mgr.auto_resolve(&HashMap::new(), 70);
+        assert!(mgr.get(&id).unwrap().recovered_since.is_none());
+    }
+
+    // ─── Notification digest ────────────────────────────────────────────────
+
+    #[test]
+    fn test_digest_batches_p3s_into_one_summary() {
+        let mut mgr = IncidentManager::new();
+        let mut digest = NotificationDigest::new();
+        let t0 = Utc::now();
+
+        for secs in [0, 10, 30] {
+            let incident = mgr.create_incident(make_trigger(3, false)).clone();
+            assert!(
+                digest
+                    .push(&incident, t0 + chrono::Duration::seconds(secs))
+                    .is_none()
+            );
+        }
+        assert!(
+            digest
+                .flush_due(t0 + chrono::Duration::seconds(60))
+                .is_none()
+        );
+
+        let Some(Notification::Digest(batch)) = digest.flush_due(t0 + chrono::Duration::minutes(2))
+        else {
+            panic!("expected a digest once the window elapsed");
+        };
+        assert_eq!(batch.total(), 3);
+        assert!(batch.p1s.is_empty());
+        assert_eq!(
+            batch.counts,
+            vec![(Severity::P3, "eu-hetzner-nbg1".to_string(), 3)]
+        );
+        assert!(batch.summary().contains("3 P3 in eu-hetzner-nbg1"));
+        assert_eq!(digest.pending_count(), 0);
+    }
+
+    #[test]
+    fn test_digest_p1_fires_immediately() {
+        let mut mgr = IncidentManager::new();
+        let mut digest = NotificationDigest::new();
+        let now = Utc::now();
+
+        let p3 = mgr.create_incident(make_trigger(3, false)).clone();
+        assert!(digest.push(&p3, now).is_none());
+        let p1 = mgr.create_incident(make_trigger(100, false)).clone();
+        match digest.push(&p1, now) {
+            Some(Notification::Immediate(notice)) => assert_eq!(notice.incident_id, p1.id),
+            other => panic!("P1 should bypass batching, got {other:?}"),
+        }
+
+        // The P1 is still listed in the digest alongside the batched P3.
+        let Some(Notification::Digest(batch)) = digest.flush(now) else {
+            panic!("expected a digest");
+        };
+        assert_eq!(batch.p1s.len(), 1);
+        assert_eq!(batch.total(), 2);
+
+        // A window holding only P1s has nothing new to say.
+        assert!(digest.push(&p1, now).is_some());
+        assert!(digest.flush(now).is_none());
+    }
+
+    // ─── Incident merge ─────────────────────────────────────────────────────
+
+    #[test]
+    fn test_merge_combines_instances_and_recomputes_severity() {
+        let mut mgr = IncidentManager::new();
+        let mut first = make_trigger(8, false);
+        first.detected_at = Utc::now() - chrono::Duration::minutes(10);
+        let into = mgr.create_incident(first).id.clone();
+        let mut second = make_trigger(7, false);
+        second.instance_id = "i-other".to_string();
+        second.detected_at = Utc::now() - chrono::Duration::minutes(20);
+        let from = mgr.create_incident(second).id.clone();
+
+        let merged = mgr.merge(&into, &from).unwrap();
+        assert_eq!(merged.affected_instances, vec!["i-test", "i-other"]);
+        assert_eq!(merged.affected_users, 15);
+        assert_eq!(merged.severity, Severity::P2);
+        // The older `from` detection now leads the timeline.
+        assert!(merged.timeline[0].timestamp < merged.timeline[1].timestamp);
+        assert_eq!(merged.timeline.last().unwrap().action, "merged");
+
+        let source = mgr.get(&from).unwrap();
+        assert_eq!(source.status, IncidentStatus::Resolved);
+        assert_eq!(
+            source.timeline.last().unwrap().outcome,
+            format!("merged into {into}")
+        );
+        assert_eq!(mgr.open_incidents().len(), 1);
+    }
+
+    #[test]
+    fn test_merge_rejects_self_and_resolved() {
+        let mut mgr = IncidentManager::new();
+        let a = mgr.create_incident(make_trigger(5, false)).id.clone();
+        let b = mgr.create_incident(make_trigger(5, false)).id.clone();
+
+        assert!(
+            mgr.merge(&a, &a)
+                .unwrap_err()
+                .message()
+                .contains("into itself")
+        );
+
+        mgr.update_status(&b, IncidentStatus::Resolved).unwrap();
+        assert!(
+            mgr.merge(&a, &b)
+                .unwrap_err()
+                .message()
+                .contains("already resolved")
+        );
+        assert!(matches!(
+            mgr.merge(&a, "missing").unwrap_err(),
+            ClawError::NotFound(_)
+        ));
+    }
+
+    // ─── Post-mortem ────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_postmortem_spof_incident_suggests_cross_region_standby() {
+        let mut mgr = IncidentManager::new();
+        let id = mgr.create_incident(make_trigger(30, false)).id.clone();
+        mgr.add_timeline_entry(
+            &id,
+            TimelineEntry {
+                timestamp: Utc::now(),
+                actor: "guardian".to_string(),
+                action: "failover skipped".to_string(),
+                outcome: "No standby outside eu-hetzner-nbg1".to_string(),
+                event: Some(TimelineEvent::SinglePointOfFailure {
+                    account_id: "acc-test".to_string(),
+                    kind: SpofKind::NoStandby,
+                }),
+            },
+        )
+        .unwrap();
+        mgr.update_status(&id, IncidentStatus::Resolved).unwrap();
+        let inc = mgr.get(&id).unwrap().clone();
+
+        let pm = mgr.generate_postmortem(&inc).unwrap();
+        assert_eq!(pm.incident_id, id);
+        assert_eq!(pm.timeline.len(), 2);
+        assert_eq!(pm.blast_radius.affected_users, 30);
+        assert_eq!(pm.blast_radius.instances, vec!["i-test"]);
+        assert!(
+            pm.contributing_factors
+                .contains(&ContributingFactor::SinglePointOfFailure)
+        );
+        assert!(
+            pm.action_items
+                .iter()
+                .any(|a| a.contains("cross-region standby") && a.contains("i-test"))
+        );
+    }
+
+    #[test]
+    fn test_postmortem_rejects_open_incident() {
+        let mut mgr = IncidentManager::new();
+        let id = mgr.create_incident(make_trigger(5, false)).id.clone();
+        let inc = mgr.get(&id).unwrap().clone();
+
+        let err = mgr.generate_postmortem(&inc).unwrap_err();
+        assert!(matches!(err, ClawError::Permanent(_)));
+        assert!(err.message().contains("not resolved"));
+    }
+
+    #[test]
+    fn test_postmortem_factors_come_from_events_not_wording() {
+        let mut mgr = IncidentManager::new();
+        let id = mgr.create_incident(make_trigger(5, false)).id.clone();
+        let entry = |action: &str, outcome: &str, event| TimelineEntry {
+            timestamp: Utc::now(),
+            actor: "guardian".to_string(),
+            action: action.to_string(),
+            outcome: outcome.to_string(),
+            event,
+        };
+        // Prose alone is not evidence of a SPOF or a failed failover.
+        mgr.add_timeline_entry(
+            &id,
+            entry("failover", "failed over to same region standby", None),
+        )
+        .unwrap();
+        mgr.update_status(&id, IncidentStatus::Resolved).unwrap();
+        let pm = mgr.generate_postmortem(mgr.get(&id).unwrap()).unwrap();
+        assert!(pm.contributing_factors.is_empty());
+
+        let mut inc = mgr.get(&id).unwrap().clone();
+        inc.timeline.push(entry(
+            "promote standby",
+            "timed out",
+            Some(TimelineEvent::Failover { succeeded: false }),
+        ));
+        let pm = mgr.generate_postmortem(&inc).unwrap();
+        assert_eq!(
+            pm.contributing_factors,
+            vec![ContributingFactor::FailedFailover]
+        );
+    }
 }
@@ -468,7 +468,14 @@ impl GatewayClient {
         info!("node registered as {} ({})", hostname, node_id);
 
         // Main event loop
-        let mut heartbeat_interval = interval(Duration::from_secs(30));
+        let heartbeat_secs = self
+            .state
+            .read()
+            .await
+            .config
+            .heartbeat_interval_secs
+            .max(1);
+        let mut heartbeat_interval = interval(Duration::from_secs(heartbeat_secs));
         let node_id_clone = node_id.clone();
 
         loop {
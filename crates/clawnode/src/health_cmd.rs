@@ -5,7 +5,7 @@
 use crate::SharedState;
 use crate::commands::CommandError;
 use chrono::Utc;
-use claw_health::{HealthThresholds, compute_health_score, evaluate_alerts, recommend_action};
+use claw_health::{HealthThresholds, evaluate_alerts, recommend_action};
 use claw_proto::{HealthReport, InstanceRole, InstanceState, ServiceStatus};
 use serde_json::{Value, json};
 use sysinfo::System;
@@ -131,6 +131,7 @@ async fn gather_health_report(state: &SharedState) -> HealthReport {
     };
 
     HealthReport {
+        report_version: claw_proto::HEALTH_REPORT_VERSION,
         instance_id: s.config.hostname.clone(),
         account_id: s.config.account_id.clone(),
         provider,
@@ -154,6 +155,8 @@ async fn gather_health_report(state: &SharedState) -> HealthReport {
         uptime_secs: System::uptime(),
         bytes_sent_per_sec: bytes_sent as f64,
         bytes_recv_per_sec: bytes_recv as f64,
+        heartbeat_interval_secs: Some(s.config.heartbeat_interval_secs),
+        critical_services: s.config.labels.get("critical_services").cloned(),
         reported_at: Utc::now(),
     }
 }
@@ -164,7 +167,7 @@ pub async fn handle_health_check(state: &SharedState) -> Result<Value, CommandEr
     let mut report = gather_health_report(state).await;
     let thresholds = HealthThresholds::default();
 
-    let score = compute_health_score(&report);
+    let score = thresholds.score(&report);
     report.health_score = score;
 
     let alerts = evaluate_alerts(&report, &thresholds);
@@ -203,7 +206,7 @@ pub async fn handle_health_check(state: &SharedState) -> Result<Value, CommandEr
 
 pub async fn handle_health_score(state: &SharedState) -> Result<Value, CommandError> {
     let report = gather_health_report(state).await;
-    let score = compute_health_score(&report);
+    let score = HealthThresholds::default().score(&report);
 
     Ok(json!({
         "ok": true,
@@ -190,6 +190,20 @@ mod tests {
         assert!(!state.approved);
     }
 
+    #[test]
+    fn node_state_commands_use_published_prefixes() {
+        let dir = tempfile::tempdir().unwrap();
+        let state = NodeState::new(make_test_config(dir.path().to_path_buf()));
+        for cmd in &state.commands {
+            assert!(
+                claw_proto::NODE_COMMAND_PREFIXES
+                    .iter()
+                    .any(|p| cmd.starts_with(p)),
+                "command {cmd} is outside NODE_COMMAND_PREFIXES"
+            );
+        }
+    }
+
     #[test]
     fn node_state_capabilities_include_all_vps_domains() {
         let dir = tempfile::tempdir().unwrap();
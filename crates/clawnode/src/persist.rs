@@ -59,6 +59,12 @@ impl VpsInstanceStore {
     }
 }
 
+impl Drop for VpsInstanceStore {
+    fn drop(&mut self) {
+        self.snapshot();
+    }
+}
+
 // ─── Event Log ────────────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -100,3 +106,9 @@ impl EventStore {
         let _ = self.store.save(&self.records);
     }
 }
+
+impl Drop for EventStore {
+    fn drop(&mut self) {
+        self.snapshot();
+    }
+}
@@ -12,6 +12,9 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::config::NodeConfig;
 
 // ─── Types ─────────────────────────────────────────────────────────────────
 
@@ -258,6 +261,168 @@ pub fn validate_batch(
     }
 }
 
+// ─── Config Diff ──────────────────────────────────────────────────────────────
+
+/// One field that differs between the current and proposed config.
+///
+/// `field` is a dotted path (`labels.env`); `None` means the field is absent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: Option<Value>,
+    pub new: Option<Value>,
+}
+
+/// Field-level diff between two configs.
+pub struct ConfigDiff;
+
+impl ConfigDiff {
+    /// Diff two node configs. The gateway token is reported but its values redacted.
+    pub fn compute(current: &NodeConfig, proposed: &NodeConfig) -> Vec<FieldChange> {
+        let to_value = |cfg: &NodeConfig| serde_json::to_value(cfg).unwrap_or(Value::Null);
+        let mut changes = Self::compute_values(&to_value(current), &to_value(proposed));
+        for change in changes.iter_mut().filter(|c| c.field == "token") {
+            let redacted = Value::String("<redacted>".to_string());
+            change.old = change.old.as_ref().map(|_| redacted.clone());
+            change.new = change.new.as_ref().map(|_| redacted.clone());
+        }
+        changes
+    }
+
+    /// Diff two JSON config payloads, recursing into objects. Sorted by field.
+    pub fn compute_values(current: &Value, proposed: &Value) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        diff_into("", Some(current), Some(proposed), &mut changes);
+        changes.sort_by(|a, b| a.field.cmp(&b.field));
+        changes
+    }
+}
+
+fn diff_into(path: &str, old: Option<&Value>, new: Option<&Value>, out: &mut Vec<FieldChange>) {
+    match (old, new) {
+        (Some(Value::Object(old)), Some(Value::Object(new))) => {
+            let mut keys: Vec<&String> = old.keys().chain(new.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let field = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                diff_into(&field, old.get(key), new.get(key), out);
+            }
+        }
+        (old, new) if old != new => out.push(FieldChange {
+            field: path.to_string(),
+            old: old.cloned(),
+            new: new.cloned(),
+        }),
+        _ => {}
+    }
+}
+
+/// What a rolling push would change on one instance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstanceDiff {
+    pub instance_id: String,
+    pub changes: Vec<FieldChange>,
+}
+
+/// Per-instance diff of a rolling push, computed without applying anything.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PushPreview {
+    pub config_name: String,
+    pub instance_diffs: Vec<InstanceDiff>,
+    /// Top-level fields of the payload that no instance would change.
+    pub unchanged_fields: Vec<String>,
+    /// Instances with no known current config (everything would be new).
+    pub missing_current: Vec<String>,
+}
+
+impl PushPreview {
+    /// Number of instances each changed field would be touched on.
+    pub fn field_counts(&self) -> BTreeMap<&str, usize> {
+        let mut counts = BTreeMap::new();
+        for diff in &self.instance_diffs {
+            for change in &diff.changes {
+                *counts.entry(change.field.as_str()).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+
+    /// Operator-facing delta, e.g. "model changes on 847 instances; heartbeat_interval unchanged."
+    pub fn summary(&self) -> String {
+        let mut parts: Vec<String> = self
+            .field_counts()
+            .into_iter()
+            .map(|(field, n)| format!("{field} changes on {n} instances"))
+            .collect();
+        parts.extend(
+            self.unchanged_fields
+                .iter()
+                .map(|f| format!("{f} unchanged")),
+        );
+        if !self.missing_current.is_empty() {
+            parts.push(format!(
+                "{} instances have no current config",
+                self.missing_current.len()
+            ));
+        }
+        if parts.is_empty() {
+            return format!("Preview {}: no changes.", self.config_name);
+        }
+        format!("Preview {}: {}.", self.config_name, parts.join("; "))
+    }
+}
+
+impl RollingPush {
+    /// Diff the payload against each instance's current config without pushing.
+    ///
+    /// `current_configs` is keyed by instance id.
+    pub fn preview(&self, current_configs: &HashMap<String, Value>) -> PushPreview {
+        let mut instance_diffs = Vec::new();
+        let mut missing_current = Vec::new();
+        for instance in &self.instances {
+            match current_configs.get(&instance.instance_id) {
+                Some(current) => {
+                    let changes = ConfigDiff::compute_values(current, &self.config_payload);
+                    if !changes.is_empty() {
+                        instance_diffs.push(InstanceDiff {
+                            instance_id: instance.instance_id.clone(),
+                            changes,
+                        });
+                    }
+                }
+                None => missing_current.push(instance.instance_id.clone()),
+            }
+        }
+
+        let unchanged_fields = match &self.config_payload {
+            Value::Object(fields) => fields
+                .keys()
+                .filter(|key| {
+                    !instance_diffs.iter().any(|d| {
+                        d.changes
+                            .iter()
+                            .any(|c| c.field == **key || c.field.starts_with(&format!("{key}.")))
+                    })
+                })
+                .cloned()
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        PushPreview {
+            config_name: self.config_name.clone(),
+            instance_diffs,
+            unchanged_fields,
+            missing_current,
+        }
+    }
+}
+
 // ─── Tests ─────────────────────────────────────────────────────────────────────
 
 #[cfg(test)]
@@ -494,4 +659,66 @@ mod tests {
         let rp = RollingPush::new("cfg", json!({}), make_instances(5), 0);
         assert_eq!(rp.batch_size, 1);
     }
+
+    // ─── ConfigDiff / preview ────────────────────────────────────────────
+
+    #[test]
+    fn test_config_diff_identical_is_empty() {
+        let cfg = NodeConfig::default();
+        assert!(ConfigDiff::compute(&cfg, &cfg.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_config_diff_surfaces_only_changed_field() {
+        let current = json!({ "model": "old-model", "heartbeat_interval": 30 });
+        let proposed = json!({ "model": "kimi-k2.5", "heartbeat_interval": 30 });
+        let changes = ConfigDiff::compute_values(&current, &proposed);
+        assert_eq!(
+            changes,
+            vec![FieldChange {
+                field: "model".to_string(),
+                old: Some(json!("old-model")),
+                new: Some(json!("kimi-k2.5")),
+            }]
+        );
+
+        let mut cfg = NodeConfig::default();
+        let mut proposed = cfg.clone();
+        proposed.token = Some("new-secret".to_string());
+        proposed
+            .labels
+            .insert("env".to_string(), "prod".to_string());
+        cfg.token = Some("old-secret".to_string());
+        let changes = ConfigDiff::compute(&cfg, &proposed);
+        let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(fields, vec!["labels.env", "token"]);
+        assert_eq!(changes[1].new, Some(json!("<redacted>")));
+    }
+
+    #[test]
+    fn test_preview_reports_per_instance_delta_without_pushing() {
+        let proposed = json!({ "model": "kimi-k2.5", "heartbeat_interval": 30 });
+        let rp = RollingPush::new("cfg-v48", proposed.clone(), make_instances(4), 2);
+        let mut current: HashMap<String, Value> = HashMap::new();
+        current.insert(
+            "i-0000".to_string(),
+            json!({ "model": "old-model", "heartbeat_interval": 30 }),
+        );
+        current.insert(
+            "i-0001".to_string(),
+            json!({ "model": "old-model", "heartbeat_interval": 30 }),
+        );
+        current.insert("i-0002".to_string(), proposed);
+
+        let preview = rp.preview(&current);
+        assert_eq!(preview.instance_diffs.len(), 2);
+        assert_eq!(preview.missing_current, vec!["i-0003".to_string()]);
+        assert_eq!(
+            preview.unchanged_fields,
+            vec!["heartbeat_interval".to_string()]
+        );
+        let summary = preview.summary();
+        assert!(summary.contains("model changes on 2 instances"));
+        assert!(summary.contains("heartbeat_interval unchanged"));
+    }
 }
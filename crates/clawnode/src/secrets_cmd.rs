@@ -5,52 +5,38 @@ use crate::commands::{CommandError, CommandRequest};
 use claw_secrets::SecretEntry;
 use serde_json::json;
 
+const BASE64_TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
 /// Simple base64 encode (no external dep, replace with AES-GCM when key manager is available).
 fn encode_value(value: &str) -> String {
-    use std::fmt::Write as FmtWrite;
-    let bytes = value.as_bytes();
-    let table = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
-    let mut out = String::new();
-    let mut i = 0;
-    while i + 2 < bytes.len() {
-        let b0 = bytes[i] as usize;
-        let b1 = bytes[i + 1] as usize;
-        let b2 = bytes[i + 2] as usize;
-        let _ = write!(
-            out,
-            "{}{}{}{}",
-            table[b0 >> 2] as char,
-            table[((b0 & 3) << 4) | (b1 >> 4)] as char,
-            table[((b1 & 0xf) << 2) | (b2 >> 6)] as char,
-            table[b2 & 0x3f] as char
-        );
-        i += 3;
-    }
-    if i < bytes.len() {
-        let b0 = bytes[i] as usize;
-        if i + 1 < bytes.len() {
-            let b1 = bytes[i + 1] as usize;
-            let _ = write!(
-                out,
-                "{}{}{}=",
-                table[b0 >> 2] as char,
-                table[((b0 & 3) << 4) | (b1 >> 4)] as char,
-                table[(b1 & 0xf) << 2] as char
-            );
+    encode_bytes(value.as_bytes())
+}
+
+/// Standard padded base64 of arbitrary bytes, three input bytes per chunk.
+fn encode_bytes(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0] as usize;
+        let b1 = chunk.get(1).copied().unwrap_or(0) as usize;
+        let b2 = chunk.get(2).copied().unwrap_or(0) as usize;
+        out.push(BASE64_TABLE[b0 >> 2] as char);
+        out.push(BASE64_TABLE[((b0 & 3) << 4) | (b1 >> 4)] as char);
+        out.push(if chunk.len() > 1 {
+            BASE64_TABLE[((b1 & 0xf) << 2) | (b2 >> 6)] as char
         } else {
-            let _ = write!(
-                out,
-                "{}{}==",
-                table[b0 >> 2] as char,
-                table[(b0 & 3) << 4] as char
-            );
-        }
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            BASE64_TABLE[b2 & 0x3f] as char
+        } else {
+            '='
+        });
     }
     out
 }
 
 fn decode_value(encoded: &str) -> String {
-    let table = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let table = BASE64_TABLE;
     let nibbles: Vec<u8> = encoded
         .bytes()
         .filter(|&b| b != b'=')
@@ -185,3 +171,42 @@ pub async fn handle_secret_command(
         other => Err(format!("unknown secret command: {other}").into()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+
+    #[test]
+    fn test_encode_bytes_matches_base64_crate() {
+        // xorshift64: deterministic byte strings covering every length mod 3
+        // and the full 0x00-0xff range.
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        for _ in 0..100 {
+            let len = (next() % 64) as usize;
+            let bytes: Vec<u8> = (0..len).map(|_| next() as u8).collect();
+            assert_eq!(encode_bytes(&bytes), STANDARD.encode(&bytes), "{bytes:?}");
+        }
+        assert_eq!(
+            encode_bytes(&[0xff, 0xfe, 0x80]),
+            STANDARD.encode([0xff, 0xfe, 0x80])
+        );
+        assert_eq!(encode_bytes(&[]), "");
+    }
+
+    #[test]
+    fn test_encode_value_round_trips_multibyte_utf8() {
+        for value in ["Zürich", "Gävle eu-hetzner-fsn1", "ä", "üü", "naïve ☃ 雪"] {
+            let encoded = encode_value(value);
+            assert_eq!(encoded, STANDARD.encode(value));
+            assert_eq!(decode_value(&encoded), value);
+        }
+    }
+}
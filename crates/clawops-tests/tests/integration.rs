@@ -8,15 +8,17 @@
 //! - Provider scoring / selection logic
 
 use chrono::Utc;
+use claw_briefer::{CostSummary, DailyBriefingData};
 use claw_health::{
     AutoHealDecision, AutoHealStep, FailoverState, FailoverStateMachine, FailoverTransition,
-    HealthThresholds, MAX_HEAL_ATTEMPTS, RecommendedAction, compute_health_score, evaluate_alerts,
-    recommend_action, sweep_fleet, verify_standby_precondition,
+    HealthCheckResult, HealthThresholds, MAX_HEAL_ATTEMPTS, RecommendedAction,
+    compute_health_score, evaluate_alerts, recommend_action, sweep_fleet,
+    verify_standby_precondition,
 };
 use claw_metrics::{CostTracker, FleetMetrics, InstanceCost, InstanceSnapshot, TimeSeriesBuffer};
 use claw_proto::{
-    CheckStatus, HealthCheck, HealthCheckResponse, HealthReport, InstancePairStatus, InstanceRole,
-    InstanceState, InstanceTier, ServiceStatus, VpsMetricsResponse, VpsProvider,
+    CheckStatus, FleetStatus, HealthCheck, HealthCheckResponse, HealthReport, InstancePairStatus,
+    InstanceRole, InstanceState, InstanceTier, ServiceStatus, VpsMetricsResponse, VpsProvider,
 };
 use claw_provision::{LatencyClass, score_provider};
 
@@ -24,6 +26,7 @@ use claw_provision::{LatencyClass, score_provider};
 
 fn healthy_report(id: &str) -> HealthReport {
     HealthReport {
+        report_version: claw_proto::HEALTH_REPORT_VERSION,
         instance_id: id.to_string(),
         account_id: "acc-test".to_string(),
         provider: VpsProvider::Hetzner,
@@ -47,6 +50,8 @@ fn healthy_report(id: &str) -> HealthReport {
         uptime_secs: 86400,
         bytes_sent_per_sec: 1024.0,
         bytes_recv_per_sec: 2048.0,
+        heartbeat_interval_secs: Some(30),
+        critical_services: None,
         reported_at: Utc::now(),
     }
 }
@@ -54,13 +59,19 @@ fn healthy_report(id: &str) -> HealthReport {
 fn snapshot(id: &str, provider: &str, health: f64, cost: f64) -> InstanceSnapshot {
     InstanceSnapshot {
         instance_id: id.to_string(),
+        account_id: format!("acct-{id}"),
         provider: provider.to_string(),
+        role: InstanceRole::Primary,
+        state: InstanceState::Active,
         cpu_pct: 25.0,
         mem_pct: 45.0,
         disk_pct: 30.0,
         health_score: health,
         monthly_cost_usd: cost,
         recorded_at: Utc::now(),
+        tier: None,
+        provider_instance_id: None,
+        region: None,
     }
 }
 
@@ -498,3 +509,60 @@ fn test_fsm_recovery_and_reset() {
     let t = fsm.transition(95, true);
     assert_eq!(t, FailoverTransition::NoAction);
 }
+
+// ─── Test 17: Health sweep alerts reach the daily briefing ────────────────────
+
+#[test]
+fn test_sweep_top_alerts_reach_daily_briefing() {
+    let thresholds = HealthThresholds::default();
+    let mut reports = vec![healthy_report("i-h1"), healthy_report("i-h2")];
+    for id in ["i-down-1", "i-down-2"] {
+        let mut r = healthy_report(id);
+        r.openclaw_status = ServiceStatus::Down;
+        reports.push(r);
+    }
+
+    let sweep = sweep_fleet(&reports, &thresholds);
+    let detailed: Vec<HealthCheckResult> = reports
+        .iter()
+        .map(|r| HealthCheckResult {
+            instance_id: r.instance_id.clone(),
+            provider: r.provider,
+            region: r.region.clone(),
+            health_score: compute_health_score(r),
+            status: r.state,
+            alerts: evaluate_alerts(r, &thresholds),
+            recommended_action: RecommendedAction::Monitor,
+            checked_at: Utc::now(),
+        })
+        .collect();
+
+    let data = DailyBriefingData {
+        fleet: FleetStatus {
+            total_instances: 4,
+            active_pairs: 2,
+            degraded_instances: sweep.degraded,
+            failed_instances: sweep.critical,
+            bootstrapping_instances: 0,
+            generated_at: Utc::now(),
+        },
+        costs: CostSummary {
+            period_label: "this week".to_string(),
+            actual_usd: 48.0,
+            projected_usd: 48.0,
+            idle_accounts: 0,
+            provider_breakdown: vec![],
+        },
+        incidents: vec![],
+        auto_heals: 0,
+        failovers: 0,
+        sla_breaches: 0,
+        recommendations: vec![],
+        top_alerts: sweep.top_alerts(&detailed, 3),
+    };
+
+    assert_eq!(data.top_alerts.len(), 1);
+    let text = data.narrative();
+    assert!(text.contains("Top alert: Critical: OpenClaw down on 2 of 4 instances"));
+    assert!(!text.contains("nominal"));
+}
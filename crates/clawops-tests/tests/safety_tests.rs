@@ -425,6 +425,7 @@ fn test_heal_recovery_before_max_retries_resets_cleanly() {
     );
 
     fsm.transition(30, true); // attempt 1 — still sick
+    fsm.transition(90, true); // first good reading — not yet confirmed
     let t = fsm.transition(90, true); // recovered before attempt 2
 
     assert!(